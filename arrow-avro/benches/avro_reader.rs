@@ -22,15 +22,21 @@
 //! 1. Array creation performance
 //! 2. String value access operations
 //! 3. Avro file reading with each array type
+//!
+//! It also compares reading all columns of a wide Avro file with reading a projection
 
 use std::fs::File;
-use std::io::{BufReader, Read, Write};
+use std::io::{BufReader, Cursor, Read, Write};
 use std::sync::Arc;
 use std::time::Duration;
 
 use arrow::array::RecordBatch;
-use arrow::datatypes::{DataType, Field, Schema};
-use arrow_array::{ArrayRef, Int32Array, StringArray, StringViewArray};
+use arrow::datatypes::{DataType, Field, Fields, Int32Type, Schema};
+use arrow_array::{
+    Array, ArrayRef, Int32Array, Int64Array, ListArray, StringArray, StringViewArray, StructArray,
+};
+use arrow_avro::reader::ReaderBuilder;
+use arrow_avro::writer::WriterBuilder;
 use arrow_schema::ArrowError;
 use criterion::*;
 use tempfile::NamedTempFile;
@@ -259,10 +265,88 @@ fn bench_avro_reader(c: &mut Criterion) {
     group.finish();
 }
 
+/// Writes an Avro file with `row_count` rows of 8 groups of integer, string, list and
+/// struct columns
+fn create_wide_avro_file(row_count: usize) -> Vec<u8> {
+    let mut fields = vec![];
+    let mut columns = vec![];
+    for group in 0..8 {
+        let struct_fields = Fields::from(vec![
+            Field::new("x", DataType::Int64, false),
+            Field::new("y", DataType::Utf8, false),
+        ]);
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(
+            (0..row_count).map(|i| Some((0..(i % 10) as i32).map(Some))),
+        );
+        let strings = create_test_data(row_count, 20);
+        fields.extend([
+            Field::new(format!("int_{group}"), DataType::Int64, false),
+            Field::new(format!("string_{group}"), DataType::Utf8, false),
+            Field::new(format!("list_{group}"), list.data_type().clone(), true),
+            Field::new(
+                format!("struct_{group}"),
+                DataType::Struct(struct_fields.clone()),
+                false,
+            ),
+        ]);
+        columns.extend([
+            Arc::new(Int64Array::from_iter_values(0..row_count as i64)) as ArrayRef,
+            Arc::new(StringArray::from_iter_values(&strings)) as ArrayRef,
+            Arc::new(list) as ArrayRef,
+            Arc::new(StructArray::new(
+                struct_fields,
+                vec![
+                    Arc::new(Int64Array::from_iter_values(0..row_count as i64)) as ArrayRef,
+                    Arc::new(StringArray::from_iter_values(&strings)) as ArrayRef,
+                ],
+                None,
+            )) as ArrayRef,
+        ]);
+    }
+    let schema = Schema::new(fields);
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), columns).unwrap();
+    let mut writer = WriterBuilder::new(schema).build(Vec::new()).unwrap();
+    writer.write(&batch).unwrap();
+    writer.into_inner().unwrap()
+}
+
+fn read_wide_avro_file(data: &[u8], projection: Option<Vec<usize>>) -> Vec<RecordBatch> {
+    let mut builder = ReaderBuilder::new().with_batch_size(8192);
+    if let Some(projection) = projection {
+        builder = builder.with_projection(projection);
+    }
+    let reader = builder.build(Cursor::new(data)).unwrap();
+    reader.collect::<Result<_, _>>().unwrap()
+}
+
+fn bench_avro_projection(c: &mut Criterion) {
+    let mut group = c.benchmark_group("avro_projection");
+    group.sample_size(20);
+    group.measurement_time(Duration::from_secs(5));
+
+    let data = create_wide_avro_file(10000);
+
+    group.bench_function("all_columns", |b| {
+        b.iter(|| std::hint::black_box(read_wide_avro_file(&data, None)))
+    });
+
+    group.bench_function("one_int_column", |b| {
+        b.iter(|| std::hint::black_box(read_wide_avro_file(&data, Some(vec![0]))))
+    });
+
+    group.bench_function("one_of_each_column", |b| {
+        let projection = vec![0, 5, 10, 15];
+        b.iter(|| std::hint::black_box(read_wide_avro_file(&data, Some(projection.clone()))))
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_array_creation,
     bench_string_operations,
-    bench_avro_reader
+    bench_avro_reader,
+    bench_avro_projection
 );
 criterion_main!(benches);
@@ -15,11 +15,15 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::schema::{Attributes, ComplexType, PrimitiveType, Record, Schema, TypeName};
+use crate::schema::{
+    Attributes, ComplexType, PrimitiveType, Record, Schema, Type, TypeName,
+    ENUM_SYMBOLS_METADATA_KEY,
+};
 use arrow_schema::{
     ArrowError, DataType, Field, FieldRef, Fields, IntervalUnit, SchemaBuilder, SchemaRef,
-    TimeUnit, DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE,
+    TimeUnit, UnionFields, UnionMode, DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE,
 };
+use serde_json::Value;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -49,8 +53,14 @@ fn with_extension_type(codec: &Codec, field: Field) -> Field {
 #[derive(Debug, Clone)]
 pub struct AvroDataType {
     nullability: Option<Nullability>,
+    /// Whether the arrow field is nullable
+    ///
+    /// This only differs from `nullability.is_some()` when the writer and reader
+    /// schemas disagree on the nullability of a resolved type
+    nullable: bool,
     metadata: HashMap<String, String>,
     codec: Codec,
+    resolution: Option<ResolutionInfo>,
 }
 
 impl AvroDataType {
@@ -63,15 +73,16 @@ impl AvroDataType {
         AvroDataType {
             codec,
             metadata,
+            nullable: nullability.is_some(),
             nullability,
+            resolution: None,
         }
     }
 
     /// Returns an arrow [`Field`] with the given name
     pub fn field_with_name(&self, name: &str) -> Field {
-        let nullable = self.nullability.is_some();
         let data_type = self.codec.data_type();
-        let field = Field::new(name, data_type, nullable).with_metadata(self.metadata.clone());
+        let field = Field::new(name, data_type, self.nullable).with_metadata(self.metadata.clone());
         #[cfg(feature = "canonical_extension_types")]
         return with_extension_type(&self.codec, field);
         #[cfg(not(feature = "canonical_extension_types"))]
@@ -96,6 +107,32 @@ impl AvroDataType {
     pub fn nullability(&self) -> Option<Nullability> {
         self.nullability
     }
+
+    /// Returns true if the arrow representation of this data type is nullable
+    pub fn is_nullable(&self) -> bool {
+        self.nullable
+    }
+
+    /// Returns how data encoded with the writer schema is resolved to this data type
+    pub(crate) fn resolution(&self) -> Option<&ResolutionInfo> {
+        self.resolution.as_ref()
+    }
+
+    /// Returns the nullable [`Codec::Recursive`] data type of the named type `(namespace, name)`
+    fn recursive((namespace, name): (&str, &str)) -> Self {
+        let mut data_type = Self::new(
+            Codec::Recursive(format!("{namespace}.{name}").into()),
+            Default::default(),
+            None,
+        );
+        data_type.nullable = true;
+        data_type
+    }
+
+    fn set_nullability(&mut self, nullability: Option<Nullability>) {
+        self.nullable = nullability.is_some();
+        self.nullability = nullability;
+    }
 }
 
 /// A named [`AvroDataType`]
@@ -161,6 +198,37 @@ impl<'a> TryFrom<&Schema<'a>> for AvroField {
     }
 }
 
+impl AvroField {
+    /// Creates an [`AvroField`] that decodes data encoded with the `writer` schema into the
+    /// arrow representation of the `reader` schema, performing Avro schema resolution
+    ///
+    /// If `projection` is provided, only the fields of `reader` at the given indices are
+    /// resolved, with all other writer fields skipped
+    ///
+    /// See <https://avro.apache.org/docs/1.11.1/specification/#schema-resolution>
+    pub(crate) fn resolve<'a>(
+        writer: &'a Schema<'a>,
+        reader: &'a Schema<'a>,
+        use_utf8view: bool,
+        projection: Option<&[usize]>,
+    ) -> Result<Self, ArrowError> {
+        match (writer, reader) {
+            (Schema::Complex(ComplexType::Record(_)), Schema::Complex(ComplexType::Record(r))) => {
+                let mut resolver = SchemaResolver::try_new(writer, reader, use_utf8view)?;
+                resolver.projection = projection.map(|p| p.to_vec());
+                let data_type = resolver.resolve(writer, reader, None, None)?;
+                Ok(AvroField {
+                    data_type,
+                    name: r.name.to_string(),
+                })
+            }
+            _ => Err(ArrowError::ParseError(format!(
+                "Expected record got writer {writer:?} and reader {reader:?}"
+            ))),
+        }
+    }
+}
+
 /// An Avro encoding
 ///
 /// <https://avro.apache.org/docs/1.11.1/specification/#encodings>
@@ -227,8 +295,27 @@ pub enum Codec {
     Map(Arc<AvroDataType>),
     /// Represents Avro duration logical type, maps to Arrow's Interval(IntervalUnit::MonthDayNano) data type
     Interval,
+    /// Represents an Avro union other than a two-variant union with `null`, maps to Arrow's
+    /// Union data type in [`UnionMode::Dense`]
+    ///
+    /// The type id of each variant is its position in the Avro union. Variants are named
+    /// after the name of named types, and the type name of all other types, e.g. `"long"`.
+    Union(Arc<[AvroField]>),
+    /// Represents a reference to a recursive named type nested more than
+    /// [`MAX_RECURSION_DEPTH`] times within itself, maps to Arrow's Null data type
+    ///
+    /// The enclosed value is the name of the recursive type. Only null values, or empty arrays
+    /// and maps, can be decoded at this depth, decoding any other value returns an error.
+    Recursive(Arc<str>),
 }
 
+/// The maximum number of times a recursive named type is expanded within itself
+///
+/// Arrow has no recursive data types, instead a recursive Avro type, such as a linked list,
+/// is expanded into nested Arrow types up to this depth. A reference to the type nested
+/// any deeper is represented by [`Codec::Recursive`].
+pub const MAX_RECURSION_DEPTH: usize = 8;
+
 impl Codec {
     fn data_type(&self) -> DataType {
         match self {
@@ -278,7 +365,7 @@ impl Codec {
             Self::Struct(f) => DataType::Struct(f.iter().map(|x| x.field()).collect()),
             Self::Map(value_type) => {
                 let val_dt = value_type.codec.data_type();
-                let val_field = Field::new("value", val_dt, value_type.nullability.is_some())
+                let val_field = Field::new("value", val_dt, value_type.nullable)
                     .with_metadata(value_type.metadata.clone());
                 DataType::Map(
                     Arc::new(Field::new(
@@ -292,6 +379,14 @@ impl Codec {
                     false,
                 )
             }
+            Self::Union(variants) => {
+                let fields = variants.iter().map(|v| v.field());
+                DataType::Union(
+                    UnionFields::new(0..variants.len() as i8, fields),
+                    UnionMode::Dense,
+                )
+            }
+            Self::Recursive(_) => DataType::Null,
         }
     }
 }
@@ -373,17 +468,20 @@ impl Codec {
 #[derive(Debug, Default)]
 struct Resolver<'a> {
     map: HashMap<(&'a str, &'a str), AvroDataType>,
+    /// The named types whose definition is currently being parsed, with their schema,
+    /// enclosing namespace and the number of nested expansions
+    ///
+    /// References to these types are recursive, see [`MAX_RECURSION_DEPTH`]
+    expanding: HashMap<(&'a str, &'a str), (&'a Schema<'a>, Option<&'a str>, usize)>,
 }
 
 impl<'a> Resolver<'a> {
     fn register(&mut self, name: &'a str, namespace: Option<&'a str>, schema: AvroDataType) {
-        self.map.insert((name, namespace.unwrap_or("")), schema);
+        self.map.insert(full_name(name, namespace), schema);
     }
 
     fn resolve(&self, name: &str, namespace: Option<&'a str>) -> Result<AvroDataType, ArrowError> {
-        let (namespace, name) = name
-            .rsplit_once('.')
-            .unwrap_or_else(|| (namespace.unwrap_or(""), name));
+        let (namespace, name) = full_name(name, namespace);
 
         self.map
             .get(&(namespace, name))
@@ -392,6 +490,72 @@ impl<'a> Resolver<'a> {
     }
 }
 
+/// Returns the [`AvroDataType`] of a reference to the named type `name`
+///
+/// References to a named type whose definition is still being parsed re-parse the
+/// definition, expanding the recursive type up to [`MAX_RECURSION_DEPTH`] times
+fn resolve_reference<'a>(
+    name: &'a str,
+    namespace: Option<&'a str>,
+    resolver: &mut Resolver<'a>,
+    use_utf8view: bool,
+) -> Result<AvroDataType, ArrowError> {
+    let key = full_name(name, namespace);
+    match resolver.expanding.get(&key) {
+        Some((_, _, depth)) if *depth >= MAX_RECURSION_DEPTH => Ok(AvroDataType::recursive(key)),
+        Some((schema, namespace, _)) => {
+            let (schema, namespace) = (*schema, *namespace);
+            make_data_type(schema, namespace, resolver, use_utf8view)
+        }
+        None => resolver.resolve(name, namespace),
+    }
+}
+
+/// Returns the name of the arrow field of a variant of an Avro union
+///
+/// This is the name of named types, and the type name of all other types
+fn union_variant_name(schema: &Schema<'_>) -> Result<String, ArrowError> {
+    Ok(match schema {
+        Schema::TypeName(TypeName::Primitive(p))
+        | Schema::Type(Type {
+            r#type: TypeName::Primitive(p),
+            ..
+        }) => match p {
+            PrimitiveType::Null => "null",
+            PrimitiveType::Boolean => "boolean",
+            PrimitiveType::Int => "int",
+            PrimitiveType::Long => "long",
+            PrimitiveType::Float => "float",
+            PrimitiveType::Double => "double",
+            PrimitiveType::Bytes => "bytes",
+            PrimitiveType::String => "string",
+        }
+        .to_string(),
+        Schema::TypeName(TypeName::Ref(name))
+        | Schema::Type(Type {
+            r#type: TypeName::Ref(name),
+            ..
+        }) => name.to_string(),
+        Schema::Complex(ComplexType::Record(r)) => r.name.to_string(),
+        Schema::Complex(ComplexType::Enum(e)) => e.name.to_string(),
+        Schema::Complex(ComplexType::Fixed(f)) => f.name.to_string(),
+        Schema::Complex(ComplexType::Array(_)) => "array".to_string(),
+        Schema::Complex(ComplexType::Map(_)) => "map".to_string(),
+        Schema::Union(_) => {
+            return Err(ArrowError::ParseError(
+                "Unions may not immediately contain other unions".to_string(),
+            ))
+        }
+    })
+}
+
+/// Splits a possibly qualified `name` into its `(namespace, name)` parts, falling back
+/// to the enclosing `namespace` if `name` is unqualified
+fn full_name<'n>(name: &'n str, namespace: Option<&'n str>) -> (&'n str, &'n str) {
+    name.rsplit_once('.')
+        .unwrap_or_else(|| (namespace.unwrap_or(""), name))
+}
+
 /// Parses a [`AvroDataType`] from the provided [`Schema`] and the given `name` and `namespace`
 ///
 /// `name`: is name used to refer to `schema` in its parent
@@ -405,7 +569,7 @@ impl<'a> Resolver<'a> {
 ///
 /// See [`Resolver`] for more information
 fn make_data_type<'a>(
-    schema: &Schema<'a>,
+    schema: &'a Schema<'a>,
     namespace: Option<&'a str>,
     resolver: &mut Resolver<'a>,
     use_utf8view: bool,
@@ -414,13 +578,11 @@ fn make_data_type<'a>(
         Schema::TypeName(TypeName::Primitive(p)) => {
             let codec: Codec = (*p).into();
             let codec = codec.with_utf8view(use_utf8view);
-            Ok(AvroDataType {
-                nullability: None,
-                metadata: Default::default(),
-                codec,
-            })
+            Ok(AvroDataType::new(codec, Default::default(), None))
+        }
+        Schema::TypeName(TypeName::Ref(name)) => {
+            resolve_reference(name, namespace, resolver, use_utf8view)
         }
-        Schema::TypeName(TypeName::Ref(name)) => resolver.resolve(name, namespace),
         Schema::Union(f) => {
             // Special case the common case of nullable primitives
             let null = f
@@ -429,21 +591,48 @@ fn make_data_type<'a>(
             match (f.len() == 2, null) {
                 (true, Some(0)) => {
                     let mut field = make_data_type(&f[1], namespace, resolver, use_utf8view)?;
-                    field.nullability = Some(Nullability::NullFirst);
+                    field.set_nullability(Some(Nullability::NullFirst));
                     Ok(field)
                 }
                 (true, Some(1)) => {
                     let mut field = make_data_type(&f[0], namespace, resolver, use_utf8view)?;
-                    field.nullability = Some(Nullability::NullSecond);
+                    field.set_nullability(Some(Nullability::NullSecond));
                     Ok(field)
                 }
-                _ => Err(ArrowError::NotYetImplemented(format!(
-                    "Union of {f:?} not currently supported"
-                ))),
+                _ => {
+                    if f.len() > i8::MAX as usize {
+                        return Err(ArrowError::ParseError(format!(
+                            "Union with {} variants exceeds the maximum of {}",
+                            f.len(),
+                            i8::MAX
+                        )));
+                    }
+                    let mut variants: Vec<AvroField> = Vec::with_capacity(f.len());
+                    for schema in f {
+                        let name = union_variant_name(schema)?;
+                        if variants.iter().any(|v| v.name == name) {
+                            return Err(ArrowError::ParseError(format!(
+                                "Duplicate type {name} in union"
+                            )));
+                        }
+                        let data_type = make_data_type(schema, namespace, resolver, use_utf8view)?;
+                        variants.push(AvroField { name, data_type });
+                    }
+                    let mut data_type =
+                        AvroDataType::new(Codec::Union(variants.into()), Default::default(), None);
+                    data_type.nullable = null.is_some();
+                    Ok(data_type)
+                }
             }
         }
         Schema::Complex(c) => match c {
             ComplexType::Record(r) => {
+                let key = full_name(r.name, r.namespace.or(namespace));
+                resolver
+                    .expanding
+                    .entry(key)
+                    .or_insert((schema, namespace, 0))
+                    .2 += 1;
                 let namespace = r.namespace.or(namespace);
                 let fields = r
                     .fields
@@ -459,23 +648,27 @@ fn make_data_type<'a>(
                             )?,
                         })
                     })
-                    .collect::<Result<_, ArrowError>>()?;
-                let field = AvroDataType {
-                    nullability: None,
-                    codec: Codec::Struct(fields),
-                    metadata: r.attributes.field_metadata(),
-                };
-                resolver.register(r.name, namespace, field.clone());
+                    .collect::<Result<_, ArrowError>>();
+                let depth = &mut resolver.expanding.get_mut(&key).unwrap().2;
+                *depth -= 1;
+                let outermost = *depth == 0;
+                let field =
+                    AvroDataType::new(Codec::Struct(fields?), r.attributes.field_metadata(), None);
+                // Only register the outermost expansion of a recursive type
+                if outermost {
+                    resolver.expanding.remove(&key);
+                    resolver.register(r.name, namespace, field.clone());
+                }
                 Ok(field)
             }
             ComplexType::Array(a) => {
                 let mut field =
                     make_data_type(a.items.as_ref(), namespace, resolver, use_utf8view)?;
-                Ok(AvroDataType {
-                    nullability: None,
-                    metadata: a.attributes.field_metadata(),
-                    codec: Codec::List(Arc::new(field)),
-                })
+                Ok(AvroDataType::new(
+                    Codec::List(Arc::new(field)),
+                    a.attributes.field_metadata(),
+                    None,
+                ))
             }
             ComplexType::Fixed(f) => {
                 let size = f.size.try_into().map_err(|e| {
@@ -486,11 +679,11 @@ fn make_data_type<'a>(
                     Some("decimal") => {
                         let (precision, scale, _) =
                             parse_decimal_attributes(&f.attributes, Some(size as usize), true)?;
-                        AvroDataType {
-                            nullability: None,
-                            metadata: md,
-                            codec: Codec::Decimal(precision, Some(scale), Some(size as usize)),
-                        }
+                        AvroDataType::new(
+                            Codec::Decimal(precision, Some(scale), Some(size as usize)),
+                            md,
+                            None,
+                        )
                     }
                     Some("duration") => {
                         if size != 12 {
@@ -498,19 +691,11 @@ fn make_data_type<'a>(
                                 "Invalid fixed size for Duration: {size}, must be 12"
                             )));
                         };
-                        AvroDataType {
-                            nullability: None,
-                            metadata: md,
-                            codec: Codec::Interval,
-                        }
+                        AvroDataType::new(Codec::Interval, md, None)
                     }
-                    _ => AvroDataType {
-                        nullability: None,
-                        metadata: md,
-                        codec: Codec::Fixed(size),
-                    },
+                    _ => AvroDataType::new(Codec::Fixed(size), md, None),
                 };
-                resolver.register(f.name, namespace, field.clone());
+                resolver.register(f.name, f.namespace.or(namespace), field.clone());
                 Ok(field)
             }
             ComplexType::Enum(e) => {
@@ -525,31 +710,28 @@ fn make_data_type<'a>(
                 let symbols_json = serde_json::to_string(&e.symbols).map_err(|e| {
                     ArrowError::ParseError(format!("Failed to serialize enum symbols: {e}"))
                 })?;
-                metadata.insert("avro.enum.symbols".to_string(), symbols_json);
-                let field = AvroDataType {
-                    nullability: None,
-                    metadata,
-                    codec: Codec::Enum(symbols),
-                };
+                metadata.insert(ENUM_SYMBOLS_METADATA_KEY.to_string(), symbols_json);
+                let field = AvroDataType::new(Codec::Enum(symbols), metadata, None);
                 resolver.register(e.name, namespace, field.clone());
                 Ok(field)
             }
             ComplexType::Map(m) => {
                 let val = make_data_type(&m.values, namespace, resolver, use_utf8view)?;
-                Ok(AvroDataType {
-                    nullability: None,
-                    metadata: m.attributes.field_metadata(),
-                    codec: Codec::Map(Arc::new(val)),
-                })
+                Ok(AvroDataType::new(
+                    Codec::Map(Arc::new(val)),
+                    m.attributes.field_metadata(),
+                    None,
+                ))
             }
         },
         Schema::Type(t) => {
-            let mut field = make_data_type(
-                &Schema::TypeName(t.r#type.clone()),
-                namespace,
-                resolver,
-                use_utf8view,
-            )?;
+            let mut field = match &t.r#type {
+                TypeName::Primitive(p) => {
+                    let codec = Codec::from(*p).with_utf8view(use_utf8view);
+                    AvroDataType::new(codec, Default::default(), None)
+                }
+                TypeName::Ref(name) => resolve_reference(name, namespace, resolver, use_utf8view)?,
+            };
 
             // https://avro.apache.org/docs/1.11.1/specification/#logical-types
             match (t.attributes.logical_type, &mut field.codec) {
@@ -586,6 +768,711 @@ fn make_data_type<'a>(
     }
 }
 
+/// A default value for a reader field that is absent from the writer schema
+///
+/// Parsed from the JSON `default` of a field against its reader [`AvroDataType`], see
+/// <https://avro.apache.org/docs/1.11.1/specification/#schema-record>
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum AvroLiteral {
+    Null,
+    Boolean(bool),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    Bytes(Vec<u8>),
+    String(String),
+    /// The index of the symbol in the reader enum
+    Enum(i32),
+    Array(Vec<AvroLiteral>),
+    Map(Vec<(String, AvroLiteral)>),
+    /// The field values of a record, in reader field order
+    Record(Vec<AvroLiteral>),
+}
+
+/// A promotion of a writer primitive type to a reader primitive type
+///
+/// See <https://avro.apache.org/docs/1.11.1/specification/#schema-resolution>
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Promotion {
+    IntToLong,
+    IntToFloat,
+    IntToDouble,
+    LongToFloat,
+    LongToDouble,
+    FloatToDouble,
+    StringToBytes,
+    BytesToString,
+}
+
+/// The fields of a writer record resolved against the fields of a reader record
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedRecord {
+    /// For each writer field, the index of the reader field it is decoded into, if any
+    pub(crate) writer_to_reader: Arc<[Option<usize>]>,
+    /// The indices of the reader fields absent from the writer, populated with their default
+    pub(crate) default_fields: Arc<[usize]>,
+    /// For each writer field not present in the reader, the writer type to skip over
+    pub(crate) skip_fields: Arc<[Option<AvroDataType>]>,
+}
+
+/// A writer union resolved against a reader type, or a writer type resolved against a
+/// reader union, see [`SchemaResolver::resolve`]
+///
+/// If the reader type is a union, its variants are those of the [`Codec::Union`] of the
+/// resolved [`AvroDataType`], otherwise values are decoded into the reader type itself
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedUnion {
+    /// Whether the writer type is a union, in which case each value is prefixed by the
+    /// index of its writer variant
+    pub(crate) writer_is_union: bool,
+    /// For each writer variant, or the writer type if it is not a union, the index of
+    /// the reader variant it is decoded into and its resolved type, or `None` if the
+    /// writer variant does not match the reader type
+    ///
+    /// The reader variant is always 0 if the reader type is not a union, with the writer
+    /// `null` variant of a nullable reader type resolved to [`Codec::Null`]
+    pub(crate) variants: Arc<[Option<(usize, AvroDataType)>]>,
+}
+
+/// Describes how data encoded with a writer schema is resolved to an [`AvroDataType`]
+/// derived from the reader schema
+#[derive(Debug, Clone)]
+pub(crate) enum ResolutionInfo {
+    /// The writer primitive type is promoted to the reader type
+    Promotion(Promotion),
+    /// The field is absent from the writer schema and is populated with this value
+    DefaultValue(AvroLiteral),
+    /// Maps each writer enum symbol index to a reader symbol index, or `None` if the
+    /// writer symbol is unknown to the reader and the reader enum has no default
+    EnumMapping(Arc<[Option<i32>]>),
+    /// The fields of the writer record are projected onto the reader record
+    Record(ResolvedRecord),
+    /// The writer or reader type is a union, other than a nullable type
+    Union(ResolvedUnion),
+}
+
+/// The named types of a [`Schema`], along with the namespace enclosing their definition
+type NamedSchemas<'a> = HashMap<(&'a str, &'a str), (&'a Schema<'a>, Option<&'a str>)>;
+
+fn collect_named<'a>(
+    schema: &'a Schema<'a>,
+    namespace: Option<&'a str>,
+    out: &mut NamedSchemas<'a>,
+) {
+    match schema {
+        Schema::TypeName(_) | Schema::Type(_) => {}
+        Schema::Union(u) => u.iter().for_each(|s| collect_named(s, namespace, out)),
+        Schema::Complex(ComplexType::Record(r)) => {
+            let ns = r.namespace.or(namespace);
+            out.insert(full_name(r.name, ns), (schema, namespace));
+            r.fields
+                .iter()
+                .for_each(|f| collect_named(&f.r#type, ns, out));
+        }
+        Schema::Complex(ComplexType::Enum(e)) => {
+            out.insert(
+                full_name(e.name, e.namespace.or(namespace)),
+                (schema, namespace),
+            );
+        }
+        Schema::Complex(ComplexType::Fixed(f)) => {
+            out.insert(
+                full_name(f.name, f.namespace.or(namespace)),
+                (schema, namespace),
+            );
+        }
+        Schema::Complex(ComplexType::Array(a)) => collect_named(&a.items, namespace, out),
+        Schema::Complex(ComplexType::Map(m)) => collect_named(&m.values, namespace, out),
+    }
+}
+
+/// Follows a named type reference, returning the referenced schema and its enclosing namespace
+fn deref_named<'a>(
+    names: &NamedSchemas<'a>,
+    schema: &'a Schema<'a>,
+    namespace: Option<&'a str>,
+) -> Result<(&'a Schema<'a>, Option<&'a str>), ArrowError> {
+    let name = match schema {
+        Schema::TypeName(TypeName::Ref(name)) => name,
+        Schema::Type(t) => match &t.r#type {
+            TypeName::Ref(name) => name,
+            TypeName::Primitive(_) => return Ok((schema, namespace)),
+        },
+        _ => return Ok((schema, namespace)),
+    };
+    let (ns, name) = full_name(name, namespace);
+    names
+        .get(&(ns, name))
+        .copied()
+        .ok_or_else(|| ArrowError::ParseError(format!("Failed to resolve {ns}.{name}")))
+}
+
+/// Returns the primitive type of `schema`, if any, ignoring logical types
+fn primitive_type(schema: &Schema<'_>) -> Option<PrimitiveType> {
+    match schema {
+        Schema::TypeName(TypeName::Primitive(p)) => Some(*p),
+        Schema::Type(t) => match t.r#type {
+            TypeName::Primitive(p) => Some(p),
+            TypeName::Ref(_) => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the nullability and the non-null variant of a two-variant union with null
+fn nullable_union<'s, 'a>(
+    union: &'s [Schema<'a>],
+) -> Result<(Nullability, &'s Schema<'a>), ArrowError> {
+    let null = Schema::TypeName(TypeName::Primitive(PrimitiveType::Null));
+    match union {
+        [a, b] if a == &null && b != &null => Ok((Nullability::NullFirst, b)),
+        [a, b] if b == &null && a != &null => Ok((Nullability::NullSecond, a)),
+        _ => Err(ArrowError::NotYetImplemented(format!(
+            "Union of {union:?} not currently supported"
+        ))),
+    }
+}
+
+fn describe(schema: &Schema<'_>) -> String {
+    serde_json::to_string(schema).unwrap_or_else(|_| format!("{schema:?}"))
+}
+
+/// Resolves a writer [`Schema`] against a reader [`Schema`], producing an [`AvroDataType`]
+/// with the arrow type of the reader schema, that decodes data encoded with the writer schema
+///
+/// See <https://avro.apache.org/docs/1.11.1/specification/#schema-resolution>
+struct SchemaResolver<'a> {
+    writer_names: NamedSchemas<'a>,
+    reader_names: NamedSchemas<'a>,
+    /// The [`AvroDataType`] of the named writer types, used for skipping fields
+    writer_types: Resolver<'a>,
+    /// The [`AvroDataType`] of the named reader types, used for defaulted fields
+    reader_types: Resolver<'a>,
+    /// The number of nested resolutions of each reader record, see [`MAX_RECURSION_DEPTH`]
+    depths: HashMap<(&'a str, &'a str), usize>,
+    /// The indices of the fields of the root reader record to resolve, if not all
+    projection: Option<Vec<usize>>,
+    use_utf8view: bool,
+}
+
+impl<'a> SchemaResolver<'a> {
+    fn try_new(
+        writer: &'a Schema<'a>,
+        reader: &'a Schema<'a>,
+        use_utf8view: bool,
+    ) -> Result<Self, ArrowError> {
+        let mut this = Self {
+            writer_names: Default::default(),
+            reader_names: Default::default(),
+            writer_types: Default::default(),
+            reader_types: Default::default(),
+            depths: Default::default(),
+            projection: None,
+            use_utf8view,
+        };
+        collect_named(writer, None, &mut this.writer_names);
+        collect_named(reader, None, &mut this.reader_names);
+        make_data_type(writer, None, &mut this.writer_types, use_utf8view)?;
+        make_data_type(reader, None, &mut this.reader_types, use_utf8view)?;
+        Ok(this)
+    }
+
+    fn mismatch(writer: &Schema<'_>, reader: &Schema<'_>) -> ArrowError {
+        ArrowError::SchemaError(format!(
+            "Cannot resolve writer schema {} with reader schema {}",
+            describe(writer),
+            describe(reader)
+        ))
+    }
+
+    fn resolve(
+        &mut self,
+        writer: &'a Schema<'a>,
+        reader: &'a Schema<'a>,
+        writer_ns: Option<&'a str>,
+        reader_ns: Option<&'a str>,
+    ) -> Result<AvroDataType, ArrowError> {
+        let (writer, writer_ns) = deref_named(&self.writer_names, writer, writer_ns)?;
+        let (reader, reader_ns) = deref_named(&self.reader_names, reader, reader_ns)?;
+        match (writer, reader) {
+            (_, Schema::Union(r)) if nullable_union(r).is_err() => {
+                self.resolve_union(writer, reader, r, writer_ns, reader_ns)
+            }
+            (Schema::Union(w), _) if nullable_union(w).is_err() => {
+                self.resolve_writer_union(writer, w, reader, writer_ns, reader_ns)
+            }
+            (Schema::Union(w), _) => {
+                let (nullability, w) = nullable_union(w)?;
+                let (nullable, r) = match reader {
+                    Schema::Union(r) => (true, nullable_union(r)?.1),
+                    _ => (false, reader),
+                };
+                let mut data_type = self.resolve(w, r, writer_ns, reader_ns)?;
+                data_type.nullability = Some(nullability);
+                data_type.nullable = nullable;
+                Ok(data_type)
+            }
+            (_, Schema::Union(r)) => {
+                let (_, r) = nullable_union(r)?;
+                let mut data_type = self.resolve(writer, r, writer_ns, reader_ns)?;
+                data_type.nullable = true;
+                Ok(data_type)
+            }
+            (Schema::Complex(ComplexType::Record(w)), Schema::Complex(ComplexType::Record(r))) => {
+                let key = full_name(r.name, r.namespace.or(reader_ns));
+                let depth = self.depths.entry(key).or_default();
+                if *depth >= MAX_RECURSION_DEPTH {
+                    return Ok(AvroDataType::recursive(key));
+                }
+                *depth += 1;
+                let resolved = self.resolve_record(w, r, writer_ns, reader_ns);
+                *self.depths.get_mut(&key).unwrap() -= 1;
+                resolved
+            }
+            (Schema::Complex(ComplexType::Enum(w)), Schema::Complex(ComplexType::Enum(r))) => {
+                let mut data_type = self.reader_type(reader, reader_ns)?;
+                let default = r
+                    .default
+                    .map(|d| {
+                        r.symbols.iter().position(|s| *s == d).ok_or_else(|| {
+                            ArrowError::SchemaError(format!(
+                                "Default \"{d}\" is not a symbol of enum {}",
+                                r.name
+                            ))
+                        })
+                    })
+                    .transpose()?;
+                let mapping = w
+                    .symbols
+                    .iter()
+                    .map(|w| {
+                        let idx = r.symbols.iter().position(|r| r == w).or(default)?;
+                        Some(idx as i32)
+                    })
+                    .collect::<Arc<[_]>>();
+                let identity = mapping
+                    .iter()
+                    .enumerate()
+                    .all(|(i, r)| *r == Some(i as i32));
+                if !identity {
+                    data_type.resolution = Some(ResolutionInfo::EnumMapping(mapping));
+                }
+                Ok(data_type)
+            }
+            (Schema::Complex(ComplexType::Fixed(w)), Schema::Complex(ComplexType::Fixed(r)))
+                if w.size == r.size =>
+            {
+                self.reader_type(reader, reader_ns)
+            }
+            (Schema::Complex(ComplexType::Array(w)), Schema::Complex(ComplexType::Array(r))) => {
+                let items = self.resolve(&w.items, &r.items, writer_ns, reader_ns)?;
+                Ok(AvroDataType::new(
+                    Codec::List(Arc::new(items)),
+                    r.attributes.field_metadata(),
+                    None,
+                ))
+            }
+            (Schema::Complex(ComplexType::Map(w)), Schema::Complex(ComplexType::Map(r))) => {
+                let values = self.resolve(&w.values, &r.values, writer_ns, reader_ns)?;
+                Ok(AvroDataType::new(
+                    Codec::Map(Arc::new(values)),
+                    r.attributes.field_metadata(),
+                    None,
+                ))
+            }
+            _ => match (primitive_type(writer), primitive_type(reader)) {
+                (Some(w), Some(r)) => {
+                    let mut data_type = self.reader_type(reader, reader_ns)?;
+                    let promotion = match (w, r) {
+                        _ if w == r => return Ok(data_type),
+                        (PrimitiveType::Int, PrimitiveType::Long) => Promotion::IntToLong,
+                        (PrimitiveType::Int, PrimitiveType::Float) => Promotion::IntToFloat,
+                        (PrimitiveType::Int, PrimitiveType::Double) => Promotion::IntToDouble,
+                        (PrimitiveType::Long, PrimitiveType::Float) => Promotion::LongToFloat,
+                        (PrimitiveType::Long, PrimitiveType::Double) => Promotion::LongToDouble,
+                        (PrimitiveType::Float, PrimitiveType::Double) => Promotion::FloatToDouble,
+                        (PrimitiveType::String, PrimitiveType::Bytes) => Promotion::StringToBytes,
+                        (PrimitiveType::Bytes, PrimitiveType::String) => Promotion::BytesToString,
+                        _ => return Err(Self::mismatch(writer, reader)),
+                    };
+                    // Promotions are only supported to the plain arrow type of the reader
+                    match (promotion, &data_type.codec) {
+                        (Promotion::IntToLong, Codec::Int64)
+                        | (Promotion::IntToFloat | Promotion::LongToFloat, Codec::Float32)
+                        | (
+                            Promotion::IntToDouble
+                            | Promotion::LongToDouble
+                            | Promotion::FloatToDouble,
+                            Codec::Float64,
+                        )
+                        | (Promotion::StringToBytes, Codec::Binary)
+                        | (Promotion::BytesToString, Codec::Utf8 | Codec::Utf8View) => {}
+                        _ => return Err(Self::mismatch(writer, reader)),
+                    }
+                    data_type.resolution = Some(ResolutionInfo::Promotion(promotion));
+                    Ok(data_type)
+                }
+                _ => Err(Self::mismatch(writer, reader)),
+            },
+        }
+    }
+
+    /// Returns false if `writer` and `reader` are named types with different unqualified names
+    fn names_match(
+        &self,
+        writer: &'a Schema<'a>,
+        reader: &'a Schema<'a>,
+        writer_ns: Option<&'a str>,
+        reader_ns: Option<&'a str>,
+    ) -> bool {
+        let name = |names: &NamedSchemas<'a>, schema: &'a Schema<'a>, ns: Option<&'a str>| {
+            match deref_named(names, schema, ns).ok()?.0 {
+                Schema::Complex(ComplexType::Record(r)) => Some(full_name(r.name, None).1),
+                Schema::Complex(ComplexType::Enum(e)) => Some(full_name(e.name, None).1),
+                Schema::Complex(ComplexType::Fixed(f)) => Some(full_name(f.name, None).1),
+                _ => None,
+            }
+        };
+        match (
+            name(&self.writer_names, writer, writer_ns),
+            name(&self.reader_names, reader, reader_ns),
+        ) {
+            (Some(w), Some(r)) => w == r,
+            _ => true,
+        }
+    }
+
+    /// Resolves `writer` against the first of the `reader` union variants it matches,
+    /// returning the index of that variant and the resolved type
+    fn resolve_variant(
+        &mut self,
+        writer: &'a Schema<'a>,
+        reader: &'a [Schema<'a>],
+        writer_ns: Option<&'a str>,
+        reader_ns: Option<&'a str>,
+    ) -> Option<(usize, AvroDataType)> {
+        for (idx, r) in reader.iter().enumerate() {
+            if !self.names_match(writer, r, writer_ns, reader_ns) {
+                continue;
+            }
+            if let Ok(data_type) = self.resolve(writer, r, writer_ns, reader_ns) {
+                return Some((idx, data_type));
+            }
+        }
+        None
+    }
+
+    /// Resolves a writer type against the `variants` of a reader union, other than a
+    /// nullable type
+    ///
+    /// Each writer union variant, or the writer type if it is not a union, is resolved
+    /// against the first reader variant it matches, returning an error if there is none
+    fn resolve_union(
+        &mut self,
+        writer: &'a Schema<'a>,
+        reader: &'a Schema<'a>,
+        variants: &'a [Schema<'a>],
+        writer_ns: Option<&'a str>,
+        reader_ns: Option<&'a str>,
+    ) -> Result<AvroDataType, ArrowError> {
+        let (writer_is_union, writer_variants) = match writer {
+            Schema::Union(w) => (true, w.as_slice()),
+            _ => (false, std::slice::from_ref(writer)),
+        };
+        let resolved = writer_variants
+            .iter()
+            .map(|w| {
+                self.resolve_variant(w, variants, writer_ns, reader_ns)
+                    .ok_or_else(|| Self::mismatch(w, reader))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut data_type = self.reader_type(reader, reader_ns)?;
+        let Codec::Union(fields) = &data_type.codec else {
+            return Err(Self::mismatch(writer, reader));
+        };
+        // Prefer the resolved types, which may expand recursive types to a different depth
+        let fields = fields
+            .iter()
+            .enumerate()
+            .map(|(idx, f)| match resolved.iter().find(|(r, _)| *r == idx) {
+                Some((_, resolved)) => AvroField {
+                    name: f.name.clone(),
+                    data_type: resolved.clone(),
+                },
+                None => f.clone(),
+            })
+            .collect();
+        data_type.codec = Codec::Union(fields);
+        data_type.resolution = Some(ResolutionInfo::Union(ResolvedUnion {
+            writer_is_union,
+            variants: resolved.into_iter().map(Some).collect(),
+        }));
+        Ok(data_type)
+    }
+
+    /// Resolves the variants of a writer union, other than a nullable type, against a
+    /// reader type that is not a union, or is a nullable type
+    ///
+    /// Writer variants that do not match the reader type can only be decoded as an error,
+    /// returning an error if no writer variant matches the reader type
+    fn resolve_writer_union(
+        &mut self,
+        writer: &'a Schema<'a>,
+        variants: &'a [Schema<'a>],
+        reader: &'a Schema<'a>,
+        writer_ns: Option<&'a str>,
+        reader_ns: Option<&'a str>,
+    ) -> Result<AvroDataType, ArrowError> {
+        let (nullable, target) = match reader {
+            Schema::Union(r) => (true, nullable_union(r)?.1),
+            _ => (false, reader),
+        };
+        let variants = variants.iter().map(|w| {
+            let resolved = match primitive_type(w) {
+                Some(PrimitiveType::Null) if nullable => {
+                    Some(AvroDataType::new(Codec::Null, Default::default(), None))
+                }
+                _ if !self.names_match(w, target, writer_ns, reader_ns) => None,
+                _ => self.resolve(w, target, writer_ns, reader_ns).ok(),
+            };
+            resolved.map(|r| (0, r))
+        });
+        let variants: Arc<[_]> = variants.collect();
+        let matched = variants
+            .iter()
+            .flatten()
+            .find(|(_, v)| !matches!(v.codec, Codec::Null));
+        let Some((_, resolved)) = matched else {
+            return Err(Self::mismatch(writer, reader));
+        };
+        let mut data_type = resolved.clone();
+        data_type.nullability = None;
+        data_type.nullable = nullable;
+        data_type.resolution = Some(ResolutionInfo::Union(ResolvedUnion {
+            writer_is_union: true,
+            variants,
+        }));
+        Ok(data_type)
+    }
+
+    fn resolve_record(
+        &mut self,
+        writer: &'a Record<'a>,
+        reader: &'a Record<'a>,
+        writer_ns: Option<&'a str>,
+        reader_ns: Option<&'a str>,
+    ) -> Result<AvroDataType, ArrowError> {
+        let writer_ns = writer.namespace.or(writer_ns);
+        let reader_ns = reader.namespace.or(reader_ns);
+
+        // The projection only applies to the root record
+        let reader_fields: Vec<_> = match self.projection.take() {
+            Some(projection) => projection
+                .iter()
+                .enumerate()
+                .map(|(i, idx)| {
+                    if projection[..i].contains(idx) {
+                        return Err(ArrowError::InvalidArgumentError(format!(
+                            "Duplicate projection index {idx} for record {}",
+                            reader.name
+                        )));
+                    }
+                    reader.fields.get(*idx).ok_or_else(|| {
+                        ArrowError::InvalidArgumentError(format!(
+                            "Projection index {idx} out of bounds for record {} with {} fields",
+                            reader.name,
+                            reader.fields.len()
+                        ))
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+            None => reader.fields.iter().collect(),
+        };
+
+        let mut resolved: Vec<Option<AvroDataType>> = vec![None; reader_fields.len()];
+        let mut writer_to_reader = Vec::with_capacity(writer.fields.len());
+        let mut skip_fields = Vec::with_capacity(writer.fields.len());
+        for w in &writer.fields {
+            // Prefer an exact name match over a match against the reader field's aliases
+            let idx = reader_fields
+                .iter()
+                .position(|r| r.name == w.name)
+                .or_else(|| {
+                    reader_fields
+                        .iter()
+                        .position(|r| r.aliases.contains(&w.name))
+                })
+                .filter(|idx| resolved[*idx].is_none());
+
+            match idx {
+                Some(idx) => {
+                    let r = reader_fields[idx];
+                    let data_type = self.resolve(&w.r#type, &r.r#type, writer_ns, reader_ns)?;
+                    resolved[idx] = Some(data_type);
+                    writer_to_reader.push(Some(idx));
+                    skip_fields.push(None);
+                }
+                None => {
+                    let data_type =
+                        make_data_type(&w.r#type, writer_ns, &mut self.writer_types, false)?;
+                    writer_to_reader.push(None);
+                    skip_fields.push(Some(data_type));
+                }
+            }
+        }
+
+        let mut default_fields = vec![];
+        let fields = reader_fields
+            .into_iter()
+            .zip(resolved)
+            .enumerate()
+            .map(|(idx, (r, resolved))| {
+                let data_type = match resolved {
+                    Some(data_type) => data_type,
+                    None => {
+                        let mut data_type = self.reader_type(&r.r#type, reader_ns)?;
+                        let default = r.default.as_ref().ok_or_else(|| {
+                            ArrowError::SchemaError(format!(
+                                "Field \"{}\" is not present in the writer schema and has no default",
+                                r.name
+                            ))
+                        })?;
+                        let default = parse_default(default, &data_type).map_err(|e| {
+                            ArrowError::SchemaError(format!(
+                                "Invalid default for field \"{}\": {e}",
+                                r.name
+                            ))
+                        })?;
+                        data_type.resolution = Some(ResolutionInfo::DefaultValue(default));
+                        default_fields.push(idx);
+                        data_type
+                    }
+                };
+                Ok(AvroField {
+                    name: r.name.to_string(),
+                    data_type,
+                })
+            })
+            .collect::<Result<Arc<[_]>, ArrowError>>()?;
+
+        let mut data_type = AvroDataType::new(
+            Codec::Struct(fields),
+            reader.attributes.field_metadata(),
+            None,
+        );
+        let identity = default_fields.is_empty()
+            && writer_to_reader
+                .iter()
+                .enumerate()
+                .all(|(i, r)| *r == Some(i));
+        if !identity {
+            data_type.resolution = Some(ResolutionInfo::Record(ResolvedRecord {
+                writer_to_reader: writer_to_reader.into(),
+                default_fields: default_fields.into(),
+                skip_fields: skip_fields.into(),
+            }));
+        }
+        Ok(data_type)
+    }
+
+    /// Returns the [`AvroDataType`] of `reader` without any resolution
+    fn reader_type(
+        &mut self,
+        reader: &'a Schema<'a>,
+        reader_ns: Option<&'a str>,
+    ) -> Result<AvroDataType, ArrowError> {
+        make_data_type(reader, reader_ns, &mut self.reader_types, self.use_utf8view)
+    }
+}
+
+/// Parses the JSON encoded default `value` of a field with the given `data_type`
+///
+/// Defaults of nullable fields may be `null`, the encoding of `bytes` and `fixed`
+/// defaults maps each code point 0-255 of a JSON string to a byte
+fn parse_default(value: &Value, data_type: &AvroDataType) -> Result<AvroLiteral, ArrowError> {
+    let invalid = || {
+        ArrowError::ParseError(format!(
+            "{value} is not a valid default for {}",
+            data_type.codec.data_type()
+        ))
+    };
+    if let Codec::Union(variants) = &data_type.codec {
+        // The default of a union corresponds to its first variant
+        let first = variants.first().ok_or_else(invalid)?;
+        return parse_default(value, first.data_type());
+    }
+    if value.is_null() && data_type.nullable {
+        return Ok(AvroLiteral::Null);
+    }
+    let bytes = |value: &Value, size: Option<usize>| {
+        let s = value.as_str().ok_or_else(invalid)?;
+        let bytes = s
+            .chars()
+            .map(|c| u8::try_from(c as u32).map_err(|_| invalid()))
+            .collect::<Result<Vec<_>, _>>()?;
+        match size {
+            Some(size) if bytes.len() != size => Err(invalid()),
+            _ => Ok(AvroLiteral::Bytes(bytes)),
+        }
+    };
+    Ok(match (&data_type.codec, value) {
+        (Codec::Null, Value::Null) => AvroLiteral::Null,
+        (Codec::Boolean, Value::Bool(b)) => AvroLiteral::Boolean(*b),
+        (Codec::Int32 | Codec::Date32 | Codec::TimeMillis, Value::Number(n)) => {
+            let v = n.as_i64().and_then(|v| i32::try_from(v).ok());
+            AvroLiteral::Int(v.ok_or_else(invalid)?)
+        }
+        (
+            Codec::Int64
+            | Codec::TimeMicros
+            | Codec::TimestampMillis(_)
+            | Codec::TimestampMicros(_),
+            Value::Number(n),
+        ) => AvroLiteral::Long(n.as_i64().ok_or_else(invalid)?),
+        (Codec::Float32, Value::Number(n)) => {
+            AvroLiteral::Float(n.as_f64().ok_or_else(invalid)? as f32)
+        }
+        (Codec::Float64, Value::Number(n)) => AvroLiteral::Double(n.as_f64().ok_or_else(invalid)?),
+        (Codec::Binary, _) | (Codec::Decimal(_, _, None), _) => bytes(value, None)?,
+        (Codec::Fixed(size), _) => bytes(value, Some(*size as usize))?,
+        (Codec::Decimal(_, _, Some(size)), _) => bytes(value, Some(*size))?,
+        (Codec::Interval, _) => bytes(value, Some(12))?,
+        (Codec::Utf8 | Codec::Utf8View, Value::String(s)) => AvroLiteral::String(s.clone()),
+        (Codec::Uuid, Value::String(s)) => {
+            uuid::Uuid::try_parse(s).map_err(|_| invalid())?;
+            AvroLiteral::String(s.clone())
+        }
+        (Codec::Enum(symbols), Value::String(s)) => {
+            let idx = symbols.iter().position(|x| x == s).ok_or_else(invalid)?;
+            AvroLiteral::Enum(idx as i32)
+        }
+        (Codec::List(items), Value::Array(values)) => AvroLiteral::Array(
+            values
+                .iter()
+                .map(|v| parse_default(v, items))
+                .collect::<Result<_, _>>()?,
+        ),
+        (Codec::Map(values), Value::Object(entries)) => AvroLiteral::Map(
+            entries
+                .iter()
+                .map(|(k, v)| Ok((k.clone(), parse_default(v, values)?)))
+                .collect::<Result<_, ArrowError>>()?,
+        ),
+        (Codec::Struct(fields), Value::Object(entries)) => AvroLiteral::Record(
+            fields
+                .iter()
+                .map(|f| {
+                    let v = entries.get(f.name()).ok_or_else(invalid)?;
+                    parse_default(v, f.data_type())
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        _ => return Err(invalid()),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -781,6 +1668,7 @@ mod tests {
             name: "string_field",
             r#type: field_schema,
             default: None,
+            aliases: vec![],
             doc: None,
         };
 
@@ -805,4 +1693,183 @@ mod tests {
             panic!("Expected Struct codec");
         }
     }
+
+    #[test]
+    fn test_parse_default() {
+        let parse = |schema: &str, value: &str| {
+            let schema: Schema = serde_json::from_str(schema).unwrap();
+            let mut resolver = Resolver::default();
+            let data_type = make_data_type(&schema, None, &mut resolver, false).unwrap();
+            parse_default(&serde_json::from_str(value).unwrap(), &data_type)
+        };
+
+        assert_eq!(
+            parse(r#""bytes""#, r#""\u00ff\u0001""#).unwrap(),
+            AvroLiteral::Bytes(vec![255, 1])
+        );
+        assert_eq!(
+            parse(r#"["null", "long"]"#, "null").unwrap(),
+            AvroLiteral::Null
+        );
+        assert_eq!(
+            parse(
+                r#"{"type": "record", "name": "r", "fields": [
+                    {"name": "a", "type": {"type": "array", "items": "double"}},
+                    {"name": "b", "type": {"type": "enum", "name": "e", "symbols": ["x", "y"]}}
+                ]}"#,
+                r#"{"a": [1, 2.5], "b": "y"}"#
+            )
+            .unwrap(),
+            AvroLiteral::Record(vec![
+                AvroLiteral::Array(vec![AvroLiteral::Double(1.), AvroLiteral::Double(2.5)]),
+                AvroLiteral::Enum(1),
+            ])
+        );
+
+        let err = parse(
+            r#"{"type": "fixed", "name": "f", "size": 2}"#,
+            r#""\u0001""#,
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: \"\\u0001\" is not a valid default for FixedSizeBinary(2)"
+        );
+        let err = parse(r#""int""#, "2147483648").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: 2147483648 is not a valid default for Int32"
+        );
+    }
+
+    #[test]
+    fn test_resolve_named_reference() {
+        let writer: Schema = serde_json::from_str(
+            r#"{"type": "record", "name": "r", "namespace": "ns", "fields": [
+                {"name": "a", "type": {"type": "record", "name": "p", "fields": [
+                    {"name": "x", "type": "int"}
+                ]}},
+                {"name": "b", "type": "ns.p"}
+            ]}"#,
+        )
+        .unwrap();
+        let reader: Schema = serde_json::from_str(
+            r#"{"type": "record", "name": "r", "namespace": "ns", "fields": [
+                {"name": "b", "type": {"type": "record", "name": "p", "fields": [
+                    {"name": "x", "type": "long"}
+                ]}},
+                {"name": "a", "type": "p"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let field = AvroField::resolve(&writer, &reader, false, None).unwrap();
+        let Codec::Struct(fields) = field.data_type().codec() else {
+            panic!("Expected Struct codec");
+        };
+        assert_eq!(fields[0].name(), "b");
+        assert_eq!(fields[1].name(), "a");
+        for f in fields.iter() {
+            let Codec::Struct(children) = f.data_type().codec() else {
+                panic!("Expected Struct codec");
+            };
+            assert!(matches!(children[0].data_type().codec(), Codec::Int64));
+            assert!(matches!(
+                children[0].data_type().resolution(),
+                Some(ResolutionInfo::Promotion(Promotion::IntToLong))
+            ));
+        }
+        match field.data_type().resolution() {
+            Some(ResolutionInfo::Record(r)) => {
+                assert_eq!(r.writer_to_reader.as_ref(), &[Some(1), Some(0)]);
+                assert!(r.default_fields.is_empty());
+            }
+            r => panic!("Expected record resolution got {r:?}"),
+        }
+    }
+
+    #[test]
+    fn test_resolve_union() {
+        let schema = |u: &str, extra: &str| {
+            format!(
+                r#"{{"type": "record", "name": "r", "fields": [
+                    {{"name": "u", "type": {u}}}{extra}
+                ]}}"#
+            )
+        };
+        let writer = schema(r#"["null", "int", "string"]"#, "");
+        let writer: Schema = serde_json::from_str(&writer).unwrap();
+        let extra = r#", {"name": "next", "type": ["null", "r"], "default": null}"#;
+        let reader = schema(r#"["null", "long", "string"]"#, extra);
+        let reader: Schema = serde_json::from_str(&reader).unwrap();
+
+        let field = AvroField::resolve(&writer, &reader, false, None).unwrap();
+        let Codec::Struct(fields) = field.data_type().codec() else {
+            panic!("Expected Struct codec");
+        };
+        let Codec::Union(variants) = fields[0].data_type().codec() else {
+            panic!("Expected Union codec");
+        };
+        assert!(fields[0].data_type().is_nullable());
+        assert_eq!(variants[1].name(), "long");
+        assert!(matches!(
+            variants[1].data_type().resolution(),
+            Some(ResolutionInfo::Promotion(Promotion::IntToLong))
+        ));
+        // The recursive default field is expanded up to the maximum depth
+        let mut data_type = fields[1].field().data_type().clone();
+        for _ in 0..MAX_RECURSION_DEPTH {
+            let DataType::Struct(fields) = data_type else {
+                panic!("Expected struct got {data_type}")
+            };
+            data_type = fields[1].data_type().clone();
+        }
+        assert_eq!(data_type, DataType::Null);
+
+        // Writer variants are resolved against the first reader variant they match
+        let reader = schema(r#"["string", "long", "null", "int"]"#, "");
+        let reader: Schema = serde_json::from_str(&reader).unwrap();
+        let field = AvroField::resolve(&writer, &reader, false, None).unwrap();
+        let Codec::Struct(fields) = field.data_type().codec() else {
+            panic!("Expected Struct codec");
+        };
+        let Some(ResolutionInfo::Union(resolved)) = fields[0].data_type().resolution() else {
+            panic!("Expected union resolution");
+        };
+        assert!(resolved.writer_is_union);
+        let mapping: Vec<_> = resolved
+            .variants
+            .iter()
+            .map(|v| v.as_ref().map(|(r, _)| *r))
+            .collect();
+        assert_eq!(mapping, [Some(2), Some(1), Some(0)]);
+
+        let reader = schema(r#"["null", "string"]"#, "");
+        let reader: Schema = serde_json::from_str(&reader).unwrap();
+        let field = AvroField::resolve(&writer, &reader, false, None).unwrap();
+        let Codec::Struct(fields) = field.data_type().codec() else {
+            panic!("Expected Struct codec");
+        };
+        assert!(matches!(fields[0].data_type().codec(), Codec::Utf8));
+        assert!(fields[0].data_type().is_nullable());
+
+        let reader = schema(r#"["null", "boolean", "string"]"#, "");
+        let reader: Schema = serde_json::from_str(&reader).unwrap();
+        let err = AvroField::resolve(&writer, &reader, false, None).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains(r#"Cannot resolve writer schema "int" with reader schema ["null","boolean","string"]"#),
+            "{err}"
+        );
+
+        let reader = schema(r#""boolean""#, "");
+        let reader: Schema = serde_json::from_str(&reader).unwrap();
+        let err = AvroField::resolve(&writer, &reader, false, None).unwrap_err();
+        assert!(
+            err.to_string().contains(
+                r#"Cannot resolve writer schema ["null","int","string"] with reader schema "boolean""#
+            ),
+            "{err}"
+        );
+    }
 }
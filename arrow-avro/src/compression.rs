@@ -17,7 +17,7 @@
 
 use arrow_schema::ArrowError;
 use std::io;
-use std::io::Read;
+use std::io::{Read, Write};
 
 /// The metadata key used for storing the JSON encoded [`CompressionCodec`]
 pub const CODEC_METADATA_KEY: &str = "avro.codec";
@@ -41,6 +41,80 @@ pub enum CompressionCodec {
 }
 
 impl CompressionCodec {
+    /// Returns the name of this codec as stored under [`CODEC_METADATA_KEY`]
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            CompressionCodec::Deflate => "deflate",
+            CompressionCodec::Snappy => "snappy",
+            CompressionCodec::ZStandard => "zstandard",
+            CompressionCodec::Bzip2 => "bzip2",
+            CompressionCodec::Xz => "xz",
+        }
+    }
+
+    pub(crate) fn compress(&self, data: &[u8]) -> Result<Vec<u8>, ArrowError> {
+        match self {
+            #[cfg(feature = "deflate")]
+            CompressionCodec::Deflate => {
+                let mut encoder =
+                    flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(data)?;
+                Ok(encoder.finish()?)
+            }
+            #[cfg(not(feature = "deflate"))]
+            CompressionCodec::Deflate => Err(ArrowError::ParseError(
+                "Deflate codec requires deflate feature".to_string(),
+            )),
+            #[cfg(feature = "snappy")]
+            CompressionCodec::Snappy => {
+                // Each compressed block is followed by the 4-byte, big-endian CRC32
+                // checksum of the uncompressed data in the block.
+                let mut encoder = snap::raw::Encoder::new();
+                let mut out = encoder
+                    .compress_vec(data)
+                    .map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
+                let checksum = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC).checksum(data);
+                out.extend_from_slice(&checksum.to_be_bytes());
+                Ok(out)
+            }
+            #[cfg(not(feature = "snappy"))]
+            CompressionCodec::Snappy => Err(ArrowError::ParseError(
+                "Snappy codec requires snappy feature".to_string(),
+            )),
+            #[cfg(feature = "zstd")]
+            CompressionCodec::ZStandard => {
+                let mut encoder = zstd::Encoder::new(Vec::new(), 0)?;
+                encoder.write_all(data)?;
+                Ok(encoder.finish()?)
+            }
+            #[cfg(not(feature = "zstd"))]
+            CompressionCodec::ZStandard => Err(ArrowError::ParseError(
+                "ZStandard codec requires zstd feature".to_string(),
+            )),
+            #[cfg(feature = "bzip2")]
+            CompressionCodec::Bzip2 => {
+                let mut encoder =
+                    bzip2::write::BzEncoder::new(Vec::new(), bzip2::Compression::default());
+                encoder.write_all(data)?;
+                Ok(encoder.finish()?)
+            }
+            #[cfg(not(feature = "bzip2"))]
+            CompressionCodec::Bzip2 => Err(ArrowError::ParseError(
+                "Bzip2 codec requires bzip2 feature".to_string(),
+            )),
+            #[cfg(feature = "xz")]
+            CompressionCodec::Xz => {
+                let mut encoder = xz::write::XzEncoder::new(Vec::new(), 6);
+                encoder.write_all(data)?;
+                Ok(encoder.finish()?)
+            }
+            #[cfg(not(feature = "xz"))]
+            CompressionCodec::Xz => Err(ArrowError::ParseError(
+                "XZ codec requires xz feature".to_string(),
+            )),
+        }
+    }
+
     pub(crate) fn decompress(&self, block: &[u8]) -> Result<Vec<u8>, ArrowError> {
         match self {
             #[cfg(feature = "deflate")]
@@ -33,6 +33,11 @@
 /// Implements the primary reader interface and record decoding logic.
 pub mod reader;
 
+/// Core functionality for writing Arrow arrays as Avro data
+///
+/// Implements the primary writer interface and record encoding logic.
+pub mod writer;
+
 // Avro schema parsing and representation
 //
 // Provides types for parsing and representing Avro schema definitions.
@@ -0,0 +1,487 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Asynchronous reading of Avro Object Container Files from byte ranges
+
+use crate::compression::CompressionCodec;
+use crate::reader::block::BlockDecoder;
+use crate::reader::header::{Header, HeaderDecoder};
+use crate::reader::{Decoder, ReaderBuilder};
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, SchemaRef};
+use bytes::{Buf, Bytes};
+use futures::future::{BoxFuture, FutureExt};
+use futures::Stream;
+use std::ops::Range;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// The default number of bytes requested by each call to [`AsyncFileReader::get_bytes`]
+const DEFAULT_FETCH_SIZE: usize = 1024 * 1024;
+
+/// The length of the sync marker following the header and each block
+const SYNC_LEN: usize = 16;
+
+/// The asynchronous interface used by [`AsyncReader`] to fetch byte ranges of an
+/// Avro file, for example from object storage
+pub trait AsyncFileReader: Send {
+    /// Retrieve the bytes in `range`
+    fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Bytes, ArrowError>>;
+}
+
+impl AsyncFileReader for Box<dyn AsyncFileReader + '_> {
+    fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        self.as_mut().get_bytes(range)
+    }
+}
+
+/// An Avro file held in memory
+impl AsyncFileReader for Bytes {
+    fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Bytes, ArrowError>> {
+        let len = self.len() as u64;
+        let result = match range.start <= range.end && range.end <= len {
+            true => Ok(self.slice(range.start as usize..range.end as usize)),
+            false => Err(ArrowError::InvalidArgumentError(format!(
+                "Range {range:?} is out of bounds for file of {len} bytes"
+            ))),
+        };
+        futures::future::ready(result).boxed()
+    }
+}
+
+impl ReaderBuilder {
+    /// Create an [`AsyncReader`] from this builder, reading an Avro file of `file_size`
+    /// bytes from an [`AsyncFileReader`]
+    ///
+    /// This fetches and parses the file header, the data blocks are only fetched once
+    /// the returned [`AsyncReader`] is polled.
+    pub async fn build_async<R: AsyncFileReader + 'static>(
+        self,
+        mut reader: R,
+        file_size: u64,
+    ) -> Result<AsyncReader<R>, ArrowError> {
+        let mut header_decoder = HeaderDecoder::default();
+        let mut offset = 0;
+        let (header, buffer) = loop {
+            if offset >= file_size {
+                return Err(ArrowError::ParseError(
+                    "Unexpected EOF while reading Avro header".to_string(),
+                ));
+            }
+            let end = file_size.min(offset + DEFAULT_FETCH_SIZE as u64);
+            let buf = reader.get_bytes(offset..end).await?;
+            let consumed = header_decoder.decode(&buf)?;
+            offset += consumed as u64;
+            if let Some(header) = header_decoder.flush() {
+                break (header, buf.slice(consumed..));
+            }
+            if buf.is_empty() {
+                return Err(ArrowError::ParseError(format!(
+                    "Expected {} bytes at offset {offset}, got none",
+                    end - offset
+                )));
+            }
+        };
+
+        let decoder = self.make_decoder(&header)?;
+        let state = ReadState {
+            reader,
+            file_size,
+            sync: header.sync(),
+            compression: header.compression()?,
+            decoder,
+            block_decoder: Default::default(),
+            buffer,
+            offset,
+            range: 0..file_size,
+            fetch_size: DEFAULT_FETCH_SIZE,
+            positioned: false,
+            finished: false,
+            block_data: vec![],
+            block_cursor: 0,
+        };
+        Ok(AsyncReader {
+            schema: state.decoder.schema(),
+            header,
+            state: StreamState::Init(Box::new(state)),
+        })
+    }
+}
+
+/// An asynchronous reader of an Avro Object Container File, that yields the decoded
+/// [`RecordBatch`]es as a [`Stream`]
+///
+/// The file is read using ranged reads of an [`AsyncFileReader`], see
+/// [`ReaderBuilder::build_async`].
+///
+/// # Splitting
+///
+/// Several readers can each decode a part of the same file by restricting them to
+/// disjoint byte ranges with [`Self::with_range`]. Each data block is decoded by the
+/// reader whose range contains the start of the sync marker preceding the block,
+/// that is the header's sync marker for the first block, such that ranges covering
+/// the whole file decode each block exactly once.
+pub struct AsyncReader<R> {
+    schema: SchemaRef,
+    header: Header,
+    state: StreamState<R>,
+}
+
+impl<R> std::fmt::Debug for AsyncReader<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncReader")
+            .field("schema", &self.schema)
+            .field("header", &self.header)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R> AsyncReader<R> {
+    /// Return the Arrow schema of the decoded [`RecordBatch`]es
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Return the Avro container-file header
+    pub fn avro_header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Only decode the data blocks of the byte `range` of the file, see [Splitting]
+    ///
+    /// This has no effect once the reader has been polled.
+    ///
+    /// [Splitting]: Self#splitting
+    pub fn with_range(mut self, range: Range<u64>) -> Self {
+        if let StreamState::Init(state) = &mut self.state {
+            state.range = range;
+        }
+        self
+    }
+
+    /// Sets the number of bytes requested by each call to [`AsyncFileReader::get_bytes`],
+    /// defaults to 1 MiB
+    ///
+    /// This has no effect once the reader has been polled.
+    pub fn with_fetch_size(mut self, fetch_size: usize) -> Self {
+        if let StreamState::Init(state) = &mut self.state {
+            state.fetch_size = fetch_size.max(1);
+        }
+        self
+    }
+}
+
+type ReadResult<R> = (Box<ReadState<R>>, Result<Option<RecordBatch>, ArrowError>);
+
+enum StreamState<R> {
+    /// Waiting to read the next batch
+    Init(Box<ReadState<R>>),
+    /// Reading the next batch
+    Reading(BoxFuture<'static, ReadResult<R>>),
+    /// Finished, or failed with an error
+    Done,
+}
+
+impl<R: AsyncFileReader + 'static> Stream for AsyncReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            match std::mem::replace(&mut self.state, StreamState::Done) {
+                StreamState::Init(mut state) => {
+                    let fut = async move {
+                        let result = state.read().await;
+                        (state, result)
+                    };
+                    self.state = StreamState::Reading(fut.boxed());
+                }
+                StreamState::Reading(mut fut) => {
+                    let (state, result) = match fut.poll_unpin(cx) {
+                        Poll::Ready(ready) => ready,
+                        Poll::Pending => {
+                            self.state = StreamState::Reading(fut);
+                            return Poll::Pending;
+                        }
+                    };
+                    return Poll::Ready(match result {
+                        Ok(Some(batch)) => {
+                            self.state = StreamState::Init(state);
+                            Some(Ok(batch))
+                        }
+                        Ok(None) => None,
+                        Err(e) => Some(Err(e)),
+                    });
+                }
+                StreamState::Done => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+/// The state of an [`AsyncReader`]
+struct ReadState<R> {
+    reader: R,
+    file_size: u64,
+    sync: [u8; 16],
+    compression: Option<CompressionCodec>,
+    decoder: Decoder,
+    block_decoder: BlockDecoder,
+    /// The fetched bytes not yet decoded, starting at `offset` in the file
+    buffer: Bytes,
+    offset: u64,
+    range: Range<u64>,
+    fetch_size: usize,
+    /// Whether `offset` has been positioned at the first block of `range`
+    positioned: bool,
+    /// Whether all blocks of `range` have been read
+    finished: bool,
+    block_data: Vec<u8>,
+    block_cursor: usize,
+}
+
+impl<R: AsyncFileReader> ReadState<R> {
+    /// Reads the next [`RecordBatch`] or `Ok(None)` once all blocks have been read
+    async fn read(&mut self) -> Result<Option<RecordBatch>, ArrowError> {
+        while !self.decoder.batch_is_full() {
+            if self.block_cursor == self.block_data.len() {
+                match self.next_block().await? {
+                    Some(data) => {
+                        self.block_data = data;
+                        self.block_cursor = 0;
+                    }
+                    None => break,
+                }
+                continue;
+            }
+            let consumed = self.decoder.decode(&self.block_data[self.block_cursor..])?;
+            if consumed == 0 {
+                self.block_cursor = self.block_data.len();
+            } else {
+                self.block_cursor += consumed;
+            }
+        }
+        self.decoder.flush()
+    }
+
+    /// Returns the decompressed data of the next block of `range`, if any
+    async fn next_block(&mut self) -> Result<Option<Vec<u8>>, ArrowError> {
+        if !self.positioned {
+            self.positioned = true;
+            self.finished = !self.seek_first_block().await?;
+        }
+        // The block belongs to the range containing its preceding sync marker
+        let sync_start = self.offset - SYNC_LEN as u64;
+        if self.finished || sync_start >= self.range.end || self.offset >= self.file_size {
+            self.finished = true;
+            return Ok(None);
+        }
+        loop {
+            if self.buffer.is_empty() {
+                self.fetch().await?;
+            }
+            let consumed = self.block_decoder.decode(&self.buffer)?;
+            self.advance(consumed);
+            if let Some(block) = self.block_decoder.flush() {
+                if block.sync != self.sync {
+                    return Err(ArrowError::ParseError(format!(
+                        "Invalid sync marker for block ending at offset {}",
+                        self.offset
+                    )));
+                }
+                return Ok(Some(match &self.compression {
+                    Some(codec) => codec.decompress(&block.data)?,
+                    None => block.data,
+                }));
+            }
+        }
+    }
+
+    /// Positions `offset` at the first block whose preceding sync marker starts within
+    /// `range`, returning `false` if there is no such block
+    async fn seek_first_block(&mut self) -> Result<bool, ArrowError> {
+        // `offset` is initially the end of the header
+        if self.offset - SYNC_LEN as u64 >= self.range.start {
+            return Ok(true);
+        }
+        let buffer_end = self.offset + self.buffer.len() as u64;
+        if self.range.start >= buffer_end {
+            self.buffer = Bytes::new();
+            self.offset = self.range.start;
+        } else if self.range.start > self.offset {
+            self.advance((self.range.start - self.offset) as usize);
+        }
+        loop {
+            let found = self.buffer.windows(SYNC_LEN).position(|w| w == self.sync);
+            if let Some(position) = found {
+                self.advance(position + SYNC_LEN);
+                return Ok(true);
+            }
+            // Retain a possible partial sync marker at the end of the buffer
+            self.advance(self.buffer.len().saturating_sub(SYNC_LEN - 1));
+            let buffer_end = self.offset + self.buffer.len() as u64;
+            if self.offset >= self.range.end || buffer_end >= self.file_size {
+                return Ok(false);
+            }
+            self.fetch().await?;
+        }
+    }
+
+    /// Fetches the next bytes of the file following `buffer`
+    async fn fetch(&mut self) -> Result<(), ArrowError> {
+        let start = self.offset + self.buffer.len() as u64;
+        let end = self.file_size.min(start + self.fetch_size as u64);
+        if start >= end {
+            return Err(ArrowError::ParseError(
+                "Unexpected EOF while reading Avro block".to_string(),
+            ));
+        }
+        let data = self.reader.get_bytes(start..end).await?;
+        if data.len() as u64 != end - start {
+            return Err(ArrowError::ParseError(format!(
+                "Expected {} bytes at offset {start}, got {}",
+                end - start,
+                data.len()
+            )));
+        }
+        self.buffer = match self.buffer.is_empty() {
+            true => data,
+            false => [self.buffer.as_ref(), data.as_ref()].concat().into(),
+        };
+        Ok(())
+    }
+
+    fn advance(&mut self, n: usize) {
+        self.buffer.advance(n);
+        self.offset += n as u64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compression::CompressionCodec;
+    use crate::writer::WriterBuilder;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::{ArrayRef, Int64Array, StringArray};
+    use futures::executor::block_on;
+    use futures::TryStreamExt;
+    use std::sync::Arc;
+
+    /// Writes an Avro file with a block for each of the given batch sizes, returning the
+    /// file and the concatenation of the written batches
+    fn write_file(sizes: &[i64], compression: Option<CompressionCodec>) -> (Bytes, RecordBatch) {
+        let mut offset = 0;
+        let batches: Vec<_> = sizes
+            .iter()
+            .map(|size| {
+                let ids = Int64Array::from_iter_values(offset..offset + size);
+                let names =
+                    StringArray::from_iter_values(ids.values().iter().map(|v| v.to_string()));
+                offset += size;
+                RecordBatch::try_from_iter([
+                    ("id", Arc::new(ids) as ArrayRef),
+                    ("name", Arc::new(names) as ArrayRef),
+                ])
+                .unwrap()
+            })
+            .collect();
+        let schema = batches[0].schema();
+        let mut writer = WriterBuilder::new(schema.as_ref().clone())
+            .with_compression(compression)
+            .build(Vec::new())
+            .unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        let data = writer.into_inner().unwrap();
+        let expected = arrow::compute::concat_batches(&schema, &batches).unwrap();
+        (data.into(), expected)
+    }
+
+    fn read(reader: AsyncReader<Bytes>) -> RecordBatch {
+        let schema = reader.schema();
+        let batches: Vec<_> = block_on(reader.try_collect()).unwrap();
+        arrow::compute::concat_batches(&schema, &batches).unwrap()
+    }
+
+    #[test]
+    fn test_async_reader() {
+        let (data, expected) = write_file(&[10, 0, 200, 33], None);
+        for fetch_size in [1, 7, 64, DEFAULT_FETCH_SIZE] {
+            let reader = block_on(
+                ReaderBuilder::new()
+                    .with_batch_size(64)
+                    .build_async(data.clone(), data.len() as u64),
+            )
+            .unwrap()
+            .with_fetch_size(fetch_size);
+            assert_eq!(read(reader), expected, "fetch size {fetch_size}");
+        }
+    }
+
+    #[test]
+    fn test_async_reader_ranges() {
+        for compression in [None, Some(CompressionCodec::Deflate)] {
+            let (data, expected) = write_file(&[10, 50, 1, 200, 33], compression);
+            let len = data.len() as u64;
+            for split in [1, 13, 100, 512, len] {
+                let batches: Vec<_> = (0..len.div_ceil(split))
+                    .map(|i| {
+                        let range = i * split..len.min((i + 1) * split);
+                        let reader = block_on(ReaderBuilder::new().build_async(data.clone(), len))
+                            .unwrap()
+                            .with_fetch_size(31)
+                            .with_range(range);
+                        read(reader)
+                    })
+                    .collect();
+                let actual = arrow::compute::concat_batches(&expected.schema(), &batches).unwrap();
+                assert_eq!(actual, expected, "split {split} {compression:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_async_reader_errors() {
+        let (data, _) = write_file(&[10, 20], None);
+        let err = block_on(ReaderBuilder::new().build_async(data.slice(..20), 20)).unwrap_err();
+        assert!(err.to_string().contains("Unexpected EOF"), "{err}");
+
+        // Corrupt the trailing sync marker of the last block
+        let mut corrupt = data.to_vec();
+        let len = corrupt.len();
+        corrupt[len - 1] ^= 1;
+        let builder = ReaderBuilder::new().with_batch_size(10);
+        let reader = block_on(builder.build_async(Bytes::from(corrupt), len as u64)).unwrap();
+        let mut batches = vec![];
+        let err = block_on(async {
+            let mut reader = reader;
+            while let Some(batch) = reader.try_next().await? {
+                batches.push(batch);
+            }
+            Ok::<_, ArrowError>(())
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("Invalid sync marker"), "{err}");
+        assert_eq!(batches.len(), 1);
+        assert_eq!(
+            batches[0].column(0).as_primitive::<Int64Type>().values(),
+            &(0..10).collect::<Vec<_>>()
+        );
+    }
+}
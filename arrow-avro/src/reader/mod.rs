@@ -23,6 +23,8 @@
 //! * `ReaderBuilder`: Configures Avro reading, e.g., batch size
 //! * `Reader`: Yields `RecordBatch` values, implementing `Iterator`
 //! * `Decoder`: A low-level push-based decoder for Avro records
+//! * `AsyncReader`: Yields `RecordBatch` values from ranged reads of a file, implementing
+//!   `Stream` (requires the `async` feature)
 //!
 //! # Basic Usage
 //!
@@ -85,22 +87,74 @@
 //! }
 //! ```
 //!
+//! # Framed Messages
+//!
+//! Avro records are commonly exchanged individually, for example as the payload of
+//! messages in a message broker, prefixed with an identifier of their writer schema.
+//! A [`Decoder`] configured with a [`SchemaStore`] decodes such messages, looking up the
+//! writer schema of each message by its [`Fingerprint`]. Both the Avro
+//! [single object encoding] and the Confluent schema registry [wire format] are supported,
+//! see [`WireFormat`].
+//!
+//! ```
+//! # use std::sync::Arc;
+//! # use arrow_avro::reader::{Fingerprint, InMemorySchemaStore, ReaderBuilder, SINGLE_OBJECT_MAGIC};
+//! let schema = r#"{"type":"record","name":"r","fields":[{"name":"a","type":"long"}]}"#;
+//! let mut store = InMemorySchemaStore::new();
+//! let fingerprint = store.register(schema).unwrap();
+//!
+//! let mut decoder = ReaderBuilder::new()
+//!     .with_writer_schema_store(Arc::new(store))
+//!     .with_active_fingerprint(fingerprint)
+//!     .build_decoder(std::io::empty())
+//!     .unwrap();
+//!
+//! // A message containing the record `{"a": 1}`
+//! let Fingerprint::Rabin(fp) = fingerprint else { unreachable!() };
+//! let mut message = SINGLE_OBJECT_MAGIC.to_vec();
+//! message.extend_from_slice(&fp.to_le_bytes());
+//! message.push(2);
+//!
+//! assert_eq!(decoder.decode(&message).unwrap(), message.len());
+//! let batch = decoder.flush().unwrap().unwrap();
+//! assert_eq!(batch.num_rows(), 1);
+//! ```
+//!
+//! [single object encoding]: https://avro.apache.org/docs/1.11.1/specification/#single-object-encoding
+//! [wire format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+//! [`Decoder`]: crate::reader::Decoder
+//! [`SchemaStore`]: crate::reader::SchemaStore
+//! [`Fingerprint`]: crate::reader::Fingerprint
+//! [`WireFormat`]: crate::reader::WireFormat
 
 use crate::codec::AvroField;
 use crate::schema::Schema as AvroSchema;
 use arrow_array::{RecordBatch, RecordBatchReader};
 use arrow_schema::{ArrowError, SchemaRef};
+use arrow_select::concat::concat_batches;
 use block::BlockDecoder;
 use header::{Header, HeaderDecoder};
 use record::RecordDecoder;
+use std::collections::HashMap;
 use std::io::BufRead;
+use std::sync::Arc;
 
+#[cfg(feature = "async")]
+mod async_reader;
 mod block;
 mod cursor;
 mod header;
 mod record;
+mod store;
 mod vlq;
 
+#[cfg(feature = "async")]
+pub use async_reader::{AsyncFileReader, AsyncReader};
+
+pub use store::{
+    Fingerprint, InMemorySchemaStore, SchemaStore, WireFormat, CONFLUENT_MAGIC, SINGLE_OBJECT_MAGIC,
+};
+
 /// Read the Avro file header (magic, metadata, sync marker) from `reader`.
 fn read_header<R: BufRead>(mut reader: R) -> Result<Header, ArrowError> {
     let mut decoder = HeaderDecoder::default();
@@ -121,12 +175,96 @@ fn read_header<R: BufRead>(mut reader: R) -> Result<Header, ArrowError> {
     })
 }
 
+/// Creates a [`RecordDecoder`] for data encoded with the `writer` schema, resolving it
+/// against the `reader` schema if provided
+///
+/// If `projection` is provided, only the fields of the reader schema, or the writer schema
+/// if no reader schema is provided, at the given indices are decoded
+fn make_record_decoder(
+    writer: &AvroSchema<'_>,
+    reader: Option<&AvroSchema<'_>>,
+    utf8_view: bool,
+    strict_mode: bool,
+    projection: Option<&[usize]>,
+) -> Result<RecordDecoder, ArrowError> {
+    let reader = reader.unwrap_or(writer);
+    let root_field = match projection {
+        None if reader == writer => AvroField::try_from(writer)?,
+        _ => AvroField::resolve(writer, reader, utf8_view, projection)?,
+    };
+    RecordDecoder::try_new_with_options(root_field.data_type(), utf8_view, strict_mode)
+}
+
+/// The state of a [`Decoder`] decoding framed messages
+#[derive(Debug)]
+struct Framing {
+    format: WireFormat,
+    store: Arc<dyn SchemaStore>,
+    reader_schema: Option<AvroSchema<'static>>,
+    utf8_view: bool,
+    strict_mode: bool,
+    projection: Option<Vec<usize>>,
+    /// The fingerprint of the writer schema of the active [`RecordDecoder`]
+    active: Option<Fingerprint>,
+    /// The inactive decoders of previously encountered writer schemas
+    decoders: HashMap<Fingerprint, RecordDecoder>,
+    /// Rows decoded with previous writer schemas not yet returned by [`Decoder::flush`]
+    ///
+    /// Only populated when a reader schema is configured, as otherwise the decoded
+    /// schema changes along with the writer schema
+    pending: Vec<RecordBatch>,
+}
+
+impl Framing {
+    fn make_record_decoder(&self, fingerprint: Fingerprint) -> Result<RecordDecoder, ArrowError> {
+        let schema = self.store.lookup(&fingerprint)?.ok_or_else(|| {
+            ArrowError::ParseError(format!(
+                "Writer schema for {fingerprint:?} not found in schema store"
+            ))
+        })?;
+        let writer: AvroSchema<'_> = serde_json::from_str(&schema).map_err(|e| {
+            ArrowError::ParseError(format!("Failed to parse Avro schema JSON: {e}"))
+        })?;
+        make_record_decoder(
+            &writer,
+            self.reader_schema.as_ref(),
+            self.utf8_view,
+            self.strict_mode,
+            self.projection.as_deref(),
+        )
+    }
+
+    /// Makes the decoder for `fingerprint` the active decoder, swapping it with `active`
+    fn activate(
+        &mut self,
+        fingerprint: Fingerprint,
+        active: &mut RecordDecoder,
+    ) -> Result<(), ArrowError> {
+        let decoder = match self.decoders.remove(&fingerprint) {
+            Some(decoder) => decoder,
+            None => self.make_record_decoder(fingerprint)?,
+        };
+        let previous = std::mem::replace(active, decoder);
+        if let Some(previous_fingerprint) = self.active.replace(fingerprint) {
+            self.decoders.insert(previous_fingerprint, previous);
+        }
+        Ok(())
+    }
+}
+
 /// A low-level interface for decoding Avro-encoded bytes into Arrow `RecordBatch`.
+///
+/// If configured with a [`SchemaStore`], see [`ReaderBuilder::with_writer_schema_store`],
+/// each record is expected to be prefixed as described by the configured [`WireFormat`].
+/// Without a reader schema, when a record is encoded with a different writer schema than
+/// the preceding records, [`Self::decode`] stops before it, requiring the decoded rows to
+/// be flushed with [`Self::flush`] before decoding continues with the new writer schema.
 #[derive(Debug)]
 pub struct Decoder {
     record_decoder: RecordDecoder,
     batch_size: usize,
     decoded_rows: usize,
+    framing: Option<Framing>,
 }
 
 impl Decoder {
@@ -135,10 +273,14 @@ impl Decoder {
             record_decoder,
             batch_size,
             decoded_rows: 0,
+            framing: None,
         }
     }
 
     /// Return the Arrow schema for the rows decoded by this decoder
+    ///
+    /// When decoding framed messages without a reader schema, this is the schema of
+    /// the writer schema of the most recently decoded record.
     pub fn schema(&self) -> SchemaRef {
         self.record_decoder.schema().clone()
     }
@@ -156,8 +298,25 @@ impl Decoder {
     pub fn decode(&mut self, data: &[u8]) -> Result<usize, ArrowError> {
         let mut total_consumed = 0usize;
         while total_consumed < data.len() && self.decoded_rows < self.batch_size {
+            if let Some(framing) = &mut self.framing {
+                let Some(fingerprint) = framing.format.parse_prefix(&data[total_consumed..])?
+                else {
+                    break;
+                };
+                if framing.active != Some(fingerprint) {
+                    if self.decoded_rows != 0 {
+                        if framing.reader_schema.is_none() {
+                            // Rows decoded with the previous writer schema must be flushed first
+                            break;
+                        }
+                        framing.pending.push(self.record_decoder.flush()?);
+                    }
+                    framing.activate(fingerprint, &mut self.record_decoder)?;
+                }
+                total_consumed += framing.format.prefix_len();
+            }
             let consumed = self.record_decoder.decode(&data[total_consumed..], 1)?;
-            if consumed == 0 {
+            if consumed == 0 && self.framing.is_none() {
                 break;
             }
             total_consumed += consumed;
@@ -172,7 +331,12 @@ impl Decoder {
         if self.decoded_rows == 0 {
             Ok(None)
         } else {
-            let batch = self.record_decoder.flush()?;
+            let mut batch = self.record_decoder.flush()?;
+            if let Some(framing) = self.framing.as_mut().filter(|f| !f.pending.is_empty()) {
+                framing.pending.push(batch);
+                batch = concat_batches(&framing.pending[0].schema(), &framing.pending)?;
+                framing.pending.clear();
+            }
             self.decoded_rows = 0;
             Ok(Some(batch))
         }
@@ -197,6 +361,10 @@ pub struct ReaderBuilder {
     strict_mode: bool,
     utf8_view: bool,
     schema: Option<AvroSchema<'static>>,
+    writer_schema_store: Option<Arc<dyn SchemaStore>>,
+    wire_format: WireFormat,
+    active_fingerprint: Option<Fingerprint>,
+    projection: Option<Vec<usize>>,
 }
 
 impl Default for ReaderBuilder {
@@ -206,6 +374,10 @@ impl Default for ReaderBuilder {
             strict_mode: false,
             utf8_view: false,
             schema: None,
+            writer_schema_store: None,
+            wire_format: WireFormat::default(),
+            active_fingerprint: None,
+            projection: None,
         }
     }
 }
@@ -216,36 +388,88 @@ impl ReaderBuilder {
     /// - `strict_mode` = false
     /// - `utf8_view` = false
     /// - `schema` = None
+    /// - `writer_schema_store` = None
+    /// - `wire_format` = [`WireFormat::SingleObject`]
+    /// - `projection` = None
     pub fn new() -> Self {
         Self::default()
     }
 
     fn make_record_decoder(&self, schema: &AvroSchema<'_>) -> Result<RecordDecoder, ArrowError> {
-        let root_field = AvroField::try_from(schema)?;
-        RecordDecoder::try_new_with_options(
-            root_field.data_type(),
+        make_record_decoder(
+            schema,
+            None,
             self.utf8_view,
             self.strict_mode,
+            self.projection.as_deref(),
         )
     }
 
+    fn build_framed_decoder(self, store: Arc<dyn SchemaStore>) -> Result<Decoder, ArrowError> {
+        let mut framing = Framing {
+            format: self.wire_format,
+            store,
+            reader_schema: self.schema,
+            utf8_view: self.utf8_view,
+            strict_mode: self.strict_mode,
+            projection: self.projection,
+            active: None,
+            decoders: HashMap::new(),
+            pending: vec![],
+        };
+        let record_decoder =
+            match (self.active_fingerprint, &framing.reader_schema) {
+                (Some(fingerprint), _) => {
+                    framing.active = Some(fingerprint);
+                    framing.make_record_decoder(fingerprint)?
+                }
+                (None, Some(reader)) => make_record_decoder(
+                    reader,
+                    None,
+                    self.utf8_view,
+                    self.strict_mode,
+                    framing.projection.as_deref(),
+                )?,
+                (None, None) => return Err(ArrowError::InvalidArgumentError(
+                    "Decoding framed messages requires a reader schema or an active fingerprint"
+                        .to_string(),
+                )),
+            };
+        let mut decoder = Decoder::new(record_decoder, self.batch_size);
+        decoder.framing = Some(framing);
+        Ok(decoder)
+    }
+
     fn build_impl<R: BufRead>(self, reader: &mut R) -> Result<(Header, Decoder), ArrowError> {
         let header = read_header(reader)?;
-        let record_decoder = if let Some(schema) = &self.schema {
-            self.make_record_decoder(schema)?
-        } else {
-            let avro_schema: Option<AvroSchema<'_>> = header
-                .schema()
-                .map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
-            let avro_schema = avro_schema.ok_or_else(|| {
-                ArrowError::ParseError("No Avro schema present in file header".to_string())
-            })?;
-            self.make_record_decoder(&avro_schema)?
-        };
-        let decoder = Decoder::new(record_decoder, self.batch_size);
+        let decoder = self.make_decoder(&header)?;
         Ok((header, decoder))
     }
 
+    /// Creates a [`Decoder`] for the blocks of the file with the given [`Header`]
+    fn make_decoder(&self, header: &Header) -> Result<Decoder, ArrowError> {
+        let writer_schema: Option<AvroSchema<'_>> = header
+            .schema()
+            .map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
+        let record_decoder = match (writer_schema, &self.schema) {
+            (Some(writer), Some(reader)) => make_record_decoder(
+                &writer,
+                Some(reader),
+                self.utf8_view,
+                self.strict_mode,
+                self.projection.as_deref(),
+            )?,
+            (None, Some(reader)) => self.make_record_decoder(reader)?,
+            (Some(writer), None) => self.make_record_decoder(&writer)?,
+            (None, None) => {
+                return Err(ArrowError::ParseError(
+                    "No Avro schema present in file header".to_string(),
+                ))
+            }
+        };
+        Ok(Decoder::new(record_decoder, self.batch_size))
+    }
+
     /// Sets the row-based batch size
     pub fn with_batch_size(mut self, batch_size: usize) -> Self {
         self.batch_size = batch_size;
@@ -272,14 +496,73 @@ impl ReaderBuilder {
         self
     }
 
-    /// Sets the Avro schema.
+    /// Sets the Avro reader schema.
     ///
     /// If a schema is not provided, the schema will be read from the Avro file header.
+    ///
+    /// When reading a file whose header contains a different writer schema, the data
+    /// is resolved from the writer schema to this schema following the Avro
+    /// [schema resolution] rules, producing the arrow type of this schema. This
+    /// supports reordered fields, defaults for fields missing in the writer schema,
+    /// skipping writer fields missing in this schema, numeric and `bytes`/`string`
+    /// promotion, enum symbol mapping and resolution of nullable unions. Each variant of
+    /// other writer unions is resolved against the first reader union variant it matches,
+    /// named types matching by name, and an error is returned if any variant has no
+    /// match. A writer union can also be read as a type that is not a union, in which
+    /// case decoding a value of a variant that does not match it returns an error.
+    ///
+    /// When decoding data without a header, see [`Self::build_decoder`], this schema is
+    /// used as the writer schema.
+    ///
+    /// [schema resolution]: https://avro.apache.org/docs/1.11.1/specification/#schema-resolution
     pub fn with_schema(mut self, schema: AvroSchema<'static>) -> Self {
         self.schema = Some(schema);
         self
     }
 
+    /// Sets the indices of the top-level fields to decode
+    ///
+    /// The indices refer to the fields of the reader schema set with [`Self::with_schema`],
+    /// or of the writer schema otherwise, and determine the order of the columns of the
+    /// decoded batches. The data of all other fields, including nested records, arrays and
+    /// maps, is skipped without being decoded.
+    ///
+    /// Building a [`Reader`] or [`Decoder`] returns an error if an index is out of bounds
+    /// or repeated
+    pub fn with_projection(mut self, projection: Vec<usize>) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Sets the [`SchemaStore`] used to look up the writer schema of framed messages
+    ///
+    /// When set, a [`Decoder`] created by [`Self::build_decoder`] decodes records prefixed
+    /// as described by [`Self::with_wire_format`], rather than the body of an Object Container
+    /// File block. If a reader schema is set with [`Self::with_schema`], each writer schema is
+    /// resolved against it, otherwise the arrow schema of the decoded batches follows the
+    /// writer schema of the decoded records.
+    pub fn with_writer_schema_store(mut self, store: Arc<dyn SchemaStore>) -> Self {
+        self.writer_schema_store = Some(store);
+        self
+    }
+
+    /// Sets the [`WireFormat`] of framed messages, defaults to [`WireFormat::SingleObject`]
+    ///
+    /// This is only used in combination with [`Self::with_writer_schema_store`]
+    pub fn with_wire_format(mut self, wire_format: WireFormat) -> Self {
+        self.wire_format = wire_format;
+        self
+    }
+
+    /// Sets the [`Fingerprint`] of the writer schema expected for the first framed message
+    ///
+    /// This determines the initial [`Decoder::schema`] when decoding framed messages, and
+    /// is required if no reader schema is set with [`Self::with_schema`]
+    pub fn with_active_fingerprint(mut self, fingerprint: Fingerprint) -> Self {
+        self.active_fingerprint = Some(fingerprint);
+        self
+    }
+
     /// Create a [`Reader`] from this builder and a `BufRead`
     pub fn build<R: BufRead>(self, mut reader: R) -> Result<Reader<R>, ArrowError> {
         let (header, decoder) = self.build_impl(&mut reader)?;
@@ -297,7 +580,13 @@ impl ReaderBuilder {
     /// Create a [`Decoder`] from this builder and a `BufRead` by
     /// reading and parsing the Avro file's header. This will
     /// not create a full [`Reader`].
+    ///
+    /// No header is read if a schema is provided with [`Self::with_schema`], or if
+    /// decoding framed messages with [`Self::with_writer_schema_store`].
     pub fn build_decoder<R: BufRead>(self, mut reader: R) -> Result<Decoder, ArrowError> {
+        if let Some(store) = self.writer_schema_store.clone() {
+            return self.build_framed_decoder(store);
+        }
         match self.schema {
             Some(ref schema) => {
                 let record_decoder = self.make_record_decoder(schema)?;
@@ -395,11 +684,16 @@ mod test {
     use crate::compression::CompressionCodec;
     use crate::reader::record::RecordDecoder;
     use crate::reader::vlq::VLQDecoder;
-    use crate::reader::{read_header, Decoder, ReaderBuilder};
+    use crate::reader::{
+        read_header, Decoder, Fingerprint, InMemorySchemaStore, ReaderBuilder, WireFormat,
+        SINGLE_OBJECT_MAGIC,
+    };
     use crate::test_util::arrow_test_data;
-    use arrow_array::types::{Int32Type, IntervalMonthDayNanoType};
+    use crate::writer::WriterBuilder;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Float64Type, Int32Type, Int64Type, IntervalMonthDayNanoType};
     use arrow_array::*;
-    use arrow_schema::{ArrowError, DataType, Field, IntervalUnit, Schema};
+    use arrow_schema::{ArrowError, DataType, Field, Fields, IntervalUnit, Schema};
     use bytes::{Buf, BufMut, Bytes};
     use futures::executor::block_on;
     use futures::{stream, Stream, StreamExt, TryStreamExt};
@@ -441,6 +735,513 @@ mod test {
         }
     }
 
+    fn write_avro(batch: &RecordBatch) -> Vec<u8> {
+        let mut writer = WriterBuilder::new(batch.schema().as_ref().clone())
+            .build(Vec::new())
+            .unwrap();
+        writer.write(batch).unwrap();
+        writer.into_inner().unwrap()
+    }
+
+    fn read_with_schema(
+        data: &[u8],
+        reader_schema: &'static str,
+    ) -> Result<RecordBatch, ArrowError> {
+        let schema: crate::schema::Schema<'static> = serde_json::from_str(reader_schema).unwrap();
+        let reader = ReaderBuilder::new()
+            .with_schema(schema)
+            .build(Cursor::new(data))?;
+        let schema = reader.schema();
+        let batches = reader.collect::<Result<Vec<_>, _>>()?;
+        arrow::compute::concat_batches(&schema, &batches)
+    }
+
+    #[test]
+    fn test_schema_resolution() {
+        let s_fields = Fields::from(vec![
+            Field::new("x", DataType::Int32, false),
+            Field::new("y", DataType::Utf8, false),
+        ]);
+        let s = StructArray::new(
+            s_fields.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])) as _,
+                Arc::new(StringArray::from(vec!["s1", "s2", "s3"])) as _,
+            ],
+            None,
+        );
+        let l = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+        ]);
+        let writer_batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new("b", DataType::Utf8, false),
+                Field::new("c", DataType::Float64, false),
+                Field::new("d", DataType::Int64, true),
+                Field::new("s", DataType::Struct(s_fields), false),
+                Field::new("l", l.data_type().clone(), true),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])) as _,
+                Arc::new(StringArray::from(vec!["x", "y", "z"])) as _,
+                Arc::new(Float64Array::from(vec![1.5, 2.5, 3.5])) as _,
+                Arc::new(Int64Array::from(vec![Some(1), None, Some(3)])) as _,
+                Arc::new(s) as _,
+                Arc::new(l) as _,
+            ],
+        )
+        .unwrap();
+        let data = write_avro(&writer_batch);
+
+        let reader_schema = r#"{
+            "type": "record",
+            "name": "topLevelRecord",
+            "fields": [
+                {"name": "d", "type": ["null", "double"], "default": null},
+                {"name": "a", "type": ["long", "null"]},
+                {"name": "bee", "type": "bytes", "aliases": ["b"]},
+                {"name": "e", "type": "int", "default": 42},
+                {"name": "f", "type": ["null", "string"], "default": null},
+                {"name": "g", "type": {"type": "array", "items": "string"}, "default": ["p", "q"]},
+                {"name": "s", "type": {
+                    "type": "record",
+                    "name": "s",
+                    "fields": [
+                        {"name": "y", "type": "string"},
+                        {"name": "z", "type": "boolean", "default": true}
+                    ]
+                }}
+            ]
+        }"#;
+        let batch = read_with_schema(&data, reader_schema).unwrap();
+
+        let schema = batch.schema();
+        let names: Vec<_> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(names, ["d", "a", "bee", "e", "f", "g", "s"]);
+        let nullable: Vec<_> = schema.fields().iter().map(|f| f.is_nullable()).collect();
+        assert_eq!(nullable, [true, true, false, false, true, false, false]);
+
+        let d = batch.column(0).as_primitive::<Float64Type>();
+        assert_eq!(d, &Float64Array::from(vec![Some(1.0), None, Some(3.0)]));
+        let a = batch.column(1).as_primitive::<Int64Type>();
+        assert_eq!(a, &Int64Array::from(vec![1, 2, 3]));
+        let bee = batch.column(2).as_binary::<i32>();
+        assert_eq!(bee, &BinaryArray::from_iter_values([b"x", b"y", b"z"]));
+        let e = batch.column(3).as_primitive::<Int32Type>();
+        assert_eq!(e, &Int32Array::from(vec![42, 42, 42]));
+        assert_eq!(batch.column(4).null_count(), 3);
+        let g = batch.column(5).as_list::<i32>();
+        for idx in 0..3 {
+            let values = g.value(idx);
+            assert_eq!(
+                values.as_string::<i32>(),
+                &StringArray::from(vec!["p", "q"])
+            );
+        }
+        let s = batch.column(6).as_struct();
+        assert_eq!(
+            s.column_by_name("y").unwrap().as_string::<i32>(),
+            &StringArray::from(vec!["s1", "s2", "s3"])
+        );
+        assert_eq!(
+            s.column_by_name("z").unwrap().as_boolean(),
+            &BooleanArray::from(vec![true, true, true])
+        );
+    }
+
+    #[test]
+    fn test_schema_resolution_enum() {
+        let mut metadata = HashMap::new();
+        metadata.insert(
+            "avro.enum.symbols".to_string(),
+            r#"["a","b","c"]"#.to_string(),
+        );
+        let dict = DictionaryArray::<Int32Type>::try_new(
+            Int32Array::from(vec![0, 1, 2]),
+            Arc::new(StringArray::from(vec!["a", "b", "c"])),
+        )
+        .unwrap();
+        let field = Field::new("e", dict.data_type().clone(), false).with_metadata(metadata);
+        let writer_batch =
+            RecordBatch::try_new(Arc::new(Schema::new(vec![field])), vec![Arc::new(dict)]).unwrap();
+        let data = write_avro(&writer_batch);
+
+        let batch = read_with_schema(
+            &data,
+            r#"{"type": "record", "name": "topLevelRecord", "fields": [{"name": "e", "type": {
+                "type": "enum", "name": "e", "symbols": ["c", "b", "z"], "default": "z"
+            }}]}"#,
+        )
+        .unwrap();
+        let e = batch.column(0).as_dictionary::<Int32Type>();
+        assert_eq!(e.keys(), &Int32Array::from(vec![2, 1, 0]));
+        assert_eq!(
+            e.values().as_string::<i32>(),
+            &StringArray::from(vec!["c", "b", "z"])
+        );
+
+        let err = read_with_schema(
+            &data,
+            r#"{"type": "record", "name": "topLevelRecord", "fields": [{"name": "e", "type": {
+                "type": "enum", "name": "e", "symbols": ["c", "b"]
+            }}]}"#,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Writer enum symbol 0 is not a symbol of the reader enum"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_schema_resolution_errors() {
+        let writer_batch = RecordBatch::try_from_iter_with_nullable([
+            (
+                "a",
+                Arc::new(StringArray::from(vec!["x"])) as ArrayRef,
+                false,
+            ),
+            (
+                "b",
+                Arc::new(Int64Array::from(vec![None])) as ArrayRef,
+                true,
+            ),
+        ])
+        .unwrap();
+        let data = write_avro(&writer_batch);
+
+        let err = read_with_schema(
+            &data,
+            r#"{"type": "record", "name": "r", "fields": [{"name": "c", "type": "int"}]}"#,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Field \"c\" is not present in the writer schema and has no default"),
+            "{err}"
+        );
+
+        let err = read_with_schema(
+            &data,
+            r#"{"type": "record", "name": "r", "fields": [{"name": "a", "type": "int"}]}"#,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains(r#"Cannot resolve writer schema "string" with reader schema "int""#),
+            "{err}"
+        );
+
+        let err = read_with_schema(
+            &data,
+            r#"{"type": "record", "name": "r", "fields": [{"name": "c", "type": "int", "default": "x"}]}"#,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("Invalid default for field \"c\""),
+            "{err}"
+        );
+
+        let err = read_with_schema(
+            &data,
+            r#"{"type": "record", "name": "r", "fields": [{"name": "b", "type": "long"}]}"#,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Found null value for non-nullable reader type"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_projection() {
+        let s_fields = Fields::from(vec![
+            Field::new("x", DataType::Int32, false),
+            Field::new("y", DataType::Utf8, false),
+        ]);
+        let s = StructArray::new(
+            s_fields.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])) as _,
+                Arc::new(StringArray::from(vec!["s1", "s2", "s3"])) as _,
+            ],
+            None,
+        );
+        let l = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![]),
+        ]);
+        let mut m = builder::MapBuilder::new(
+            None,
+            builder::StringBuilder::new(),
+            builder::Int64Builder::new(),
+        );
+        m.keys().append_value("k1");
+        m.values().append_value(1);
+        m.keys().append_value("k2");
+        m.values().append_value(2);
+        m.append(true).unwrap();
+        m.append(true).unwrap();
+        m.keys().append_value("k3");
+        m.values().append_value(3);
+        m.append(true).unwrap();
+        let m = m.finish();
+        let writer_batch = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new("s", DataType::Struct(s_fields), false),
+                Field::new("l", l.data_type().clone(), true),
+                Field::new("m", m.data_type().clone(), false),
+                Field::new("b", DataType::Utf8, true),
+            ])),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])) as _,
+                Arc::new(s) as _,
+                Arc::new(l) as _,
+                Arc::new(m) as _,
+                Arc::new(StringArray::from(vec![Some("x"), None, Some("z")])) as _,
+            ],
+        )
+        .unwrap();
+        let data = write_avro(&writer_batch);
+
+        let read = |builder: ReaderBuilder| {
+            let reader = builder.with_batch_size(2).build(Cursor::new(&data))?;
+            let schema = reader.schema();
+            let batches = reader.collect::<Result<Vec<_>, _>>()?;
+            arrow::compute::concat_batches(&schema, &batches)
+        };
+
+        let batch = read(ReaderBuilder::new().with_projection(vec![4, 0])).unwrap();
+        assert_eq!(batch.num_columns(), 2);
+        assert_eq!(batch.schema().field(0).name(), "b");
+        assert_eq!(batch.schema().field(1).name(), "a");
+        assert_eq!(
+            batch.column(0).as_string::<i32>(),
+            &StringArray::from(vec![Some("x"), None, Some("z")])
+        );
+        assert_eq!(
+            batch.column(1).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![1, 2, 3])
+        );
+
+        let full = read(ReaderBuilder::new()).unwrap();
+        let batch = read(ReaderBuilder::new().with_projection(vec![2, 3])).unwrap();
+        assert_eq!(batch.columns(), &full.columns()[2..4]);
+
+        let batch = read(ReaderBuilder::new().with_projection(vec![])).unwrap();
+        assert_eq!(batch.num_columns(), 0);
+        assert_eq!(batch.num_rows(), 3);
+
+        // The projection refers to the fields of the reader schema
+        let schema: crate::schema::Schema<'static> = serde_json::from_str(
+            r#"{"type": "record", "name": "r", "fields": [
+                {"name": "e", "type": "int", "default": 42},
+                {"name": "b", "type": ["null", "string"]},
+                {"name": "a", "type": "long"}
+            ]}"#,
+        )
+        .unwrap();
+        let builder = ReaderBuilder::new()
+            .with_schema(schema.clone())
+            .with_projection(vec![2, 0]);
+        let batch = read(builder).unwrap();
+        assert_eq!(
+            batch.column(0).as_primitive::<Int64Type>(),
+            &Int64Array::from(vec![1, 2, 3])
+        );
+        assert_eq!(
+            batch.column(1).as_primitive::<Int32Type>(),
+            &Int32Array::from(vec![42, 42, 42])
+        );
+
+        let builder = ReaderBuilder::new()
+            .with_schema(schema.clone())
+            .with_projection(vec![3]);
+        let err = read(builder).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Projection index 3 out of bounds for record r with 3 fields"),
+            "{err}"
+        );
+
+        let builder = ReaderBuilder::new()
+            .with_schema(schema)
+            .with_projection(vec![0, 2, 0]);
+        let err = read(builder).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Invalid argument error: Duplicate projection index 0 for record r"),
+            "{err}"
+        );
+    }
+
+    const FRAMED_V1: &str = r#"{"type":"record","name":"r","fields":[{"name":"a","type":"long"}]}"#;
+    const FRAMED_V2: &str = r#"{"type":"record","name":"r","fields":[{"name":"a","type":"long"},{"name":"b","type":"string"}]}"#;
+
+    /// Encodes a message with the given `prefix` and record `body`
+    fn framed(prefix: &[u8], a: i64, b: Option<&str>) -> Vec<u8> {
+        let mut out = prefix.to_vec();
+        let mut v = ((a << 1) ^ (a >> 63)) as u64;
+        while v >= 0x80 {
+            out.push((v as u8) | 0x80);
+            v >>= 7;
+        }
+        out.push(v as u8);
+        if let Some(b) = b {
+            out.push((b.len() as u8) << 1);
+            out.extend_from_slice(b.as_bytes());
+        }
+        out
+    }
+
+    fn single_object_prefix(fingerprint: Fingerprint) -> Vec<u8> {
+        let Fingerprint::Rabin(fp) = fingerprint else {
+            unreachable!()
+        };
+        let mut prefix = SINGLE_OBJECT_MAGIC.to_vec();
+        prefix.extend_from_slice(&fp.to_le_bytes());
+        prefix
+    }
+
+    fn decode_framed(decoder: &mut Decoder, data: &[u8]) -> Vec<RecordBatch> {
+        let mut batches = vec![];
+        let mut offset = 0;
+        while offset < data.len() {
+            let consumed = decoder.decode(&data[offset..]).unwrap();
+            offset += consumed;
+            let batch = decoder.flush().unwrap();
+            assert!(consumed > 0 || batch.is_some(), "decoder made no progress");
+            batches.extend(batch);
+        }
+        batches.extend(decoder.flush().unwrap());
+        batches
+    }
+
+    #[test]
+    fn test_single_object_decoding() {
+        let mut store = InMemorySchemaStore::new();
+        let v1 = store.register(FRAMED_V1).unwrap();
+        let v2 = store.register(FRAMED_V2).unwrap();
+
+        let mut data = framed(&single_object_prefix(v1), 1, None);
+        data.extend(framed(&single_object_prefix(v1), -2, None));
+        data.extend(framed(&single_object_prefix(v2), 3, Some("c")));
+        data.extend(framed(&single_object_prefix(v1), 4, None));
+
+        let mut decoder = ReaderBuilder::new()
+            .with_writer_schema_store(Arc::new(store.clone()))
+            .with_active_fingerprint(v1)
+            .build_decoder(std::io::empty())
+            .unwrap();
+        assert_eq!(decoder.schema().fields().len(), 1);
+
+        // Without a reader schema each change of writer schema starts a new batch
+        let batches = decode_framed(&mut decoder, &data);
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[0].num_rows(), 2);
+        assert_eq!(
+            batches[0].column(0).as_primitive::<Int64Type>().values(),
+            &[1, -2]
+        );
+        assert_eq!(batches[1].num_columns(), 2);
+        assert_eq!(batches[1].column(1).as_string::<i32>().value(0), "c");
+        assert_eq!(batches[2].num_columns(), 1);
+        assert_eq!(decoder.schema().fields().len(), 1);
+
+        // With a reader schema each writer schema is resolved against it
+        let reader_schema = r#"{"type":"record","name":"r","fields":[{"name":"a","type":"long"},{"name":"b","type":"string","default":"d"}]}"#;
+        let mut decoder = ReaderBuilder::new()
+            .with_schema(serde_json::from_str(reader_schema).unwrap())
+            .with_writer_schema_store(Arc::new(store))
+            .build_decoder(std::io::empty())
+            .unwrap();
+        let batches = decode_framed(&mut decoder, &data);
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        assert_eq!(
+            batch.column(0).as_primitive::<Int64Type>().values(),
+            &[1, -2, 3, 4]
+        );
+        let b = batch.column(1).as_string::<i32>();
+        assert_eq!(
+            b.iter().collect::<Vec<_>>(),
+            [Some("d"), Some("d"), Some("c"), Some("d")]
+        );
+    }
+
+    #[test]
+    fn test_confluent_decoding() {
+        let mut store = InMemorySchemaStore::new();
+        store
+            .register_with_fingerprint(Fingerprint::Id(7), FRAMED_V2)
+            .unwrap();
+        let prefix = [0, 0, 0, 0, 7];
+
+        let mut decoder = ReaderBuilder::new()
+            .with_writer_schema_store(Arc::new(store))
+            .with_wire_format(WireFormat::Confluent)
+            .with_active_fingerprint(Fingerprint::Id(7))
+            .build_decoder(std::io::empty())
+            .unwrap();
+
+        let mut data = framed(&prefix, 300, Some("x"));
+        let second = framed(&prefix, 5, Some("yz"));
+        // An incomplete prefix is not consumed
+        data.extend_from_slice(&second[..3]);
+        let consumed = decoder.decode(&data).unwrap();
+        assert_eq!(consumed, data.len() - 3);
+        assert_eq!(decoder.decode(&second).unwrap(), second.len());
+
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(
+            batch.column(0).as_primitive::<Int64Type>().values(),
+            &[300, 5]
+        );
+        let b = batch.column(1).as_string::<i32>();
+        assert_eq!(b.iter().collect::<Vec<_>>(), [Some("x"), Some("yz")]);
+    }
+
+    #[test]
+    fn test_framed_decoding_errors() {
+        let mut store = InMemorySchemaStore::new();
+        let v1 = store.register(FRAMED_V1).unwrap();
+        let store = Arc::new(store);
+
+        let err = ReaderBuilder::new()
+            .with_writer_schema_store(store.clone())
+            .build_decoder(std::io::empty())
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("requires a reader schema"),
+            "{err}"
+        );
+
+        let mut decoder = ReaderBuilder::new()
+            .with_writer_schema_store(store)
+            .with_active_fingerprint(v1)
+            .build_decoder(std::io::empty())
+            .unwrap();
+
+        let unknown = framed(&single_object_prefix(Fingerprint::Rabin(42)), 1, None);
+        let err = decoder.decode(&unknown).unwrap_err();
+        assert!(
+            err.to_string().contains("not found in schema store"),
+            "{err}"
+        );
+
+        let err = decoder
+            .decode(&framed(&[0, 0, 0, 0, 1], 1, None))
+            .unwrap_err();
+        assert!(err.to_string().contains("to start with magic"), "{err}");
+    }
+
     #[test]
     fn test_utf8view_support() {
         let schema_json = r#"{
@@ -15,11 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use crate::codec::{AvroDataType, Codec, Nullability};
+use crate::codec::{
+    AvroDataType, AvroField, AvroLiteral, Codec, Nullability, Promotion, ResolutionInfo,
+    ResolvedRecord, ResolvedUnion, MAX_RECURSION_DEPTH,
+};
 use crate::reader::block::{Block, BlockDecoder};
 use crate::reader::cursor::AvroCursor;
 use crate::reader::header::Header;
-use crate::schema::*;
+use crate::schema::Schema;
 use arrow_array::builder::{
     ArrayBuilder, Decimal128Builder, Decimal256Builder, IntervalMonthDayNanoBuilder,
     PrimitiveBuilder,
@@ -29,8 +32,10 @@ use arrow_array::*;
 use arrow_buffer::*;
 use arrow_schema::{
     ArrowError, DataType, Field as ArrowField, FieldRef, Fields, IntervalUnit,
-    Schema as ArrowSchema, SchemaRef, DECIMAL128_MAX_PRECISION, DECIMAL256_MAX_PRECISION,
+    Schema as ArrowSchema, SchemaRef, UnionFields, DECIMAL128_MAX_PRECISION,
+    DECIMAL256_MAX_PRECISION,
 };
+use arrow_select::interleave::interleave;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::io::Read;
@@ -76,6 +81,9 @@ impl<'a> RecordDecoderBuilder<'a> {
 pub(crate) struct RecordDecoder {
     schema: SchemaRef,
     fields: Vec<Decoder>,
+    projector: Option<Projector>,
+    /// The number of decoded records, which may have no fields
+    num_rows: usize,
     use_utf8view: bool,
     strict_mode: bool,
 }
@@ -112,9 +120,11 @@ impl RecordDecoder {
         strict_mode: bool,
     ) -> Result<Self, ArrowError> {
         match Decoder::try_new(data_type)? {
-            Decoder::Record(fields, encodings) => Ok(Self {
+            Decoder::Record(fields, encodings, projector) => Ok(Self {
                 schema: Arc::new(ArrowSchema::new(fields)),
                 fields: encodings,
+                projector,
+                num_rows: 0,
                 use_utf8view,
                 strict_mode,
             }),
@@ -133,9 +143,15 @@ impl RecordDecoder {
     pub(crate) fn decode(&mut self, buf: &[u8], count: usize) -> Result<usize, ArrowError> {
         let mut cursor = AvroCursor::new(buf);
         for _ in 0..count {
-            for field in &mut self.fields {
-                field.decode(&mut cursor)?;
+            match &self.projector {
+                Some(projector) => projector.project(&mut cursor, &mut self.fields)?,
+                None => {
+                    for field in &mut self.fields {
+                        field.decode(&mut cursor)?;
+                    }
+                }
             }
+            self.num_rows += 1;
         }
         Ok(cursor.position())
     }
@@ -148,7 +164,9 @@ impl RecordDecoder {
             .map(|x| x.flush(None))
             .collect::<Result<Vec<_>, _>>()?;
 
-        RecordBatch::try_new(self.schema.clone(), arrays)
+        let options = RecordBatchOptions::new().with_row_count(Some(self.num_rows));
+        self.num_rows = 0;
+        RecordBatch::try_new_with_options(self.schema.clone(), arrays, &options)
     }
 }
 
@@ -160,6 +178,12 @@ enum Decoder {
     Int64(Vec<i64>),
     Float32(Vec<f32>),
     Float64(Vec<f64>),
+    Int32ToInt64(Vec<i64>),
+    Int32ToFloat32(Vec<f32>),
+    Int32ToFloat64(Vec<f64>),
+    Int64ToFloat32(Vec<f32>),
+    Int64ToFloat64(Vec<f64>),
+    Float32ToFloat64(Vec<f64>),
     Date32(Vec<i32>),
     TimeMillis(Vec<i32>),
     TimeMicros(Vec<i64>),
@@ -171,7 +195,8 @@ enum Decoder {
     /// String data encoded as UTF-8 bytes, but mapped to Arrow's StringViewArray
     StringView(OffsetBufferBuilder<i32>, Vec<u8>),
     Array(FieldRef, OffsetBufferBuilder<i32>, Box<Decoder>),
-    Record(Fields, Vec<Decoder>),
+    /// A record, with an optional [`Projector`] if the writer and reader fields differ
+    Record(Fields, Vec<Decoder>, Option<Projector>),
     Map(
         FieldRef,
         OffsetBufferBuilder<i32>,
@@ -180,46 +205,80 @@ enum Decoder {
         Box<Decoder>,
     ),
     Fixed(i32, Vec<u8>),
-    Enum(Vec<i32>, Arc<[String]>),
+    /// An enum, with an optional mapping from writer to reader symbol indices
+    Enum(Vec<i32>, Arc<[String]>, Option<Arc<[Option<i32>]>>),
     Duration(IntervalMonthDayNanoBuilder),
     Uuid(Vec<u8>),
     Decimal128(usize, Option<usize>, Option<usize>, Decimal128Builder),
     Decimal256(usize, Option<usize>, Option<usize>, Decimal256Builder),
+    /// A dense union, with the decoders and number of decoded values of each variant,
+    /// and the type ids and offsets of the decoded values
+    Union(UnionFields, Vec<Decoder>, Vec<i32>, Vec<i8>, Vec<i32>),
+    /// A writer union resolved against a reader type, or a writer type resolved against
+    /// a reader union
+    ResolvedUnion(Box<UnionResolver>),
+    /// A recursive type nested beyond [`MAX_RECURSION_DEPTH`], see [`Codec::Recursive`]
+    Recursive(Arc<str>, usize),
     Nullable(Nullability, NullBufferBuilder, Box<Decoder>),
+    /// A nullable writer type resolved against a non-nullable reader type
+    ///
+    /// Decoding a null value returns an error
+    NonNullable(Nullability, Box<Decoder>),
 }
 
 impl Decoder {
     fn try_new(data_type: &AvroDataType) -> Result<Self, ArrowError> {
-        let decoder = match data_type.codec() {
-            Codec::Null => Self::Null(0),
-            Codec::Boolean => Self::Boolean(BooleanBufferBuilder::new(DEFAULT_CAPACITY)),
-            Codec::Int32 => Self::Int32(Vec::with_capacity(DEFAULT_CAPACITY)),
-            Codec::Int64 => Self::Int64(Vec::with_capacity(DEFAULT_CAPACITY)),
-            Codec::Float32 => Self::Float32(Vec::with_capacity(DEFAULT_CAPACITY)),
-            Codec::Float64 => Self::Float64(Vec::with_capacity(DEFAULT_CAPACITY)),
-            Codec::Binary => Self::Binary(
+        let decoder = match (data_type.codec(), data_type.resolution()) {
+            (_, Some(ResolutionInfo::Union(resolved))) => {
+                Self::ResolvedUnion(Box::new(UnionResolver::try_new(data_type, resolved)?))
+            }
+            (Codec::Int64, Some(ResolutionInfo::Promotion(Promotion::IntToLong))) => {
+                Self::Int32ToInt64(Vec::with_capacity(DEFAULT_CAPACITY))
+            }
+            (Codec::Float32, Some(ResolutionInfo::Promotion(Promotion::IntToFloat))) => {
+                Self::Int32ToFloat32(Vec::with_capacity(DEFAULT_CAPACITY))
+            }
+            (Codec::Float64, Some(ResolutionInfo::Promotion(Promotion::IntToDouble))) => {
+                Self::Int32ToFloat64(Vec::with_capacity(DEFAULT_CAPACITY))
+            }
+            (Codec::Float32, Some(ResolutionInfo::Promotion(Promotion::LongToFloat))) => {
+                Self::Int64ToFloat32(Vec::with_capacity(DEFAULT_CAPACITY))
+            }
+            (Codec::Float64, Some(ResolutionInfo::Promotion(Promotion::LongToDouble))) => {
+                Self::Int64ToFloat64(Vec::with_capacity(DEFAULT_CAPACITY))
+            }
+            (Codec::Float64, Some(ResolutionInfo::Promotion(Promotion::FloatToDouble))) => {
+                Self::Float32ToFloat64(Vec::with_capacity(DEFAULT_CAPACITY))
+            }
+            (Codec::Null, _) => Self::Null(0),
+            (Codec::Boolean, _) => Self::Boolean(BooleanBufferBuilder::new(DEFAULT_CAPACITY)),
+            (Codec::Int32, _) => Self::Int32(Vec::with_capacity(DEFAULT_CAPACITY)),
+            (Codec::Int64, _) => Self::Int64(Vec::with_capacity(DEFAULT_CAPACITY)),
+            (Codec::Float32, _) => Self::Float32(Vec::with_capacity(DEFAULT_CAPACITY)),
+            (Codec::Float64, _) => Self::Float64(Vec::with_capacity(DEFAULT_CAPACITY)),
+            (Codec::Binary, _) => Self::Binary(
                 OffsetBufferBuilder::new(DEFAULT_CAPACITY),
                 Vec::with_capacity(DEFAULT_CAPACITY),
             ),
-            Codec::Utf8 => Self::String(
+            (Codec::Utf8, _) => Self::String(
                 OffsetBufferBuilder::new(DEFAULT_CAPACITY),
                 Vec::with_capacity(DEFAULT_CAPACITY),
             ),
-            Codec::Utf8View => Self::StringView(
+            (Codec::Utf8View, _) => Self::StringView(
                 OffsetBufferBuilder::new(DEFAULT_CAPACITY),
                 Vec::with_capacity(DEFAULT_CAPACITY),
             ),
-            Codec::Date32 => Self::Date32(Vec::with_capacity(DEFAULT_CAPACITY)),
-            Codec::TimeMillis => Self::TimeMillis(Vec::with_capacity(DEFAULT_CAPACITY)),
-            Codec::TimeMicros => Self::TimeMicros(Vec::with_capacity(DEFAULT_CAPACITY)),
-            Codec::TimestampMillis(is_utc) => {
+            (Codec::Date32, _) => Self::Date32(Vec::with_capacity(DEFAULT_CAPACITY)),
+            (Codec::TimeMillis, _) => Self::TimeMillis(Vec::with_capacity(DEFAULT_CAPACITY)),
+            (Codec::TimeMicros, _) => Self::TimeMicros(Vec::with_capacity(DEFAULT_CAPACITY)),
+            (Codec::TimestampMillis(is_utc), _) => {
                 Self::TimestampMillis(*is_utc, Vec::with_capacity(DEFAULT_CAPACITY))
             }
-            Codec::TimestampMicros(is_utc) => {
+            (Codec::TimestampMicros(is_utc), _) => {
                 Self::TimestampMicros(*is_utc, Vec::with_capacity(DEFAULT_CAPACITY))
             }
-            Codec::Fixed(sz) => Self::Fixed(*sz, Vec::with_capacity(DEFAULT_CAPACITY)),
-            Codec::Decimal(precision, scale, size) => {
+            (Codec::Fixed(sz), _) => Self::Fixed(*sz, Vec::with_capacity(DEFAULT_CAPACITY)),
+            (Codec::Decimal(precision, scale, size), _) => {
                 let p = *precision;
                 let s = *scale;
                 let sz = *size;
@@ -258,8 +317,8 @@ impl Decoder {
                     }
                 }
             }
-            Codec::Interval => Self::Duration(IntervalMonthDayNanoBuilder::new()),
-            Codec::List(item) => {
+            (Codec::Interval, _) => Self::Duration(IntervalMonthDayNanoBuilder::new()),
+            (Codec::List(item), _) => {
                 let decoder = Self::try_new(item)?;
                 Self::Array(
                     Arc::new(item.field_with_name("item")),
@@ -267,10 +326,18 @@ impl Decoder {
                     Box::new(decoder),
                 )
             }
-            Codec::Enum(symbols) => {
-                Self::Enum(Vec::with_capacity(DEFAULT_CAPACITY), symbols.clone())
+            (Codec::Enum(symbols), resolution) => {
+                let mapping = match resolution {
+                    Some(ResolutionInfo::EnumMapping(mapping)) => Some(mapping.clone()),
+                    _ => None,
+                };
+                Self::Enum(
+                    Vec::with_capacity(DEFAULT_CAPACITY),
+                    symbols.clone(),
+                    mapping,
+                )
             }
-            Codec::Struct(fields) => {
+            (Codec::Struct(fields), resolution) => {
                 let mut arrow_fields = Vec::with_capacity(fields.len());
                 let mut encodings = Vec::with_capacity(fields.len());
                 for avro_field in fields.iter() {
@@ -278,9 +345,15 @@ impl Decoder {
                     arrow_fields.push(avro_field.field());
                     encodings.push(encoding);
                 }
-                Self::Record(arrow_fields.into(), encodings)
+                let projector = match resolution {
+                    Some(ResolutionInfo::Record(resolved)) => {
+                        Some(Projector::try_new(resolved, fields)?)
+                    }
+                    _ => None,
+                };
+                Self::Record(arrow_fields.into(), encodings, projector)
             }
-            Codec::Map(child) => {
+            (Codec::Map(child), _) => {
                 let val_field = child.field_with_name("value").with_nullable(true);
                 let map_field = Arc::new(ArrowField::new(
                     "entries",
@@ -299,15 +372,32 @@ impl Decoder {
                     Box::new(val_dec),
                 )
             }
-            Codec::Uuid => Self::Uuid(Vec::with_capacity(DEFAULT_CAPACITY)),
+            (Codec::Uuid, _) => Self::Uuid(Vec::with_capacity(DEFAULT_CAPACITY)),
+            (Codec::Union(variants), _) => {
+                let fields =
+                    UnionFields::new(0..variants.len() as i8, variants.iter().map(|v| v.field()));
+                let decoders = variants
+                    .iter()
+                    .map(|v| Self::try_new(v.data_type()))
+                    .collect::<Result<_, _>>()?;
+                Self::Union(
+                    fields,
+                    decoders,
+                    vec![0; variants.len()],
+                    Vec::with_capacity(DEFAULT_CAPACITY),
+                    Vec::with_capacity(DEFAULT_CAPACITY),
+                )
+            }
+            (Codec::Recursive(name), _) => Self::Recursive(name.clone(), 0),
         };
-        Ok(match data_type.nullability() {
-            Some(nullability) => Self::Nullable(
+        Ok(match (data_type.nullability(), data_type.is_nullable()) {
+            (Some(nullability), true) => Self::Nullable(
                 nullability,
                 NullBufferBuilder::new(DEFAULT_CAPACITY),
                 Box::new(decoder),
             ),
-            None => decoder,
+            (Some(nullability), false) => Self::NonNullable(nullability, Box::new(decoder)),
+            (None, _) => decoder,
         })
     }
 
@@ -318,11 +408,15 @@ impl Decoder {
             Self::Boolean(b) => b.append(false),
             Self::Int32(v) | Self::Date32(v) | Self::TimeMillis(v) => v.push(0),
             Self::Int64(v)
+            | Self::Int32ToInt64(v)
             | Self::TimeMicros(v)
             | Self::TimestampMillis(_, v)
             | Self::TimestampMicros(_, v) => v.push(0),
-            Self::Float32(v) => v.push(0.),
-            Self::Float64(v) => v.push(0.),
+            Self::Float32(v) | Self::Int32ToFloat32(v) | Self::Int64ToFloat32(v) => v.push(0.),
+            Self::Float64(v)
+            | Self::Int32ToFloat64(v)
+            | Self::Int64ToFloat64(v)
+            | Self::Float32ToFloat64(v) => v.push(0.),
             Self::Binary(offsets, _) | Self::String(offsets, _) | Self::StringView(offsets, _) => {
                 offsets.push_length(0);
             }
@@ -333,7 +427,7 @@ impl Decoder {
                 offsets.push_length(0);
                 e.append_null();
             }
-            Self::Record(_, e) => e.iter_mut().for_each(|e| e.append_null()),
+            Self::Record(_, e, _) => e.iter_mut().for_each(|e| e.append_null()),
             Self::Map(_, _koff, moff, _, _) => {
                 moff.push_length(0);
             }
@@ -342,10 +436,128 @@ impl Decoder {
             }
             Self::Decimal128(_, _, _, builder) => builder.append_value(0),
             Self::Decimal256(_, _, _, builder) => builder.append_value(i256::ZERO),
-            Self::Enum(indices, _) => indices.push(0),
+            Self::Enum(indices, _, _) => indices.push(0),
             Self::Duration(builder) => builder.append_null(),
-            Self::Nullable(_, _, _) => unreachable!("Nulls cannot be nested"),
+            Self::Union(_, decoders, lengths, type_ids, offsets) => {
+                // Unions have no null buffer, prefer the null variant if any
+                let idx = decoders
+                    .iter()
+                    .position(|d| matches!(d, Self::Null(_)))
+                    .unwrap_or_default();
+                type_ids.push(idx as i8);
+                offsets.push(lengths[idx]);
+                lengths[idx] += 1;
+                decoders[idx].append_null();
+            }
+            Self::ResolvedUnion(u) => u.append_null(),
+            Self::Recursive(_, count) => *count += 1,
+            Self::Nullable(_, nulls, e) => {
+                nulls.append_null();
+                e.append_null();
+            }
+            Self::NonNullable(_, e) => e.append_null(),
+        }
+    }
+
+    /// Append the default value of a field absent from the writer schema
+    fn append_default(&mut self, default: &AvroLiteral) -> Result<(), ArrowError> {
+        match (self, default) {
+            (Self::Nullable(_, nulls, e), AvroLiteral::Null) => {
+                nulls.append_null();
+                e.append_null();
+            }
+            (Self::Nullable(_, nulls, e), default) => {
+                nulls.append_non_null();
+                e.append_default(default)?;
+            }
+            (Self::NonNullable(_, e), default) => e.append_default(default)?,
+            (Self::Null(count) | Self::Recursive(_, count), AvroLiteral::Null) => *count += 1,
+            (Self::Union(_, decoders, lengths, type_ids, offsets), default)
+                if !decoders.is_empty() =>
+            {
+                // The default of a union corresponds to its first variant
+                decoders[0].append_default(default)?;
+                type_ids.push(0);
+                offsets.push(lengths[0]);
+                lengths[0] += 1;
+            }
+            (Self::ResolvedUnion(u), default) => u.append_default(default)?,
+            (Self::Boolean(b), AvroLiteral::Boolean(v)) => b.append(*v),
+            (Self::Int32(v) | Self::Date32(v) | Self::TimeMillis(v), AvroLiteral::Int(i)) => {
+                v.push(*i)
+            }
+            (
+                Self::Int64(v)
+                | Self::Int32ToInt64(v)
+                | Self::TimeMicros(v)
+                | Self::TimestampMillis(_, v)
+                | Self::TimestampMicros(_, v),
+                AvroLiteral::Long(i),
+            ) => v.push(*i),
+            (
+                Self::Float32(v) | Self::Int32ToFloat32(v) | Self::Int64ToFloat32(v),
+                AvroLiteral::Float(f),
+            ) => v.push(*f),
+            (
+                Self::Float64(v)
+                | Self::Int32ToFloat64(v)
+                | Self::Int64ToFloat64(v)
+                | Self::Float32ToFloat64(v),
+                AvroLiteral::Double(f),
+            ) => v.push(*f),
+            (Self::Binary(offsets, values), AvroLiteral::Bytes(b)) => {
+                offsets.push_length(b.len());
+                values.extend_from_slice(b);
+            }
+            (
+                Self::String(offsets, values) | Self::StringView(offsets, values),
+                AvroLiteral::String(s),
+            ) => {
+                offsets.push_length(s.len());
+                values.extend_from_slice(s.as_bytes());
+            }
+            (Self::Uuid(values), AvroLiteral::String(s)) => {
+                let uuid = Uuid::try_parse(s)
+                    .map_err(|e| ArrowError::ParseError(format!("Failed to parse uuid: {e}")))?;
+                values.extend_from_slice(uuid.as_bytes());
+            }
+            (Self::Fixed(_, accum), AvroLiteral::Bytes(b)) => accum.extend_from_slice(b),
+            (Self::Decimal128(_, _, _, builder), AvroLiteral::Bytes(b)) => {
+                builder.append_value(i128::from_be_bytes(sign_extend_to::<16>(b)?))
+            }
+            (Self::Decimal256(_, _, _, builder), AvroLiteral::Bytes(b)) => {
+                builder.append_value(i256::from_be_bytes(sign_extend_to::<32>(b)?))
+            }
+            (Self::Duration(builder), AvroLiteral::Bytes(b)) => {
+                builder.append_value(decode_duration(b))
+            }
+            (Self::Enum(indices, _, _), AvroLiteral::Enum(idx)) => indices.push(*idx),
+            (Self::Array(_, offsets, e), AvroLiteral::Array(items)) => {
+                for item in items {
+                    e.append_default(item)?;
+                }
+                offsets.push_length(items.len());
+            }
+            (Self::Map(_, koff, moff, kdata, valdec), AvroLiteral::Map(entries)) => {
+                for (k, v) in entries {
+                    koff.push_length(k.len());
+                    kdata.extend_from_slice(k.as_bytes());
+                    valdec.append_default(v)?;
+                }
+                moff.push_length(entries.len());
+            }
+            (Self::Record(_, encodings, _), AvroLiteral::Record(values)) => {
+                for (encoding, value) in encodings.iter_mut().zip(values) {
+                    encoding.append_default(value)?;
+                }
+            }
+            (_, default) => {
+                return Err(ArrowError::SchemaError(format!(
+                    "Invalid default value {default:?}"
+                )))
+            }
         }
+        Ok(())
     }
 
     /// Decode a single record from `buf`
@@ -362,6 +574,12 @@ impl Decoder {
             | Self::TimestampMicros(_, values) => values.push(buf.get_long()?),
             Self::Float32(values) => values.push(buf.get_float()?),
             Self::Float64(values) => values.push(buf.get_double()?),
+            Self::Int32ToInt64(values) => values.push(buf.get_int()? as i64),
+            Self::Int32ToFloat32(values) => values.push(buf.get_int()? as f32),
+            Self::Int32ToFloat64(values) => values.push(buf.get_int()? as f64),
+            Self::Int64ToFloat32(values) => values.push(buf.get_long()? as f32),
+            Self::Int64ToFloat64(values) => values.push(buf.get_long()? as f64),
+            Self::Float32ToFloat64(values) => values.push(buf.get_float()? as f64),
             Self::Binary(offsets, values)
             | Self::String(offsets, values)
             | Self::StringView(offsets, values) => {
@@ -382,11 +600,12 @@ impl Decoder {
                 let total_items = read_blocks(buf, |cursor| encoding.decode(cursor))?;
                 off.push_length(total_items);
             }
-            Self::Record(_, encodings) => {
+            Self::Record(_, encodings, None) => {
                 for encoding in encodings {
                     encoding.decode(buf)?;
                 }
             }
+            Self::Record(_, encodings, Some(projector)) => projector.project(buf, encodings)?,
             Self::Map(_, koff, moff, kdata, valdec) => {
                 let newly_added = read_blocks(buf, |cur| {
                     let kb = cur.get_bytes()?;
@@ -420,17 +639,43 @@ impl Decoder {
                 let val = i256::from_be_bytes(ext);
                 builder.append_value(val);
             }
-            Self::Enum(indices, _) => {
+            Self::Enum(indices, _, None) => {
                 indices.push(buf.get_int()?);
             }
+            Self::Enum(indices, symbols, Some(mapping)) => {
+                let idx = buf.get_int()?;
+                let mapped = usize::try_from(idx)
+                    .ok()
+                    .and_then(|idx| mapping.get(idx).copied().flatten())
+                    .ok_or_else(|| {
+                        ArrowError::ParseError(format!(
+                            "Writer enum symbol {idx} is not a symbol of the reader enum {symbols:?}"
+                        ))
+                    })?;
+                indices.push(mapped);
+            }
             Self::Duration(builder) => {
                 let b = buf.get_fixed(12)?;
-                let months = u32::from_le_bytes(b[0..4].try_into().unwrap());
-                let days = u32::from_le_bytes(b[4..8].try_into().unwrap());
-                let millis = u32::from_le_bytes(b[8..12].try_into().unwrap());
-                let nanos = (millis as i64) * 1_000_000;
-                builder.append_value(IntervalMonthDayNano::new(months as i32, days as i32, nanos));
+                builder.append_value(decode_duration(b));
+            }
+            Self::Union(_, decoders, lengths, type_ids, offsets) => {
+                let idx = buf.get_long()?;
+                let variant = usize::try_from(idx)
+                    .ok()
+                    .filter(|idx| *idx < decoders.len())
+                    .ok_or_else(|| {
+                        ArrowError::ParseError(format!(
+                            "Union variant {idx} out of range for union of {} variants",
+                            decoders.len()
+                        ))
+                    })?;
+                decoders[variant].decode(buf)?;
+                type_ids.push(variant as i8);
+                offsets.push(lengths[variant]);
+                lengths[variant] += 1;
             }
+            Self::ResolvedUnion(u) => u.decode(buf)?,
+            Self::Recursive(name, _) => return Err(recursion_error(name)),
             Self::Nullable(nullability, nulls, e) => {
                 let is_valid = buf.get_bool()? == matches!(nullability, Nullability::NullFirst);
                 nulls.append(is_valid);
@@ -439,6 +684,16 @@ impl Decoder {
                     false => e.append_null(),
                 }
             }
+            Self::NonNullable(nullability, e) => {
+                match buf.get_bool()? == matches!(nullability, Nullability::NullFirst) {
+                    true => e.decode(buf)?,
+                    false => {
+                        return Err(ArrowError::ParseError(
+                            "Found null value for non-nullable reader type".to_string(),
+                        ))
+                    }
+                }
+            }
         }
         Ok(())
     }
@@ -447,11 +702,16 @@ impl Decoder {
     fn flush(&mut self, nulls: Option<NullBuffer>) -> Result<ArrayRef, ArrowError> {
         Ok(match self {
             Self::Nullable(_, n, e) => e.flush(n.finish())?,
-            Self::Null(size) => Arc::new(NullArray::new(std::mem::replace(size, 0))),
+            Self::NonNullable(_, e) => e.flush(nulls)?,
+            Self::Null(size) | Self::Recursive(_, size) => {
+                Arc::new(NullArray::new(std::mem::replace(size, 0)))
+            }
             Self::Boolean(b) => Arc::new(BooleanArray::new(b.finish(), nulls)),
             Self::Int32(values) => Arc::new(flush_primitive::<Int32Type>(values, nulls)),
             Self::Date32(values) => Arc::new(flush_primitive::<Date32Type>(values, nulls)),
-            Self::Int64(values) => Arc::new(flush_primitive::<Int64Type>(values, nulls)),
+            Self::Int64(values) | Self::Int32ToInt64(values) => {
+                Arc::new(flush_primitive::<Int64Type>(values, nulls))
+            }
             Self::TimeMillis(values) => {
                 Arc::new(flush_primitive::<Time32MillisecondType>(values, nulls))
             }
@@ -466,8 +726,15 @@ impl Decoder {
                 flush_primitive::<TimestampMicrosecondType>(values, nulls)
                     .with_timezone_opt(is_utc.then(|| "+00:00")),
             ),
-            Self::Float32(values) => Arc::new(flush_primitive::<Float32Type>(values, nulls)),
-            Self::Float64(values) => Arc::new(flush_primitive::<Float64Type>(values, nulls)),
+            Self::Float32(values) | Self::Int32ToFloat32(values) | Self::Int64ToFloat32(values) => {
+                Arc::new(flush_primitive::<Float32Type>(values, nulls))
+            }
+            Self::Float64(values)
+            | Self::Int32ToFloat64(values)
+            | Self::Int64ToFloat64(values)
+            | Self::Float32ToFloat64(values) => {
+                Arc::new(flush_primitive::<Float64Type>(values, nulls))
+            }
             Self::Binary(offsets, values) => {
                 let offsets = flush_offsets(offsets);
                 let values = flush_values(values).into();
@@ -476,7 +743,7 @@ impl Decoder {
             Self::String(offsets, values) => {
                 let offsets = flush_offsets(offsets);
                 let values = flush_values(values).into();
-                Arc::new(StringArray::new(offsets, values, nulls))
+                Arc::new(StringArray::try_new(offsets, values, nulls)?)
             }
             Self::StringView(offsets, values) => {
                 let offsets = flush_offsets(offsets);
@@ -498,7 +765,7 @@ impl Decoder {
                 let offsets = flush_offsets(offsets);
                 Arc::new(ListArray::new(field.clone(), offsets, values, nulls))
             }
-            Self::Record(fields, encodings) => {
+            Self::Record(fields, encodings, _) => {
                 let arrays = encodings
                     .iter_mut()
                     .map(|x| x.flush(None))
@@ -565,7 +832,7 @@ impl Decoder {
                     .map_err(|e| ArrowError::ParseError(e.to_string()))?;
                 Arc::new(dec)
             }
-            Self::Enum(indices, symbols) => {
+            Self::Enum(indices, symbols, _) => {
                 let keys = flush_primitive::<Int32Type>(indices, nulls);
                 let values = Arc::new(StringArray::from(
                     symbols.iter().map(|s| s.as_str()).collect::<Vec<_>>(),
@@ -578,10 +845,444 @@ impl Decoder {
                     .map_err(|e| ArrowError::ParseError(e.to_string()))?;
                 Arc::new(vals)
             }
+            Self::ResolvedUnion(u) => u.flush()?,
+            Self::Union(fields, decoders, lengths, type_ids, offsets) => {
+                let children = decoders
+                    .iter_mut()
+                    .map(|d| d.flush(None))
+                    .collect::<Result<Vec<_>, _>>()?;
+                lengths.fill(0);
+                Arc::new(UnionArray::try_new(
+                    fields.clone(),
+                    std::mem::take(type_ids).into(),
+                    Some(std::mem::take(offsets).into()),
+                    children,
+                )?)
+            }
         })
     }
 }
 
+/// Decodes a writer union resolved against a reader type, see [`ResolvedUnion`]
+///
+/// Each writer variant is decoded with its own [`Decoder`], with the values of the writer
+/// variants resolved to the same reader type interleaved when flushed
+#[derive(Debug)]
+struct UnionResolver {
+    /// Whether each value is prefixed by the index of its writer variant
+    writer_is_union: bool,
+    /// The index of the decoder of each writer variant, or `None` if it does not match
+    /// the reader type
+    variants: Vec<Option<usize>>,
+    /// The writer variant decoded as null, if the reader type is nullable and not a union
+    null_variant: Option<usize>,
+    /// The decoders of the writer variants, followed by those of any reader union
+    /// variants without a matching writer variant
+    decoders: Vec<Decoder>,
+    /// The reader union fields, and the type id of each decoder, if the reader is a union
+    union: Option<(UnionFields, Vec<i8>)>,
+    /// The nulls of each decoder, if the reader type is nullable and not a union
+    nulls: Option<Vec<NullBufferBuilder>>,
+    /// The number of values decoded by each decoder
+    lengths: Vec<usize>,
+    /// The decoder of each value, and its index within the values of that decoder
+    indices: Vec<(usize, usize)>,
+}
+
+impl UnionResolver {
+    fn try_new(data_type: &AvroDataType, resolved: &ResolvedUnion) -> Result<Self, ArrowError> {
+        let reader_variants = match data_type.codec() {
+            Codec::Union(variants) => Some(variants),
+            _ => None,
+        };
+        let nullable = reader_variants.is_none() && data_type.is_nullable();
+        let mut null_variant = None;
+        let mut decoders = vec![];
+        let mut type_ids = vec![];
+        let mut variants = Vec::with_capacity(resolved.variants.len());
+        for (idx, variant) in resolved.variants.iter().enumerate() {
+            variants.push(match variant {
+                Some((_, v)) if nullable && matches!(v.codec(), Codec::Null) => {
+                    null_variant = Some(idx);
+                    None
+                }
+                Some((reader, v)) => {
+                    decoders.push(Decoder::try_new(v)?);
+                    type_ids.push(*reader as i8);
+                    Some(decoders.len() - 1)
+                }
+                None => None,
+            });
+        }
+        let union = match reader_variants {
+            Some(reader_variants) => {
+                for (idx, v) in reader_variants.iter().enumerate() {
+                    if !type_ids.contains(&(idx as i8)) {
+                        decoders.push(Decoder::try_new(v.data_type())?);
+                        type_ids.push(idx as i8);
+                    }
+                }
+                let fields = reader_variants.iter().map(|v| v.field());
+                Some((
+                    UnionFields::new(0..reader_variants.len() as i8, fields),
+                    type_ids,
+                ))
+            }
+            None => None,
+        };
+        let nulls = nullable.then(|| {
+            let builder = || NullBufferBuilder::new(DEFAULT_CAPACITY);
+            std::iter::repeat_with(builder)
+                .take(decoders.len())
+                .collect()
+        });
+        Ok(Self {
+            writer_is_union: resolved.writer_is_union,
+            variants,
+            null_variant,
+            lengths: vec![0; decoders.len()],
+            decoders,
+            union,
+            nulls,
+            indices: Vec::with_capacity(DEFAULT_CAPACITY),
+        })
+    }
+
+    /// Record a value appended to the decoder at `idx`
+    fn push(&mut self, idx: usize, is_valid: bool) {
+        if let Some(nulls) = &mut self.nulls {
+            nulls[idx].append(is_valid);
+        }
+        self.indices.push((idx, self.lengths[idx]));
+        self.lengths[idx] += 1;
+    }
+
+    fn append_null(&mut self) {
+        // Prefer the null variant of a reader union, if any
+        let idx = self
+            .decoders
+            .iter()
+            .position(|d| self.union.is_some() && matches!(d, Decoder::Null(_)))
+            .unwrap_or_default();
+        self.decoders[idx].append_null();
+        self.push(idx, false);
+    }
+
+    fn append_default(&mut self, default: &AvroLiteral) -> Result<(), ArrowError> {
+        if self.nulls.is_some() && matches!(default, AvroLiteral::Null) {
+            self.append_null();
+            return Ok(());
+        }
+        // The default of a union corresponds to its first variant
+        let idx = match &self.union {
+            Some((_, type_ids)) => type_ids.iter().position(|t| *t == 0).unwrap_or_default(),
+            None => 0,
+        };
+        self.decoders[idx].append_default(default)?;
+        self.push(idx, true);
+        Ok(())
+    }
+
+    fn decode(&mut self, buf: &mut AvroCursor<'_>) -> Result<(), ArrowError> {
+        let variant = match self.writer_is_union {
+            true => {
+                let idx = buf.get_long()?;
+                usize::try_from(idx)
+                    .ok()
+                    .filter(|idx| *idx < self.variants.len())
+                    .ok_or_else(|| {
+                        ArrowError::ParseError(format!(
+                            "Union variant {idx} out of range for union of {} variants",
+                            self.variants.len()
+                        ))
+                    })?
+            }
+            false => 0,
+        };
+        if self.null_variant == Some(variant) {
+            self.append_null();
+            return Ok(());
+        }
+        let idx = self.variants[variant].ok_or_else(|| {
+            ArrowError::ParseError(format!(
+                "Union variant {variant} of the writer schema does not match the reader schema"
+            ))
+        })?;
+        self.decoders[idx].decode(buf)?;
+        self.push(idx, true);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<ArrayRef, ArrowError> {
+        let mut nulls = self.nulls.as_mut().map(|n| n.iter_mut());
+        let arrays = self
+            .decoders
+            .iter_mut()
+            .map(|d| d.flush(nulls.as_mut().and_then(|n| n.next()?.finish())))
+            .collect::<Result<Vec<_>, _>>()?;
+        let indices = std::mem::take(&mut self.indices);
+        self.lengths.fill(0);
+
+        let Some((fields, type_ids)) = &self.union else {
+            return match arrays.len() {
+                1 => Ok(arrays.into_iter().next().unwrap()),
+                _ => interleave(
+                    &arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>(),
+                    &indices,
+                ),
+            };
+        };
+        let mut lengths = vec![0_i32; fields.len()];
+        let mut value_type_ids = Vec::with_capacity(indices.len());
+        let mut offsets = Vec::with_capacity(indices.len());
+        for (idx, _) in &indices {
+            let type_id = type_ids[*idx];
+            value_type_ids.push(type_id);
+            offsets.push(lengths[type_id as usize]);
+            lengths[type_id as usize] += 1;
+        }
+        let children = (0..fields.len() as i8)
+            .map(|type_id| {
+                let decoders: Vec<_> = (0..arrays.len())
+                    .filter(|idx| type_ids[*idx] == type_id)
+                    .collect();
+                if let [idx] = decoders.as_slice() {
+                    return Ok(arrays[*idx].clone());
+                }
+                let values: Vec<_> = decoders.iter().map(|idx| arrays[*idx].as_ref()).collect();
+                let indices: Vec<_> = indices
+                    .iter()
+                    .filter(|(idx, _)| type_ids[*idx] == type_id)
+                    .map(|(idx, offset)| (decoders.iter().position(|d| d == idx).unwrap(), *offset))
+                    .collect();
+                interleave(&values, &indices)
+            })
+            .collect::<Result<Vec<_>, ArrowError>>()?;
+        Ok(Arc::new(UnionArray::try_new(
+            fields.clone(),
+            value_type_ids.into(),
+            Some(offsets.into()),
+            children,
+        )?))
+    }
+}
+
+/// Decodes the writer fields of a record into the reader fields, skipping writer fields
+/// absent from the reader and populating reader fields absent from the writer with defaults
+#[derive(Debug)]
+struct Projector {
+    writer_fields: Vec<WriterField>,
+    /// The reader fields absent from the writer, and their default values
+    defaults: Vec<(usize, AvroLiteral)>,
+}
+
+/// How to decode a field of the writer record
+#[derive(Debug)]
+enum WriterField {
+    /// Decode the field into the reader field with the given index
+    Decode(usize),
+    /// The field is absent from the reader, skip it
+    Skip(Skipper),
+}
+
+impl Projector {
+    fn try_new(resolved: &ResolvedRecord, fields: &[AvroField]) -> Result<Self, ArrowError> {
+        let writer_fields = resolved
+            .writer_to_reader
+            .iter()
+            .zip(resolved.skip_fields.iter())
+            .map(|(reader, skip)| match (reader, skip) {
+                (Some(idx), _) => Ok(WriterField::Decode(*idx)),
+                (None, Some(data_type)) => Ok(WriterField::Skip(Skipper::try_new(data_type)?)),
+                (None, None) => Err(ArrowError::SchemaError(
+                    "Writer field has neither a reader field nor a type to skip".to_string(),
+                )),
+            })
+            .collect::<Result<_, ArrowError>>()?;
+        let defaults = resolved
+            .default_fields
+            .iter()
+            .map(|idx| match fields[*idx].data_type().resolution() {
+                Some(ResolutionInfo::DefaultValue(default)) => Ok((*idx, default.clone())),
+                _ => Err(ArrowError::SchemaError(format!(
+                    "Field \"{}\" has no default value",
+                    fields[*idx].name()
+                ))),
+            })
+            .collect::<Result<_, ArrowError>>()?;
+        Ok(Self {
+            writer_fields,
+            defaults,
+        })
+    }
+
+    /// Decode a single record from `buf` into `encodings`
+    fn project(
+        &self,
+        buf: &mut AvroCursor<'_>,
+        encodings: &mut [Decoder],
+    ) -> Result<(), ArrowError> {
+        for field in &self.writer_fields {
+            match field {
+                WriterField::Decode(idx) => encodings[*idx].decode(buf)?,
+                WriterField::Skip(skipper) => skipper.skip(buf)?,
+            }
+        }
+        for (idx, default) in &self.defaults {
+            encodings[*idx].append_default(default)?;
+        }
+        Ok(())
+    }
+}
+
+/// Skips over Avro encoded values without decoding them
+#[derive(Debug)]
+enum Skipper {
+    Null,
+    Boolean,
+    /// A zig-zag varint encoded `int` or `long`, including enum indices
+    VarInt,
+    Float32,
+    Float64,
+    Bytes,
+    Fixed(usize),
+    List(Box<Skipper>),
+    Map(Box<Skipper>),
+    Struct(Vec<Skipper>),
+    Union(Vec<Skipper>),
+    Recursive(Arc<str>),
+    Nullable(Nullability, Box<Skipper>),
+}
+
+impl Skipper {
+    fn try_new(data_type: &AvroDataType) -> Result<Self, ArrowError> {
+        let skipper = match data_type.codec() {
+            Codec::Null => Self::Null,
+            Codec::Boolean => Self::Boolean,
+            Codec::Int32
+            | Codec::Int64
+            | Codec::Date32
+            | Codec::TimeMillis
+            | Codec::TimeMicros
+            | Codec::TimestampMillis(_)
+            | Codec::TimestampMicros(_)
+            | Codec::Enum(_) => Self::VarInt,
+            Codec::Float32 => Self::Float32,
+            Codec::Float64 => Self::Float64,
+            Codec::Binary | Codec::Utf8 | Codec::Utf8View | Codec::Uuid => Self::Bytes,
+            Codec::Decimal(_, _, None) => Self::Bytes,
+            Codec::Decimal(_, _, Some(size)) => Self::Fixed(*size),
+            Codec::Fixed(size) => Self::Fixed(*size as usize),
+            Codec::Interval => Self::Fixed(12),
+            Codec::List(item) => Self::List(Box::new(Self::try_new(item)?)),
+            Codec::Map(values) => Self::Map(Box::new(Self::try_new(values)?)),
+            Codec::Struct(fields) => Self::Struct(
+                fields
+                    .iter()
+                    .map(|f| Self::try_new(f.data_type()))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Codec::Union(variants) => Self::Union(
+                variants
+                    .iter()
+                    .map(|v| Self::try_new(v.data_type()))
+                    .collect::<Result<_, _>>()?,
+            ),
+            Codec::Recursive(name) => Self::Recursive(name.clone()),
+        };
+        Ok(match data_type.nullability() {
+            Some(nullability) => Self::Nullable(nullability, Box::new(skipper)),
+            None => skipper,
+        })
+    }
+
+    /// Skip a single value in `buf`
+    fn skip(&self, buf: &mut AvroCursor<'_>) -> Result<(), ArrowError> {
+        match self {
+            Self::Null => {}
+            Self::Boolean => {
+                buf.get_bool()?;
+            }
+            Self::VarInt => {
+                buf.read_vlq()?;
+            }
+            Self::Float32 => {
+                buf.get_fixed(4)?;
+            }
+            Self::Float64 => {
+                buf.get_fixed(8)?;
+            }
+            Self::Bytes => {
+                buf.get_bytes()?;
+            }
+            Self::Fixed(size) => {
+                buf.get_fixed(*size)?;
+            }
+            Self::List(item) => skip_blocks(buf, |cursor| item.skip(cursor))?,
+            Self::Map(values) => skip_blocks(buf, |cursor| {
+                cursor.get_bytes()?;
+                values.skip(cursor)
+            })?,
+            Self::Struct(fields) => {
+                for field in fields {
+                    field.skip(buf)?;
+                }
+            }
+            Self::Union(variants) => {
+                let idx = buf.get_long()?;
+                let variant = usize::try_from(idx)
+                    .ok()
+                    .and_then(|idx| variants.get(idx))
+                    .ok_or_else(|| {
+                        ArrowError::ParseError(format!(
+                            "Union variant {idx} out of range for union of {} variants",
+                            variants.len()
+                        ))
+                    })?;
+                variant.skip(buf)?;
+            }
+            Self::Recursive(name) => return Err(recursion_error(name)),
+            Self::Nullable(nullability, skipper) => {
+                if buf.get_bool()? == matches!(nullability, Nullability::NullFirst) {
+                    skipper.skip(buf)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn recursion_error(name: &str) -> ArrowError {
+    ArrowError::ParseError(format!(
+        "Value of recursive type {name} nested more than {MAX_RECURSION_DEPTH} times within itself"
+    ))
+}
+
+/// Skips the blocks of an Avro array or map, using the block size where
+/// available to avoid skipping each item individually
+fn skip_blocks(
+    buf: &mut AvroCursor,
+    mut skip_item: impl FnMut(&mut AvroCursor) -> Result<(), ArrowError>,
+) -> Result<(), ArrowError> {
+    loop {
+        let block_count = buf.get_long()?;
+        match block_count.cmp(&0) {
+            Ordering::Equal => return Ok(()),
+            Ordering::Less => {
+                let size = usize::try_from(buf.get_long()?).map_err(|_| {
+                    ArrowError::ParseError("Negative block size in Avro block".to_string())
+                })?;
+                buf.get_fixed(size)?;
+            }
+            Ordering::Greater => {
+                for _ in 0..block_count {
+                    skip_item(buf)?;
+                }
+            }
+        }
+    }
+}
+
 #[inline]
 fn read_blocks(
     buf: &mut AvroCursor,
@@ -648,6 +1349,17 @@ fn flush_primitive<T: ArrowPrimitiveType>(
     PrimitiveArray::new(flush_values(values).into(), nulls)
 }
 
+/// Decodes an Avro `duration`, a fixed of 12 bytes containing three little-endian
+/// unsigned integers representing months, days and milliseconds
+#[inline]
+fn decode_duration(b: &[u8]) -> IntervalMonthDayNano {
+    let months = u32::from_le_bytes(b[0..4].try_into().unwrap());
+    let days = u32::from_le_bytes(b[4..8].try_into().unwrap());
+    let millis = u32::from_le_bytes(b[8..12].try_into().unwrap());
+    let nanos = (millis as i64) * 1_000_000;
+    IntervalMonthDayNano::new(months as i32, days as i32, nanos)
+}
+
 /// Sign extends a byte slice to a fixed-size array of N bytes.
 /// This is done by filling the leading bytes with 0x00 for positive numbers
 /// or 0xFF for negative numbers.
@@ -680,6 +1392,7 @@ mod tests {
         cast::AsArray, Array, Decimal128Array, DictionaryArray, FixedSizeBinaryArray,
         IntervalMonthDayNanoArray, ListArray, MapArray, StringArray, StructArray,
     };
+    use arrow_schema::UnionMode;
 
     fn encode_avro_int(value: i32) -> Vec<u8> {
         let mut buf = Vec::new();
@@ -1192,4 +1905,223 @@ mod tests {
         let array = decoder.flush(None).unwrap();
         assert_eq!(array.len(), 0);
     }
+
+    fn record_decoder(schema: &str) -> RecordDecoder {
+        let schema: Schema = serde_json::from_str(schema).unwrap();
+        let field = AvroField::try_from(&schema).unwrap();
+        RecordDecoder::try_new_with_options(field.data_type(), false, false).unwrap()
+    }
+
+    #[test]
+    fn test_union_decoding() {
+        let mut decoder = record_decoder(
+            r#"{"type":"record","name":"r","fields":[
+                {"name":"u","type":["null","string","long"]},
+                {"name":"a","type":{"type":"array","items":["int",{"type":"string","logicalType":"x"}]}}
+            ]}"#,
+        );
+        let mut data = Vec::new();
+        // {"u": null, "a": [1, "x"]}
+        data.extend(encode_avro_long(0));
+        data.extend(encode_avro_long(2));
+        data.extend(encode_avro_long(0));
+        data.extend(encode_avro_int(1));
+        data.extend(encode_avro_long(1));
+        data.extend(encode_avro_bytes(b"x"));
+        data.extend(encode_avro_long(0));
+        // {"u": "ab", "a": []}
+        data.extend(encode_avro_long(1));
+        data.extend(encode_avro_bytes(b"ab"));
+        data.extend(encode_avro_long(0));
+        // {"u": 5, "a": [2]}
+        data.extend(encode_avro_long(2));
+        data.extend(encode_avro_long(5));
+        data.extend(encode_avro_long(1));
+        data.extend(encode_avro_long(0));
+        data.extend(encode_avro_int(2));
+        data.extend(encode_avro_long(0));
+        assert_eq!(decoder.decode(&data, 3).unwrap(), data.len());
+
+        let batch = decoder.flush().unwrap();
+        let field = batch.schema().field(0).clone();
+        assert!(field.is_nullable());
+        let DataType::Union(fields, UnionMode::Dense) = field.data_type() else {
+            panic!("Expected dense union got {}", field.data_type())
+        };
+        let names: Vec<_> = fields.iter().map(|(_, f)| f.name().as_str()).collect();
+        assert_eq!(names, ["null", "string", "long"]);
+
+        let u = batch.column(0).as_union();
+        assert_eq!(u.type_ids(), &[0, 1, 2]);
+        assert_eq!(u.offsets().unwrap().as_ref(), &[0, 0, 0]);
+        assert_eq!(u.child(0).len(), 1);
+        assert_eq!(u.child(1).as_string::<i32>().value(0), "ab");
+        assert_eq!(u.child(2).as_primitive::<Int64Type>().value(0), 5);
+
+        let a = batch.column(1).as_list::<i32>();
+        assert_eq!(a.value_offsets(), &[0, 2, 2, 3]);
+        let items = a.values().as_union();
+        assert_eq!(items.type_ids(), &[0, 1, 0]);
+        assert_eq!(items.offsets().unwrap().as_ref(), &[0, 0, 1]);
+        assert_eq!(items.child(0).as_primitive::<Int32Type>().values(), &[1, 2]);
+        assert_eq!(items.child(1).as_string::<i32>().value(0), "x");
+
+        let err = decoder.decode(&encode_avro_long(3), 1).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Union variant 3 out of range for union of 3 variants"),
+            "{err}"
+        );
+    }
+
+    fn resolved_decoder(writer: &str, reader: &str) -> RecordDecoder {
+        let schema = |u: &str| {
+            format!(r#"{{"type":"record","name":"r","fields":[{{"name":"u","type":{u}}}]}}"#)
+        };
+        let writer = schema(writer);
+        let writer: Schema = serde_json::from_str(&writer).unwrap();
+        let reader = schema(reader);
+        let reader: Schema = serde_json::from_str(&reader).unwrap();
+        let field = AvroField::resolve(&writer, &reader, false, None).unwrap();
+        RecordDecoder::try_new_with_options(field.data_type(), false, false).unwrap()
+    }
+
+    #[test]
+    fn test_resolved_union_decoding() {
+        // Writer variants are reordered and promoted into an extended reader union
+        let mut decoder =
+            resolved_decoder(r#"["int","string","long"]"#, r#"["string","long","null"]"#);
+        let mut data = Vec::new();
+        for (variant, value) in [(0, 1), (1, 0), (2, 2), (0, 3)] {
+            data.extend(encode_avro_long(variant));
+            match variant {
+                0 => data.extend(encode_avro_int(value)),
+                1 => data.extend(encode_avro_bytes(b"a")),
+                _ => data.extend(encode_avro_long(value as i64)),
+            }
+        }
+        assert_eq!(decoder.decode(&data, 4).unwrap(), data.len());
+        let batch = decoder.flush().unwrap();
+        let DataType::Union(fields, UnionMode::Dense) = batch.schema().field(0).data_type().clone()
+        else {
+            panic!("Expected dense union")
+        };
+        let names: Vec<_> = fields.iter().map(|(_, f)| f.name().clone()).collect();
+        assert_eq!(names, ["string", "long", "null"]);
+        let u = batch.column(0).as_union();
+        assert_eq!(u.type_ids(), &[1, 0, 1, 1]);
+        assert_eq!(u.offsets().unwrap().as_ref(), &[0, 0, 1, 2]);
+        assert_eq!(u.child(0).as_string::<i32>().value(0), "a");
+        assert_eq!(u.child(1).as_primitive::<Int64Type>().values(), &[1, 2, 3]);
+        assert_eq!(u.child(2).len(), 0);
+
+        // A writer type is resolved against the first matching reader variant
+        let mut decoder = resolved_decoder(r#""int""#, r#"["string","long"]"#);
+        let data = encode_avro_int(7);
+        assert_eq!(decoder.decode(&data, 1).unwrap(), data.len());
+        let batch = decoder.flush().unwrap();
+        let u = batch.column(0).as_union();
+        assert_eq!(u.type_ids(), &[1]);
+        assert_eq!(u.child(1).as_primitive::<Int64Type>().values(), &[7]);
+
+        // Writer variants matching the reader type are decoded, any other variant errors
+        let mut decoder =
+            resolved_decoder(r#"["null","int","long","string"]"#, r#"["null","long"]"#);
+        let mut data = Vec::new();
+        data.extend(encode_avro_long(1));
+        data.extend(encode_avro_int(1));
+        data.extend(encode_avro_long(0));
+        data.extend(encode_avro_long(2));
+        data.extend(encode_avro_long(2));
+        assert_eq!(decoder.decode(&data, 3).unwrap(), data.len());
+        let batch = decoder.flush().unwrap();
+        assert!(batch.schema().field(0).is_nullable());
+        let values = batch.column(0).as_primitive::<Int64Type>();
+        assert_eq!(values.iter().collect::<Vec<_>>(), [Some(1), None, Some(2)]);
+
+        let mut data = encode_avro_long(3);
+        data.extend(encode_avro_bytes(b"a"));
+        let err = decoder.decode(&data, 1).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Union variant 3 of the writer schema does not match the reader schema"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_union_schema_errors() {
+        let schema: Schema = serde_json::from_str(
+            r#"{"type":"record","name":"r","fields":[{"name":"u","type":["int","string","int"]}]}"#,
+        )
+        .unwrap();
+        let err = AvroField::try_from(&schema).unwrap_err();
+        assert!(
+            err.to_string().contains("Duplicate type int in union"),
+            "{err}"
+        );
+
+        let schema: Schema = serde_json::from_str(
+            r#"{"type":"record","name":"r","fields":[{"name":"u","type":["int",["null","string"]]}]}"#,
+        )
+        .unwrap();
+        let err = AvroField::try_from(&schema).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Unions may not immediately contain other unions"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_recursive_decoding() {
+        let mut decoder = record_decoder(
+            r#"{"type":"record","name":"LongList","namespace":"ns","fields":[
+                {"name":"value","type":"long"},
+                {"name":"next","type":["null","LongList"]}
+            ]}"#,
+        );
+        let list = |len: usize| {
+            let mut data = Vec::new();
+            for i in 0..len {
+                if i != 0 {
+                    data.extend(encode_avro_long(1));
+                }
+                data.extend(encode_avro_long(i as i64));
+            }
+            data.extend(encode_avro_long(0));
+            data
+        };
+
+        // A list of the maximum depth
+        let data = list(MAX_RECURSION_DEPTH);
+        assert_eq!(decoder.decode(&data, 1).unwrap(), data.len());
+        let data = list(1);
+        assert_eq!(decoder.decode(&data, 1).unwrap(), data.len());
+        let batch = decoder.flush().unwrap();
+        assert_eq!(batch.num_rows(), 2);
+
+        let mut next = batch.column(1).clone();
+        for depth in 1..MAX_RECURSION_DEPTH {
+            let s = next.as_struct();
+            assert_eq!(
+                s.column(0).as_primitive::<Int64Type>().value(0),
+                depth as i64
+            );
+            assert!(s.is_valid(0));
+            assert!(s.is_null(1));
+            next = s.column(1).clone();
+        }
+        assert_eq!(next.data_type(), &DataType::Null);
+        assert_eq!(next.len(), 2);
+
+        let err = decoder
+            .decode(&list(MAX_RECURSION_DEPTH + 1), 1)
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Value of recursive type ns.LongList nested more than"),
+            "{err}"
+        );
+    }
 }
@@ -0,0 +1,389 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Writer schema lookup for framed Avro messages
+
+use arrow_schema::ArrowError;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// The magic bytes prefixing a message in the Avro [single object encoding]
+///
+/// [single object encoding]: https://avro.apache.org/docs/1.11.1/specification/#single-object-encoding
+pub const SINGLE_OBJECT_MAGIC: [u8; 2] = [0xC3, 0x01];
+
+/// The magic byte prefixing a message in the Confluent schema registry [wire format]
+///
+/// [wire format]: https://docs.confluent.io/platform/current/schema-registry/fundamentals/serdes-develop/index.html#wire-format
+pub const CONFLUENT_MAGIC: [u8; 1] = [0x00];
+
+/// Identifies the writer schema of a framed Avro message
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Fingerprint {
+    /// The 64-bit Rabin fingerprint of the schema's [parsing canonical form], as used by
+    /// the single object encoding
+    ///
+    /// [parsing canonical form]: https://avro.apache.org/docs/1.11.1/specification/#parsing-canonical-form-for-schemas
+    Rabin(u64),
+    /// A schema id assigned by a Confluent schema registry
+    Id(u32),
+}
+
+impl Fingerprint {
+    /// Computes the [`Fingerprint::Rabin`] of the JSON encoded Avro `schema`
+    pub fn rabin(schema: &str) -> Result<Self, ArrowError> {
+        let canonical = parsing_canonical_form(schema)?;
+        Ok(Self::Rabin(rabin_fingerprint(canonical.as_bytes())))
+    }
+}
+
+/// The framing of individual Avro messages decoded by a [`Decoder`](super::Decoder)
+/// configured with a [`SchemaStore`]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum WireFormat {
+    /// Messages are prefixed by [`SINGLE_OBJECT_MAGIC`] followed by the little-endian
+    /// [`Fingerprint::Rabin`] of the writer schema
+    #[default]
+    SingleObject,
+    /// Messages are prefixed by [`CONFLUENT_MAGIC`] followed by the big-endian
+    /// [`Fingerprint::Id`] of the writer schema
+    Confluent,
+}
+
+impl WireFormat {
+    /// Returns the length of the prefix of each message
+    pub(crate) fn prefix_len(&self) -> usize {
+        match self {
+            Self::SingleObject => 10,
+            Self::Confluent => 5,
+        }
+    }
+
+    /// Parses the prefix of a message, returning `Ok(None)` if `buf` is too short
+    pub(crate) fn parse_prefix(&self, buf: &[u8]) -> Result<Option<Fingerprint>, ArrowError> {
+        let magic: &[u8] = match self {
+            Self::SingleObject => &SINGLE_OBJECT_MAGIC,
+            Self::Confluent => &CONFLUENT_MAGIC,
+        };
+        let n = buf.len().min(magic.len());
+        if buf[..n] != magic[..n] {
+            return Err(ArrowError::ParseError(format!(
+                "Expected {self:?} message to start with magic {magic:02X?}, got {:02X?}",
+                &buf[..n]
+            )));
+        }
+        let Some(fingerprint) = buf.get(magic.len()..self.prefix_len()) else {
+            return Ok(None);
+        };
+        Ok(Some(match self {
+            Self::SingleObject => {
+                Fingerprint::Rabin(u64::from_le_bytes(fingerprint.try_into().unwrap()))
+            }
+            Self::Confluent => Fingerprint::Id(u32::from_be_bytes(fingerprint.try_into().unwrap())),
+        }))
+    }
+}
+
+/// A source of writer schemas for framed Avro messages, keyed by [`Fingerprint`]
+///
+/// A [`Decoder`](super::Decoder) looks up the writer schema the first time it encounters
+/// a fingerprint, caching the resulting decoder for subsequent messages. Implementations
+/// may therefore perform comparatively expensive lookups, such as querying a schema registry.
+pub trait SchemaStore: std::fmt::Debug + Send + Sync {
+    /// Returns the JSON encoded writer schema identified by `fingerprint`, if any
+    fn lookup(&self, fingerprint: &Fingerprint) -> Result<Option<String>, ArrowError>;
+}
+
+/// A [`SchemaStore`] holding its schemas in memory
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySchemaStore {
+    schemas: HashMap<Fingerprint, String>,
+}
+
+impl InMemorySchemaStore {
+    /// Creates an empty [`InMemorySchemaStore`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the JSON encoded Avro `schema` under its [`Fingerprint::Rabin`],
+    /// returning the fingerprint
+    pub fn register(&mut self, schema: &str) -> Result<Fingerprint, ArrowError> {
+        let fingerprint = Fingerprint::rabin(schema)?;
+        self.schemas.insert(fingerprint, schema.to_string());
+        Ok(fingerprint)
+    }
+
+    /// Registers the JSON encoded Avro `schema` under the given `fingerprint`
+    ///
+    /// This can be used to register schemas under their [`Fingerprint::Id`]
+    pub fn register_with_fingerprint(
+        &mut self,
+        fingerprint: Fingerprint,
+        schema: &str,
+    ) -> Result<(), ArrowError> {
+        serde_json::from_str::<Value>(schema).map_err(|e| {
+            ArrowError::ParseError(format!("Failed to parse Avro schema JSON: {e}"))
+        })?;
+        self.schemas.insert(fingerprint, schema.to_string());
+        Ok(())
+    }
+}
+
+impl SchemaStore for InMemorySchemaStore {
+    fn lookup(&self, fingerprint: &Fingerprint) -> Result<Option<String>, ArrowError> {
+        Ok(self.schemas.get(fingerprint).cloned())
+    }
+}
+
+/// The empty fingerprint of the CRC-64-AVRO Rabin fingerprint
+const EMPTY: u64 = 0xc15d213aa4d7a795;
+
+/// The lookup table of the CRC-64-AVRO Rabin fingerprint
+const FINGERPRINT_TABLE: [u64; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut fp = i as u64;
+        let mut j = 0;
+        while j < 8 {
+            fp = (fp >> 1) ^ (EMPTY & (fp & 1).wrapping_neg());
+            j += 1;
+        }
+        table[i] = fp;
+        i += 1;
+    }
+    table
+};
+
+/// Computes the 64-bit Rabin fingerprint of `data`
+///
+/// See <https://avro.apache.org/docs/1.11.1/specification/#schema-fingerprints>
+pub(crate) fn rabin_fingerprint(data: &[u8]) -> u64 {
+    data.iter().fold(EMPTY, |fp, b| {
+        (fp >> 8) ^ FINGERPRINT_TABLE[((fp ^ *b as u64) & 0xff) as usize]
+    })
+}
+
+const PRIMITIVES: [&str; 8] = [
+    "null", "boolean", "int", "long", "float", "double", "bytes", "string",
+];
+
+/// Returns the parsing canonical form of the JSON encoded Avro `schema`
+///
+/// See <https://avro.apache.org/docs/1.11.1/specification/#parsing-canonical-form-for-schemas>
+pub(crate) fn parsing_canonical_form(schema: &str) -> Result<String, ArrowError> {
+    let value: Value = serde_json::from_str(schema)
+        .map_err(|e| ArrowError::ParseError(format!("Failed to parse Avro schema JSON: {e}")))?;
+    let mut out = String::with_capacity(schema.len());
+    write_canonical(&value, None, &mut out)?;
+    Ok(out)
+}
+
+fn invalid(value: &Value) -> ArrowError {
+    ArrowError::ParseError(format!("Invalid Avro schema: {value}"))
+}
+
+/// Returns the full name of a named type, see [`crate::codec`] for the resolution of names
+fn full_name(name: &str, namespace: Option<&str>) -> String {
+    match namespace {
+        Some(ns) if !name.contains('.') && !ns.is_empty() => format!("{ns}.{name}"),
+        _ => name.to_string(),
+    }
+}
+
+fn write_str(s: &str, out: &mut String) {
+    // Serializing a str cannot fail
+    out.push_str(&serde_json::to_string(s).unwrap());
+}
+
+fn write_canonical(
+    value: &Value,
+    namespace: Option<&str>,
+    out: &mut String,
+) -> Result<(), ArrowError> {
+    match value {
+        Value::String(s) if PRIMITIVES.contains(&s.as_str()) => write_str(s, out),
+        Value::String(name) => write_str(&full_name(name, namespace), out),
+        Value::Array(variants) => {
+            out.push('[');
+            for (idx, v) in variants.iter().enumerate() {
+                if idx != 0 {
+                    out.push(',');
+                }
+                write_canonical(v, namespace, out)?;
+            }
+            out.push(']');
+        }
+        Value::Object(o) => {
+            let t = o.get("type").ok_or_else(|| invalid(value))?;
+            let t = match t {
+                Value::String(t) => t.as_str(),
+                // A type wrapped in an object, e.g. `{"type": {"type": "array", ...}}`
+                _ => return write_canonical(t, namespace, out),
+            };
+            match t {
+                "record" | "error" | "enum" | "fixed" => {
+                    let name = o.get("name").and_then(Value::as_str);
+                    let name = name.ok_or_else(|| invalid(value))?;
+                    let ns = o.get("namespace").and_then(Value::as_str).or(namespace);
+                    let name = full_name(name, ns);
+                    // Names nested within this type are resolved relative to its namespace
+                    let ns = name.rsplit_once('.').map(|(ns, _)| ns);
+
+                    out.push_str("{\"name\":");
+                    write_str(&name, out);
+                    out.push_str(",\"type\":");
+                    match t {
+                        "enum" => {
+                            out.push_str("\"enum\",\"symbols\":");
+                            let symbols = o.get("symbols").ok_or_else(|| invalid(value))?;
+                            out.push_str(&symbols.to_string());
+                        }
+                        "fixed" => {
+                            let size = o.get("size").and_then(Value::as_u64);
+                            let size = size.ok_or_else(|| invalid(value))?;
+                            write!(out, "\"fixed\",\"size\":{size}").unwrap();
+                        }
+                        _ => {
+                            out.push_str("\"record\",\"fields\":[");
+                            let fields = o.get("fields").and_then(Value::as_array);
+                            for (idx, f) in fields.ok_or_else(|| invalid(value))?.iter().enumerate()
+                            {
+                                if idx != 0 {
+                                    out.push(',');
+                                }
+                                let field_name = f.get("name").and_then(Value::as_str);
+                                let field_type = f.get("type");
+                                let (Some(field_name), Some(field_type)) = (field_name, field_type)
+                                else {
+                                    return Err(invalid(f));
+                                };
+                                out.push_str("{\"name\":");
+                                write_str(field_name, out);
+                                out.push_str(",\"type\":");
+                                write_canonical(field_type, ns, out)?;
+                                out.push('}');
+                            }
+                            out.push(']');
+                        }
+                    }
+                    out.push('}');
+                }
+                "array" => {
+                    out.push_str("{\"type\":\"array\",\"items\":");
+                    write_canonical(
+                        o.get("items").ok_or_else(|| invalid(value))?,
+                        namespace,
+                        out,
+                    )?;
+                    out.push('}');
+                }
+                "map" => {
+                    out.push_str("{\"type\":\"map\",\"values\":");
+                    write_canonical(
+                        o.get("values").ok_or_else(|| invalid(value))?,
+                        namespace,
+                        out,
+                    )?;
+                    out.push('}');
+                }
+                // Primitive types, possibly annotated with a logical type, and named references
+                t => write_canonical(&Value::String(t.to_string()), namespace, out)?,
+            }
+        }
+        _ => return Err(invalid(value)),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parsing_canonical_form() {
+        let cases = [
+            (r#""int""#, r#""int""#),
+            (
+                r#"{"type": "long", "logicalType": "timestamp-micros"}"#,
+                r#""long""#,
+            ),
+            (
+                r#"{"type": "record", "name": "r", "namespace": "ns", "doc": "a record", "fields": [
+                    {"name": "a", "type": ["null", "string"], "default": null},
+                    {"name": "b", "type": {"type": "enum", "name": "e", "symbols": ["x", "y"]}},
+                    {"name": "c", "type": {"type": "array", "items": "e"}},
+                    {"name": "d", "type": {"type": "fixed", "name": "other.f", "size": 4}},
+                    {"name": "e", "type": {"type": "map", "values": "f"}}
+                ]}"#,
+                concat!(
+                    r#"{"name":"ns.r","type":"record","fields":["#,
+                    r#"{"name":"a","type":["null","string"]},"#,
+                    r#"{"name":"b","type":{"name":"ns.e","type":"enum","symbols":["x","y"]}},"#,
+                    r#"{"name":"c","type":{"type":"array","items":"ns.e"}},"#,
+                    r#"{"name":"d","type":{"name":"other.f","type":"fixed","size":4}},"#,
+                    r#"{"name":"e","type":{"type":"map","values":"ns.f"}}]}"#,
+                ),
+            ),
+        ];
+        for (schema, expected) in cases {
+            assert_eq!(parsing_canonical_form(schema).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_rabin_fingerprint() {
+        // Test vectors from the Avro specification test suite
+        let cases = [
+            (r#""null""#, 7195948357588979594_u64),
+            (r#""int""#, 8247732601305521295_u64),
+            (r#""long""#, -3434872931120570953_i64 as u64),
+        ];
+        for (schema, expected) in cases {
+            assert_eq!(
+                Fingerprint::rabin(schema).unwrap(),
+                Fingerprint::Rabin(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_prefix() {
+        let format = WireFormat::SingleObject;
+        let mut prefix = SINGLE_OBJECT_MAGIC.to_vec();
+        prefix.extend_from_slice(&42_u64.to_le_bytes());
+        assert_eq!(
+            format.parse_prefix(&prefix).unwrap(),
+            Some(Fingerprint::Rabin(42))
+        );
+        assert_eq!(format.parse_prefix(&prefix[..9]).unwrap(), None);
+        let err = format.parse_prefix(&[0xC3, 0x02]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Expected SingleObject message to start with magic [C3, 01], got [C3, 02]"
+        );
+
+        let format = WireFormat::Confluent;
+        assert_eq!(
+            format.parse_prefix(&[0, 0, 0, 1, 2]).unwrap(),
+            Some(Fingerprint::Id(258))
+        );
+        assert_eq!(format.parse_prefix(&[0, 0]).unwrap(), None);
+    }
+}
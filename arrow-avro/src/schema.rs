@@ -15,12 +15,23 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use arrow_schema::extension::EXTENSION_TYPE_NAME_KEY;
+use arrow_schema::{
+    ArrowError, DataType, Field as ArrowField, IntervalUnit, Schema as ArrowSchema, TimeUnit,
+};
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Map as JsonMap, Value};
 use std::collections::HashMap;
 
 /// The metadata key used for storing the JSON encoded [`Schema`]
 pub const SCHEMA_METADATA_KEY: &str = "avro.schema";
 
+/// The name used for the top-level record when converting an Arrow schema
+pub(crate) const DEFAULT_RECORD_NAME: &str = "topLevelRecord";
+
+/// The field metadata key used for storing the symbols of an Avro enum
+pub(crate) const ENUM_SYMBOLS_METADATA_KEY: &str = "avro.enum.symbols";
+
 /// Either a [`PrimitiveType`] or a reference to a previously defined named type
 ///
 /// <https://avro.apache.org/docs/1.11.1/specification/#names>
@@ -181,8 +192,26 @@ pub struct Field<'a> {
     #[serde(borrow)]
     pub r#type: Schema<'a>,
     /// Optional default value for this field
-    #[serde(borrow, default)]
-    pub default: Option<&'a str>,
+    ///
+    /// Used when resolving data written without this field, note that an explicit
+    /// `null` default is represented as `Some(Value::Null)`
+    #[serde(
+        default,
+        deserialize_with = "deserialize_default",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub default: Option<Value>,
+    /// Alternative names for this field
+    #[serde(borrow, default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<&'a str>,
+}
+
+/// Deserializes a field default, distinguishing an explicit `null` from an absent default
+fn deserialize_default<'de, D>(deserializer: D) -> Result<Option<Value>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Value::deserialize(deserializer).map(Some)
 }
 
 /// An enumeration
@@ -260,10 +289,204 @@ pub struct Fixed<'a> {
     pub attributes: Attributes<'a>,
 }
 
+/// Returns the Avro enum symbols recorded in the metadata of `field`, if any
+pub(crate) fn enum_symbols(field: &ArrowField) -> Result<Option<Vec<String>>, ArrowError> {
+    field
+        .metadata()
+        .get(ENUM_SYMBOLS_METADATA_KEY)
+        .map(|s| {
+            serde_json::from_str(s).map_err(|e| {
+                ArrowError::SchemaError(format!(
+                    "Failed to parse enum symbols for {}: {e}",
+                    field.name()
+                ))
+            })
+        })
+        .transpose()
+}
+
+/// Returns true if `field` is annotated with the canonical `arrow.uuid` extension type
+pub(crate) fn is_uuid_field(field: &ArrowField) -> bool {
+    matches!(field.data_type(), DataType::FixedSizeBinary(16))
+        && field
+            .metadata()
+            .get(EXTENSION_TYPE_NAME_KEY)
+            .is_some_and(|n| n == "arrow.uuid")
+}
+
+/// Returns `name` if it is a valid Avro name, matching `[A-Za-z_][A-Za-z0-9_]*`
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#names>
+fn validate_name(name: &str) -> Result<&str, ArrowError> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    match valid {
+        true => Ok(name),
+        false => Err(ArrowError::SchemaError(format!(
+            "Invalid Avro name \"{name}\", names must match [A-Za-z_][A-Za-z0-9_]*"
+        ))),
+    }
+}
+
+/// Returns `full_name` if it is a sequence of valid Avro names separated by dots
+fn validate_full_name(full_name: &str) -> Result<&str, ArrowError> {
+    for name in full_name.split('.') {
+        validate_name(name).map_err(|_| {
+            ArrowError::SchemaError(format!(
+                "Invalid Avro name \"{full_name}\", names must be dot-separated sequences of [A-Za-z_][A-Za-z0-9_]*"
+            ))
+        })?;
+    }
+    Ok(full_name)
+}
+
+/// Converts an Arrow [`ArrowSchema`] to the JSON representation of an Avro record schema
+///
+/// Nullable Arrow fields are mapped to a union of `null` and the field type, with `null`
+/// as the first variant. Named Avro types (records, enums and fixed) are namespaced by
+/// the path of the field they were derived from, ensuring their full names are unique.
+pub(crate) fn to_avro_json_schema(schema: &ArrowSchema, name: &str) -> Result<Value, ArrowError> {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|f| field_to_json(f, name))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(json!({
+        "type": "record",
+        "name": validate_full_name(name)?,
+        "fields": fields,
+    }))
+}
+
+fn field_to_json(field: &ArrowField, namespace: &str) -> Result<Value, ArrowError> {
+    let mut out = JsonMap::new();
+    out.insert("name".into(), json!(validate_name(field.name())?));
+    out.insert("type".into(), nullable_type_to_json(field, namespace)?);
+    if field.is_nullable() {
+        out.insert("default".into(), Value::Null);
+    }
+    Ok(Value::Object(out))
+}
+
+fn nullable_type_to_json(field: &ArrowField, namespace: &str) -> Result<Value, ArrowError> {
+    let t = type_to_json(field, namespace)?;
+    Ok(match field.is_nullable() && !field.data_type().is_null() {
+        true => json!(["null", t]),
+        false => t,
+    })
+}
+
+fn type_to_json(field: &ArrowField, namespace: &str) -> Result<Value, ArrowError> {
+    let name = field.name();
+    let path = format!("{namespace}.{name}");
+    let logical = |t: &str, l: &str| json!({"type": t, "logicalType": l});
+    Ok(match field.data_type() {
+        DataType::Null => json!("null"),
+        DataType::Boolean => json!("boolean"),
+        DataType::Int8 | DataType::Int16 | DataType::Int32 | DataType::UInt8 | DataType::UInt16 => {
+            json!("int")
+        }
+        DataType::Int64 | DataType::UInt32 => json!("long"),
+        DataType::Float32 => json!("float"),
+        DataType::Float64 => json!("double"),
+        DataType::Binary | DataType::LargeBinary | DataType::BinaryView => json!("bytes"),
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => json!("string"),
+        DataType::FixedSizeBinary(16) if is_uuid_field(field) => logical("string", "uuid"),
+        DataType::FixedSizeBinary(size) => json!({
+            "type": "fixed",
+            "name": validate_name(name)?,
+            "namespace": validate_full_name(namespace)?,
+            "size": size,
+        }),
+        DataType::Date32 => logical("int", "date"),
+        DataType::Time32(TimeUnit::Millisecond) => logical("int", "time-millis"),
+        DataType::Time64(TimeUnit::Microsecond) => logical("long", "time-micros"),
+        DataType::Timestamp(TimeUnit::Millisecond, tz) => match tz {
+            Some(_) => logical("long", "timestamp-millis"),
+            None => logical("long", "local-timestamp-millis"),
+        },
+        DataType::Timestamp(TimeUnit::Microsecond, tz) => match tz {
+            Some(_) => logical("long", "timestamp-micros"),
+            None => logical("long", "local-timestamp-micros"),
+        },
+        DataType::Decimal128(precision, scale) => json!({
+            "type": "bytes",
+            "logicalType": "decimal",
+            "precision": precision,
+            "scale": scale,
+        }),
+        DataType::Decimal256(precision, scale) => json!({
+            "type": "fixed",
+            "name": validate_name(name)?,
+            "namespace": validate_full_name(namespace)?,
+            "size": 32,
+            "logicalType": "decimal",
+            "precision": precision,
+            "scale": scale,
+        }),
+        DataType::Interval(IntervalUnit::MonthDayNano) => json!({
+            "type": "fixed",
+            "name": validate_name(name)?,
+            "namespace": validate_full_name(namespace)?,
+            "size": 12,
+            "logicalType": "duration",
+        }),
+        DataType::Dictionary(_, value) if value.as_ref() == &DataType::Utf8 => {
+            match enum_symbols(field)? {
+                Some(symbols) => json!({
+                    "type": "enum",
+                    "name": validate_name(name)?,
+                    "namespace": validate_full_name(namespace)?,
+                    "symbols": symbols,
+                }),
+                None => json!("string"),
+            }
+        }
+        DataType::Dictionary(_, value) => {
+            let values = ArrowField::new(name, value.as_ref().clone(), false);
+            type_to_json(&values, namespace)?
+        }
+        DataType::List(item) | DataType::LargeList(item) => json!({
+            "type": "array",
+            "items": nullable_type_to_json(item, &path)?,
+        }),
+        DataType::Struct(fields) => json!({
+            "type": "record",
+            "name": validate_name(name)?,
+            "namespace": validate_full_name(namespace)?,
+            "fields": fields
+                .iter()
+                .map(|f| field_to_json(f, &path))
+                .collect::<Result<Vec<_>, _>>()?,
+        }),
+        DataType::Map(entries, _) => match entries.data_type() {
+            DataType::Struct(kv) if kv.len() == 2 && kv[0].data_type() == &DataType::Utf8 => {
+                json!({
+                    "type": "map",
+                    "values": nullable_type_to_json(&kv[1], &path)?,
+                })
+            }
+            other => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Avro maps require Utf8 keys, got {other}"
+                )))
+            }
+        },
+        other => {
+            return Err(ArrowError::NotYetImplemented(format!(
+                "Writing {other} to Avro is not currently supported"
+            )))
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::codec::{AvroDataType, AvroField};
+    use crate::codec::{AvroDataType, AvroField, MAX_RECURSION_DEPTH};
     use arrow_schema::{DataType, Fields, TimeUnit};
     use serde_json::json;
 
@@ -370,6 +593,7 @@ mod tests {
                         Schema::TypeName(TypeName::Primitive(PrimitiveType::Null)),
                     ]),
                     default: None,
+                    aliases: vec![],
                 },],
                 attributes: Default::default(),
             }))
@@ -401,6 +625,7 @@ mod tests {
                         doc: None,
                         r#type: Schema::TypeName(TypeName::Primitive(PrimitiveType::Long)),
                         default: None,
+                        aliases: vec![],
                     },
                     Field {
                         name: "next",
@@ -410,15 +635,25 @@ mod tests {
                             Schema::TypeName(TypeName::Ref("LongList")),
                         ]),
                         default: None,
+                        aliases: vec![],
                     }
                 ],
                 attributes: Attributes::default(),
             }))
         );
 
-        // Recursive schema are not supported
-        let err = AvroField::try_from(&schema).unwrap_err().to_string();
-        assert_eq!(err, "Parser error: Failed to resolve .LongList");
+        // Recursive schema are expanded up to MAX_RECURSION_DEPTH
+        let field = AvroField::try_from(&schema).unwrap().field();
+        let mut data_type = field.data_type();
+        for _ in 0..MAX_RECURSION_DEPTH {
+            let DataType::Struct(fields) = data_type else {
+                panic!("Expected struct got {data_type}")
+            };
+            assert_eq!(fields[0].data_type(), &DataType::Int64);
+            assert!(fields[1].is_nullable());
+            data_type = fields[1].data_type();
+        }
+        assert_eq!(data_type, &DataType::Null);
 
         let schema: Schema = serde_json::from_str(
             r#"{
@@ -463,6 +698,7 @@ mod tests {
                             Schema::TypeName(TypeName::Primitive(PrimitiveType::Null)),
                         ]),
                         default: None,
+                        aliases: vec![],
                     },
                     Field {
                         name: "timestamp_col",
@@ -472,6 +708,7 @@ mod tests {
                             Schema::TypeName(TypeName::Primitive(PrimitiveType::Null)),
                         ]),
                         default: None,
+                        aliases: vec![],
                     }
                 ],
                 attributes: Default::default(),
@@ -527,6 +764,7 @@ mod tests {
                             attributes: Default::default(),
                         })),
                         default: None,
+                        aliases: vec![],
                     },
                     Field {
                         name: "clientProtocol",
@@ -536,12 +774,14 @@ mod tests {
                             Schema::TypeName(TypeName::Primitive(PrimitiveType::String)),
                         ]),
                         default: None,
+                        aliases: vec![],
                     },
                     Field {
                         name: "serverHash",
                         doc: None,
                         r#type: Schema::TypeName(TypeName::Ref("MD5")),
                         default: None,
+                        aliases: vec![],
                     },
                     Field {
                         name: "meta",
@@ -556,10 +796,93 @@ mod tests {
                             })),
                         ]),
                         default: None,
+                        aliases: vec![],
                     }
                 ],
                 attributes: Default::default(),
             }))
         );
     }
+
+    #[test]
+    fn test_to_avro_json_schema() {
+        let inner = Fields::from(vec![
+            arrow_schema::Field::new("a", DataType::Decimal128(10, 2), false),
+            arrow_schema::Field::new("b", DataType::FixedSizeBinary(4), true),
+        ]);
+        let schema = ArrowSchema::new(vec![
+            arrow_schema::Field::new("id", DataType::Int64, false),
+            arrow_schema::Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Millisecond, Some("+00:00".into())),
+                true,
+            ),
+            arrow_schema::Field::new_list(
+                "tags",
+                arrow_schema::Field::new_list_field(DataType::Utf8, true),
+                false,
+            ),
+            arrow_schema::Field::new_struct("inner", inner, true),
+        ]);
+
+        let avro = to_avro_json_schema(&schema, DEFAULT_RECORD_NAME).unwrap();
+        assert_eq!(
+            avro,
+            json!({
+                "type": "record",
+                "name": "topLevelRecord",
+                "fields": [
+                    {"name": "id", "type": "long"},
+                    {
+                        "name": "ts",
+                        "type": ["null", {"type": "long", "logicalType": "timestamp-millis"}],
+                        "default": null
+                    },
+                    {"name": "tags", "type": {"type": "array", "items": ["null", "string"]}},
+                    {
+                        "name": "inner",
+                        "type": ["null", {
+                            "type": "record",
+                            "name": "inner",
+                            "namespace": "topLevelRecord",
+                            "fields": [
+                                {
+                                    "name": "a",
+                                    "type": {
+                                        "type": "bytes",
+                                        "logicalType": "decimal",
+                                        "precision": 10,
+                                        "scale": 2
+                                    }
+                                },
+                                {
+                                    "name": "b",
+                                    "type": ["null", {
+                                        "type": "fixed",
+                                        "name": "b",
+                                        "namespace": "topLevelRecord.inner",
+                                        "size": 4
+                                    }],
+                                    "default": null
+                                }
+                            ]
+                        }],
+                        "default": null
+                    }
+                ]
+            })
+        );
+
+        // The generated schema can be parsed and converted back to the same Arrow types
+        let json = avro.to_string();
+        let parsed: Schema = serde_json::from_str(&json).unwrap();
+        let field = AvroField::try_from(&parsed).unwrap();
+        let DataType::Struct(fields) = field.field().data_type().clone() else {
+            unreachable!()
+        };
+        for (actual, expected) in fields.iter().zip(schema.fields()) {
+            assert!(actual.data_type().equals_datatype(expected.data_type()));
+            assert_eq!(actual.is_nullable(), expected.is_nullable());
+        }
+    }
 }
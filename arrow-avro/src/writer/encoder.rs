@@ -0,0 +1,533 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Encoders converting Arrow arrays into Avro binary encoded records
+
+use crate::schema::{enum_symbols, is_uuid_field};
+use arrow_array::cast::AsArray;
+use arrow_array::types::*;
+use arrow_array::*;
+use arrow_buffer::{
+    i256, ArrowNativeType, IntervalMonthDayNano, NullBuffer, OffsetBuffer, ScalarBuffer,
+};
+use arrow_schema::{ArrowError, DataType, Field, Fields, IntervalUnit, TimeUnit};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Write a zig-zag encoded variable length `long` to `out`
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#primitive-types-1>
+#[inline]
+pub(crate) fn write_long(out: &mut Vec<u8>, value: i64) {
+    let mut v = ((value << 1) ^ (value >> 63)) as u64;
+    while v >= 0x80 {
+        out.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+/// Write a length-prefixed byte sequence to `out`
+#[inline]
+pub(crate) fn write_bytes(out: &mut Vec<u8>, value: &[u8]) {
+    write_long(out, value.len() as i64);
+    out.extend_from_slice(value);
+}
+
+/// Encodes the rows of a set of columns as Avro records
+#[derive(Debug)]
+pub(crate) struct RecordEncoder<'a> {
+    fields: Vec<FieldEncoder<'a>>,
+}
+
+impl<'a> RecordEncoder<'a> {
+    /// Create a new [`RecordEncoder`] for `columns` with the Arrow `fields` they correspond to
+    pub(crate) fn try_new(fields: &'a Fields, columns: &'a [ArrayRef]) -> Result<Self, ArrowError> {
+        let fields = fields
+            .iter()
+            .zip(columns)
+            .map(|(f, c)| FieldEncoder::try_new(f, c.as_ref(), None))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { fields })
+    }
+
+    /// Append the Avro encoding of the row at `idx` to `out`
+    pub(crate) fn encode(&self, out: &mut Vec<u8>, idx: usize) -> Result<(), ArrowError> {
+        for field in &self.fields {
+            field.encode(out, idx)?;
+        }
+        Ok(())
+    }
+}
+
+/// An [`Encoder`] for a single, potentially nullable, Arrow field
+#[derive(Debug)]
+struct FieldEncoder<'a> {
+    name: &'a str,
+    /// The logical nulls of the array if the field is nullable
+    ///
+    /// Nullable fields are written as the union `["null", T]`
+    nulls: Option<Option<NullBuffer>>,
+    encoder: Encoder<'a>,
+}
+
+impl<'a> FieldEncoder<'a> {
+    /// Create a new [`FieldEncoder`] for `array`
+    ///
+    /// `parent_nulls` are the nulls of the enclosing struct, if any, which may mask
+    /// nulls in the children of non-nullable fields
+    fn try_new(
+        field: &'a Field,
+        array: &'a dyn Array,
+        parent_nulls: Option<&NullBuffer>,
+    ) -> Result<Self, ArrowError> {
+        let nulls =
+            (field.is_nullable() && !field.data_type().is_null()).then(|| array.logical_nulls());
+        if nulls.is_none() && !field.data_type().is_null() {
+            let unmasked = match (array.logical_nulls(), parent_nulls) {
+                (Some(n), Some(p)) => !p.contains(&n),
+                (Some(n), None) => n.null_count() > 0,
+                (None, _) => false,
+            };
+            if unmasked {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Found nulls in non-nullable field \"{}\"",
+                    field.name()
+                )));
+            }
+        }
+        Ok(Self {
+            name: field.name(),
+            nulls,
+            encoder: Encoder::try_new(field, array)?,
+        })
+    }
+
+    fn encode(&self, out: &mut Vec<u8>, idx: usize) -> Result<(), ArrowError> {
+        match &self.nulls {
+            None => {}
+            Some(Some(nulls)) if nulls.is_null(idx) => {
+                write_long(out, 0);
+                return Ok(());
+            }
+            Some(_) => write_long(out, 1),
+        }
+        self.encoder.encode(out, idx).map_err(|e| match e {
+            ArrowError::InvalidArgumentError(msg) => {
+                ArrowError::InvalidArgumentError(format!("{}: {msg}", self.name))
+            }
+            e => e,
+        })
+    }
+}
+
+#[derive(Debug)]
+enum Encoder<'a> {
+    Null,
+    Boolean(&'a BooleanArray),
+    Int(ScalarBuffer<i32>),
+    Long(ScalarBuffer<i64>),
+    Float(ScalarBuffer<f32>),
+    Double(ScalarBuffer<f64>),
+    Binary(&'a BinaryArray),
+    LargeBinary(&'a LargeBinaryArray),
+    BinaryView(&'a BinaryViewArray),
+    String(&'a StringArray),
+    LargeString(&'a LargeStringArray),
+    StringView(&'a StringViewArray),
+    Fixed(&'a FixedSizeBinaryArray),
+    Uuid(&'a FixedSizeBinaryArray),
+    Decimal128(ScalarBuffer<i128>),
+    Decimal256(ScalarBuffer<i256>),
+    Duration(ScalarBuffer<IntervalMonthDayNano>),
+    /// The index of the enum symbol of each row
+    Enum(Vec<i32>),
+    /// The dictionary keys of each row, and an encoder for the dictionary values
+    Dictionary(Vec<usize>, Box<Encoder<'a>>),
+    List(OffsetBuffer<i32>, Box<FieldEncoder<'a>>),
+    LargeList(OffsetBuffer<i64>, Box<FieldEncoder<'a>>),
+    Struct(Vec<FieldEncoder<'a>>),
+    Map(OffsetBuffer<i32>, &'a StringArray, Box<FieldEncoder<'a>>),
+}
+
+impl<'a> Encoder<'a> {
+    fn try_new(field: &'a Field, array: &'a dyn Array) -> Result<Self, ArrowError> {
+        match field.data_type() {
+            DataType::FixedSizeBinary(_) if is_uuid_field(field) => {
+                Ok(Self::Uuid(array.as_fixed_size_binary()))
+            }
+            DataType::Dictionary(_, value_type) if value_type.as_ref() == &DataType::Utf8 => {
+                match enum_symbols(field)? {
+                    Some(symbols) => Self::try_new_enum(&symbols, array),
+                    None => Self::try_new_with_type(field.data_type(), array),
+                }
+            }
+            data_type => Self::try_new_with_type(data_type, array),
+        }
+    }
+
+    fn try_new_enum(symbols: &[String], array: &'a dyn Array) -> Result<Self, ArrowError> {
+        let lookup: HashMap<&str, i32> = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.as_str(), i as i32))
+            .collect();
+        let dictionary = array.as_any_dictionary();
+        let values = dictionary.values().as_string::<i32>();
+        // Map each dictionary value to the index of its symbol, null values are
+        // never encoded and so can be mapped to an arbitrary index
+        let mapping = (0..values.len())
+            .map(|i| match values.is_valid(i) {
+                true => lookup.get(values.value(i)).copied().ok_or_else(|| {
+                    ArrowError::InvalidArgumentError(format!(
+                        "Value \"{}\" is not a symbol of the enum",
+                        values.value(i)
+                    ))
+                }),
+                false => Ok(0),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let indices = dictionary
+            .normalized_keys()
+            .into_iter()
+            .map(|k| mapping.get(k).copied().unwrap_or_default())
+            .collect();
+        Ok(Self::Enum(indices))
+    }
+
+    fn try_new_with_type(
+        data_type: &'a DataType,
+        array: &'a dyn Array,
+    ) -> Result<Self, ArrowError> {
+        Ok(match data_type {
+            DataType::Null => Self::Null,
+            DataType::Boolean => Self::Boolean(array.as_boolean()),
+            DataType::Int8 => Self::Int(widen::<Int8Type, _>(array)),
+            DataType::Int16 => Self::Int(widen::<Int16Type, _>(array)),
+            DataType::UInt8 => Self::Int(widen::<UInt8Type, _>(array)),
+            DataType::UInt16 => Self::Int(widen::<UInt16Type, _>(array)),
+            DataType::Int32 => Self::Int(array.as_primitive::<Int32Type>().values().clone()),
+            DataType::Date32 => Self::Int(array.as_primitive::<Date32Type>().values().clone()),
+            DataType::Time32(TimeUnit::Millisecond) => Self::Int(
+                array
+                    .as_primitive::<Time32MillisecondType>()
+                    .values()
+                    .clone(),
+            ),
+            DataType::UInt32 => Self::Long(widen::<UInt32Type, _>(array)),
+            DataType::Int64 => Self::Long(array.as_primitive::<Int64Type>().values().clone()),
+            DataType::Time64(TimeUnit::Microsecond) => Self::Long(
+                array
+                    .as_primitive::<Time64MicrosecondType>()
+                    .values()
+                    .clone(),
+            ),
+            DataType::Timestamp(TimeUnit::Millisecond, _) => Self::Long(
+                array
+                    .as_primitive::<TimestampMillisecondType>()
+                    .values()
+                    .clone(),
+            ),
+            DataType::Timestamp(TimeUnit::Microsecond, _) => Self::Long(
+                array
+                    .as_primitive::<TimestampMicrosecondType>()
+                    .values()
+                    .clone(),
+            ),
+            DataType::Float32 => Self::Float(array.as_primitive::<Float32Type>().values().clone()),
+            DataType::Float64 => Self::Double(array.as_primitive::<Float64Type>().values().clone()),
+            DataType::Binary => Self::Binary(array.as_binary()),
+            DataType::LargeBinary => Self::LargeBinary(array.as_binary()),
+            DataType::BinaryView => Self::BinaryView(array.as_binary_view()),
+            DataType::Utf8 => Self::String(array.as_string()),
+            DataType::LargeUtf8 => Self::LargeString(array.as_string()),
+            DataType::Utf8View => Self::StringView(array.as_string_view()),
+            DataType::FixedSizeBinary(_) => Self::Fixed(array.as_fixed_size_binary()),
+            DataType::Decimal128(_, _) => {
+                Self::Decimal128(array.as_primitive::<Decimal128Type>().values().clone())
+            }
+            DataType::Decimal256(_, _) => {
+                Self::Decimal256(array.as_primitive::<Decimal256Type>().values().clone())
+            }
+            DataType::Interval(IntervalUnit::MonthDayNano) => Self::Duration(
+                array
+                    .as_primitive::<IntervalMonthDayNanoType>()
+                    .values()
+                    .clone(),
+            ),
+            DataType::Dictionary(_, value_type) => {
+                let dictionary = array.as_any_dictionary();
+                let values = Self::try_new_with_type(value_type, dictionary.values().as_ref())?;
+                Self::Dictionary(dictionary.normalized_keys(), Box::new(values))
+            }
+            DataType::List(item) => {
+                let list = array.as_list::<i32>();
+                Self::List(
+                    list.offsets().clone(),
+                    Box::new(FieldEncoder::try_new(item, list.values().as_ref(), None)?),
+                )
+            }
+            DataType::LargeList(item) => {
+                let list = array.as_list::<i64>();
+                Self::LargeList(
+                    list.offsets().clone(),
+                    Box::new(FieldEncoder::try_new(item, list.values().as_ref(), None)?),
+                )
+            }
+            DataType::Struct(fields) => {
+                let s = array.as_struct();
+                Self::Struct(
+                    fields
+                        .iter()
+                        .zip(s.columns())
+                        .map(|(f, c)| FieldEncoder::try_new(f, c.as_ref(), s.nulls()))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            DataType::Map(entries, _) => {
+                let map = array.as_map();
+                let value_field = match entries.data_type() {
+                    DataType::Struct(f) if f.len() == 2 => &f[1],
+                    other => {
+                        return Err(ArrowError::InvalidArgumentError(format!(
+                            "Invalid map entries type {other}"
+                        )))
+                    }
+                };
+                let keys = map.keys().as_string_opt::<i32>().ok_or_else(|| {
+                    ArrowError::NotYetImplemented("Avro maps require Utf8 keys".to_string())
+                })?;
+                Self::Map(
+                    map.offsets().clone(),
+                    keys,
+                    Box::new(FieldEncoder::try_new(
+                        value_field,
+                        map.values().as_ref(),
+                        None,
+                    )?),
+                )
+            }
+            other => {
+                return Err(ArrowError::NotYetImplemented(format!(
+                    "Writing {other} to Avro is not currently supported"
+                )))
+            }
+        })
+    }
+
+    fn encode(&self, out: &mut Vec<u8>, idx: usize) -> Result<(), ArrowError> {
+        match self {
+            Self::Null => {}
+            Self::Boolean(values) => out.push(values.value(idx) as u8),
+            Self::Int(values) => write_long(out, values[idx] as i64),
+            Self::Long(values) => write_long(out, values[idx]),
+            Self::Float(values) => out.extend_from_slice(&values[idx].to_le_bytes()),
+            Self::Double(values) => out.extend_from_slice(&values[idx].to_le_bytes()),
+            Self::Binary(values) => write_bytes(out, values.value(idx)),
+            Self::LargeBinary(values) => write_bytes(out, values.value(idx)),
+            Self::BinaryView(values) => write_bytes(out, values.value(idx)),
+            Self::String(values) => write_bytes(out, values.value(idx).as_bytes()),
+            Self::LargeString(values) => write_bytes(out, values.value(idx).as_bytes()),
+            Self::StringView(values) => write_bytes(out, values.value(idx).as_bytes()),
+            Self::Fixed(values) => out.extend_from_slice(values.value(idx)),
+            Self::Uuid(values) => {
+                let uuid = Uuid::from_slice(values.value(idx))
+                    .map_err(|e| ArrowError::InvalidArgumentError(e.to_string()))?;
+                write_bytes(out, uuid.hyphenated().to_string().as_bytes())
+            }
+            Self::Decimal128(values) => {
+                write_bytes(out, minimal_twos_complement(&values[idx].to_be_bytes()))
+            }
+            Self::Decimal256(values) => out.extend_from_slice(&values[idx].to_be_bytes()),
+            Self::Duration(values) => {
+                let v = values[idx];
+                let millis = match v.nanoseconds % 1_000_000 {
+                    0 => u32::try_from(v.nanoseconds / 1_000_000).ok(),
+                    _ => None,
+                };
+                let (months, days, millis) =
+                    match (u32::try_from(v.months), u32::try_from(v.days), millis) {
+                        (Ok(months), Ok(days), Some(millis)) => (months, days, millis),
+                        _ => {
+                            return Err(ArrowError::InvalidArgumentError(format!(
+                                "Interval {v:?} cannot be represented as an Avro duration"
+                            )))
+                        }
+                    };
+                out.extend_from_slice(&months.to_le_bytes());
+                out.extend_from_slice(&days.to_le_bytes());
+                out.extend_from_slice(&millis.to_le_bytes());
+            }
+            Self::Enum(indices) => write_long(out, indices[idx] as i64),
+            Self::Dictionary(keys, values) => values.encode(out, keys[idx])?,
+            Self::List(offsets, items) => {
+                let (start, end) = (offsets[idx] as usize, offsets[idx + 1] as usize);
+                write_block(out, start..end, |out, i| items.encode(out, i))?
+            }
+            Self::LargeList(offsets, items) => {
+                let (start, end) = (offsets[idx] as usize, offsets[idx + 1] as usize);
+                write_block(out, start..end, |out, i| items.encode(out, i))?
+            }
+            Self::Struct(fields) => {
+                for field in fields {
+                    field.encode(out, idx)?;
+                }
+            }
+            Self::Map(offsets, keys, values) => {
+                let (start, end) = (offsets[idx] as usize, offsets[idx + 1] as usize);
+                write_block(out, start..end, |out, i| {
+                    write_bytes(out, keys.value(i).as_bytes());
+                    values.encode(out, i)
+                })?
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Widen the values of a primitive array to `N`
+fn widen<T, N>(array: &dyn Array) -> ScalarBuffer<N>
+where
+    T: ArrowPrimitiveType,
+    N: ArrowNativeType + From<T::Native>,
+{
+    array
+        .as_primitive::<T>()
+        .values()
+        .iter()
+        .map(|v| N::from(*v))
+        .collect()
+}
+
+/// Writes the items in `range` as a single Avro array / map block followed by the
+/// terminating zero-length block
+///
+/// <https://avro.apache.org/docs/1.11.1/specification/#arrays-1>
+fn write_block(
+    out: &mut Vec<u8>,
+    range: std::ops::Range<usize>,
+    mut encode_item: impl FnMut(&mut Vec<u8>, usize) -> Result<(), ArrowError>,
+) -> Result<(), ArrowError> {
+    if !range.is_empty() {
+        write_long(out, range.len() as i64);
+        for i in range {
+            encode_item(out, i)?;
+        }
+    }
+    write_long(out, 0);
+    Ok(())
+}
+
+/// Strips redundant leading sign bytes from a big-endian two's complement integer
+fn minimal_twos_complement(bytes: &[u8]) -> &[u8] {
+    let mut start = 0;
+    while start + 1 < bytes.len() {
+        let redundant = match bytes[start] {
+            0x00 => bytes[start + 1] & 0x80 == 0,
+            0xFF => bytes[start + 1] & 0x80 != 0,
+            _ => false,
+        };
+        if !redundant {
+            break;
+        }
+        start += 1;
+    }
+    &bytes[start..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_long(buf: &[u8]) -> (i64, usize) {
+        let mut value = 0_u64;
+        for (idx, b) in buf.iter().enumerate() {
+            value |= ((b & 0x7F) as u64) << (idx * 7);
+            if b & 0x80 == 0 {
+                return ((value >> 1) as i64 ^ -((value & 1) as i64), idx + 1);
+            }
+        }
+        panic!("truncated varint")
+    }
+
+    #[test]
+    fn test_write_long() {
+        for v in [0, 1, -1, 63, -64, 64, i32::MAX as i64, i64::MIN, i64::MAX] {
+            let mut out = vec![];
+            write_long(&mut out, v);
+            assert_eq!(read_long(&out), (v, out.len()));
+        }
+
+        let mut out = vec![];
+        write_long(&mut out, 1);
+        write_long(&mut out, -1);
+        write_long(&mut out, 64);
+        assert_eq!(out, [0x02, 0x01, 0x80, 0x01]);
+    }
+
+    #[test]
+    fn test_minimal_twos_complement() {
+        let cases: [(i128, &[u8]); 6] = [
+            (0, &[0x00]),
+            (1, &[0x01]),
+            (-1, &[0xFF]),
+            (127, &[0x7F]),
+            (128, &[0x00, 0x80]),
+            (-129, &[0xFF, 0x7F]),
+        ];
+        for (v, expected) in cases {
+            assert_eq!(minimal_twos_complement(&v.to_be_bytes()), expected, "{v}");
+        }
+    }
+
+    #[test]
+    fn test_encode_nullable_list() {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>([
+            Some(vec![Some(1), None]),
+            None,
+            Some(vec![]),
+        ]);
+        let fields = Fields::from(vec![Field::new("l", list.data_type().clone(), true)]);
+        let columns = [std::sync::Arc::new(list) as ArrayRef];
+        let encoder = RecordEncoder::try_new(&fields, &columns).unwrap();
+
+        let mut out = vec![];
+        (0..3).for_each(|i| encoder.encode(&mut out, i).unwrap());
+        assert_eq!(
+            out,
+            [
+                0x02, 0x04, 0x02, 0x02, 0x00, 0x00, // [1, null]
+                0x00, // null
+                0x02, 0x00, // []
+            ]
+        );
+    }
+
+    #[test]
+    fn test_encode_enum_not_in_symbols() {
+        let dict: DictionaryArray<Int32Type> = ["a", "z"].into_iter().collect();
+        let field = Field::new("e", dict.data_type().clone(), false)
+            .with_metadata([("avro.enum.symbols".to_string(), r#"["a","b"]"#.to_string())].into());
+        let fields = Fields::from(vec![field]);
+        let columns = [std::sync::Arc::new(dict) as ArrayRef];
+        let err = RecordEncoder::try_new(&fields, &columns).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Value \"z\" is not a symbol of the enum"
+        );
+    }
+}
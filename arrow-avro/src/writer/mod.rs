@@ -0,0 +1,802 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Avro writer
+//!
+//! This module provides facilities to write Arrow [`RecordBatch`] to
+//! [Avro Object Container Files], in particular it introduces:
+//!
+//! * `WriterBuilder`: Configures Avro writing, e.g. the compression codec
+//! * `Writer`: Encodes `RecordBatch` into blocks of Avro records
+//!
+//! The Avro schema written to the file header is derived from the Arrow schema,
+//! with nullable fields mapped to a union of `null` and the field type.
+//!
+//! # Example
+//!
+//! ```
+//! # use std::io::Cursor;
+//! # use std::sync::Arc;
+//! # use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray};
+//! # use arrow_avro::compression::CompressionCodec;
+//! # use arrow_avro::reader::ReaderBuilder;
+//! # use arrow_avro::writer::WriterBuilder;
+//! let batch = RecordBatch::try_from_iter([
+//!     ("id", Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef),
+//!     ("name", Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef),
+//! ])
+//! .unwrap();
+//!
+//! let mut writer = WriterBuilder::new(batch.schema().as_ref().clone())
+//!     .with_compression(Some(CompressionCodec::Deflate))
+//!     .build(Vec::new())
+//!     .unwrap();
+//! writer.write(&batch).unwrap();
+//! let buffer = writer.into_inner().unwrap();
+//!
+//! let mut reader = ReaderBuilder::new().build(Cursor::new(buffer)).unwrap();
+//! let read = reader.next().unwrap().unwrap();
+//! assert_eq!(read.num_rows(), 3);
+//! ```
+//!
+//! [Avro Object Container Files]: https://avro.apache.org/docs/1.11.1/specification/#object-container-files
+//! [`RecordBatch`]: arrow_array::RecordBatch
+
+use crate::compression::{CompressionCodec, CODEC_METADATA_KEY};
+use crate::schema::{to_avro_json_schema, DEFAULT_RECORD_NAME, SCHEMA_METADATA_KEY};
+use arrow_array::{RecordBatch, RecordBatchWriter};
+use arrow_schema::{ArrowError, Schema, SchemaRef};
+use encoder::{write_bytes, write_long, RecordEncoder};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::io::Write;
+use std::sync::Arc;
+
+mod encoder;
+
+const MAGIC: &[u8; 4] = b"Obj\x01";
+
+/// A builder to create an Avro [`Writer`]
+#[derive(Debug, Clone)]
+pub struct WriterBuilder {
+    schema: Schema,
+    compression: Option<CompressionCodec>,
+    record_name: String,
+}
+
+impl WriterBuilder {
+    /// Creates a new [`WriterBuilder`] for batches with the given [`Schema`]
+    ///
+    /// Defaults to no compression, and a top-level record named `topLevelRecord`
+    pub fn new(schema: Schema) -> Self {
+        Self {
+            schema,
+            compression: None,
+            record_name: DEFAULT_RECORD_NAME.to_string(),
+        }
+    }
+
+    /// Sets the [`CompressionCodec`] used to compress each block, or `None` for no compression
+    pub fn with_compression(mut self, compression: Option<CompressionCodec>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Get the [`CompressionCodec`] used to compress each block
+    pub fn compression(&self) -> Option<CompressionCodec> {
+        self.compression
+    }
+
+    /// Sets the name of the top-level Avro record
+    pub fn with_record_name(mut self, record_name: impl Into<String>) -> Self {
+        self.record_name = record_name.into();
+        self
+    }
+
+    /// Get the name of the top-level Avro record
+    pub fn record_name(&self) -> &str {
+        &self.record_name
+    }
+
+    /// Create a new [`Writer`] writing to `writer`
+    ///
+    /// This will write the file header, including the Avro schema derived from the
+    /// Arrow schema, returning an error if the schema contains unsupported types
+    pub fn build<W: Write>(self, mut writer: W) -> Result<Writer<W>, ArrowError> {
+        let avro_schema = to_avro_json_schema(&self.schema, &self.record_name)?;
+        let sync_marker = sync_marker();
+        let codec = self.compression.map(|c| c.name()).unwrap_or("null");
+
+        let mut header = Vec::with_capacity(1024);
+        header.extend_from_slice(MAGIC);
+        write_long(&mut header, 2);
+        write_bytes(&mut header, SCHEMA_METADATA_KEY.as_bytes());
+        write_bytes(&mut header, avro_schema.to_string().as_bytes());
+        write_bytes(&mut header, CODEC_METADATA_KEY.as_bytes());
+        write_bytes(&mut header, codec.as_bytes());
+        write_long(&mut header, 0);
+        header.extend_from_slice(&sync_marker);
+        writer.write_all(&header)?;
+
+        Ok(Writer {
+            writer,
+            schema: Arc::new(self.schema),
+            compression: self.compression,
+            sync_marker,
+            buffer: Vec::with_capacity(1024),
+        })
+    }
+}
+
+/// Generates a random sync marker
+fn sync_marker() -> [u8; 16] {
+    let mut marker = [0; 16];
+    for chunk in marker.chunks_exact_mut(8) {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_usize(chunk.as_ptr() as usize);
+        chunk.copy_from_slice(&hasher.finish().to_le_bytes());
+    }
+    marker
+}
+
+/// Writes Arrow [`RecordBatch`] to an [Avro Object Container File]
+///
+/// Each non-empty batch is written as a single block of records
+///
+/// [Avro Object Container File]: https://avro.apache.org/docs/1.11.1/specification/#object-container-files
+#[derive(Debug)]
+pub struct Writer<W: Write> {
+    writer: W,
+    schema: SchemaRef,
+    compression: Option<CompressionCodec>,
+    sync_marker: [u8; 16],
+    /// Scratch space for encoding blocks
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> Writer<W> {
+    /// Create a new [`Writer`] with default options, see [`WriterBuilder`]
+    pub fn try_new(writer: W, schema: &Schema) -> Result<Self, ArrowError> {
+        WriterBuilder::new(schema.clone()).build(writer)
+    }
+
+    /// Returns the Arrow schema of this writer
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Returns the sync marker written after each block
+    pub fn sync_marker(&self) -> [u8; 16] {
+        self.sync_marker
+    }
+
+    /// Write a [`RecordBatch`] as a block of Avro records
+    pub fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        if batch.schema().fields() != self.schema.fields() {
+            return Err(ArrowError::SchemaError(
+                "Cannot write record batch with different schema".to_string(),
+            ));
+        }
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        self.buffer.clear();
+        let encoder = RecordEncoder::try_new(self.schema.fields(), batch.columns())?;
+        for idx in 0..batch.num_rows() {
+            encoder.encode(&mut self.buffer, idx)?;
+        }
+
+        let compressed;
+        let data = match &self.compression {
+            Some(codec) => {
+                compressed = codec.compress(&self.buffer)?;
+                &compressed
+            }
+            None => &self.buffer,
+        };
+
+        let mut prefix = Vec::with_capacity(20);
+        write_long(&mut prefix, batch.num_rows() as i64);
+        write_long(&mut prefix, data.len() as i64);
+        self.writer.write_all(&prefix)?;
+        self.writer.write_all(data)?;
+        self.writer.write_all(&self.sync_marker)?;
+        Ok(())
+    }
+
+    /// Flushes the underlying writer
+    pub fn flush(&mut self) -> Result<(), ArrowError> {
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Gets a reference to the underlying writer
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Gets a mutable reference to the underlying writer
+    ///
+    /// Writing to the underlying writer directly will corrupt the Avro file
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Flushes the underlying writer and returns it
+    pub fn into_inner(mut self) -> Result<W, ArrowError> {
+        self.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write> RecordBatchWriter for Writer<W> {
+    fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.write(batch)
+    }
+
+    fn close(mut self) -> Result<(), ArrowError> {
+        self.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::ReaderBuilder;
+    use arrow_array::builder::{
+        Decimal128Builder, Int64Builder, ListBuilder, MapBuilder, MapFieldNames, StringBuilder,
+    };
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int32Type, IntervalMonthDayNanoType};
+    use arrow_array::*;
+    use arrow_buffer::{i256, NullBuffer};
+    use arrow_schema::{DataType, Field, Fields, IntervalUnit, TimeUnit};
+    use std::collections::HashMap;
+    use std::io::Cursor;
+
+    fn write(batches: &[RecordBatch], compression: Option<CompressionCodec>) -> Vec<u8> {
+        let mut writer = WriterBuilder::new(batches[0].schema().as_ref().clone())
+            .with_compression(compression)
+            .build(Vec::new())
+            .unwrap();
+        for batch in batches {
+            writer.write(batch).unwrap();
+        }
+        writer.into_inner().unwrap()
+    }
+
+    fn round_trip(batch: &RecordBatch, compression: Option<CompressionCodec>) -> RecordBatch {
+        let buffer = write(std::slice::from_ref(batch), compression);
+        let reader = ReaderBuilder::new()
+            .with_batch_size(1024)
+            .build(Cursor::new(buffer))
+            .unwrap();
+        let schema = reader.schema();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        arrow::compute::concat_batches(&schema, &batches).unwrap()
+    }
+
+    /// Asserts the data in `actual` matches `expected`, ignoring field metadata
+    fn assert_batches_eq(actual: &RecordBatch, expected: &RecordBatch) {
+        assert_eq!(actual.num_columns(), expected.num_columns());
+        for (idx, field) in expected.schema().fields().iter().enumerate() {
+            let actual_field = actual.schema().field(idx).clone();
+            assert_eq!(actual_field.name(), field.name());
+            assert_eq!(
+                actual_field.data_type(),
+                field.data_type(),
+                "{}",
+                field.name()
+            );
+            assert_eq!(actual.column(idx), expected.column(idx), "{}", field.name());
+        }
+    }
+
+    #[test]
+    fn test_primitives() {
+        let batch = RecordBatch::try_from_iter_with_nullable([
+            (
+                "bool",
+                Arc::new(BooleanArray::from(vec![Some(true), None, Some(false)])) as ArrayRef,
+                true,
+            ),
+            (
+                "int",
+                Arc::new(Int32Array::from(vec![1, -2, i32::MAX])) as _,
+                false,
+            ),
+            (
+                "long",
+                Arc::new(Int64Array::from(vec![None, Some(i64::MIN), Some(3)])) as _,
+                true,
+            ),
+            (
+                "float",
+                Arc::new(Float32Array::from(vec![1.5, -0.0, f32::MAX])) as _,
+                false,
+            ),
+            (
+                "double",
+                Arc::new(Float64Array::from(vec![1.5, 2.25, -3.0])) as _,
+                false,
+            ),
+            (
+                "bytes",
+                Arc::new(BinaryArray::from_opt_vec(vec![
+                    Some(b"ab"),
+                    Some(b""),
+                    None,
+                ])) as _,
+                true,
+            ),
+            (
+                "string",
+                Arc::new(StringArray::from(vec!["a", "bb", "ccc"])) as _,
+                false,
+            ),
+            (
+                "fixed",
+                Arc::new(
+                    FixedSizeBinaryArray::try_from_iter([b"abc", b"def", b"ghi"].into_iter())
+                        .unwrap(),
+                ) as _,
+                false,
+            ),
+        ])
+        .unwrap();
+
+        let read = round_trip(&batch, None);
+        assert_batches_eq(&read, &batch);
+    }
+
+    #[test]
+    fn test_widened_integers() {
+        let batch = RecordBatch::try_from_iter([
+            (
+                "i8",
+                Arc::new(Int8Array::from(vec![i8::MIN, 0, i8::MAX])) as ArrayRef,
+            ),
+            (
+                "u16",
+                Arc::new(UInt16Array::from(vec![0, 1, u16::MAX])) as _,
+            ),
+            (
+                "u32",
+                Arc::new(UInt32Array::from(vec![0, 1, u32::MAX])) as _,
+            ),
+        ])
+        .unwrap();
+
+        let read = round_trip(&batch, None);
+        assert_eq!(
+            read.column(0).as_ref(),
+            &Int32Array::from(vec![i8::MIN as i32, 0, i8::MAX as i32])
+        );
+        assert_eq!(
+            read.column(1).as_ref(),
+            &Int32Array::from(vec![0, 1, u16::MAX as i32])
+        );
+        assert_eq!(
+            read.column(2).as_ref(),
+            &Int64Array::from(vec![0, 1, u32::MAX as i64])
+        );
+    }
+
+    #[test]
+    fn test_logical_types() {
+        let batch = RecordBatch::try_from_iter_with_nullable([
+            (
+                "date",
+                Arc::new(Date32Array::from(vec![Some(0), None, Some(19000)])) as ArrayRef,
+                true,
+            ),
+            (
+                "time_millis",
+                Arc::new(Time32MillisecondArray::from(vec![0, 1000, 86_399_999])) as _,
+                false,
+            ),
+            (
+                "time_micros",
+                Arc::new(Time64MicrosecondArray::from(vec![0, 1, 86_399_999_999])) as _,
+                false,
+            ),
+            (
+                "ts_millis",
+                Arc::new(
+                    TimestampMillisecondArray::from(vec![0, -1, 1_700_000_000_000])
+                        .with_timezone("+00:00"),
+                ) as _,
+                false,
+            ),
+            (
+                "ts_micros",
+                Arc::new(
+                    TimestampMicrosecondArray::from(vec![Some(0), None, Some(-5)])
+                        .with_timezone("+00:00"),
+                ) as _,
+                true,
+            ),
+            (
+                "local_ts_micros",
+                Arc::new(TimestampMicrosecondArray::from(vec![1, 2, 3])) as _,
+                false,
+            ),
+            (
+                "duration",
+                Arc::new(IntervalMonthDayNanoArray::from(vec![
+                    IntervalMonthDayNanoType::make_value(1, 2, 3_000_000),
+                    IntervalMonthDayNanoType::make_value(0, 0, 0),
+                    IntervalMonthDayNanoType::make_value(12, 31, 999_000_000),
+                ])) as _,
+                false,
+            ),
+        ])
+        .unwrap();
+
+        let read = round_trip(&batch, None);
+        assert_batches_eq(&read, &batch);
+
+        let schema = read.schema();
+        assert_eq!(
+            schema.field(5).data_type(),
+            &DataType::Timestamp(TimeUnit::Microsecond, None)
+        );
+        assert_eq!(
+            schema.field(6).data_type(),
+            &DataType::Interval(IntervalUnit::MonthDayNano)
+        );
+    }
+
+    #[test]
+    fn test_invalid_duration() {
+        let batch = RecordBatch::try_from_iter([(
+            "duration",
+            Arc::new(IntervalMonthDayNanoArray::from(vec![
+                IntervalMonthDayNanoType::make_value(1, 2, 3),
+            ])) as ArrayRef,
+        )])
+        .unwrap();
+
+        let mut writer = Writer::try_new(Vec::new(), &batch.schema()).unwrap();
+        let err = writer.write(&batch).unwrap_err().to_string();
+        assert!(
+            err.contains("duration: Interval") && err.contains("cannot be represented"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_decimals() {
+        let mut builder = Decimal128Builder::new()
+            .with_precision_and_scale(10, 2)
+            .unwrap();
+        builder.append_value(12345);
+        builder.append_null();
+        builder.append_value(-1);
+        let d128 = builder.finish();
+
+        let d256 = Decimal256Array::from(vec![
+            i256::from_i128(1),
+            i256::MIN.wrapping_add(i256::ONE),
+            i256::MAX,
+        ])
+        .with_precision_and_scale(76, 10)
+        .unwrap();
+
+        let batch = RecordBatch::try_from_iter_with_nullable([
+            ("d128", Arc::new(d128) as ArrayRef, true),
+            ("d256", Arc::new(d256) as ArrayRef, false),
+        ])
+        .unwrap();
+
+        let read = round_trip(&batch, None);
+        assert_batches_eq(&read, &batch);
+    }
+
+    #[test]
+    fn test_enum() {
+        let keys = Int32Array::from(vec![Some(1), None, Some(0), Some(1)]);
+        // Dictionary values in a different order to the enum symbols
+        let values = StringArray::from(vec!["c", "a"]);
+        let dict = DictionaryArray::<Int32Type>::try_new(keys, Arc::new(values)).unwrap();
+
+        let metadata = HashMap::from([(
+            "avro.enum.symbols".to_string(),
+            r#"["a","b","c"]"#.to_string(),
+        )]);
+        let field = Field::new("e", dict.data_type().clone(), true).with_metadata(metadata);
+        let schema = Arc::new(Schema::new(vec![field]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(dict)]).unwrap();
+
+        let read = round_trip(&batch, None);
+        let expected = DictionaryArray::<Int32Type>::try_new(
+            Int32Array::from(vec![Some(0), None, Some(2), Some(0)]),
+            Arc::new(StringArray::from(vec!["a", "b", "c"])),
+        )
+        .unwrap();
+        assert_eq!(read.column(0).as_ref(), &expected);
+        assert_eq!(
+            read.schema().field(0).metadata()["avro.enum.symbols"],
+            r#"["a","b","c"]"#
+        );
+    }
+
+    #[test]
+    fn test_dictionary_without_symbols() {
+        let dict: DictionaryArray<Int32Type> = vec![Some("x"), None, Some("y"), Some("x")]
+            .into_iter()
+            .collect();
+        let batch =
+            RecordBatch::try_from_iter_with_nullable([("d", Arc::new(dict) as ArrayRef, true)])
+                .unwrap();
+
+        let read = round_trip(&batch, None);
+        assert_eq!(
+            read.column(0).as_ref(),
+            &StringArray::from(vec![Some("x"), None, Some("y"), Some("x")])
+        );
+    }
+
+    #[test]
+    fn test_nested() {
+        let mut list = ListBuilder::new(Int64Builder::new());
+        list.append_value([Some(1), None, Some(3)]);
+        list.append_null();
+        list.append_value([]);
+        let list = list.finish();
+
+        let names = MapFieldNames {
+            entry: "entries".to_string(),
+            key: "key".to_string(),
+            value: "value".to_string(),
+        };
+        let mut map = MapBuilder::new(Some(names), StringBuilder::new(), StringBuilder::new());
+        map.keys().append_value("k1");
+        map.values().append_value("v1");
+        map.keys().append_value("k2");
+        map.values().append_null();
+        map.append(true).unwrap();
+        map.append(true).unwrap();
+        map.keys().append_value("k3");
+        map.values().append_value("v3");
+        map.append(true).unwrap();
+        let map = map.finish();
+
+        let inner = StructArray::new(
+            Fields::from(vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new("b", DataType::Utf8, true),
+            ]),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec![Some("x"), None, Some("z")])),
+            ],
+            None,
+        );
+        let outer = StructArray::new(
+            Fields::from(vec![
+                Field::new("inner", inner.data_type().clone(), false),
+                Field::new("c", DataType::Float64, false),
+            ]),
+            vec![
+                Arc::new(inner),
+                Arc::new(Float64Array::from(vec![1.0, 2.0, 3.0])),
+            ],
+            Some(vec![true, false, true].into()),
+        );
+
+        let batch = RecordBatch::try_from_iter_with_nullable([
+            ("list", Arc::new(list) as ArrayRef, true),
+            ("map", Arc::new(map) as ArrayRef, false),
+            ("struct", Arc::new(outer) as ArrayRef, true),
+        ])
+        .unwrap();
+
+        let read = round_trip(&batch, None);
+        assert_eq!(read.column(0), batch.column(0));
+
+        let read_map = read.column(1).as_map();
+        let map = batch.column(1).as_map();
+        assert_eq!(read_map.offsets(), map.offsets());
+        assert_eq!(read_map.keys(), map.keys());
+        assert_eq!(read_map.values(), map.values());
+
+        // Children of null struct slots are not encoded, and are read as default values
+        let read_struct = read.column(2).as_struct();
+        assert_eq!(read_struct.nulls(), batch.column(2).nulls());
+        let inner = read_struct.column(0).as_struct();
+        assert_eq!(inner.column(0).as_ref(), &Int32Array::from(vec![1, 0, 3]));
+        assert_eq!(
+            inner.column(1).as_ref(),
+            &StringArray::from(vec![Some("x"), None, Some("z")])
+        );
+    }
+
+    #[test]
+    fn test_compression() {
+        let batch = RecordBatch::try_from_iter_with_nullable([
+            (
+                "id",
+                Arc::new(Int32Array::from_iter_values(0..1000)) as ArrayRef,
+                false,
+            ),
+            (
+                "s",
+                Arc::new(StringArray::from_iter(
+                    (0..1000).map(|x| (x % 3 != 0).then(|| format!("value {}", x % 10))),
+                )) as _,
+                true,
+            ),
+        ])
+        .unwrap();
+
+        let uncompressed = write(std::slice::from_ref(&batch), None);
+        for codec in [
+            CompressionCodec::Deflate,
+            CompressionCodec::Snappy,
+            CompressionCodec::ZStandard,
+            CompressionCodec::Bzip2,
+            CompressionCodec::Xz,
+        ] {
+            let compressed = write(std::slice::from_ref(&batch), Some(codec));
+            assert!(compressed.len() < uncompressed.len(), "{codec:?}");
+
+            let reader = ReaderBuilder::new().build(Cursor::new(compressed)).unwrap();
+            assert_eq!(reader.avro_header().compression().unwrap(), Some(codec));
+            let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+            let read = arrow::compute::concat_batches(&batches[0].schema(), &batches).unwrap();
+            assert_batches_eq(&read, &batch);
+        }
+    }
+
+    #[test]
+    fn test_multiple_blocks() {
+        let batches = (0..3)
+            .map(|i| {
+                RecordBatch::try_from_iter([(
+                    "v",
+                    Arc::new(Int64Array::from_iter_values(i * 10..i * 10 + 10)) as ArrayRef,
+                )])
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let buffer = write(&batches, Some(CompressionCodec::Snappy));
+
+        let reader = ReaderBuilder::new()
+            .with_batch_size(7)
+            .build(Cursor::new(buffer))
+            .unwrap();
+        let sync = reader.avro_header().sync();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let read = arrow::compute::concat_batches(&read[0].schema(), &read).unwrap();
+        assert_eq!(
+            read.column(0).as_ref(),
+            &Int64Array::from_iter_values(0..30)
+        );
+        assert_ne!(sync, [0; 16]);
+    }
+
+    #[test]
+    fn test_header_schema() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        let buffer = WriterBuilder::new(schema)
+            .with_record_name("person")
+            .build(Vec::new())
+            .unwrap()
+            .into_inner()
+            .unwrap();
+
+        let reader = ReaderBuilder::new().build(Cursor::new(buffer)).unwrap();
+        let header = reader.avro_header();
+        assert_eq!(
+            header.get(SCHEMA_METADATA_KEY).unwrap(),
+            br#"{"fields":[{"name":"id","type":"long"},{"default":null,"name":"name","type":["null","string"]}],"name":"person","type":"record"}"#
+        );
+        assert_eq!(header.get(CODEC_METADATA_KEY).unwrap(), b"null");
+        assert_eq!(header.compression().unwrap(), None);
+    }
+
+    #[test]
+    fn test_schema_mismatch() {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let mut writer = Writer::try_new(Vec::new(), &schema).unwrap();
+        let batch =
+            RecordBatch::try_from_iter([("b", Arc::new(Int32Array::from(vec![1])) as ArrayRef)])
+                .unwrap();
+        let err = writer.write(&batch).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Schema error: Cannot write record batch with different schema"
+        );
+    }
+
+    #[test]
+    fn test_non_nullable_nulls() {
+        let child = Field::new("b", DataType::Int32, false);
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let fields = Fields::from(vec![child]);
+        let schema = Schema::new(vec![Field::new(
+            "a",
+            DataType::Struct(fields.clone()),
+            true,
+        )]);
+
+        // Nulls masked by the parent struct are never written
+        let nulls = NullBuffer::from(vec![true, false, true]);
+        let s = StructArray::new(fields.clone(), vec![values.clone()], Some(nulls));
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(s)]).unwrap();
+        let mut writer = Writer::try_new(Vec::new(), &schema).unwrap();
+        writer.write(&batch).unwrap();
+
+        // SAFETY: deliberately construct an invalid array to check the writer rejects it
+        let s = unsafe { StructArray::new_unchecked(fields, vec![values], None) };
+        let batch = RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(s)]).unwrap();
+        let mut writer = Writer::try_new(Vec::new(), &schema).unwrap();
+        let err = writer.write(&batch).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Invalid argument error: Found nulls in non-nullable field \"b\""
+        );
+    }
+
+    #[test]
+    fn test_invalid_names() {
+        let schema = Schema::new(vec![Field::new("a-b", DataType::Int32, false)]);
+        let err = Writer::try_new(Vec::new(), &schema)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Schema error: Invalid Avro name \"a-b\", names must match [A-Za-z_][A-Za-z0-9_]*"
+        );
+
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        WriterBuilder::new(schema.clone())
+            .with_record_name("org.apache.Record")
+            .build(Vec::new())
+            .unwrap();
+        let err = WriterBuilder::new(schema)
+            .with_record_name("org..Record")
+            .build(Vec::new())
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Schema error: Invalid Avro name \"org..Record\", names must be dot-separated sequences of [A-Za-z_][A-Za-z0-9_]*"
+        );
+    }
+
+    #[test]
+    fn test_unsupported_type() {
+        let schema = Schema::new(vec![Field::new(
+            "a",
+            DataType::Timestamp(TimeUnit::Nanosecond, None),
+            false,
+        )]);
+        let err = Writer::try_new(Vec::new(), &schema)
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            err,
+            "Not yet implemented: Writing Timestamp(Nanosecond, None) to Avro is not currently supported"
+        );
+    }
+}
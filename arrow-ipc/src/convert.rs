@@ -204,12 +204,6 @@ pub fn fb_to_schema(fb: crate::Schema) -> Schema {
     let len = c_fields.len();
     for i in 0..len {
         let c_field: crate::Field = c_fields.get(i);
-        match c_field.type_type() {
-            crate::Type::Decimal if fb.endianness() == crate::Endianness::Big => {
-                unimplemented!("Big Endian is not supported for Decimal!")
-            }
-            _ => (),
-        };
         fields.push(c_field.into());
     }
 
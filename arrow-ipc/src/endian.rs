@@ -0,0 +1,153 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Byte-swapping of buffers written with a different endianness to the target
+
+use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_schema::{DataType, IntervalUnit};
+
+/// Byte-swaps the values of a buffer of fixed-width `data_type` values
+///
+/// Values of a single byte, or with no fixed width, are returned unchanged
+pub(crate) fn swap_values(buffer: &Buffer, data_type: &DataType) -> Buffer {
+    match data_type {
+        // Two 32-bit integers
+        DataType::Interval(IntervalUnit::DayTime) => swap_each(buffer, 4, |v| v.reverse()),
+        // Two 32-bit integers followed by a 64-bit integer
+        DataType::Interval(IntervalUnit::MonthDayNano) => swap_each(buffer, 16, |v| {
+            v[..4].reverse();
+            v[4..8].reverse();
+            v[8..].reverse();
+        }),
+        _ => match data_type.primitive_width() {
+            Some(width) if width > 1 => swap_each(buffer, width, |v| v.reverse()),
+            _ => buffer.clone(),
+        },
+    }
+}
+
+/// Byte-swaps the views of a `Utf8View` or `BinaryView` array
+///
+/// Each view is a 32-bit length followed by either the inlined data, for lengths of
+/// at most 12, or a 4 byte prefix and the 32-bit buffer index and offset of the data
+pub(crate) fn swap_views(buffer: &Buffer) -> Buffer {
+    swap_each(buffer, 16, |view| {
+        view[..4].reverse();
+        let len = u32::from_ne_bytes(view[..4].try_into().unwrap());
+        if len > 12 {
+            view[8..12].reverse();
+            view[12..].reverse();
+        }
+    })
+}
+
+/// Copies `buffer`, applying `f` to each `width` byte chunk
+fn swap_each(buffer: &Buffer, width: usize, f: impl Fn(&mut [u8])) -> Buffer {
+    let mut out = MutableBuffer::new(buffer.len());
+    out.extend_from_slice(buffer.as_slice());
+    out.as_slice_mut().chunks_exact_mut(width).for_each(f);
+    out.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow_buffer::{i256, IntervalDayTime, IntervalMonthDayNano};
+
+    #[test]
+    fn test_swap_values() {
+        let be: Vec<u8> = [1_i32, -2, 300]
+            .iter()
+            .flat_map(|v| v.to_be_bytes())
+            .collect();
+        let swapped = swap_values(&Buffer::from_vec(be), &DataType::Int32);
+        assert_eq!(swapped.typed_data::<i32>(), &[1, -2, 300]);
+
+        let be: Vec<u8> = [1.5_f64, -0.25]
+            .iter()
+            .flat_map(|v| v.to_be_bytes())
+            .collect();
+        let swapped = swap_values(&Buffer::from_vec(be), &DataType::Float64);
+        assert_eq!(swapped.typed_data::<f64>(), &[1.5, -0.25]);
+
+        let be = i128::MIN.to_be_bytes().to_vec();
+        let swapped = swap_values(&Buffer::from_vec(be), &DataType::Decimal128(38, 0));
+        assert_eq!(swapped.typed_data::<i128>(), &[i128::MIN]);
+
+        let value = i256::from_parts(5, -7);
+        let mut be = value.to_le_bytes();
+        be.reverse();
+        let swapped = swap_values(&Buffer::from_vec(be.to_vec()), &DataType::Decimal256(76, 0));
+        assert_eq!(swapped.typed_data::<i256>(), &[value]);
+
+        let be = [0_u8, 1, 0, 2];
+        let swapped = swap_values(&Buffer::from(&be), &DataType::Int8);
+        assert_eq!(swapped.as_slice(), &be);
+    }
+
+    #[test]
+    fn test_swap_intervals() {
+        let mut be = Vec::new();
+        be.extend_from_slice(&3_i32.to_be_bytes());
+        be.extend_from_slice(&(-4_i32).to_be_bytes());
+        let swapped = swap_values(
+            &Buffer::from_vec(be),
+            &DataType::Interval(IntervalUnit::DayTime),
+        );
+        assert_eq!(
+            swapped.typed_data::<IntervalDayTime>(),
+            &[IntervalDayTime::new(3, -4)]
+        );
+
+        let mut be = Vec::new();
+        be.extend_from_slice(&1_i32.to_be_bytes());
+        be.extend_from_slice(&2_i32.to_be_bytes());
+        be.extend_from_slice(&(-3_i64).to_be_bytes());
+        let swapped = swap_values(
+            &Buffer::from_vec(be),
+            &DataType::Interval(IntervalUnit::MonthDayNano),
+        );
+        assert_eq!(
+            swapped.typed_data::<IntervalMonthDayNano>(),
+            &[IntervalMonthDayNano::new(1, 2, -3)]
+        );
+    }
+
+    #[test]
+    fn test_swap_views() {
+        // An inlined view of "hello" and a view of 20 bytes at offset 7 of buffer 1
+        let mut be = Vec::new();
+        be.extend_from_slice(&5_u32.to_be_bytes());
+        be.extend_from_slice(b"hello\0\0\0\0\0\0\0");
+        be.extend_from_slice(&20_u32.to_be_bytes());
+        be.extend_from_slice(b"abcd");
+        be.extend_from_slice(&1_u32.to_be_bytes());
+        be.extend_from_slice(&7_u32.to_be_bytes());
+
+        let mut expected = Vec::new();
+        expected.extend_from_slice(&5_u32.to_ne_bytes());
+        expected.extend_from_slice(b"hello\0\0\0\0\0\0\0");
+        expected.extend_from_slice(&20_u32.to_ne_bytes());
+        expected.extend_from_slice(b"abcd");
+        expected.extend_from_slice(&1_u32.to_ne_bytes());
+        expected.extend_from_slice(&7_u32.to_ne_bytes());
+
+        let swapped = swap_views(&Buffer::from_vec(be));
+        assert_eq!(swapped.as_slice(), expected.as_slice());
+    }
+}
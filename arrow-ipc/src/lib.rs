@@ -49,6 +49,7 @@ pub mod reader;
 pub mod writer;
 
 mod compression;
+mod endian;
 
 #[allow(clippy::redundant_closure)]
 #[allow(clippy::needless_lifetimes)]
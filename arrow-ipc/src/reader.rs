@@ -22,10 +22,17 @@
 //! The [`FileReader`] and [`StreamReader`] have similar interfaces,
 //! however the [`FileReader`] expects a reader that supports [`Seek`]ing
 //!
+//! The `async` feature adds `AsyncStreamReader` and `AsyncFileReader`, which read
+//! from async sources, the latter only fetching the byte ranges of the batches read
+//!
 //! [`Seek`]: std::io::Seek
 
+#[cfg(feature = "async")]
+mod async_reader;
 mod stream;
 
+#[cfg(feature = "async")]
+pub use async_reader::*;
 pub use stream::*;
 
 use flatbuffers::{VectorIter, VerifierOptions};
@@ -36,10 +43,12 @@ use std::sync::Arc;
 
 use arrow_array::*;
 use arrow_buffer::{ArrowNativeType, BooleanBuffer, Buffer, MutableBuffer, ScalarBuffer};
+use arrow_data::transform::MutableArrayData;
 use arrow_data::{ArrayData, ArrayDataBuilder, UnsafeFlag};
 use arrow_schema::*;
 
 use crate::compression::CompressionCodec;
+use crate::endian;
 use crate::{Block, FieldNode, Message, MetadataVersion, CONTINUATION_MARKER};
 use DataType::*;
 
@@ -87,9 +96,13 @@ impl RecordBatchDecoder<'_> {
         match data_type {
             Utf8 | Binary | LargeBinary | LargeUtf8 => {
                 let field_node = self.next_node(field)?;
+                let offsets = match data_type {
+                    Utf8 | Binary => &Int32,
+                    _ => &Int64,
+                };
                 let buffers = [
                     self.next_buffer()?,
-                    self.next_buffer()?,
+                    self.next_values_buffer(offsets)?,
                     self.next_buffer()?,
                 ];
                 self.create_primitive_array(field_node, data_type, &buffers)
@@ -101,9 +114,12 @@ impl RecordBatchDecoder<'_> {
                         "Missing variadic count for {data_type} column"
                     )))?;
                 let count = count + 2; // view and null buffer.
-                let buffers = (0..count)
+                let mut buffers = (0..count)
                     .map(|_| self.next_buffer())
                     .collect::<Result<Vec<_>, _>>()?;
+                if self.swap_endianness {
+                    buffers[1] = endian::swap_views(&buffers[1]);
+                }
                 let field_node = self.next_node(field)?;
                 self.create_primitive_array(field_node, data_type, &buffers)
             }
@@ -114,7 +130,11 @@ impl RecordBatchDecoder<'_> {
             }
             List(ref list_field) | LargeList(ref list_field) | Map(ref list_field, _) => {
                 let list_node = self.next_node(field)?;
-                let list_buffers = [self.next_buffer()?, self.next_buffer()?];
+                let offsets = match data_type {
+                    LargeList(_) => &Int64,
+                    _ => &Int32,
+                };
+                let list_buffers = [self.next_buffer()?, self.next_values_buffer(offsets)?];
                 let values = self.create_array(list_field, variadic_counts)?;
                 self.create_list_array(list_node, data_type, &list_buffers, values)
             }
@@ -152,9 +172,9 @@ impl RecordBatchDecoder<'_> {
                 self.create_array_from_builder(builder)
             }
             // Create dictionary array from RecordBatch
-            Dictionary(_, _) => {
+            Dictionary(ref key_type, _) => {
                 let index_node = self.next_node(field)?;
-                let index_buffers = [self.next_buffer()?, self.next_buffer()?];
+                let index_buffers = [self.next_buffer()?, self.next_values_buffer(key_type)?];
 
                 #[allow(deprecated)]
                 let dict_id = field.dict_id().ok_or_else(|| {
@@ -189,8 +209,10 @@ impl RecordBatchDecoder<'_> {
 
                 let value_offsets = match mode {
                     UnionMode::Dense => {
-                        let offsets: ScalarBuffer<i32> =
-                            self.next_buffer()?.slice_with_length(0, len * 4).into();
+                        let offsets: ScalarBuffer<i32> = self
+                            .next_values_buffer(&Int32)?
+                            .slice_with_length(0, len * 4)
+                            .into();
                         Some(offsets)
                     }
                     UnionMode::Sparse => None,
@@ -231,7 +253,7 @@ impl RecordBatchDecoder<'_> {
             }
             _ => {
                 let field_node = self.next_node(field)?;
-                let buffers = [self.next_buffer()?, self.next_buffer()?];
+                let buffers = [self.next_buffer()?, self.next_values_buffer(data_type)?];
                 self.create_primitive_array(field_node, data_type, &buffers)
             }
         }
@@ -393,6 +415,9 @@ struct RecordBatchDecoder<'a> {
     ///
     /// See [`FileDecoder::with_skip_validation`] for details.
     skip_validation: UnsafeFlag,
+    /// Were the buffers written with a different endianness to the target? See
+    /// [`RecordBatchDecoder::with_swap_endianness`] for details
+    swap_endianness: bool,
 }
 
 impl<'a> RecordBatchDecoder<'a> {
@@ -428,6 +453,7 @@ impl<'a> RecordBatchDecoder<'a> {
             projection: None,
             require_alignment: false,
             skip_validation: UnsafeFlag::new(),
+            swap_endianness: false,
         })
     }
 
@@ -466,6 +492,15 @@ impl<'a> RecordBatchDecoder<'a> {
         self
     }
 
+    /// Set swap_endianness (default: false)
+    ///
+    /// If true, the buffers were written with a different endianness to the
+    /// target, and fixed-width values will be byte-swapped into native order.
+    pub fn with_swap_endianness(mut self, swap_endianness: bool) -> Self {
+        self.swap_endianness = swap_endianness;
+        self
+    }
+
     /// Read the record batch, consuming the reader
     fn read_record_batch(mut self) -> Result<RecordBatch, ArrowError> {
         let mut variadic_counts: VecDeque<i64> = self
@@ -537,6 +572,16 @@ impl<'a> RecordBatchDecoder<'a> {
         read_buffer(self.buffers.next().unwrap(), self.data, self.compression)
     }
 
+    /// Reads the next buffer of fixed-width `data_type` values, byte-swapping
+    /// them into native order if necessary
+    fn next_values_buffer(&mut self, data_type: &DataType) -> Result<Buffer, ArrowError> {
+        let buffer = self.next_buffer()?;
+        Ok(match self.swap_endianness {
+            true => endian::swap_values(&buffer, data_type),
+            false => buffer,
+        })
+    }
+
     fn skip_buffer(&mut self) {
         self.buffers.next().unwrap();
     }
@@ -666,9 +711,11 @@ pub fn read_dictionary(
         metadata,
         false,
         UnsafeFlag::new(),
+        false,
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 fn read_dictionary_impl(
     buf: &Buffer,
     batch: crate::DictionaryBatch,
@@ -677,13 +724,8 @@ fn read_dictionary_impl(
     metadata: &MetadataVersion,
     require_alignment: bool,
     skip_validation: UnsafeFlag,
+    swap_endianness: bool,
 ) -> Result<(), ArrowError> {
-    if batch.isDelta() {
-        return Err(ArrowError::InvalidArgumentError(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
-
     let id = batch.id();
     #[allow(deprecated)]
     let fields_using_this_dictionary = schema.fields_with_dict_id(id);
@@ -709,6 +751,7 @@ fn read_dictionary_impl(
             )?
             .with_require_alignment(require_alignment)
             .with_skip_validation(skip_validation)
+            .with_swap_endianness(swap_endianness)
             .read_record_batch()?;
 
             Some(record_batch.column(0).clone())
@@ -719,14 +762,37 @@ fn read_dictionary_impl(
         ArrowError::InvalidArgumentError(format!("dictionary id {id} not found in schema"))
     })?;
 
+    // A delta dictionary batch appends its values to the existing dictionary
+    let dictionary_values = if batch.isDelta() {
+        let existing = dictionaries_by_id.get(&id).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "delta dictionary batch for dictionary id {id} without an existing dictionary"
+            ))
+        })?;
+        append_dictionary(existing, &dictionary_values)
+    } else {
+        dictionary_values
+    };
+
     // We don't currently record the isOrdered field. This could be general
     // attributes of arrays.
     // Add (possibly multiple) array refs to the dictionaries array.
-    dictionaries_by_id.insert(id, dictionary_values.clone());
+    dictionaries_by_id.insert(id, dictionary_values);
 
     Ok(())
 }
 
+/// Returns the values of the dictionary `existing` followed by the values of `delta`
+fn append_dictionary(existing: &ArrayRef, delta: &ArrayRef) -> ArrayRef {
+    let existing = existing.to_data();
+    let delta = delta.to_data();
+    let mut data =
+        MutableArrayData::new(vec![&existing, &delta], false, existing.len() + delta.len());
+    data.extend(0, 0, existing.len());
+    data.extend(1, 0, delta.len());
+    make_array(data.freeze())
+}
+
 /// Read the data for a given block
 fn read_block<R: Read + Seek>(mut reader: R, block: &Block) -> Result<Buffer, ArrowError> {
     reader.seek(SeekFrom::Start(block.offset() as u64))?;
@@ -812,7 +878,9 @@ pub fn read_footer_length(buf: [u8; 10]) -> Result<usize, ArrowError> {
 /// let back = fb_to_schema(footer.schema().unwrap());
 /// assert_eq!(&back, schema.as_ref());
 ///
-/// let mut decoder = FileDecoder::new(schema, footer.version());
+/// let ipc_schema = footer.schema().unwrap();
+/// let mut decoder = FileDecoder::new(schema, footer.version())
+///     .with_endianness(ipc_schema.endianness());
 ///
 /// // Read dictionaries
 /// for block in footer.dictionaries().iter().flatten() {
@@ -840,6 +908,7 @@ pub struct FileDecoder {
     projection: Option<Vec<usize>>,
     require_alignment: bool,
     skip_validation: UnsafeFlag,
+    swap_endianness: bool,
 }
 
 impl FileDecoder {
@@ -852,6 +921,7 @@ impl FileDecoder {
             projection: None,
             require_alignment: false,
             skip_validation: UnsafeFlag::new(),
+            swap_endianness: false,
         }
     }
 
@@ -893,6 +963,16 @@ impl FileDecoder {
         self
     }
 
+    /// Specifies the endianness of the file, from the `endianness` of its schema
+    /// (defaults to the endianness of the target)
+    ///
+    /// Fixed-width values written with a different endianness to the target are
+    /// byte-swapped into native order when read.
+    pub fn with_endianness(mut self, endianness: crate::Endianness) -> Self {
+        self.swap_endianness = !endianness.equals_to_target_endianness();
+        self
+    }
+
     fn read_message<'a>(&self, buf: &'a [u8]) -> Result<Message<'a>, ArrowError> {
         let message = parse_message(buf)?;
 
@@ -919,6 +999,7 @@ impl FileDecoder {
                     &message.version(),
                     self.require_alignment,
                     self.skip_validation.clone(),
+                    self.swap_endianness,
                 )
             }
             t => Err(ArrowError::ParseError(format!(
@@ -953,6 +1034,7 @@ impl FileDecoder {
                 .with_projection(self.projection.as_deref())
                 .with_require_alignment(self.require_alignment)
                 .with_skip_validation(self.skip_validation.clone())
+                .with_swap_endianness(self.swap_endianness)
                 .read_record_batch()
                 .map(Some)
             }
@@ -1048,28 +1130,41 @@ impl FileReaderBuilder {
         reader.seek(SeekFrom::End(-10 - footer_len as i64))?;
         reader.read_exact(&mut footer_data)?;
 
+        let mut footer = self.decode_footer(&footer_data)?;
+
+        // Create an array of optional dictionary value arrays, one per field.
+        for block in &footer.dictionaries {
+            let buf = read_block(&mut reader, block)?;
+            footer.decoder.read_dictionary(block, &buf)?;
+        }
+
+        Ok(FileReader {
+            reader,
+            total_blocks: footer.batches.len(),
+            blocks: footer.batches,
+            current_block: 0,
+            decoder: footer.decoder,
+            custom_metadata: footer.custom_metadata,
+        })
+    }
+
+    /// Decodes the flatbuffer footer of an IPC file
+    fn decode_footer(self, footer_data: &[u8]) -> Result<Footer, ArrowError> {
         let verifier_options = VerifierOptions {
             max_tables: self.max_footer_fb_tables,
             max_depth: self.max_footer_fb_depth,
             ..Default::default()
         };
-        let footer = crate::root_as_footer_with_opts(&verifier_options, &footer_data[..]).map_err(
-            |err| ArrowError::ParseError(format!("Unable to get root as footer: {err:?}")),
-        )?;
+        let footer =
+            crate::root_as_footer_with_opts(&verifier_options, footer_data).map_err(|err| {
+                ArrowError::ParseError(format!("Unable to get root as footer: {err:?}"))
+            })?;
 
         let blocks = footer.recordBatches().ok_or_else(|| {
             ArrowError::ParseError("Unable to get record batches from IPC Footer".to_string())
         })?;
 
-        let total_blocks = blocks.len();
-
         let ipc_schema = footer.schema().unwrap();
-        if !ipc_schema.endianness().equals_to_target_endianness() {
-            return Err(ArrowError::IpcError(
-                "the endianness of the source system does not match the endianness of the target system.".to_owned()
-            ));
-        }
-
         let schema = crate::convert::fb_to_schema(ipc_schema);
 
         let mut custom_metadata = HashMap::new();
@@ -1082,30 +1177,35 @@ impl FileReaderBuilder {
             }
         }
 
-        let mut decoder = FileDecoder::new(Arc::new(schema), footer.version());
+        let mut decoder = FileDecoder::new(Arc::new(schema), footer.version())
+            .with_endianness(ipc_schema.endianness());
         if let Some(projection) = self.projection {
             decoder = decoder.with_projection(projection)
         }
 
-        // Create an array of optional dictionary value arrays, one per field.
-        if let Some(dictionaries) = footer.dictionaries() {
-            for block in dictionaries {
-                let buf = read_block(&mut reader, block)?;
-                decoder.read_dictionary(block, &buf)?;
-            }
-        }
-
-        Ok(FileReader {
-            reader,
-            blocks: blocks.iter().copied().collect(),
-            current_block: 0,
-            total_blocks,
+        Ok(Footer {
             decoder,
+            batches: blocks.iter().copied().collect(),
+            dictionaries: footer
+                .dictionaries()
+                .map(|d| d.iter().copied().collect())
+                .unwrap_or_default(),
             custom_metadata,
         })
     }
 }
 
+/// The decoded footer of an IPC file
+struct Footer {
+    /// The decoder of the file, without any dictionaries
+    decoder: FileDecoder,
+    /// The blocks of the record batches
+    batches: Vec<Block>,
+    /// The blocks of the dictionary batches
+    dictionaries: Vec<Block>,
+    custom_metadata: HashMap<String, String>,
+}
+
 /// Arrow File Reader
 ///
 /// Reads Arrow [`RecordBatch`]es from bytes in the [IPC File Format],
@@ -1200,9 +1300,8 @@ impl<R: Read + Seek> FileReader<R> {
     ///
     /// # Errors
     ///
-    /// An ['Err'](Result::Err) may be returned if:
-    /// - the file does not meet the Arrow Format footer requirements, or
-    /// - file endianness does not match the target endianness.
+    /// An ['Err'](Result::Err) may be returned if the file does not meet the Arrow
+    /// Format footer requirements.
     pub fn try_new(reader: R, projection: Option<Vec<usize>>) -> Result<Self, ArrowError> {
         let builder = FileReaderBuilder {
             projection,
@@ -1351,6 +1450,9 @@ pub struct StreamReader<R> {
     ///
     /// See [`FileDecoder::with_skip_validation`] for details.
     skip_validation: UnsafeFlag,
+
+    /// Was the stream written with a different endianness to the target?
+    swap_endianness: bool,
 }
 
 impl<R> fmt::Debug for StreamReader<R> {
@@ -1412,6 +1514,7 @@ impl<R: Read> StreamReader<R> {
         let ipc_schema: crate::Schema = message.header_as_schema().ok_or_else(|| {
             ArrowError::ParseError("Unable to read IPC message as schema".to_string())
         })?;
+        let swap_endianness = !ipc_schema.endianness().equals_to_target_endianness();
         let schema = crate::convert::fb_to_schema(ipc_schema);
 
         // Create an array of optional dictionary value arrays, one per field.
@@ -1431,6 +1534,7 @@ impl<R: Read> StreamReader<R> {
             dictionaries_by_id,
             projection,
             skip_validation: UnsafeFlag::new(),
+            swap_endianness,
         })
     }
 
@@ -1520,6 +1624,7 @@ impl<R: Read> StreamReader<R> {
                 .with_projection(self.projection.as_ref().map(|x| x.0.as_ref()))
                 .with_require_alignment(false)
                 .with_skip_validation(self.skip_validation.clone())
+                .with_swap_endianness(self.swap_endianness)
                 .read_record_batch()
                 .map(Some)
             }
@@ -1541,6 +1646,7 @@ impl<R: Read> StreamReader<R> {
                     &message.version(),
                     false,
                     self.skip_validation.clone(),
+                    self.swap_endianness,
                 )?;
 
                 // read the next message until we encounter a RecordBatch
@@ -1596,7 +1702,8 @@ impl<R: Read> RecordBatchReader for StreamReader<R> {
 mod tests {
     use crate::convert::fb_to_schema;
     use crate::writer::{
-        unslice_run_array, write_message, DictionaryTracker, IpcDataGenerator, IpcWriteOptions,
+        unslice_run_array, write_message, DictionaryTracker, EncodedData, IpcDataGenerator,
+        IpcWriteOptions, StreamWriter,
     };
 
     use super::*;
@@ -2875,4 +2982,242 @@ mod tests {
 
         assert_eq!(schema, new_schema);
     }
+
+    /// Rewrites the little endian IPC `stream` of `schema` as big endian, calling
+    /// `swap` with the header type, index and data of each buffer of each batch
+    fn to_big_endian(
+        schema: &Schema,
+        stream: &[u8],
+        swap: impl Fn(crate::MessageHeader, usize, &mut [u8]),
+    ) -> Vec<u8> {
+        let mut fbb = flatbuffers::FlatBufferBuilder::new();
+        let fields: Vec<_> = schema
+            .fields()
+            .iter()
+            .map(|f| crate::convert::build_field(&mut fbb, &mut None, f))
+            .collect();
+        let fields = fbb.create_vector(&fields);
+        let mut builder = crate::SchemaBuilder::new(&mut fbb);
+        builder.add_fields(fields);
+        builder.add_endianness(crate::Endianness::Big);
+        let ipc_schema = builder.finish().as_union_value();
+        let mut message = crate::MessageBuilder::new(&mut fbb);
+        message.add_version(MetadataVersion::V5);
+        message.add_header_type(crate::MessageHeader::Schema);
+        message.add_header(ipc_schema);
+        let message = message.finish();
+        fbb.finish(message, None);
+
+        let mut out = vec![];
+        let encoded = EncodedData {
+            ipc_message: fbb.finished_data().to_vec(),
+            arrow_data: vec![],
+        };
+        write_message(&mut out, encoded, &IpcWriteOptions::default()).unwrap();
+
+        // Copy the messages following the schema, byte-swapping their buffers
+        let mut offset = 0;
+        loop {
+            let len = i32::from_le_bytes(stream[offset + 4..offset + 8].try_into().unwrap());
+            let body_start = offset + 8 + len as usize;
+            if len == 0 {
+                out.extend_from_slice(&stream[offset..body_start]);
+                return out;
+            }
+            let message = root_as_message(&stream[offset + 8..body_start]).unwrap();
+            let body_end = body_start + message.bodyLength() as usize;
+            let buffers = match message.header_type() {
+                crate::MessageHeader::RecordBatch => message.header_as_record_batch(),
+                crate::MessageHeader::DictionaryBatch => {
+                    message.header_as_dictionary_batch().unwrap().data()
+                }
+                _ => None,
+            };
+            if let Some(buffers) = buffers {
+                let mut body = stream[body_start..body_end].to_vec();
+                for (i, b) in buffers.buffers().unwrap().iter().enumerate() {
+                    let start = b.offset() as usize;
+                    swap(
+                        message.header_type(),
+                        i,
+                        &mut body[start..start + b.length() as usize],
+                    );
+                }
+                out.extend_from_slice(&stream[offset..body_start]);
+                out.extend_from_slice(&body);
+            }
+            offset = body_end;
+        }
+    }
+
+    fn swap_each(data: &mut [u8], width: usize) {
+        data.chunks_exact_mut(width).for_each(|v| v.reverse());
+    }
+
+    fn read_stream_both_ways(stream: Vec<u8>) -> Vec<RecordBatch> {
+        let batches = StreamReader::try_new(stream.as_slice(), None)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let mut decoder = StreamDecoder::new();
+        let mut buffer = Buffer::from_vec(stream);
+        let mut decoded = vec![];
+        while let Some(batch) = decoder.decode(&mut buffer).unwrap() {
+            decoded.push(batch);
+        }
+        decoder.finish().unwrap();
+        assert_eq!(batches, decoded);
+        batches
+    }
+
+    #[test]
+    fn test_read_big_endian_stream() {
+        let list = ListArray::from_iter_primitive::<Int16Type, _, _>(vec![
+            Some(vec![Some(1), None, Some(-300)]),
+            None,
+            Some(vec![Some(i16::MAX)]),
+        ]);
+        let batch = RecordBatch::try_from_iter([
+            (
+                "i32",
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(-70000)])) as ArrayRef,
+            ),
+            (
+                "f64",
+                Arc::new(Float64Array::from(vec![1.5, -0.0, 1e100])) as _,
+            ),
+            (
+                "decimal",
+                Arc::new(
+                    Decimal128Array::from(vec![12345, -1, i64::MAX as i128 * 3])
+                        .with_precision_and_scale(38, 2)
+                        .unwrap(),
+                ) as _,
+            ),
+            (
+                "month_day_nano",
+                Arc::new(IntervalMonthDayNanoArray::from(vec![
+                    IntervalMonthDayNano::new(1, -2, 3),
+                    IntervalMonthDayNano::new(0, 0, -1),
+                    IntervalMonthDayNano::new(i32::MAX, 5, 6),
+                ])) as _,
+            ),
+            (
+                "day_time",
+                Arc::new(IntervalDayTimeArray::from(vec![
+                    IntervalDayTime::new(1, 2),
+                    IntervalDayTime::new(-3, 4),
+                    IntervalDayTime::new(5, -6),
+                ])) as _,
+            ),
+            (
+                "utf8",
+                Arc::new(StringArray::from(vec![
+                    Some("a"),
+                    None,
+                    Some("hello world"),
+                ])) as _,
+            ),
+            (
+                "large_binary",
+                Arc::new(LargeBinaryArray::from(vec![&b"x"[..], b"", b"yz"])) as _,
+            ),
+            ("list", Arc::new(list) as _),
+            (
+                "bool",
+                Arc::new(BooleanArray::from(vec![true, false, true])) as _,
+            ),
+            (
+                "utf8_view",
+                Arc::new(StringViewArray::from(vec![
+                    "short",
+                    "a string longer than twelve bytes",
+                    "",
+                ])) as _,
+            ),
+        ])
+        .unwrap();
+
+        let mut stream = vec![];
+        let mut writer = StreamWriter::try_new(&mut stream, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let stream = to_big_endian(&batch.schema(), &stream, |_, i, data| match i {
+            1 | 9 | 11 | 17 => swap_each(data, 4),
+            3 => swap_each(data, 8),
+            5 => swap_each(data, 16),
+            7 => data.chunks_exact_mut(16).for_each(|v| {
+                v[..4].reverse();
+                v[4..8].reverse();
+                v[8..].reverse();
+            }),
+            14 => swap_each(data, 8),
+            19 => swap_each(data, 2),
+            23 => data.chunks_exact_mut(16).for_each(|v| {
+                v[..4].reverse();
+                if v[3] > 12 {
+                    v[8..12].reverse();
+                    v[12..].reverse();
+                }
+            }),
+            _ => {}
+        });
+
+        let batches = read_stream_both_ways(stream);
+        assert_eq!(batches, vec![batch]);
+    }
+
+    #[test]
+    fn test_read_big_endian_dictionary_stream() {
+        let dict: DictionaryArray<Int16Type> = vec![Some("foo"), None, Some("bar"), Some("foo")]
+            .into_iter()
+            .collect();
+        let union = UnionArray::try_new(
+            UnionFields::new(
+                vec![0, 1],
+                vec![
+                    Field::new("a", DataType::Int64, false),
+                    Field::new("b", DataType::Int8, false),
+                ],
+            ),
+            vec![0_i8, 1, 0, 0].into(),
+            Some(vec![0_i32, 0, 1, 2].into()),
+            vec![
+                Arc::new(Int64Array::from(vec![1, -2, 3])) as ArrayRef,
+                Arc::new(Int8Array::from(vec![4])),
+            ],
+        )
+        .unwrap();
+        let batch = RecordBatch::try_from_iter([
+            ("dict", Arc::new(dict) as ArrayRef),
+            ("union", Arc::new(union) as _),
+        ])
+        .unwrap();
+
+        let mut stream = vec![];
+        let mut writer = StreamWriter::try_new(&mut stream, &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.finish().unwrap();
+        drop(writer);
+
+        let stream = to_big_endian(&batch.schema(), &stream, |header, i, data| {
+            match (header, i) {
+                // Dictionary values offsets
+                (crate::MessageHeader::DictionaryBatch, 1) => swap_each(data, 4),
+                // Dictionary keys
+                (crate::MessageHeader::RecordBatch, 1) => swap_each(data, 2),
+                // Union offsets
+                (crate::MessageHeader::RecordBatch, 3) => swap_each(data, 4),
+                // Union Int64 child values
+                (crate::MessageHeader::RecordBatch, 5) => swap_each(data, 8),
+                _ => {}
+            }
+        });
+
+        let batches = read_stream_both_ways(stream);
+        assert_eq!(batches, vec![batch]);
+    }
 }
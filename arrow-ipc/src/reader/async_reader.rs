@@ -0,0 +1,615 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Async readers for the Arrow IPC stream and file formats
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::SeekFrom;
+use std::ops::Range;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+
+use arrow_array::RecordBatch;
+use arrow_buffer::{Buffer, MutableBuffer};
+use arrow_schema::{ArrowError, SchemaRef};
+use futures::future::BoxFuture;
+use futures::{FutureExt, Stream};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, ReadBuf};
+
+use crate::reader::{read_footer_length, FileDecoder, FileReaderBuilder, StreamDecoder};
+use crate::Block;
+
+/// The number of bytes requested from the underlying reader by [`AsyncStreamReader`]
+const READ_SIZE: usize = 64 * 1024;
+
+/// Async Arrow Stream Reader
+///
+/// Reads Arrow [`RecordBatch`]es from an [`AsyncRead`] of bytes in the
+/// [IPC Streaming Format], yielding them as a [`Stream`].
+///
+/// # See Also
+///
+/// * [`StreamReader`] for a blocking reader
+/// * [`StreamDecoder`] for a push-based decoder
+///
+/// # Example
+/// ```
+/// # use arrow_array::record_batch;
+/// # use arrow_ipc::reader::AsyncStreamReader;
+/// # use arrow_ipc::writer::StreamWriter;
+/// # use futures::TryStreamExt;
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let batch = record_batch!(("a", Int32, [1, 2, 3])).unwrap();
+/// # let mut stream = vec![]; // mimic a stream for the example
+/// # {
+/// #  let mut writer = StreamWriter::try_new(&mut stream, &batch.schema()).unwrap();
+/// #  writer.write(&batch).unwrap();
+/// #  writer.finish().unwrap();
+/// # }
+/// let projection = None; // read all columns
+/// let reader = AsyncStreamReader::try_new(stream.as_slice(), projection)
+///     .await
+///     .unwrap();
+/// let batches: Vec<_> = reader.try_collect().await.unwrap();
+/// assert_eq!(batches, [batch]);
+/// # }
+/// ```
+///
+/// [IPC Streaming Format]: https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
+/// [`StreamReader`]: crate::reader::StreamReader
+pub struct AsyncStreamReader<R> {
+    /// Stream reader
+    reader: R,
+    /// The decoder of the stream
+    decoder: StreamDecoder,
+    /// The bytes read from `reader` that have not yet been decoded
+    buffer: Buffer,
+    /// The scratch space for reads from `reader`
+    read_buf: Box<[u8]>,
+    /// A record batch decoded along with the schema
+    pending: Option<RecordBatch>,
+    /// The schema of the read batches
+    schema: SchemaRef,
+    /// Optional projection
+    projection: Option<Vec<usize>>,
+    /// Whether the end of the stream has been reached, or an error was returned
+    finished: bool,
+}
+
+impl<R> fmt::Debug for AsyncStreamReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncStreamReader<R>")
+            .field("decoder", &self.decoder)
+            .field("schema", &self.schema)
+            .field("projection", &self.projection)
+            .field("finished", &self.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncStreamReader<R> {
+    /// Try to create a new stream reader, reading the schema from the start of the stream
+    ///
+    /// # Errors
+    ///
+    /// An ['Err'](Result::Err) may be returned if the stream ends before the schema
+    /// message is read, or the schema is not valid.
+    pub async fn try_new(
+        mut reader: R,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        let mut decoder = StreamDecoder::new();
+        let mut buffer = Buffer::from(MutableBuffer::new(0));
+        let mut read_buf = vec![0; READ_SIZE].into_boxed_slice();
+        let (schema, pending) = loop {
+            if buffer.is_empty() {
+                let read = reader.read(&mut read_buf).await?;
+                if read == 0 {
+                    return Err(ArrowError::IpcError(
+                        "Unexpected end of stream before the schema message".to_string(),
+                    ));
+                }
+                buffer = Buffer::from(&read_buf[..read]);
+            }
+            let batch = decoder.decode(&mut buffer)?;
+            if let Some(schema) = decoder.schema() {
+                break (schema, batch);
+            }
+        };
+
+        let schema = match &projection {
+            Some(projection) => Arc::new(schema.project(projection)?),
+            None => schema,
+        };
+        let finished = decoder.is_finished();
+
+        Ok(Self {
+            reader,
+            decoder,
+            buffer,
+            read_buf,
+            pending,
+            schema,
+            projection,
+            finished,
+        })
+    }
+
+    /// Return the schema of the stream
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Gets a reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    ///
+    /// It is inadvisable to directly read from the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+
+    fn project(&self, batch: RecordBatch) -> Result<RecordBatch, ArrowError> {
+        match &self.projection {
+            Some(projection) => batch.project(projection),
+            None => Ok(batch),
+        }
+    }
+
+    fn poll_next_batch(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Option<RecordBatch>, ArrowError>> {
+        if let Some(batch) = self.pending.take() {
+            return Poll::Ready(self.project(batch).map(Some));
+        }
+        while !self.finished {
+            if self.buffer.is_empty() {
+                let mut read_buf = ReadBuf::new(&mut self.read_buf);
+                ready!(Pin::new(&mut self.reader).poll_read(cx, &mut read_buf))?;
+                if read_buf.filled().is_empty() {
+                    self.finished = true;
+                    self.decoder.finish()?;
+                    break;
+                }
+                self.buffer = Buffer::from(read_buf.filled());
+            }
+            if let Some(batch) = self.decoder.decode(&mut self.buffer)? {
+                return Poll::Ready(self.project(batch).map(Some));
+            }
+            self.finished = self.decoder.is_finished();
+        }
+        Poll::Ready(Ok(None))
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for AsyncStreamReader<R> {
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let result = ready!(this.poll_next_batch(cx));
+        if result.is_err() {
+            this.finished = true;
+        }
+        Poll::Ready(result.transpose())
+    }
+}
+
+/// The interface used by [`AsyncFileReader`] to fetch byte ranges of an IPC file
+///
+/// This is implemented for all types implementing [`AsyncRead`] and [`AsyncSeek`], such
+/// as [`tokio::fs::File`], and can be implemented for remote storage, such as object
+/// stores, so that only the footer, the dictionaries and the read record batches of a
+/// file are fetched.
+///
+/// [`tokio::fs::File`]: https://docs.rs/tokio/latest/tokio/fs/struct.File.html
+pub trait AsyncRangeReader: Send {
+    /// Retrieve the bytes in `range`
+    fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Buffer, ArrowError>>;
+}
+
+impl AsyncRangeReader for Box<dyn AsyncRangeReader + '_> {
+    fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Buffer, ArrowError>> {
+        self.as_mut().get_bytes(range)
+    }
+}
+
+impl<T: AsyncRead + AsyncSeek + Unpin + Send> AsyncRangeReader for T {
+    fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Buffer, ArrowError>> {
+        async move {
+            let len = usize::try_from(range.end.saturating_sub(range.start)).map_err(|_| {
+                ArrowError::IpcError(format!("Byte range {range:?} exceeds the address space"))
+            })?;
+            self.seek(SeekFrom::Start(range.start)).await?;
+            let mut buf = MutableBuffer::from_len_zeroed(len);
+            self.read_exact(&mut buf).await?;
+            Ok(buf.into())
+        }
+        .boxed()
+    }
+}
+
+/// Returns the byte range of `block` in an IPC file
+fn block_range(block: &Block) -> Result<Range<u64>, ArrowError> {
+    let offset = u64::try_from(block.offset());
+    let len = u64::try_from(block.metaDataLength() as i64 + block.bodyLength());
+    match (offset, len) {
+        (Ok(offset), Ok(len)) => Ok(offset..offset + len),
+        _ => Err(ArrowError::ParseError(format!(
+            "Invalid block in IPC footer: {block:?}"
+        ))),
+    }
+}
+
+impl FileReaderBuilder {
+    /// Build an [`AsyncFileReader`] reading the IPC file of `file_size` bytes from `reader`
+    ///
+    /// This fetches the footer and the dictionaries of the file.
+    pub async fn build_async<R: AsyncRangeReader>(
+        self,
+        mut reader: R,
+        file_size: u64,
+    ) -> Result<AsyncFileReader<R>, ArrowError> {
+        // Space for ARROW_MAGIC (6 bytes) and length (4 bytes)
+        let footer_end = file_size.checked_sub(10).ok_or_else(|| {
+            ArrowError::ParseError("Arrow file does not contain correct footer".to_string())
+        })?;
+        let buffer = reader.get_bytes(footer_end..file_size).await?;
+        let buffer = buffer.as_slice().try_into().map_err(|_| {
+            ArrowError::IpcError(format!(
+                "Expected 10 bytes for the footer length, got {}",
+                buffer.len()
+            ))
+        })?;
+        let footer_len = read_footer_length(buffer)?;
+
+        let footer_start = footer_end.checked_sub(footer_len as u64).ok_or_else(|| {
+            ArrowError::ParseError(format!("Invalid footer length: {footer_len}"))
+        })?;
+        let footer_data = reader.get_bytes(footer_start..footer_end).await?;
+        let mut footer = self.decode_footer(&footer_data)?;
+
+        for block in &footer.dictionaries {
+            let buf = reader.get_bytes(block_range(block)?).await?;
+            footer.decoder.read_dictionary(block, &buf)?;
+        }
+
+        Ok(AsyncFileReader {
+            reader,
+            decoder: footer.decoder,
+            blocks: footer.batches,
+            custom_metadata: footer.custom_metadata,
+        })
+    }
+}
+
+/// Async Arrow File Reader
+///
+/// Reads Arrow [`RecordBatch`]es from an [`AsyncRangeReader`] of bytes in the
+/// [IPC File Format], fetching only the byte ranges of the footer, the dictionaries and
+/// the requested record batches.
+///
+/// # See Also
+///
+/// * [`FileReader`] for a blocking reader
+/// * [`FileReaderBuilder::build_async`] for additional options
+///
+/// # Example
+/// ```
+/// # use arrow_array::record_batch;
+/// # use arrow_ipc::reader::AsyncFileReader;
+/// # use arrow_ipc::writer::FileWriter;
+/// # use futures::TryStreamExt;
+/// # use std::io::Cursor;
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let batch = record_batch!(("a", Int32, [1, 2, 3])).unwrap();
+/// # let mut file = vec![]; // mimic a file for the example
+/// # {
+/// #  let mut writer = FileWriter::try_new(&mut file, &batch.schema()).unwrap();
+/// #  writer.write(&batch).unwrap();
+/// #  writer.write(&batch).unwrap();
+/// #  writer.finish().unwrap();
+/// # }
+/// let file_size = file.len() as u64;
+/// let mut reader = AsyncFileReader::try_new(Cursor::new(file), file_size, None)
+///     .await
+///     .unwrap();
+/// assert_eq!(reader.num_batches(), 2);
+/// // Read the second batch
+/// assert_eq!(reader.read_batch(1).await.unwrap(), batch);
+/// // Read all batches
+/// let batches: Vec<_> = reader.into_stream().try_collect().await.unwrap();
+/// assert_eq!(batches.len(), 2);
+/// # }
+/// ```
+///
+/// [IPC File Format]: https://arrow.apache.org/docs/format/Columnar.html#ipc-file-format
+/// [`FileReader`]: crate::reader::FileReader
+pub struct AsyncFileReader<R> {
+    /// File reader that supports fetching byte ranges
+    reader: R,
+
+    /// The decoder
+    decoder: FileDecoder,
+
+    /// The blocks of the record batches in the file
+    blocks: Vec<Block>,
+
+    /// User defined metadata
+    custom_metadata: HashMap<String, String>,
+}
+
+impl<R> fmt::Debug for AsyncFileReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncFileReader<R>")
+            .field("decoder", &self.decoder)
+            .field("blocks", &self.blocks)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: AsyncRangeReader> AsyncFileReader<R> {
+    /// Try to create a new file reader for the IPC file of `file_size` bytes
+    ///
+    /// # Errors
+    ///
+    /// An ['Err'](Result::Err) may be returned if the file does not meet the Arrow
+    /// Format footer requirements.
+    pub async fn try_new(
+        reader: R,
+        file_size: u64,
+        projection: Option<Vec<usize>>,
+    ) -> Result<Self, ArrowError> {
+        let builder = FileReaderBuilder {
+            projection,
+            ..Default::default()
+        };
+        builder.build_async(reader, file_size).await
+    }
+
+    /// Return user defined customized metadata
+    pub fn custom_metadata(&self) -> &HashMap<String, String> {
+        &self.custom_metadata
+    }
+
+    /// Return the number of batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Return the schema of the file
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema.clone()
+    }
+
+    /// Fetch and decode the record batch at `index`
+    pub async fn read_batch(&mut self, index: usize) -> Result<RecordBatch, ArrowError> {
+        let block = *self.blocks.get(index).ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Cannot read batch {index} from {} total batches",
+                self.blocks.len()
+            ))
+        })?;
+        let buffer = self.reader.get_bytes(block_range(&block)?).await?;
+        self.decoder
+            .read_record_batch(&block, &buffer)?
+            .ok_or_else(|| {
+                ArrowError::IpcError(format!("Block {index} does not contain a record batch"))
+            })
+    }
+
+    /// Convert this reader into a [`Stream`] of all record batches in the file
+    pub fn into_stream(self) -> impl Stream<Item = Result<RecordBatch, ArrowError>> {
+        futures::stream::try_unfold((self, 0), |(mut reader, index)| async move {
+            if index == reader.num_batches() {
+                return Ok(None);
+            }
+            let batch = reader.read_batch(index).await?;
+            Ok(Some((batch, (reader, index + 1))))
+        })
+    }
+
+    /// Gets a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.reader
+    }
+
+    /// Gets a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.reader
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::writer::{FileWriter, StreamWriter};
+    use arrow_array::types::Int32Type;
+    use arrow_array::{ArrayRef, DictionaryArray, Int64Array};
+    use futures::TryStreamExt;
+
+    fn test_batches() -> Vec<RecordBatch> {
+        (0..4)
+            .map(|i| {
+                let dict: DictionaryArray<Int32Type> =
+                    vec![Some("a"), None, Some("b")].into_iter().collect();
+                RecordBatch::try_from_iter([
+                    (
+                        "i",
+                        Arc::new(Int64Array::from(vec![i, i + 1, i + 2])) as ArrayRef,
+                    ),
+                    ("d", Arc::new(dict) as ArrayRef),
+                ])
+                .unwrap()
+            })
+            .collect()
+    }
+
+    /// An [`AsyncRead`] returning at most `chunk_size` bytes from each read
+    struct ChunkedReader {
+        data: Vec<u8>,
+        offset: usize,
+        chunk_size: usize,
+    }
+
+    impl AsyncRead for ChunkedReader {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<std::io::Result<()>> {
+            let end = self.data.len().min(self.offset + self.chunk_size);
+            let len = buf.remaining().min(end - self.offset);
+            buf.put_slice(&self.data[self.offset..self.offset + len]);
+            self.offset += len;
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    /// An [`AsyncRangeReader`] recording the requested ranges
+    struct RecordingReader {
+        data: Buffer,
+        ranges: Vec<Range<u64>>,
+    }
+
+    impl AsyncRangeReader for RecordingReader {
+        fn get_bytes(&mut self, range: Range<u64>) -> BoxFuture<'_, Result<Buffer, ArrowError>> {
+            self.ranges.push(range.clone());
+            let len = (range.end - range.start) as usize;
+            let data = self.data.slice_with_length(range.start as usize, len);
+            async move { Ok(data) }.boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_reader() {
+        let batches = test_batches();
+        let mut writer = StreamWriter::try_new(vec![], &batches[0].schema()).unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        let data = writer.into_inner().unwrap();
+
+        for chunk_size in [1, 7, 100, data.len()] {
+            let reader = ChunkedReader {
+                data: data.clone(),
+                offset: 0,
+                chunk_size,
+            };
+            let reader = AsyncStreamReader::try_new(reader, None).await.unwrap();
+            assert_eq!(reader.schema(), batches[0].schema());
+            let read: Vec<_> = reader.try_collect().await.unwrap();
+            assert_eq!(read, batches);
+        }
+
+        let reader = AsyncStreamReader::try_new(data.as_slice(), Some(vec![1]))
+            .await
+            .unwrap();
+        assert_eq!(reader.schema().fields().len(), 1);
+        let read: Vec<_> = reader.try_collect().await.unwrap();
+        let expected: Vec<_> = batches.iter().map(|b| b.project(&[1]).unwrap()).collect();
+        assert_eq!(read, expected);
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_reader_errors() {
+        let err = AsyncStreamReader::try_new([].as_slice(), None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Unexpected end of stream before the schema message"
+        );
+
+        let batches = test_batches();
+        let mut writer = StreamWriter::try_new(vec![], &batches[0].schema()).unwrap();
+        writer.write(&batches[0]).unwrap();
+        writer.write(&batches[1]).unwrap();
+        let data = writer.into_inner().unwrap();
+
+        // Truncate the last batch
+        let truncated = &data[..data.len() - 20];
+        let mut reader = AsyncStreamReader::try_new(truncated, None).await.unwrap();
+        assert_eq!(reader.try_next().await.unwrap().unwrap(), batches[0]);
+        let err = reader.try_next().await.unwrap_err();
+        assert_eq!(err.to_string(), "Ipc error: Unexpected End of Stream");
+        assert!(reader.try_next().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_async_file_reader() {
+        let batches = test_batches();
+        let mut writer = FileWriter::try_new(vec![], &batches[0].schema()).unwrap();
+        writer.write_metadata("key", "value");
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        let data = writer.into_inner().unwrap();
+        let file_size = data.len() as u64;
+
+        let reader = RecordingReader {
+            data: Buffer::from(data.as_slice()),
+            ranges: vec![],
+        };
+        let mut reader = AsyncFileReader::try_new(reader, file_size, None)
+            .await
+            .unwrap();
+        assert_eq!(reader.num_batches(), 4);
+        assert_eq!(reader.schema(), batches[0].schema());
+        assert_eq!(reader.custom_metadata()["key"], "value");
+        // The footer length, the footer and the dictionary
+        assert_eq!(reader.get_ref().ranges.len(), 3);
+
+        assert_eq!(reader.read_batch(2).await.unwrap(), batches[2]);
+        let block = block_range(&reader.blocks[2]).unwrap();
+        assert_eq!(reader.get_ref().ranges[3], block);
+
+        let err = reader.read_batch(4).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Cannot read batch 4 from 4 total batches"
+        );
+
+        let read: Vec<_> = reader.into_stream().try_collect().await.unwrap();
+        assert_eq!(read, batches);
+
+        let reader = std::io::Cursor::new(data.clone());
+        let reader = AsyncFileReader::try_new(reader, file_size, Some(vec![1]))
+            .await
+            .unwrap();
+        let read: Vec<_> = reader.into_stream().try_collect().await.unwrap();
+        let expected: Vec<_> = batches.iter().map(|b| b.project(&[1]).unwrap()).collect();
+        assert_eq!(read, expected);
+
+        let reader = std::io::Cursor::new(data.clone());
+        let err = AsyncFileReader::try_new(reader, 5, None).await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Arrow file does not contain correct footer"
+        );
+    }
+}
@@ -49,6 +49,8 @@ pub struct StreamDecoder {
     ///
     /// [`FileDecoder::with_skip_validation`]: crate::reader::FileDecoder::with_skip_validation
     skip_validation: UnsafeFlag,
+    /// Was the stream written with a different endianness to the target?
+    swap_endianness: bool,
 }
 
 #[derive(Debug)]
@@ -217,6 +219,8 @@ impl StreamDecoder {
                             }
 
                             let ipc_schema = message.header_as_schema().unwrap();
+                            self.swap_endianness =
+                                !ipc_schema.endianness().equals_to_target_endianness();
                             let schema = crate::convert::fb_to_schema(ipc_schema);
                             self.state = DecoderState::default();
                             self.schema = Some(Arc::new(schema));
@@ -234,6 +238,7 @@ impl StreamDecoder {
                                 &version,
                             )?
                             .with_require_alignment(self.require_alignment)
+                            .with_swap_endianness(self.swap_endianness)
                             .read_record_batch()?;
                             self.state = DecoderState::default();
                             return Ok(Some(batch));
@@ -251,6 +256,7 @@ impl StreamDecoder {
                                 &version,
                                 self.require_alignment,
                                 self.skip_validation.clone(),
+                                self.swap_endianness,
                             )?;
                             self.state = DecoderState::default();
                         }
@@ -272,6 +278,12 @@ impl StreamDecoder {
         Ok(None)
     }
 
+    /// Returns true if the end of stream marker has been decoded
+    #[cfg(feature = "async")]
+    pub(crate) fn is_finished(&self) -> bool {
+        matches!(self.state, DecoderState::Finished)
+    }
+
     /// Signal the end of stream
     ///
     /// Returns an error if any partial data remains in the stream
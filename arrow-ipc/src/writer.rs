@@ -22,8 +22,17 @@
 //! [`FileWriter`] and [`StreamWriter`] have similar interfaces,
 //! however the [`FileWriter`] expects a reader that supports [`Seek`]ing
 //!
+//! The `async` feature adds `AsyncStreamWriter` and `AsyncFileWriter`, which write
+//! to an async sink
+//!
 //! [`Seek`]: std::io::Seek
 
+#[cfg(feature = "async")]
+mod async_writer;
+
+#[cfg(feature = "async")]
+pub use async_writer::*;
+
 use std::cmp::min;
 use std::collections::HashMap;
 use std::io::{BufWriter, Write};
@@ -74,6 +83,8 @@ pub struct IpcWriteOptions {
         note = "The ability to preserve dictionary IDs will be removed. With it, all fields related to it."
     )]
     preserve_dict_id: bool,
+    /// How dictionaries that change between batches are written, see [`DictionaryHandling`]
+    dictionary_handling: DictionaryHandling,
 }
 
 impl IpcWriteOptions {
@@ -123,6 +134,7 @@ impl IpcWriteOptions {
                 metadata_version,
                 batch_compression_type: None,
                 preserve_dict_id: false,
+                dictionary_handling: DictionaryHandling::default(),
             }),
             crate::MetadataVersion::V5 => {
                 if write_legacy_ipc_format {
@@ -137,6 +149,7 @@ impl IpcWriteOptions {
                         metadata_version,
                         batch_compression_type: None,
                         preserve_dict_id: false,
+                        dictionary_handling: DictionaryHandling::default(),
                     })
                 }
             }
@@ -173,6 +186,38 @@ impl IpcWriteOptions {
         self.preserve_dict_id = preserve_dict_id;
         self
     }
+
+    /// Set how dictionaries that change between batches are written,
+    /// defaults to [`DictionaryHandling::Resend`]
+    pub fn with_dictionary_handling(mut self, dictionary_handling: DictionaryHandling) -> Self {
+        self.dictionary_handling = dictionary_handling;
+        self
+    }
+
+    /// Return how dictionaries that change between batches are written
+    pub fn dictionary_handling(&self) -> DictionaryHandling {
+        self.dictionary_handling
+    }
+}
+
+/// Controls how the IPC writer emits a dictionary that differs from the
+/// dictionary previously written for the same dictionary ID
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DictionaryHandling {
+    /// Write the full dictionary, replacing the previous dictionary
+    ///
+    /// Dictionary replacement is not supported by the IPC file format
+    #[default]
+    Resend,
+    /// If the new dictionary starts with the values of the previous dictionary, only
+    /// write the additional values as a [delta dictionary batch], otherwise write the
+    /// full dictionary as for [`DictionaryHandling::Resend`]
+    ///
+    /// Readers append the values of a delta dictionary batch to the existing dictionary,
+    /// allowing long-lived streams with growing dictionaries to only send new values.
+    ///
+    /// [delta dictionary batch]: https://arrow.apache.org/docs/format/Columnar.html#delta-dictionaries
+    Delta,
 }
 
 impl Default for IpcWriteOptions {
@@ -184,6 +229,7 @@ impl Default for IpcWriteOptions {
             metadata_version: crate::MetadataVersion::V5,
             batch_compression_type: None,
             preserve_dict_id: false,
+            dictionary_handling: DictionaryHandling::default(),
         }
     }
 }
@@ -449,14 +495,30 @@ impl IpcDataGenerator {
                         ArrowError::IpcError(format!("no dict id for field {}", field.name()))
                     })?;
 
-                let emit = dictionary_tracker.insert(dict_id, column)?;
+                let update = dictionary_tracker.insert_column(
+                    dict_id,
+                    column,
+                    write_options.dictionary_handling,
+                )?;
 
-                if emit {
-                    encoded_dictionaries.push(self.dictionary_batch_to_bytes(
-                        dict_id,
-                        dict_values,
-                        write_options,
-                    )?);
+                match update {
+                    DictionaryUpdate::None => {}
+                    DictionaryUpdate::New | DictionaryUpdate::Replaced => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            dict_values,
+                            false,
+                            write_options,
+                        )?);
+                    }
+                    DictionaryUpdate::Delta(delta) => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            &delta,
+                            true,
+                            write_options,
+                        )?);
+                    }
                 }
             }
             _ => self._encode_dictionaries(
@@ -593,10 +655,13 @@ impl IpcDataGenerator {
 
     /// Write dictionary values into two sets of bytes, one for the header (crate::Message) and the
     /// other for the data
+    ///
+    /// If `is_delta` is true, the values are written as a delta to the existing dictionary
     fn dictionary_batch_to_bytes(
         &self,
         dict_id: i64,
         array_data: &ArrayData,
+        is_delta: bool,
         write_options: &IpcWriteOptions,
     ) -> Result<EncodedData, ArrowError> {
         let mut fbb = FlatBufferBuilder::new();
@@ -666,6 +731,7 @@ impl IpcDataGenerator {
             let mut batch_builder = crate::DictionaryBatchBuilder::new(&mut fbb);
             batch_builder.add_id(dict_id);
             batch_builder.add_data(root);
+            batch_builder.add_isDelta(is_delta);
             batch_builder.finish().as_union_value()
         };
 
@@ -880,35 +946,86 @@ impl DictionaryTracker {
     ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
     ///   inserted.
     pub fn insert(&mut self, dict_id: i64, column: &ArrayRef) -> Result<bool, ArrowError> {
+        let update = self.insert_column(dict_id, column, DictionaryHandling::Resend)?;
+        Ok(!matches!(update, DictionaryUpdate::None))
+    }
+
+    /// Keep track of the dictionary with the given ID and values, returning the
+    /// [`DictionaryUpdate`] that needs to be written. Behavior:
+    ///
+    /// * If this ID has been written already and has the same data, return
+    ///   [`DictionaryUpdate::None`].
+    /// * If `dict_handling` is [`DictionaryHandling::Delta`] and the values previously
+    ///   written for this ID are a prefix of the new values, return the additional values
+    ///   as a [`DictionaryUpdate::Delta`].
+    /// * If this ID has been written already but with different data, and this tracker is
+    ///   configured to return an error, return an error.
+    /// * Otherwise return [`DictionaryUpdate::New`] or [`DictionaryUpdate::Replaced`].
+    pub fn insert_column(
+        &mut self,
+        dict_id: i64,
+        column: &ArrayRef,
+        dict_handling: DictionaryHandling,
+    ) -> Result<DictionaryUpdate, ArrowError> {
         let dict_data = column.to_data();
         let dict_values = &dict_data.child_data()[0];
 
         // If a dictionary with this id was already emitted, check if it was the same.
-        if let Some(last) = self.written.get(&dict_id) {
-            if ArrayData::ptr_eq(&last.child_data()[0], dict_values) {
-                // Same dictionary values => no need to emit it again
-                return Ok(false);
+        let Some(last) = self.written.get(&dict_id) else {
+            self.written.insert(dict_id, dict_data);
+            return Ok(DictionaryUpdate::New);
+        };
+        let last_values = &last.child_data()[0];
+        if ArrayData::ptr_eq(last_values, dict_values) {
+            // Same dictionary values => no need to emit it again
+            return Ok(DictionaryUpdate::None);
+        }
+
+        if dict_handling == DictionaryHandling::Delta
+            && last_values.len() <= dict_values.len()
+            && dict_values.slice(0, last_values.len()) == *last_values
+        {
+            if last_values.len() == dict_values.len() {
+                return Ok(DictionaryUpdate::None);
             }
-            if self.error_on_replacement {
-                // If error on replacement perform a logical comparison
-                if last.child_data()[0] == *dict_values {
-                    // Same dictionary values => no need to emit it again
-                    return Ok(false);
-                }
-                return Err(ArrowError::InvalidArgumentError(
-                    "Dictionary replacement detected when writing IPC file format. \
-                     Arrow IPC files only support a single dictionary for a given field \
-                     across all batches."
-                        .to_string(),
-                ));
+            let delta = dict_values.slice(last_values.len(), dict_values.len() - last_values.len());
+            self.written.insert(dict_id, dict_data);
+            return Ok(DictionaryUpdate::Delta(delta));
+        }
+
+        if self.error_on_replacement {
+            // If error on replacement perform a logical comparison
+            if last_values == dict_values {
+                // Same dictionary values => no need to emit it again
+                return Ok(DictionaryUpdate::None);
             }
+            return Err(ArrowError::InvalidArgumentError(
+                "Dictionary replacement detected when writing IPC file format. \
+                 Arrow IPC files only support a single dictionary for a given field \
+                 across all batches."
+                    .to_string(),
+            ));
         }
 
         self.written.insert(dict_id, dict_data);
-        Ok(true)
+        Ok(DictionaryUpdate::Replaced)
     }
 }
 
+/// The update to a dictionary determined by [`DictionaryTracker::insert_column`]
+#[derive(Debug, Clone)]
+pub enum DictionaryUpdate {
+    /// The dictionary is unchanged and does not need to be written
+    None,
+    /// The dictionary was not written before, and must be written in full
+    New,
+    /// The dictionary replaces a previously written dictionary, and must be written in full
+    Replaced,
+    /// The dictionary extends the previously written dictionary with the contained values,
+    /// which must be written as a delta dictionary batch
+    Delta(ArrayData),
+}
+
 /// Arrow File Writer
 ///
 /// Writes Arrow [`RecordBatch`]es in the [IPC File Format].
@@ -2403,6 +2520,85 @@ mod tests {
         assert_eq!(record_batch_slice, deserialized_batch);
     }
 
+    fn dictionary_batch(values: &[&str]) -> RecordBatch {
+        let keys = Int32Array::from_iter_values(0..values.len() as i32);
+        let array = DictionaryArray::new(keys, Arc::new(StringArray::from(values.to_vec())));
+        let schema = Schema::new(vec![Field::new("dict", array.data_type().clone(), true)]);
+        RecordBatch::try_new(Arc::new(schema), vec![Arc::new(array)]).unwrap()
+    }
+
+    #[test]
+    fn test_delta_dictionary_encoding() {
+        let options =
+            IpcWriteOptions::default().with_dictionary_handling(DictionaryHandling::Delta);
+        let generator = IpcDataGenerator::default();
+        let mut tracker = DictionaryTracker::new(false);
+
+        // Returns the length and `isDelta` of each encoded dictionary batch
+        let mut encode = |batch: &RecordBatch| {
+            let (dictionaries, _) = generator
+                .encoded_batch(batch, &mut tracker, &options)
+                .unwrap();
+            dictionaries
+                .iter()
+                .map(|d| {
+                    let message = crate::root_as_message(&d.ipc_message).unwrap();
+                    let dictionary = message.header_as_dictionary_batch().unwrap();
+                    (dictionary.data().unwrap().length(), dictionary.isDelta())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(encode(&dictionary_batch(&["a", "b"])), [(2, false)]);
+        assert_eq!(encode(&dictionary_batch(&["a", "b", "c"])), [(1, true)]);
+        assert_eq!(encode(&dictionary_batch(&["a", "b", "c"])), []);
+        assert_eq!(
+            encode(&dictionary_batch(&["a", "b", "c", "d", "e"])),
+            [(2, true)]
+        );
+        assert_eq!(encode(&dictionary_batch(&["x", "y"])), [(2, false)]);
+        assert_eq!(encode(&dictionary_batch(&["x", "y", "z"])), [(1, true)]);
+    }
+
+    #[test]
+    fn test_delta_dictionary_roundtrip() {
+        let batches = [
+            dictionary_batch(&["a", "b"]),
+            dictionary_batch(&["a", "b", "c"]),
+            dictionary_batch(&["x"]),
+            dictionary_batch(&["x", "y", "z"]),
+        ];
+        let options =
+            IpcWriteOptions::default().with_dictionary_handling(DictionaryHandling::Delta);
+
+        let mut writer =
+            StreamWriter::try_new_with_options(vec![], &batches[0].schema(), options.clone())
+                .unwrap();
+        for batch in &batches {
+            writer.write(batch).unwrap();
+        }
+        let stream = writer.into_inner().unwrap();
+        let reader = StreamReader::try_new(Cursor::new(stream), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+
+        // The file format only supports deltas, not replacements
+        let mut writer =
+            FileWriter::try_new_with_options(vec![], &batches[0].schema(), options).unwrap();
+        writer.write(&batches[0]).unwrap();
+        writer.write(&batches[1]).unwrap();
+        let err = writer.write(&batches[2]).unwrap_err();
+        assert!(
+            err.to_string().contains("Dictionary replacement detected"),
+            "{err}"
+        );
+        writer.finish().unwrap();
+        let file = writer.into_inner().unwrap();
+        let reader = FileReader::try_new(Cursor::new(file), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches[..2]);
+    }
+
     #[test]
     fn truncate_ipc_struct_array() {
         fn create_batch() -> RecordBatch {
@@ -0,0 +1,313 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Async writers for the Arrow IPC stream and file formats
+
+use std::fmt;
+
+use arrow_array::RecordBatch;
+use arrow_schema::{ArrowError, Schema, SchemaRef};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::writer::{FileWriter, IpcWriteOptions, StreamWriter};
+
+/// Writes the bytes encoded into `buffer` to `writer`, clearing `buffer`
+async fn write_buffer<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    buffer: &mut Vec<u8>,
+) -> Result<(), ArrowError> {
+    if !buffer.is_empty() {
+        writer.write_all(buffer).await?;
+        buffer.clear();
+    }
+    Ok(())
+}
+
+/// Async Arrow Stream Writer
+///
+/// Writes Arrow [`RecordBatch`]es to an [`AsyncWrite`] using the [IPC Streaming Format].
+///
+/// Each batch is encoded by a [`StreamWriter`] into an in-memory buffer, which is then
+/// written to the underlying writer.
+///
+/// # Example
+/// ```
+/// # use arrow_array::record_batch;
+/// # use arrow_ipc::writer::AsyncStreamWriter;
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let batch = record_batch!(("a", Int32, [1, 2, 3])).unwrap();
+/// // create a new writer, the schema must be known in advance
+/// let mut writer = AsyncStreamWriter::try_new(vec![], &batch.schema()).unwrap();
+/// // write each batch to the underlying stream
+/// writer.write(&batch).await.unwrap();
+/// // When all batches are written, call finish to flush all buffers
+/// writer.finish().await.unwrap();
+/// # }
+/// ```
+///
+/// [IPC Streaming Format]: https://arrow.apache.org/docs/format/Columnar.html#ipc-streaming-format
+pub struct AsyncStreamWriter<W> {
+    /// The object to write to
+    writer: W,
+    /// The writer encoding the stream into an in-memory buffer
+    sync_writer: StreamWriter<Vec<u8>>,
+}
+
+impl<W> fmt::Debug for AsyncStreamWriter<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncStreamWriter<W>")
+            .field("finished", &self.sync_writer.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W: AsyncWrite + Unpin + Send> AsyncStreamWriter<W> {
+    /// Try to create a new writer, with the schema written as part of the header
+    ///
+    /// The header is written to `writer` along with the first batch, or when finished
+    pub fn try_new(writer: W, schema: &Schema) -> Result<Self, ArrowError> {
+        Self::try_new_with_options(writer, schema, IpcWriteOptions::default())
+    }
+
+    /// Try to create a new writer with [`IpcWriteOptions`]
+    pub fn try_new_with_options(
+        writer: W,
+        schema: &Schema,
+        write_options: IpcWriteOptions,
+    ) -> Result<Self, ArrowError> {
+        let sync_writer = StreamWriter::try_new_with_options(vec![], schema, write_options)?;
+        Ok(Self {
+            writer,
+            sync_writer,
+        })
+    }
+
+    /// Write a record batch to the stream
+    pub async fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.sync_writer.write(batch)?;
+        write_buffer(&mut self.writer, self.sync_writer.get_mut()).await
+    }
+
+    /// Write continuation bytes, and mark the stream as done
+    pub async fn finish(&mut self) -> Result<(), ArrowError> {
+        self.sync_writer.finish()?;
+        write_buffer(&mut self.writer, self.sync_writer.get_mut()).await?;
+        self.writer.flush().await?;
+        Ok(())
+    }
+
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// It is inadvisable to directly write to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Unwraps the underlying writer.
+    ///
+    /// The writer is finished and flushed before returning.
+    pub async fn into_inner(mut self) -> Result<W, ArrowError> {
+        if !self.sync_writer.finished {
+            self.finish().await?;
+        }
+        Ok(self.writer)
+    }
+}
+
+/// Async Arrow File Writer
+///
+/// Writes Arrow [`RecordBatch`]es to an [`AsyncWrite`] using the [IPC File Format].
+///
+/// Each batch is encoded by a [`FileWriter`] into an in-memory buffer, which is then
+/// written to the underlying writer.
+///
+/// # Example
+/// ```
+/// # use arrow_array::record_batch;
+/// # use arrow_ipc::writer::AsyncFileWriter;
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let batch = record_batch!(("a", Int32, [1, 2, 3])).unwrap();
+/// let mut writer = AsyncFileWriter::try_new(vec![], &batch.schema()).unwrap();
+/// writer.write(&batch).await.unwrap();
+/// // When all batches are written, call finish to write the footer
+/// writer.finish().await.unwrap();
+/// # }
+/// ```
+///
+/// [IPC File Format]: https://arrow.apache.org/docs/format/Columnar.html#ipc-file-format
+pub struct AsyncFileWriter<W> {
+    /// The object to write to
+    writer: W,
+    /// The writer encoding the file into an in-memory buffer
+    sync_writer: FileWriter<Vec<u8>>,
+}
+
+impl<W> fmt::Debug for AsyncFileWriter<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncFileWriter<W>")
+            .field("schema", &self.sync_writer.schema)
+            .field("finished", &self.sync_writer.finished)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<W: AsyncWrite + Unpin + Send> AsyncFileWriter<W> {
+    /// Try to create a new writer, with the schema written as part of the header
+    ///
+    /// The header is written to `writer` along with the first batch, or when finished
+    pub fn try_new(writer: W, schema: &Schema) -> Result<Self, ArrowError> {
+        Self::try_new_with_options(writer, schema, IpcWriteOptions::default())
+    }
+
+    /// Try to create a new writer with [`IpcWriteOptions`]
+    pub fn try_new_with_options(
+        writer: W,
+        schema: &Schema,
+        write_options: IpcWriteOptions,
+    ) -> Result<Self, ArrowError> {
+        let sync_writer = FileWriter::try_new_with_options(vec![], schema, write_options)?;
+        Ok(Self {
+            writer,
+            sync_writer,
+        })
+    }
+
+    /// Adds a key-value pair to the [AsyncFileWriter]'s custom metadata
+    pub fn write_metadata(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.sync_writer.write_metadata(key, value);
+    }
+
+    /// Write a record batch to the file
+    pub async fn write(&mut self, batch: &RecordBatch) -> Result<(), ArrowError> {
+        self.sync_writer.write(batch)?;
+        write_buffer(&mut self.writer, self.sync_writer.get_mut()).await
+    }
+
+    /// Write footer and closing tag, then mark the writer as done
+    pub async fn finish(&mut self) -> Result<(), ArrowError> {
+        self.sync_writer.finish()?;
+        write_buffer(&mut self.writer, self.sync_writer.get_mut()).await?;
+        self.writer.flush().await?;
+        Ok(())
+    }
+
+    /// Returns the arrow [`SchemaRef`] for this arrow file.
+    pub fn schema(&self) -> &SchemaRef {
+        self.sync_writer.schema()
+    }
+
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// It is inadvisable to directly write to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
+    /// Unwraps the underlying writer.
+    ///
+    /// The writer is finished and flushed before returning.
+    pub async fn into_inner(mut self) -> Result<W, ArrowError> {
+        if !self.sync_writer.finished {
+            self.finish().await?;
+        }
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::{FileReader, StreamReader};
+    use crate::writer::DictionaryHandling;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{ArrayRef, DictionaryArray, Int64Array};
+    use std::io::Cursor;
+    use std::sync::Arc;
+
+    fn test_batches() -> Vec<RecordBatch> {
+        (0..3)
+            .map(|i| {
+                let values = ["a", "b", "c", "d"];
+                let dict: DictionaryArray<Int32Type> = values[..i + 2].iter().copied().collect();
+                RecordBatch::try_from_iter([
+                    (
+                        "i",
+                        Arc::new(Int64Array::from(vec![i as i64; i + 2])) as ArrayRef,
+                    ),
+                    ("d", Arc::new(dict) as ArrayRef),
+                ])
+                .unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_async_stream_writer() {
+        let batches = test_batches();
+        let options =
+            IpcWriteOptions::default().with_dictionary_handling(DictionaryHandling::Delta);
+        let mut writer =
+            AsyncStreamWriter::try_new_with_options(vec![], &batches[0].schema(), options).unwrap();
+        assert!(writer.get_ref().is_empty());
+        for batch in &batches {
+            writer.write(batch).await.unwrap();
+        }
+        writer.finish().await.unwrap();
+        let err = writer.write(&batches[0]).await.unwrap_err();
+        assert!(err.to_string().contains("closed"), "{err}");
+        let data = writer.into_inner().await.unwrap();
+
+        let reader = StreamReader::try_new(Cursor::new(data), None).unwrap();
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, batches);
+    }
+
+    #[tokio::test]
+    async fn test_async_file_writer() {
+        let batches = test_batches();
+        let schema = batches[0].schema();
+        let mut writer = AsyncFileWriter::try_new(vec![], &schema).unwrap();
+        assert_eq!(writer.schema(), &schema);
+        writer.write_metadata("key", "value");
+        writer.write(&batches[0]).await.unwrap();
+        writer.write(&batches[0]).await.unwrap();
+        let data = writer.into_inner().await.unwrap();
+
+        let mut sync_writer = FileWriter::try_new(vec![], &schema).unwrap();
+        sync_writer.write_metadata("key", "value");
+        sync_writer.write(&batches[0]).unwrap();
+        sync_writer.write(&batches[0]).unwrap();
+        assert_eq!(data, sync_writer.into_inner().unwrap());
+
+        let reader = FileReader::try_new(Cursor::new(data), None).unwrap();
+        assert_eq!(reader.custom_metadata()["key"], "value");
+        let read = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(read, [batches[0].clone(), batches[0].clone()]);
+    }
+}
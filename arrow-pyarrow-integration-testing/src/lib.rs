@@ -25,15 +25,18 @@ use arrow::array::new_empty_array;
 use arrow::record_batch::{RecordBatchIterator, RecordBatchReader};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
 use pyo3::wrap_pyfunction;
 
 use arrow::array::{make_array, Array, ArrayData, ArrayRef, Int64Array};
+use arrow::buffer::Buffer;
 use arrow::compute::kernels;
-use arrow::datatypes::{DataType, Field, Schema};
+use arrow::datatypes::{DataType, Field, Int64Type, Schema};
 use arrow::error::ArrowError;
 use arrow::ffi_stream::ArrowArrayStreamReader;
 use arrow::pyarrow::{FromPyArrow, PyArrowException, PyArrowType, ToPyArrow};
 use arrow::record_batch::RecordBatch;
+use arrow::tensor::{read_tensor, write_tensor};
 
 fn to_py_err(err: ArrowError) -> PyErr {
     PyArrowException::new_err(err.to_string())
@@ -161,6 +164,16 @@ fn boxed_reader_roundtrip(
     PyArrowType(reader)
 }
 
+/// Reads an int64 tensor IPC message and writes it back, as with `pyarrow.ipc.write_tensor`
+#[pyfunction]
+fn round_trip_int64_tensor(message: &[u8], py: Python) -> PyResult<PyObject> {
+    let message = Buffer::from(message);
+    let tensor = read_tensor::<Int64Type>(&message).map_err(to_py_err)?;
+    let mut out = Vec::new();
+    write_tensor(&mut out, &tensor).map_err(to_py_err)?;
+    Ok(PyBytes::new(py, &out).into_any().unbind())
+}
+
 #[pymodule]
 fn arrow_pyarrow_integration_testing(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(double))?;
@@ -176,5 +189,6 @@ fn arrow_pyarrow_integration_testing(_py: Python, m: &Bound<PyModule>) -> PyResu
     m.add_wrapped(wrap_pyfunction!(round_trip_record_batch_reader))?;
     m.add_wrapped(wrap_pyfunction!(reader_return_errors))?;
     m.add_wrapped(wrap_pyfunction!(boxed_reader_roundtrip))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip_int64_tensor))?;
     Ok(())
 }
@@ -477,6 +477,25 @@ def test_tensor_array():
     del b
 
 
+@pytest.mark.parametrize("order", ["C", "F"])
+def test_tensor_ipc_round_trip(order):
+    np = pytest.importorskip("numpy")
+    values = np.arange(6, dtype=np.int64).reshape((2, 3))
+    values = np.asarray(values, order=order)
+    tensor = pa.Tensor.from_numpy(values, dim_names=["rows", "cols"])
+
+    # A tensor written by pyarrow is read and written back by arrow-rs
+    sink = pa.BufferOutputStream()
+    pa.ipc.write_tensor(tensor, sink)
+    message = rust.round_trip_int64_tensor(sink.getvalue().to_pybytes())
+
+    b = pa.ipc.read_tensor(pa.py_buffer(message))
+    assert b.equals(tensor)
+    assert b.shape == tensor.shape
+    assert b.strides == tensor.strides
+    assert b.dim_names == tensor.dim_names
+
+
 def test_empty_recordbatch_with_row_count():
     """
     A pyarrow.RecordBatch with no columns but with `num_rows` set.
@@ -26,6 +26,14 @@ use crate::datatypes::*;
 
 use crate::error::{ArrowError, Result};
 
+#[cfg(feature = "ipc")]
+mod ipc;
+mod sparse;
+
+#[cfg(feature = "ipc")]
+pub use ipc::*;
+pub use sparse::*;
+
 /// Computes the strides required assuming a row major memory layout
 fn compute_row_major_strides<T: ArrowPrimitiveType>(shape: &[usize]) -> Result<Vec<usize>> {
     let mut remaining_bytes = mem::size_of::<T::Native>();
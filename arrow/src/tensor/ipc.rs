@@ -0,0 +1,727 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reading and writing [`Tensor`] and [`SparseTensor`] as Arrow IPC messages
+//!
+//! Each tensor is written as a single encapsulated IPC message, followed by a body
+//! holding its values and, for a sparse tensor, its indices. This is the format
+//! read and written by `pyarrow.ipc.read_tensor` and `pyarrow.ipc.write_tensor`.
+
+use std::io::Write;
+use std::mem;
+
+use arrow_ipc as ipc;
+use arrow_ipc::writer::{write_message, EncodedData, IpcWriteOptions};
+use flatbuffers::{FlatBufferBuilder, ForwardsUOffset, UnionWIPOffset, Vector, WIPOffset};
+
+use super::{SparseTensor, SparseTensorIndex, Tensor};
+use crate::buffer::{Buffer, ScalarBuffer};
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+
+const CONTINUATION_MARKER: [u8; 4] = [0xff; 4];
+
+/// The alignment of the buffers in the message body, matching the metadata
+const ALIGNMENT: usize = 64;
+
+/// Writes `tensor` as an IPC `Tensor` message, returning the number of bytes written
+pub fn write_tensor<T: ArrowPrimitiveType, W: Write>(
+    writer: W,
+    tensor: &Tensor<'_, T>,
+) -> Result<usize> {
+    let mut fbb = FlatBufferBuilder::new();
+    let (type_type, type_) = fb_type(&mut fbb, tensor.data_type())?;
+    let shape = tensor.shape().map(Vec::as_slice).unwrap_or_default();
+    let dims = fb_dims(&mut fbb, shape, tensor.names().map(Vec::as_slice));
+    let strides: Vec<i64> = tensor
+        .strides()
+        .into_iter()
+        .flatten()
+        .map(|s| *s as i64)
+        .collect();
+    let strides = fbb.create_vector(&strides);
+
+    let mut body = Vec::new();
+    let data = push_buffer(&mut body, tensor.data());
+
+    let mut builder = ipc::TensorBuilder::new(&mut fbb);
+    builder.add_type_type(type_type);
+    builder.add_type_(type_);
+    builder.add_shape(dims);
+    builder.add_strides(strides);
+    builder.add_data(&data);
+    let header = builder.finish().as_union_value();
+
+    finish_message(writer, fbb, ipc::MessageHeader::Tensor, header, body)
+}
+
+/// Reads a [`Tensor`] from an IPC `Tensor` message, such as one written by [`write_tensor`]
+///
+/// The returned tensor shares memory with `message`, and borrows its dimension names from it
+pub fn read_tensor<T: ArrowPrimitiveType>(message: &Buffer) -> Result<Tensor<'_, T>> {
+    let (message, body) = decode_message(message)?;
+    let tensor = message.header_as_tensor().ok_or_else(|| {
+        ArrowError::IpcError(format!(
+            "Expected a Tensor message, got {:?}",
+            message.header_type()
+        ))
+    })?;
+    check_data_type::<T>(
+        tensor.type_type(),
+        tensor.type_as_int(),
+        tensor.type_as_floating_point(),
+    )?;
+
+    let (shape, names) = decode_dims(tensor.shape())?;
+    let strides = tensor
+        .strides()
+        .filter(|s| !s.is_empty())
+        .map(|s| s.iter().map(|s| s as usize).collect());
+    let data = body_buffer(&body, tensor.data())?;
+
+    match shape.is_empty() {
+        true => Tensor::try_new(data, None, None, None),
+        false => Tensor::try_new(data, Some(shape), strides, names),
+    }
+}
+
+/// Writes `tensor` as an IPC `SparseTensor` message, returning the number of bytes written
+pub fn write_sparse_tensor<T: ArrowPrimitiveType, W: Write>(
+    writer: W,
+    tensor: &SparseTensor<'_, T>,
+) -> Result<usize> {
+    let mut fbb = FlatBufferBuilder::new();
+    let (type_type, type_) = fb_type(&mut fbb, tensor.data_type())?;
+    let dims = fb_dims(&mut fbb, tensor.shape(), tensor.names().map(Vec::as_slice));
+
+    let mut body = Vec::new();
+    let (index_type, index) = match tensor.index() {
+        SparseTensorIndex::Coo {
+            indices,
+            is_canonical,
+        } => {
+            let indices_type = fb_int(&mut fbb, 64, true);
+            let ndim = tensor.ndim() as i64;
+            let strides = fbb.create_vector(&[ndim * 8, 8]);
+            let indices = push_buffer(&mut body, indices.inner());
+
+            let mut builder = ipc::SparseTensorIndexCOOBuilder::new(&mut fbb);
+            builder.add_indicesType(indices_type);
+            builder.add_indicesStrides(strides);
+            builder.add_indicesBuffer(&indices);
+            builder.add_isCanonical(*is_canonical);
+            let index = builder.finish().as_union_value();
+            (ipc::SparseTensorIndex::SparseTensorIndexCOO, index)
+        }
+        SparseTensorIndex::Csr { indptr, indices } | SparseTensorIndex::Csc { indptr, indices } => {
+            let axis = match tensor.index() {
+                SparseTensorIndex::Csr { .. } => ipc::SparseMatrixCompressedAxis::Row,
+                _ => ipc::SparseMatrixCompressedAxis::Column,
+            };
+            let indptr_type = fb_int(&mut fbb, 64, true);
+            let indices_type = fb_int(&mut fbb, 64, true);
+            let indptr = push_buffer(&mut body, indptr.inner());
+            let indices = push_buffer(&mut body, indices.inner());
+
+            let mut builder = ipc::SparseMatrixIndexCSXBuilder::new(&mut fbb);
+            builder.add_compressedAxis(axis);
+            builder.add_indptrType(indptr_type);
+            builder.add_indptrBuffer(&indptr);
+            builder.add_indicesType(indices_type);
+            builder.add_indicesBuffer(&indices);
+            let index = builder.finish().as_union_value();
+            (ipc::SparseTensorIndex::SparseMatrixIndexCSX, index)
+        }
+        SparseTensorIndex::Csf {
+            indptr,
+            indices,
+            axis_order,
+        } => {
+            let indptr_type = fb_int(&mut fbb, 64, true);
+            let indices_type = fb_int(&mut fbb, 64, true);
+            let indptr: Vec<_> = indptr
+                .iter()
+                .map(|b| push_buffer(&mut body, b.inner()))
+                .collect();
+            let indices: Vec<_> = indices
+                .iter()
+                .map(|b| push_buffer(&mut body, b.inner()))
+                .collect();
+            let indptr = fbb.create_vector(&indptr);
+            let indices = fbb.create_vector(&indices);
+            let axis_order: Vec<i32> = axis_order.iter().map(|a| *a as i32).collect();
+            let axis_order = fbb.create_vector(&axis_order);
+
+            let mut builder = ipc::SparseTensorIndexCSFBuilder::new(&mut fbb);
+            builder.add_indptrType(indptr_type);
+            builder.add_indptrBuffers(indptr);
+            builder.add_indicesType(indices_type);
+            builder.add_indicesBuffers(indices);
+            builder.add_axisOrder(axis_order);
+            let index = builder.finish().as_union_value();
+            (ipc::SparseTensorIndex::SparseTensorIndexCSF, index)
+        }
+    };
+    let data = push_buffer(&mut body, tensor.data());
+
+    let mut builder = ipc::SparseTensorBuilder::new(&mut fbb);
+    builder.add_type_type(type_type);
+    builder.add_type_(type_);
+    builder.add_shape(dims);
+    builder.add_non_zero_length(tensor.non_zero_length() as i64);
+    builder.add_sparseIndex_type(index_type);
+    builder.add_sparseIndex(index);
+    builder.add_data(&data);
+    let header = builder.finish().as_union_value();
+
+    finish_message(writer, fbb, ipc::MessageHeader::SparseTensor, header, body)
+}
+
+/// Reads a [`SparseTensor`] from an IPC `SparseTensor` message, such as one written by
+/// [`write_sparse_tensor`]
+///
+/// Indices of any integer type are converted to `i64`. The returned tensor borrows its
+/// dimension names from `message`
+pub fn read_sparse_tensor<T: ArrowPrimitiveType>(message: &Buffer) -> Result<SparseTensor<'_, T>> {
+    let (message, body) = decode_message(message)?;
+    let tensor = message.header_as_sparse_tensor().ok_or_else(|| {
+        ArrowError::IpcError(format!(
+            "Expected a SparseTensor message, got {:?}",
+            message.header_type()
+        ))
+    })?;
+    check_data_type::<T>(
+        tensor.type_type(),
+        tensor.type_as_int(),
+        tensor.type_as_floating_point(),
+    )?;
+
+    let (shape, names) = decode_dims(tensor.shape())?;
+    let data = body_buffer(&body, tensor.data())?;
+    let non_zero_length = data.len() / mem::size_of::<T::Native>();
+
+    let index = if let Some(coo) = tensor.sparseIndex_as_sparse_tensor_index_coo() {
+        let indices = read_indices(&body, coo.indicesType(), coo.indicesBuffer())?;
+        let ndim = shape.len();
+        let width = (coo.indicesType().bitWidth() / 8) as i64;
+        let strides: Vec<i64> = coo.indicesStrides().into_iter().flatten().collect();
+
+        let row_major = [ndim as i64 * width, width];
+        let column_major = [width, non_zero_length as i64 * width];
+        let indices = if strides.is_empty() || strides == row_major || ndim <= 1 {
+            indices
+        } else if strides == column_major {
+            (0..non_zero_length)
+                .flat_map(|row| (0..ndim).map(move |col| (row, col)))
+                .map(|(row, col)| indices[col * non_zero_length + row])
+                .collect()
+        } else {
+            return Err(ArrowError::IpcError(format!(
+                "Unsupported strides {strides:?} for COO indices"
+            )));
+        };
+        SparseTensorIndex::Coo {
+            indices,
+            is_canonical: coo.isCanonical(),
+        }
+    } else if let Some(csx) = tensor.sparseIndex_as_sparse_matrix_index_csx() {
+        let indptr = read_indices(&body, csx.indptrType(), csx.indptrBuffer())?;
+        let indices = read_indices(&body, csx.indicesType(), csx.indicesBuffer())?;
+        match csx.compressedAxis() {
+            ipc::SparseMatrixCompressedAxis::Row => SparseTensorIndex::Csr { indptr, indices },
+            ipc::SparseMatrixCompressedAxis::Column => SparseTensorIndex::Csc { indptr, indices },
+            axis => {
+                return Err(ArrowError::IpcError(format!(
+                    "Unsupported compressed axis {axis:?}"
+                )))
+            }
+        }
+    } else if let Some(csf) = tensor.sparseIndex_as_sparse_tensor_index_csf() {
+        let indptr = csf
+            .indptrBuffers()
+            .iter()
+            .map(|b| read_indices(&body, csf.indptrType(), b))
+            .collect::<Result<_>>()?;
+        let indices = csf
+            .indicesBuffers()
+            .iter()
+            .map(|b| read_indices(&body, csf.indicesType(), b))
+            .collect::<Result<_>>()?;
+        let axis_order = csf
+            .axisOrder()
+            .iter()
+            .map(|a| {
+                usize::try_from(a)
+                    .map_err(|_| ArrowError::IpcError(format!("Invalid CSF axis {a}")))
+            })
+            .collect::<Result<_>>()?;
+        SparseTensorIndex::Csf {
+            indptr,
+            indices,
+            axis_order,
+        }
+    } else {
+        return Err(ArrowError::IpcError(format!(
+            "Unsupported sparse tensor index {:?}",
+            tensor.sparseIndex_type()
+        )));
+    };
+
+    SparseTensor::try_new(data, shape, index, names)
+}
+
+/// Wraps `header` in a `Message` and writes it, followed by `body`
+fn finish_message<W: Write>(
+    writer: W,
+    mut fbb: FlatBufferBuilder<'_>,
+    header_type: ipc::MessageHeader,
+    header: WIPOffset<UnionWIPOffset>,
+    body: Vec<u8>,
+) -> Result<usize> {
+    let mut message = ipc::MessageBuilder::new(&mut fbb);
+    message.add_version(ipc::MetadataVersion::V5);
+    message.add_header_type(header_type);
+    message.add_bodyLength(body.len() as i64);
+    message.add_header(header);
+    let message = message.finish();
+    fbb.finish(message, None);
+
+    let encoded = EncodedData {
+        ipc_message: fbb.finished_data().to_vec(),
+        arrow_data: body,
+    };
+    let (metadata_len, body_len) = write_message(writer, encoded, &IpcWriteOptions::default())?;
+    Ok(metadata_len + body_len)
+}
+
+/// Appends `data` to `body`, padded to [`ALIGNMENT`], returning its location
+fn push_buffer(body: &mut Vec<u8>, data: &[u8]) -> ipc::Buffer {
+    let offset = body.len();
+    body.extend_from_slice(data);
+    body.resize(body.len().next_multiple_of(ALIGNMENT), 0);
+    ipc::Buffer::new(offset as i64, data.len() as i64)
+}
+
+fn fb_int<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    bit_width: i32,
+    is_signed: bool,
+) -> WIPOffset<ipc::Int<'a>> {
+    ipc::Int::create(
+        fbb,
+        &ipc::IntArgs {
+            bitWidth: bit_width,
+            is_signed,
+        },
+    )
+}
+
+fn fb_type(
+    fbb: &mut FlatBufferBuilder<'_>,
+    data_type: &DataType,
+) -> Result<(ipc::Type, WIPOffset<UnionWIPOffset>)> {
+    let int = |fbb: &mut FlatBufferBuilder<'_>, bit_width, is_signed| {
+        (
+            ipc::Type::Int,
+            fb_int(fbb, bit_width, is_signed).as_union_value(),
+        )
+    };
+    let float = |fbb: &mut FlatBufferBuilder<'_>, precision| {
+        let float = ipc::FloatingPoint::create(fbb, &ipc::FloatingPointArgs { precision });
+        (ipc::Type::FloatingPoint, float.as_union_value())
+    };
+    Ok(match data_type {
+        DataType::Int8 => int(fbb, 8, true),
+        DataType::Int16 => int(fbb, 16, true),
+        DataType::Int32 => int(fbb, 32, true),
+        DataType::Int64 => int(fbb, 64, true),
+        DataType::UInt8 => int(fbb, 8, false),
+        DataType::UInt16 => int(fbb, 16, false),
+        DataType::UInt32 => int(fbb, 32, false),
+        DataType::UInt64 => int(fbb, 64, false),
+        DataType::Float16 => float(fbb, ipc::Precision::HALF),
+        DataType::Float32 => float(fbb, ipc::Precision::SINGLE),
+        DataType::Float64 => float(fbb, ipc::Precision::DOUBLE),
+        _ => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "IPC tensors of type {data_type} are not supported"
+            )))
+        }
+    })
+}
+
+fn fb_dims<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    shape: &[usize],
+    names: Option<&[&str]>,
+) -> WIPOffset<Vector<'a, ForwardsUOffset<ipc::TensorDim<'a>>>> {
+    let dims: Vec<_> = shape
+        .iter()
+        .enumerate()
+        .map(|(i, size)| {
+            let name = names.map(|names| fbb.create_string(names[i]));
+            let mut dim = ipc::TensorDimBuilder::new(fbb);
+            dim.add_size_(*size as i64);
+            if let Some(name) = name {
+                dim.add_name(name);
+            }
+            dim.finish()
+        })
+        .collect();
+    fbb.create_vector(&dims)
+}
+
+fn check_data_type<T: ArrowPrimitiveType>(
+    type_type: ipc::Type,
+    int: Option<ipc::Int<'_>>,
+    float: Option<ipc::FloatingPoint<'_>>,
+) -> Result<()> {
+    let data_type = match (int, float) {
+        (Some(int), _) => match (int.bitWidth(), int.is_signed()) {
+            (8, true) => Some(DataType::Int8),
+            (16, true) => Some(DataType::Int16),
+            (32, true) => Some(DataType::Int32),
+            (64, true) => Some(DataType::Int64),
+            (8, false) => Some(DataType::UInt8),
+            (16, false) => Some(DataType::UInt16),
+            (32, false) => Some(DataType::UInt32),
+            (64, false) => Some(DataType::UInt64),
+            _ => None,
+        },
+        (_, Some(float)) => match float.precision() {
+            ipc::Precision::HALF => Some(DataType::Float16),
+            ipc::Precision::SINGLE => Some(DataType::Float32),
+            ipc::Precision::DOUBLE => Some(DataType::Float64),
+            _ => None,
+        },
+        _ => None,
+    };
+    match data_type {
+        Some(data_type) if data_type == T::DATA_TYPE => Ok(()),
+        Some(data_type) => Err(ArrowError::InvalidArgumentError(format!(
+            "Tensor of type {data_type} cannot be read as {}",
+            T::DATA_TYPE
+        ))),
+        None => Err(ArrowError::IpcError(format!(
+            "Unsupported tensor type {type_type:?}"
+        ))),
+    }
+}
+
+fn decode_dims<'a>(
+    dims: Vector<'a, ForwardsUOffset<ipc::TensorDim<'a>>>,
+) -> Result<(Vec<usize>, Option<Vec<&'a str>>)> {
+    let shape = dims
+        .iter()
+        .map(|dim| {
+            usize::try_from(dim.size_()).map_err(|_| {
+                ArrowError::IpcError(format!("Invalid tensor dimension size {}", dim.size_()))
+            })
+        })
+        .collect::<Result<_>>()?;
+    let names = dims.iter().any(|dim| dim.name().is_some()).then(|| {
+        dims.iter()
+            .map(|dim| dim.name().unwrap_or_default())
+            .collect()
+    });
+    Ok((shape, names))
+}
+
+/// Decodes an encapsulated IPC message, returning it and its body
+fn decode_message(buf: &Buffer) -> Result<(ipc::Message<'_>, Buffer)> {
+    let (prefix, len) = match buf.get(..4) {
+        Some(marker) if marker == CONTINUATION_MARKER => (8, buf.get(4..8)),
+        _ => (4, buf.get(..4)),
+    };
+    let truncated = || ArrowError::IpcError("Tensor message is truncated".to_string());
+    let len = len.ok_or_else(truncated)?;
+    let len = usize::try_from(i32::from_le_bytes(len.try_into().unwrap()))
+        .map_err(|_| ArrowError::IpcError("Invalid tensor message length".to_string()))?;
+    let metadata = buf.get(prefix..prefix + len).ok_or_else(truncated)?;
+    let message = ipc::root_as_message(metadata)
+        .map_err(|err| ArrowError::ParseError(format!("Unable to get root as message: {err:?}")))?;
+
+    let start = prefix + len;
+    let body_len = usize::try_from(message.bodyLength())
+        .map_err(|_| ArrowError::IpcError("Invalid tensor message body length".to_string()))?;
+    if start
+        .checked_add(body_len)
+        .is_none_or(|end| end > buf.len())
+    {
+        return Err(truncated());
+    }
+    Ok((message, buf.slice_with_length(start, body_len)))
+}
+
+/// Returns the region of `body` described by `buffer`, aligned for any tensor type
+fn body_buffer(body: &Buffer, buffer: &ipc::Buffer) -> Result<Buffer> {
+    let offset = usize::try_from(buffer.offset()).ok();
+    let length = usize::try_from(buffer.length()).ok();
+    match (offset, length) {
+        (Some(offset), Some(length))
+            if offset
+                .checked_add(length)
+                .is_some_and(|end| end <= body.len()) =>
+        {
+            let data = body.slice_with_length(offset, length);
+            match data.as_ptr().align_offset(mem::align_of::<u64>()) {
+                0 => Ok(data),
+                _ => Ok(Buffer::from_slice_ref(data.as_slice())),
+            }
+        }
+        _ => Err(ArrowError::IpcError(format!(
+            "Buffer at offset {} with length {} exceeds tensor message body of {} bytes",
+            buffer.offset(),
+            buffer.length(),
+            body.len()
+        ))),
+    }
+}
+
+/// Reads the sparse tensor indices described by `buffer`, widening them to `i64`
+fn read_indices(
+    body: &Buffer,
+    int: ipc::Int<'_>,
+    buffer: &ipc::Buffer,
+) -> Result<ScalarBuffer<i64>> {
+    let data = body_buffer(body, buffer)?;
+    match (int.bitWidth(), int.is_signed()) {
+        (64, true) => Ok(ScalarBuffer::new(data.clone(), 0, data.len() / 8)),
+        (8, true) => widen_indices::<i8>(data),
+        (16, true) => widen_indices::<i16>(data),
+        (32, true) => widen_indices::<i32>(data),
+        (8, false) => widen_indices::<u8>(data),
+        (16, false) => widen_indices::<u16>(data),
+        (32, false) => widen_indices::<u32>(data),
+        (64, false) => widen_indices::<u64>(data),
+        (bit_width, is_signed) => Err(ArrowError::IpcError(format!(
+            "Unsupported sparse tensor index type with bit width {bit_width} and signed {is_signed}"
+        ))),
+    }
+}
+
+fn widen_indices<N: ArrowNativeType>(data: Buffer) -> Result<ScalarBuffer<i64>> {
+    let len = data.len() / mem::size_of::<N>();
+    ScalarBuffer::<N>::new(data, 0, len)
+        .iter()
+        .map(|v| {
+            v.to_i64().ok_or_else(|| {
+                ArrowError::IpcError(format!("Sparse tensor index {v:?} overflows i64"))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::tensor::{
+        Float32Tensor, Int32Tensor, Int64Tensor, Int8Tensor, TimestampSecondTensor, UInt16Tensor,
+    };
+
+    fn roundtrip_tensor<T: ArrowPrimitiveType>(tensor: &Tensor<'_, T>) -> Buffer {
+        let mut buf = Vec::new();
+        let written = write_tensor(&mut buf, tensor).unwrap();
+        assert_eq!(written, buf.len());
+        assert_eq!(buf.len() % ALIGNMENT, 0);
+
+        let message = Buffer::from_vec(buf);
+        let read = read_tensor::<T>(&message).unwrap();
+        assert_eq!(read.data_type(), tensor.data_type());
+        assert_eq!(read.shape(), tensor.shape());
+        assert_eq!(read.strides(), tensor.strides());
+        assert_eq!(read.names(), tensor.names());
+        assert_eq!(read.data(), tensor.data());
+        message
+    }
+
+    #[test]
+    fn test_tensor_roundtrip() {
+        let buf = Buffer::from_slice_ref([1_i64, 2, 3, 4, 5, 6]);
+        let tensor =
+            Int64Tensor::new_row_major(buf, Some(vec![2, 3]), Some(vec!["rows", "cols"])).unwrap();
+        let message = roundtrip_tensor(&tensor);
+
+        // Encapsulated message with a continuation marker, padded to 64 bytes
+        assert_eq!(message[..4], CONTINUATION_MARKER);
+        let metadata_len = i32::from_le_bytes(message[4..8].try_into().unwrap()) as usize;
+        assert_eq!((metadata_len + 8) % ALIGNMENT, 0);
+
+        let buf = Buffer::from_slice_ref([1.0_f32, 2.0, 3.0, 4.0]);
+        let tensor = Float32Tensor::new_column_major(buf, Some(vec![2, 2]), None).unwrap();
+        roundtrip_tensor(&tensor);
+
+        let buf = Buffer::from_slice_ref([1_u16, 2, 3, 4, 5, 6, 7, 8]);
+        let tensor = UInt16Tensor::new_row_major(buf, Some(vec![2, 2, 2]), None).unwrap();
+        roundtrip_tensor(&tensor);
+
+        let buf = Buffer::from_slice_ref([42_i8]);
+        let tensor = Int8Tensor::try_new(buf, None, None, None).unwrap();
+        roundtrip_tensor(&tensor);
+    }
+
+    #[test]
+    fn test_read_tensor_errors() {
+        let buf = Buffer::from_slice_ref([1_i32, 2]);
+        let tensor = Int32Tensor::new_row_major(buf, Some(vec![2]), None).unwrap();
+        let mut message = Vec::new();
+        write_tensor(&mut message, &tensor).unwrap();
+        let message = Buffer::from_vec(message);
+
+        let err = read_tensor::<Int64Type>(&message).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: Tensor of type Int32 cannot be read as Int64"
+        );
+
+        let err = read_sparse_tensor::<Int32Type>(&message).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Ipc error: Expected a SparseTensor message, got Tensor"
+        );
+
+        let truncated = message.slice_with_length(0, message.len() - 1);
+        let err = read_tensor::<Int32Type>(&truncated).unwrap_err();
+        assert_eq!(err.to_string(), "Ipc error: Tensor message is truncated");
+
+        let buf = Buffer::from_slice_ref([1_i64]);
+        let tensor = TimestampSecondTensor::new_row_major(buf, Some(vec![1]), None).unwrap();
+        let err = write_tensor(&mut Vec::new(), &tensor).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: IPC tensors of type Timestamp(Second, None) are not supported"
+        );
+    }
+
+    fn roundtrip_sparse<T: ArrowPrimitiveType>(tensor: &SparseTensor<'_, T>) {
+        let mut buf = Vec::new();
+        let written = write_sparse_tensor(&mut buf, tensor).unwrap();
+        assert_eq!(written, buf.len());
+
+        let message = Buffer::from_vec(buf);
+        let read = read_sparse_tensor::<T>(&message).unwrap();
+        assert_eq!(read.data_type(), tensor.data_type());
+        assert_eq!(read.shape(), tensor.shape());
+        assert_eq!(read.names(), tensor.names());
+        assert_eq!(read.index(), tensor.index());
+        assert_eq!(read.data(), tensor.data());
+    }
+
+    #[test]
+    fn test_sparse_tensor_roundtrip() {
+        let index = SparseTensorIndex::Coo {
+            indices: ScalarBuffer::from(vec![0, 1, 1, 0, 2, 2]),
+            is_canonical: true,
+        };
+        let buf = Buffer::from_slice_ref([1.0_f64, 2.0, 3.0]);
+        let tensor =
+            SparseTensor::<Float64Type>::try_new(buf, vec![3, 3], index, Some(vec!["x", "y"]))
+                .unwrap();
+        roundtrip_sparse(&tensor);
+
+        let index = SparseTensorIndex::Csr {
+            indptr: ScalarBuffer::from(vec![0, 2, 3]),
+            indices: ScalarBuffer::from(vec![0, 2, 2]),
+        };
+        let buf = Buffer::from_slice_ref([1_i32, 2, 3]);
+        let tensor = SparseTensor::<Int32Type>::try_new(buf, vec![2, 3], index, None).unwrap();
+        roundtrip_sparse(&tensor);
+
+        let index = SparseTensorIndex::Csc {
+            indptr: ScalarBuffer::from(vec![0, 1, 1, 3]),
+            indices: ScalarBuffer::from(vec![0, 0, 1]),
+        };
+        let buf = Buffer::from_slice_ref([1_u8, 2, 3]);
+        let tensor = SparseTensor::<UInt8Type>::try_new(buf, vec![2, 3], index, None).unwrap();
+        roundtrip_sparse(&tensor);
+
+        let index = SparseTensorIndex::Csf {
+            indptr: vec![
+                ScalarBuffer::from(vec![0, 2, 3]),
+                ScalarBuffer::from(vec![0, 1, 2, 3]),
+            ],
+            indices: vec![
+                ScalarBuffer::from(vec![0, 1]),
+                ScalarBuffer::from(vec![0, 2, 1]),
+                ScalarBuffer::from(vec![1, 0, 1]),
+            ],
+            axis_order: vec![0, 1, 2],
+        };
+        let buf = Buffer::from_slice_ref([1_i16, 2, 3]);
+        let tensor = SparseTensor::<Int16Type>::try_new(
+            buf,
+            vec![2, 3, 2],
+            index,
+            Some(vec!["a", "b", "c"]),
+        )
+        .unwrap();
+        roundtrip_sparse(&tensor);
+    }
+
+    #[test]
+    fn test_read_sparse_tensor_narrow_column_major_indices() {
+        // COO indices of (0, 1) and (2, 0) as a column major int32 matrix
+        let mut fbb = FlatBufferBuilder::new();
+        let (type_type, type_) = fb_type(&mut fbb, &DataType::Int64).unwrap();
+        let dims = fb_dims(&mut fbb, &[3, 2], None);
+        let mut body = Vec::new();
+        let indices = push_buffer(
+            &mut body,
+            Buffer::from_slice_ref([0_i32, 2, 1, 0]).as_slice(),
+        );
+        let data = push_buffer(&mut body, Buffer::from_slice_ref([7_i64, 8]).as_slice());
+
+        let indices_type = fb_int(&mut fbb, 32, true);
+        let strides = fbb.create_vector(&[4_i64, 8]);
+        let mut builder = ipc::SparseTensorIndexCOOBuilder::new(&mut fbb);
+        builder.add_indicesType(indices_type);
+        builder.add_indicesStrides(strides);
+        builder.add_indicesBuffer(&indices);
+        let index = builder.finish().as_union_value();
+
+        let mut builder = ipc::SparseTensorBuilder::new(&mut fbb);
+        builder.add_type_type(type_type);
+        builder.add_type_(type_);
+        builder.add_shape(dims);
+        builder.add_non_zero_length(2);
+        builder.add_sparseIndex_type(ipc::SparseTensorIndex::SparseTensorIndexCOO);
+        builder.add_sparseIndex(index);
+        builder.add_data(&data);
+        let header = builder.finish().as_union_value();
+
+        let mut buf = Vec::new();
+        finish_message(
+            &mut buf,
+            fbb,
+            ipc::MessageHeader::SparseTensor,
+            header,
+            body,
+        )
+        .unwrap();
+        let message = Buffer::from_vec(buf);
+
+        let tensor = read_sparse_tensor::<Int64Type>(&message).unwrap();
+        assert_eq!(
+            tensor.index(),
+            &SparseTensorIndex::Coo {
+                indices: ScalarBuffer::from(vec![0, 1, 2, 0]),
+                is_canonical: false,
+            }
+        );
+    }
+}
@@ -0,0 +1,407 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Arrow Sparse Tensor Type, defined in
+//! [`format/SparseTensor.fbs`](https://github.com/apache/arrow/blob/master/format/SparseTensor.fbs).
+
+use std::marker::PhantomData;
+use std::mem;
+
+use crate::buffer::{Buffer, ScalarBuffer};
+use crate::datatypes::*;
+
+use crate::error::{ArrowError, Result};
+
+/// The location of the non-zero values of a [`SparseTensor`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SparseTensorIndex {
+    /// Coordinate (COO) format, storing the coordinates of each non-zero value
+    Coo {
+        /// A row major `non_zero_length x ndim` matrix of coordinates
+        indices: ScalarBuffer<i64>,
+        /// If the coordinates are sorted lexicographically and contain no duplicates
+        is_canonical: bool,
+    },
+    /// Compressed sparse row (CSR) format of a matrix
+    Csr {
+        /// The offsets into `indices` of the values of each row
+        indptr: ScalarBuffer<i64>,
+        /// The column of each non-zero value
+        indices: ScalarBuffer<i64>,
+    },
+    /// Compressed sparse column (CSC) format of a matrix
+    Csc {
+        /// The offsets into `indices` of the values of each column
+        indptr: ScalarBuffer<i64>,
+        /// The row of each non-zero value
+        indices: ScalarBuffer<i64>,
+    },
+    /// Compressed sparse fiber (CSF) format, a tree with one level per dimension
+    Csf {
+        /// For each level but the last, the offsets into the next level of the
+        /// children of each node
+        indptr: Vec<ScalarBuffer<i64>>,
+        /// For each level, the coordinate of each node along its dimension
+        indices: Vec<ScalarBuffer<i64>>,
+        /// The dimension of each level
+        axis_order: Vec<usize>,
+    },
+}
+
+/// Sparse tensor of primitive types, storing only its non-zero values
+#[derive(Debug)]
+pub struct SparseTensor<'a, T: ArrowPrimitiveType> {
+    data_type: DataType,
+    buffer: Buffer,
+    shape: Vec<usize>,
+    names: Option<Vec<&'a str>>,
+    index: SparseTensorIndex,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T: ArrowPrimitiveType> SparseTensor<'a, T> {
+    /// Creates a new `SparseTensor` from its non-zero values and their [`SparseTensorIndex`]
+    pub fn try_new(
+        buffer: Buffer,
+        shape: Vec<usize>,
+        index: SparseTensorIndex,
+        names: Option<Vec<&'a str>>,
+    ) -> Result<Self> {
+        if buffer.len() % mem::size_of::<T::Native>() != 0 {
+            return Err(ArrowError::InvalidArgumentError(
+                "buffer length is not a multiple of the element size".to_string(),
+            ));
+        }
+
+        if let Some(ref n) = names {
+            if n.len() != shape.len() {
+                return Err(ArrowError::InvalidArgumentError(
+                    "number of dimensions and number of dimension names differ".to_string(),
+                ));
+            }
+        }
+
+        let non_zero_length = buffer.len() / mem::size_of::<T::Native>();
+        validate_index(&index, &shape, non_zero_length)?;
+
+        Ok(Self {
+            data_type: T::DATA_TYPE,
+            buffer,
+            shape,
+            names,
+            index,
+            _marker: PhantomData,
+        })
+    }
+
+    /// The data type of the `SparseTensor`
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// The sizes of the dimensions
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Returns a reference to the underlying `Buffer` of non-zero values
+    pub fn data(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// The location of the non-zero values
+    pub fn index(&self) -> &SparseTensorIndex {
+        &self.index
+    }
+
+    /// The names of the dimensions
+    pub fn names(&self) -> Option<&Vec<&'a str>> {
+        self.names.as_ref()
+    }
+
+    /// The number of dimensions
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+
+    /// The name of dimension i
+    pub fn dim_name(&self, i: usize) -> Option<&'a str> {
+        self.names.as_ref().map(|names| names[i])
+    }
+
+    /// The total number of elements in the `SparseTensor`, including zeros
+    pub fn size(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// The number of non-zero values
+    pub fn non_zero_length(&self) -> usize {
+        self.buffer.len() / mem::size_of::<T::Native>()
+    }
+}
+
+fn validate_index(
+    index: &SparseTensorIndex,
+    shape: &[usize],
+    non_zero_length: usize,
+) -> Result<()> {
+    match index {
+        SparseTensorIndex::Coo {
+            indices,
+            is_canonical,
+        } => {
+            let ndim = shape.len();
+            if indices.len() != non_zero_length * ndim {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "expected {} COO indices for {non_zero_length} values with {ndim} dimensions, got {}",
+                    non_zero_length * ndim,
+                    indices.len()
+                )));
+            }
+            if ndim == 0 {
+                return Ok(());
+            }
+            for coords in indices.chunks_exact(ndim) {
+                for (c, s) in coords.iter().zip(shape) {
+                    check_coordinate(*c, *s)?;
+                }
+            }
+            if *is_canonical {
+                let mut rows = indices.chunks_exact(ndim);
+                let mut prev = rows.next();
+                for row in rows {
+                    if prev >= Some(row) {
+                        return Err(ArrowError::InvalidArgumentError(
+                            "canonical COO indices must be sorted and unique".to_string(),
+                        ));
+                    }
+                    prev = Some(row);
+                }
+            }
+        }
+        SparseTensorIndex::Csr { indptr, indices } | SparseTensorIndex::Csc { indptr, indices } => {
+            if shape.len() != 2 {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "CSR and CSC indices require a matrix, got {} dimensions",
+                    shape.len()
+                )));
+            }
+            let (compressed, other) = match index {
+                SparseTensorIndex::Csr { .. } => (shape[0], shape[1]),
+                _ => (shape[1], shape[0]),
+            };
+            if indptr.len() != compressed + 1 {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "expected {} indptr values, got {}",
+                    compressed + 1,
+                    indptr.len()
+                )));
+            }
+            check_indptr(indptr, non_zero_length)?;
+            if indices.len() != non_zero_length {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "expected {non_zero_length} indices, got {}",
+                    indices.len()
+                )));
+            }
+            for c in indices.iter() {
+                check_coordinate(*c, other)?;
+            }
+        }
+        SparseTensorIndex::Csf {
+            indptr,
+            indices,
+            axis_order,
+        } => {
+            let ndim = shape.len();
+            let mut sorted = axis_order.clone();
+            sorted.sort_unstable();
+            if ndim == 0 || !sorted.iter().copied().eq(0..ndim) {
+                return Err(ArrowError::InvalidArgumentError(
+                    "CSF axis order must be a permutation of the dimensions".to_string(),
+                ));
+            }
+            if indices.len() != ndim || indptr.len() != ndim - 1 {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "expected {ndim} CSF indices and {} indptr buffers, got {} and {}",
+                    ndim - 1,
+                    indices.len(),
+                    indptr.len()
+                )));
+            }
+            for (level, (idx, axis)) in indices.iter().zip(axis_order).enumerate() {
+                for c in idx.iter() {
+                    check_coordinate(*c, shape[*axis])?;
+                }
+                if let Some(ptr) = indptr.get(level) {
+                    if ptr.len() != idx.len() + 1 {
+                        return Err(ArrowError::InvalidArgumentError(format!(
+                            "expected {} indptr values for CSF level {level}, got {}",
+                            idx.len() + 1,
+                            ptr.len()
+                        )));
+                    }
+                    check_indptr(ptr, indices[level + 1].len())?;
+                }
+            }
+            if indices[ndim - 1].len() != non_zero_length {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "expected {non_zero_length} indices in the last CSF level, got {}",
+                    indices[ndim - 1].len()
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks `indptr` starts at 0, is non-decreasing, and ends at `end`
+fn check_indptr(indptr: &[i64], end: usize) -> Result<()> {
+    let valid = indptr.first() == Some(&0)
+        && indptr.windows(2).all(|w| w[0] <= w[1])
+        && indptr.last().map(|l| *l as usize) == Some(end);
+    match valid {
+        true => Ok(()),
+        false => Err(ArrowError::InvalidArgumentError(format!(
+            "indptr must be non-decreasing from 0 to {end}"
+        ))),
+    }
+}
+
+fn check_coordinate(c: i64, dim: usize) -> Result<()> {
+    match usize::try_from(c) {
+        Ok(c) if c < dim => Ok(()),
+        _ => Err(ArrowError::InvalidArgumentError(format!(
+            "index {c} out of bounds for dimension of size {dim}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indices(v: &[i64]) -> ScalarBuffer<i64> {
+        ScalarBuffer::from(v.to_vec())
+    }
+
+    #[test]
+    fn test_coo() {
+        let index = SparseTensorIndex::Coo {
+            indices: indices(&[0, 1, 1, 0, 2, 2]),
+            is_canonical: true,
+        };
+        let buf = Buffer::from_slice_ref([1.0_f64, 2.0, 3.0]);
+        let tensor =
+            SparseTensor::<Float64Type>::try_new(buf, vec![3, 3], index, Some(vec!["x", "y"]))
+                .unwrap();
+        assert_eq!(tensor.non_zero_length(), 3);
+        assert_eq!(tensor.size(), 9);
+        assert_eq!(tensor.ndim(), 2);
+        assert_eq!(tensor.dim_name(1), Some("y"));
+        assert_eq!(tensor.data_type(), &DataType::Float64);
+
+        let index = SparseTensorIndex::Coo {
+            indices: indices(&[1, 0, 0, 1]),
+            is_canonical: true,
+        };
+        let buf = Buffer::from_slice_ref([1_i32, 2]);
+        let err = SparseTensor::<Int32Type>::try_new(buf, vec![2, 2], index, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: canonical COO indices must be sorted and unique"
+        );
+
+        let index = SparseTensorIndex::Coo {
+            indices: indices(&[0, 2]),
+            is_canonical: false,
+        };
+        let buf = Buffer::from_slice_ref([1_i32]);
+        let err = SparseTensor::<Int32Type>::try_new(buf, vec![2, 2], index, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: index 2 out of bounds for dimension of size 2"
+        );
+    }
+
+    #[test]
+    fn test_csr_csc() {
+        // [[1, 0, 2], [0, 0, 3]]
+        let index = SparseTensorIndex::Csr {
+            indptr: indices(&[0, 2, 3]),
+            indices: indices(&[0, 2, 2]),
+        };
+        let buf = Buffer::from_slice_ref([1_i64, 2, 3]);
+        let tensor = SparseTensor::<Int64Type>::try_new(buf, vec![2, 3], index, None).unwrap();
+        assert_eq!(tensor.non_zero_length(), 3);
+
+        let index = SparseTensorIndex::Csc {
+            indptr: indices(&[0, 1, 1, 3]),
+            indices: indices(&[0, 0, 1]),
+        };
+        let buf = Buffer::from_slice_ref([1_i64, 2, 3]);
+        SparseTensor::<Int64Type>::try_new(buf, vec![2, 3], index, None).unwrap();
+
+        let index = SparseTensorIndex::Csr {
+            indptr: indices(&[0, 2, 1]),
+            indices: indices(&[0, 2]),
+        };
+        let buf = Buffer::from_slice_ref([1_i64, 2]);
+        let err = SparseTensor::<Int64Type>::try_new(buf, vec![2, 3], index, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: indptr must be non-decreasing from 0 to 2"
+        );
+
+        let index = SparseTensorIndex::Csr {
+            indptr: indices(&[0, 1]),
+            indices: indices(&[0]),
+        };
+        let buf = Buffer::from_slice_ref([1_i64]);
+        let err = SparseTensor::<Int64Type>::try_new(buf, vec![1, 1, 1], index, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: CSR and CSC indices require a matrix, got 3 dimensions"
+        );
+    }
+
+    #[test]
+    fn test_csf() {
+        // Non-zero values at (0, 0, 1), (0, 2, 0), (1, 1, 1)
+        let index = SparseTensorIndex::Csf {
+            indptr: vec![indices(&[0, 2, 3]), indices(&[0, 1, 2, 3])],
+            indices: vec![indices(&[0, 1]), indices(&[0, 2, 1]), indices(&[1, 0, 1])],
+            axis_order: vec![0, 1, 2],
+        };
+        let buf = Buffer::from_slice_ref([1_u8, 2, 3]);
+        let tensor = SparseTensor::<UInt8Type>::try_new(buf, vec![2, 3, 2], index, None).unwrap();
+        assert_eq!(tensor.non_zero_length(), 3);
+        assert_eq!(tensor.size(), 12);
+
+        let index = SparseTensorIndex::Csf {
+            indptr: vec![indices(&[0, 1])],
+            indices: vec![indices(&[0]), indices(&[0])],
+            axis_order: vec![0, 0],
+        };
+        let buf = Buffer::from_slice_ref([1_u8]);
+        let err = SparseTensor::<UInt8Type>::try_new(buf, vec![1, 1], index, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: CSF axis order must be a permutation of the dimensions"
+        );
+    }
+}
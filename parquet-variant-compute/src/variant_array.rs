@@ -19,11 +19,74 @@
 
 use arrow::array::{Array, ArrayData, ArrayRef, AsArray, StructArray};
 use arrow::buffer::NullBuffer;
-use arrow_schema::{ArrowError, DataType};
+use arrow_schema::extension::ExtensionType;
+use arrow_schema::{ArrowError, DataType, Field};
 use parquet_variant::Variant;
 use std::any::Any;
 use std::sync::Arc;
 
+/// The extension type for [`VariantArray`]
+///
+/// Extension name: `arrow.parquet.variant`.
+///
+/// The storage type of this extension is a `Struct` with the `metadata`, and
+/// optionally `value` and `typed_value`, fields described in [`VariantArray`].
+///
+/// This type does not have any parameters, and its metadata is an empty string.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct VariantType;
+
+impl ExtensionType for VariantType {
+    const NAME: &'static str = "arrow.parquet.variant";
+
+    type Metadata = &'static str;
+
+    fn metadata(&self) -> &Self::Metadata {
+        &""
+    }
+
+    fn serialize_metadata(&self) -> Option<String> {
+        Some(String::default())
+    }
+
+    fn deserialize_metadata(_metadata: Option<&str>) -> Result<Self::Metadata, ArrowError> {
+        Ok("")
+    }
+
+    fn supports_data_type(&self, data_type: &DataType) -> Result<(), ArrowError> {
+        let is_binary = |f: &Field| {
+            matches!(
+                f.data_type(),
+                DataType::Binary | DataType::LargeBinary | DataType::BinaryView
+            )
+        };
+        let valid = match data_type {
+            DataType::Struct(fields) => {
+                let metadata = fields.find("metadata").map(|(_, f)| f);
+                let value = fields.find("value").map(|(_, f)| f);
+                let typed_value = fields.find("typed_value");
+                metadata.is_some_and(|f| is_binary(f))
+                    && value.is_none_or(|f| is_binary(f))
+                    && (value.is_some() || typed_value.is_some())
+                    && fields
+                        .iter()
+                        .all(|f| matches!(f.name().as_str(), "metadata" | "value" | "typed_value"))
+            }
+            _ => false,
+        };
+        match valid {
+            true => Ok(()),
+            false => Err(ArrowError::InvalidArgumentError(format!(
+                "VariantType data type mismatch, expected Struct with a binary 'metadata' field, and a binary 'value' and/or a 'typed_value' field, found {data_type}"
+            ))),
+        }
+    }
+
+    fn try_new(data_type: &DataType, _metadata: Self::Metadata) -> Result<Self, ArrowError> {
+        Self.supports_data_type(data_type).map(|_| Self)
+    }
+}
+
 /// An array of Parquet [`Variant`] values
 ///
 /// A [`VariantArray`] wraps an Arrow [`StructArray`] that stores the underlying
@@ -126,6 +189,14 @@ impl VariantArray {
         self.inner
     }
 
+    /// Returns a nullable [`Field`] named `name` for this array, tagged with
+    /// the [`VariantType`] extension type
+    ///
+    /// This is the field with which the `parquet` crate writes a `VARIANT` column
+    pub fn field(&self, name: impl Into<String>) -> Field {
+        Field::new(name, self.data_type().clone(), true).with_extension_type(VariantType)
+    }
+
     /// Return the [`Variant`] instance stored at the given row
     ///
     /// Panics if the index is out of bounds.
@@ -203,7 +274,65 @@ impl Array for VariantArray {
 mod test {
     use super::*;
     use arrow::array::{BinaryArray, BinaryViewArray};
-    use arrow_schema::{Field, Fields};
+    use arrow_schema::Fields;
+
+    #[test]
+    fn variant_type() {
+        let array = VariantArray::try_new(Arc::new(StructArray::new(
+            Fields::from(vec![
+                Field::new("metadata", DataType::BinaryView, false),
+                Field::new("value", DataType::BinaryView, true),
+            ]),
+            vec![make_binary_view_array(), make_binary_view_array()],
+            None,
+        )))
+        .unwrap();
+        let field = array.field("v");
+        assert_eq!(field.name(), "v");
+        assert!(field.is_nullable());
+        assert_eq!(field.extension_type_name(), Some(VariantType::NAME));
+        field.try_extension_type::<VariantType>().unwrap();
+
+        let mut field = Field::new("v", DataType::BinaryView, true);
+        let err = field.try_with_extension_type(VariantType).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: VariantType data type mismatch, expected Struct with a binary 'metadata' field, and a binary 'value' and/or a 'typed_value' field, found BinaryView"
+        );
+
+        let unsupported = [
+            // Missing both value and typed_value
+            vec![Field::new("metadata", DataType::BinaryView, false)],
+            // Non-binary metadata
+            vec![
+                Field::new("metadata", DataType::Utf8, false),
+                Field::new("value", DataType::BinaryView, true),
+            ],
+            // Non-binary value
+            vec![
+                Field::new("metadata", DataType::Binary, false),
+                Field::new("value", DataType::Int64, true),
+            ],
+            // Unknown field
+            vec![
+                Field::new("metadata", DataType::Binary, false),
+                Field::new("value", DataType::Binary, true),
+                Field::new("other", DataType::Int64, true),
+            ],
+        ];
+        for fields in unsupported {
+            let data_type = DataType::Struct(fields.into());
+            VariantType.supports_data_type(&data_type).unwrap_err();
+        }
+
+        let shredded = Fields::from(vec![
+            Field::new("metadata", DataType::LargeBinary, false),
+            Field::new("typed_value", DataType::Int64, true),
+        ]);
+        VariantType
+            .supports_data_type(&DataType::Struct(shredded))
+            .unwrap();
+    }
 
     #[test]
     fn invalid_not_a_struct_array() {
@@ -15,19 +15,21 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::ops::Range;
 use std::sync::Arc;
 
-use arrow_schema::{DataType, Fields, SchemaBuilder};
+use arrow_schema::{DataType, FieldRef, Fields};
 
 use crate::arrow::array_reader::byte_view_array::make_byte_view_array_reader;
 use crate::arrow::array_reader::empty_array::make_empty_array_reader;
 use crate::arrow::array_reader::fixed_len_byte_array::make_fixed_len_byte_array_reader;
 use crate::arrow::array_reader::{
-    make_byte_array_dictionary_reader, make_byte_array_reader, ArrayReader,
-    FixedSizeListArrayReader, ListArrayReader, MapArrayReader, NullArrayReader,
-    PrimitiveArrayReader, RowGroups, StructArrayReader,
+    check_row_number_field, make_byte_array_dictionary_reader, make_byte_array_reader,
+    make_primitive_dictionary_reader, ArrayReader, FixedSizeListArrayReader, ListArrayReader,
+    MapArrayReader, MissingFieldsArrayReader, NullArrayReader, PrimitiveArrayReader, RowGroups,
+    RowNumberReader, StructArrayReader, UnionArrayReader,
 };
-use crate::arrow::schema::{ParquetField, ParquetFieldType};
+use crate::arrow::schema::{union_struct_fields, ParquetField, ParquetFieldType};
 use crate::arrow::ProjectionMask;
 use crate::basic::Type as PhysicalType;
 use crate::data_type::{BoolType, DoubleType, FloatType, Int32Type, Int64Type, Int96Type};
@@ -37,11 +39,25 @@ use crate::schema::types::{ColumnDescriptor, ColumnPath, Type};
 /// Builds [`ArrayReader`]s from parquet schema, projection mask, and RowGroups reader
 pub struct ArrayReaderBuilder<'a> {
     row_groups: &'a dyn RowGroups,
+    /// The row number column to append, and the file positions of the rows in `row_groups`
+    row_numbers: Option<(FieldRef, Vec<Range<i64>>)>,
 }
 
 impl<'a> ArrayReaderBuilder<'a> {
     pub fn new(row_groups: &'a dyn RowGroups) -> Self {
-        Self { row_groups }
+        Self {
+            row_groups,
+            row_numbers: None,
+        }
+    }
+
+    /// Append a column `field` containing the position within the file of each row,
+    /// where `ranges` are the positions of the rows of each row group in `row_groups`
+    pub fn with_row_numbers(self, field: FieldRef, ranges: Vec<Range<i64>>) -> Self {
+        Self {
+            row_numbers: Some((field, ranges)),
+            ..self
+        }
     }
 
     /// Create [`ArrayReader`] from parquet schema, projection mask, and parquet file reader.
@@ -50,12 +66,27 @@ impl<'a> ArrayReaderBuilder<'a> {
         field: Option<&ParquetField>,
         mask: &ProjectionMask,
     ) -> Result<Box<dyn ArrayReader>> {
-        let reader = field
-            .and_then(|field| self.build_reader(field, mask).transpose())
-            .transpose()?
-            .unwrap_or_else(|| make_empty_array_reader(self.num_rows()));
+        let mut children = match field {
+            Some(field) => self.build_struct_children(field, mask)?,
+            None => StructChildren::default(),
+        };
 
-        Ok(reader)
+        if let Some((row_number_field, ranges)) = &self.row_numbers {
+            check_row_number_field(row_number_field, &children.fields)?;
+            let row_numbers =
+                RowNumberReader::new(row_number_field.data_type().clone(), ranges.iter().cloned());
+            children.push(row_number_field.clone(), Some(Box::new(row_numbers)));
+        }
+
+        if children.readers.is_empty() {
+            let reader = make_empty_array_reader(self.num_rows());
+            return Ok(children.with_struct_reader(reader));
+        }
+
+        let (def_level, rep_level, nullable) = field
+            .map(|f| (f.def_level, f.rep_level, f.nullable))
+            .unwrap_or_default();
+        Ok(children.into_struct_reader(def_level, rep_level, nullable))
     }
 
     /// Return the total number of rows
@@ -70,9 +101,13 @@ impl<'a> ArrayReaderBuilder<'a> {
     ) -> Result<Option<Box<dyn ArrayReader>>> {
         match field.field_type {
             ParquetFieldType::Primitive { .. } => self.build_primitive_reader(field, mask),
+            ParquetFieldType::Missing => {
+                unreachable!("missing fields are read by their parent struct")
+            }
             ParquetFieldType::Group { .. } => match &field.arrow_type {
                 DataType::Map(_, _) => self.build_map_reader(field, mask),
                 DataType::Struct(_) => self.build_struct_reader(field, mask),
+                DataType::Union(_, _) => self.build_union_reader(field, mask),
                 DataType::List(_) => self.build_list_reader(field, mask, false),
                 DataType::LargeList(_) => self.build_list_reader(field, mask, true),
                 DataType::FixedSizeList(_, _) => self.build_fixed_size_list_reader(field, mask),
@@ -261,40 +296,55 @@ impl<'a> ArrayReaderBuilder<'a> {
                 column_desc,
                 arrow_type,
             )?) as _,
-            PhysicalType::INT32 => {
-                if let Some(DataType::Null) = arrow_type {
-                    Box::new(NullArrayReader::<Int32Type>::new(
-                        page_iterator,
-                        column_desc,
-                    )?) as _
-                } else {
-                    Box::new(PrimitiveArrayReader::<Int32Type>::new(
-                        page_iterator,
-                        column_desc,
-                        arrow_type,
-                    )?) as _
+            PhysicalType::INT32 => match arrow_type {
+                Some(DataType::Null) => Box::new(NullArrayReader::<Int32Type>::new(
+                    page_iterator,
+                    column_desc,
+                )?) as _,
+                Some(DataType::Dictionary(_, _)) => {
+                    make_primitive_dictionary_reader(page_iterator, column_desc, arrow_type)?
                 }
-            }
-            PhysicalType::INT64 => Box::new(PrimitiveArrayReader::<Int64Type>::new(
-                page_iterator,
-                column_desc,
-                arrow_type,
-            )?) as _,
+                _ => Box::new(PrimitiveArrayReader::<Int32Type>::new(
+                    page_iterator,
+                    column_desc,
+                    arrow_type,
+                )?) as _,
+            },
+            PhysicalType::INT64 => match arrow_type {
+                Some(DataType::Dictionary(_, _)) => {
+                    make_primitive_dictionary_reader(page_iterator, column_desc, arrow_type)?
+                }
+                _ => Box::new(PrimitiveArrayReader::<Int64Type>::new(
+                    page_iterator,
+                    column_desc,
+                    arrow_type,
+                )?) as _,
+            },
             PhysicalType::INT96 => Box::new(PrimitiveArrayReader::<Int96Type>::new(
                 page_iterator,
                 column_desc,
                 arrow_type,
             )?) as _,
-            PhysicalType::FLOAT => Box::new(PrimitiveArrayReader::<FloatType>::new(
-                page_iterator,
-                column_desc,
-                arrow_type,
-            )?) as _,
-            PhysicalType::DOUBLE => Box::new(PrimitiveArrayReader::<DoubleType>::new(
-                page_iterator,
-                column_desc,
-                arrow_type,
-            )?) as _,
+            PhysicalType::FLOAT => match arrow_type {
+                Some(DataType::Dictionary(_, _)) => {
+                    make_primitive_dictionary_reader(page_iterator, column_desc, arrow_type)?
+                }
+                _ => Box::new(PrimitiveArrayReader::<FloatType>::new(
+                    page_iterator,
+                    column_desc,
+                    arrow_type,
+                )?) as _,
+            },
+            PhysicalType::DOUBLE => match arrow_type {
+                Some(DataType::Dictionary(_, _)) => {
+                    make_primitive_dictionary_reader(page_iterator, column_desc, arrow_type)?
+                }
+                _ => Box::new(PrimitiveArrayReader::<DoubleType>::new(
+                    page_iterator,
+                    column_desc,
+                    arrow_type,
+                )?) as _,
+            },
             PhysicalType::BYTE_ARRAY => match arrow_type {
                 Some(DataType::Dictionary(_, _)) => {
                     make_byte_array_dictionary_reader(page_iterator, column_desc, arrow_type)?
@@ -319,6 +369,67 @@ impl<'a> ArrayReaderBuilder<'a> {
         field: &ParquetField,
         mask: &ProjectionMask,
     ) -> Result<Option<Box<dyn ArrayReader>>> {
+        let children = self.build_struct_children(field, mask)?;
+        if children.readers.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(children.into_struct_reader(
+            field.def_level,
+            field.rep_level,
+            field.nullable,
+        )))
+    }
+
+    /// Build array reader for union type, which is stored as a struct.
+    ///
+    /// Returns an error if only some children of the union are projected
+    fn build_union_reader(
+        &self,
+        field: &ParquetField,
+        mask: &ProjectionMask,
+    ) -> Result<Option<Box<dyn ArrayReader>>> {
+        let (union_fields, mode) = match &field.arrow_type {
+            DataType::Union(fields, mode) => (fields, *mode),
+            _ => unreachable!(),
+        };
+        let struct_field = ParquetField {
+            arrow_type: DataType::Struct(union_struct_fields(union_fields)),
+            ..field.clone()
+        };
+        let Some(reader) = self.build_struct_reader(&struct_field, mask)? else {
+            return Ok(None);
+        };
+
+        let fields = match reader.get_data_type() {
+            DataType::Struct(fields) if fields.len() == union_fields.len() + 1 => fields,
+            _ => {
+                return Err(general_err!(
+                    "partial projection of UnionArray is not supported"
+                ))
+            }
+        };
+        // Need to retrieve underlying data types to handle projection
+        let union_fields = union_fields
+            .iter()
+            .zip(fields.iter().skip(1))
+            .map(|((id, f), c)| {
+                (
+                    id,
+                    Arc::new(f.as_ref().clone().with_data_type(c.data_type().clone())),
+                )
+            })
+            .collect();
+        let data_type = DataType::Union(union_fields, mode);
+        Ok(Some(Box::new(UnionArrayReader::new(reader, data_type))))
+    }
+
+    /// Build the readers, and their projected fields, for the children of struct `field`
+    fn build_struct_children(
+        &self,
+        field: &ParquetField,
+        mask: &ProjectionMask,
+    ) -> Result<StructChildren> {
         let arrow_fields = match &field.arrow_type {
             DataType::Struct(children) => children,
             _ => unreachable!(),
@@ -326,29 +437,83 @@ impl<'a> ArrayReaderBuilder<'a> {
         let children = field.children().unwrap();
         assert_eq!(arrow_fields.len(), children.len());
 
-        let mut readers = Vec::with_capacity(children.len());
-        let mut builder = SchemaBuilder::with_capacity(children.len());
-
+        let mut struct_children = StructChildren::default();
         for (arrow, parquet) in arrow_fields.iter().zip(children) {
-            if let Some(reader) = self.build_reader(parquet, mask)? {
+            if let ParquetFieldType::Missing = parquet.field_type {
+                struct_children.push(arrow.clone(), None);
+            } else if let Some(reader) = self.build_reader(parquet, mask)? {
                 // Need to retrieve underlying data type to handle projection
                 let child_type = reader.get_data_type().clone();
-                builder.push(arrow.as_ref().clone().with_data_type(child_type));
-                readers.push(reader);
+                let field = arrow.as_ref().clone().with_data_type(child_type);
+                struct_children.push(Arc::new(field), Some(reader));
             }
         }
 
-        if readers.is_empty() {
-            return Ok(None);
+        Ok(struct_children)
+    }
+}
+
+/// The projected children of a struct
+#[derive(Default)]
+struct StructChildren {
+    /// The readers of the children present in the parquet file
+    readers: Vec<Box<dyn ArrayReader>>,
+    /// The fields of the children, including those missing from the parquet file
+    fields: Vec<FieldRef>,
+    /// For each of `fields`, the index of its reader in `readers`, or `None` if
+    /// it is missing from the parquet file
+    columns: Vec<Option<usize>>,
+}
+
+impl StructChildren {
+    /// Append a child `field`, read by `reader` or missing from the parquet file
+    fn push(&mut self, field: FieldRef, reader: Option<Box<dyn ArrayReader>>) {
+        match reader {
+            Some(reader) => {
+                self.columns.push(Some(self.readers.len()));
+                self.readers.push(reader);
+            }
+            None => self.columns.push(None),
         }
+        self.fields.push(field);
+    }
 
-        Ok(Some(Box::new(StructArrayReader::new(
-            DataType::Struct(builder.finish().fields),
-            readers,
-            field.def_level,
-            field.rep_level,
-            field.nullable,
-        ))))
+    /// Build a [`StructArrayReader`] of the children
+    fn into_struct_reader(
+        mut self,
+        def_level: i16,
+        rep_level: i16,
+        nullable: bool,
+    ) -> Box<dyn ArrayReader> {
+        let fields = self
+            .fields
+            .iter()
+            .zip(&self.columns)
+            .filter(|(_, column)| column.is_some())
+            .map(|(field, _)| field.clone())
+            .collect();
+        let reader = StructArrayReader::new(
+            DataType::Struct(fields),
+            std::mem::take(&mut self.readers),
+            def_level,
+            rep_level,
+            nullable,
+        );
+        self.with_struct_reader(Box::new(reader))
+    }
+
+    /// Returns a reader of the children, where `struct_reader` reads those present
+    /// in the parquet file
+    fn with_struct_reader(self, struct_reader: Box<dyn ArrayReader>) -> Box<dyn ArrayReader> {
+        if self.columns.iter().all(Option::is_some) {
+            return struct_reader;
+        }
+        let data_type = DataType::Struct(self.fields.into());
+        Box::new(MissingFieldsArrayReader::new(
+            struct_reader,
+            data_type,
+            self.columns,
+        ))
     }
 }
 
@@ -372,6 +537,7 @@ mod tests {
             file_metadata.schema_descr(),
             ProjectionMask::all(),
             file_metadata.key_value_metadata(),
+            None,
         )
         .unwrap();
 
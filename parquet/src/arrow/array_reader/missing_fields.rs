@@ -0,0 +1,154 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::arrow::array_reader::ArrayReader;
+use crate::errors::Result;
+use arrow_array::cast::AsArray;
+use arrow_array::{new_null_array, Array, ArrayRef, StructArray};
+use arrow_schema::DataType as ArrowType;
+use std::any::Any;
+use std::sync::Arc;
+
+/// An [`ArrayReader`] for a struct with fields that are not present in the parquet
+/// file, which are read as nulls
+///
+/// The fields present in the file are read with `struct_reader`, and the fields
+/// missing from it are inserted between them.
+pub struct MissingFieldsArrayReader {
+    struct_reader: Box<dyn ArrayReader>,
+    data_type: ArrowType,
+    /// For each field of `data_type`, the index of its column in the structs read
+    /// by `struct_reader`, or `None` if it is missing from the file
+    columns: Vec<Option<usize>>,
+}
+
+impl MissingFieldsArrayReader {
+    /// Construct a reader of `data_type`, which must be a struct, from a reader of
+    /// the struct of the fields present in the file
+    pub fn new(
+        struct_reader: Box<dyn ArrayReader>,
+        data_type: ArrowType,
+        columns: Vec<Option<usize>>,
+    ) -> Self {
+        Self {
+            struct_reader,
+            data_type,
+            columns,
+        }
+    }
+}
+
+impl ArrayReader for MissingFieldsArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        self.struct_reader.read_records(batch_size)
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        let array = self.struct_reader.consume_batch()?;
+        let array = array.as_struct();
+        let fields = match &self.data_type {
+            ArrowType::Struct(fields) => fields,
+            _ => unreachable!(),
+        };
+
+        let columns = fields
+            .iter()
+            .zip(&self.columns)
+            .map(|(field, column)| match column {
+                Some(idx) => array.column(*idx).clone(),
+                None => new_null_array(field.data_type(), array.len()),
+            })
+            .collect();
+        let array = StructArray::try_new(fields.clone(), columns, array.nulls().cloned())?;
+        Ok(Arc::new(array))
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        self.struct_reader.skip_records(num_records)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.struct_reader.get_def_levels()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.struct_reader.get_rep_levels()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::array_reader::test_util::InMemoryArrayReader;
+    use arrow_array::{Int32Array, StringArray};
+    use arrow_buffer::NullBuffer;
+    use arrow_schema::{Field, Fields};
+
+    #[test]
+    fn test_missing_fields_array_reader() {
+        let struct_fields = Fields::from(vec![
+            Field::new("a", ArrowType::Int32, false),
+            Field::new("c", ArrowType::Int32, true),
+        ]);
+        let array = StructArray::new(
+            struct_fields.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(Int32Array::from(vec![Some(4), None, Some(6)])),
+            ],
+            Some(NullBuffer::from(vec![true, true, false])),
+        );
+        let struct_reader = InMemoryArrayReader::new(
+            ArrowType::Struct(struct_fields),
+            Arc::new(array),
+            None,
+            None,
+        );
+
+        let fields = Fields::from(vec![
+            Field::new("b", ArrowType::Utf8, true),
+            Field::new("c", ArrowType::Int32, true),
+            Field::new("a", ArrowType::Int32, false),
+        ]);
+        let data_type = ArrowType::Struct(fields.clone());
+        let columns = vec![None, Some(1), Some(0)];
+        let mut reader =
+            MissingFieldsArrayReader::new(Box::new(struct_reader), data_type.clone(), columns);
+
+        let array = reader.next_batch(3).unwrap();
+        assert_eq!(array.data_type(), &data_type);
+        let array = array.as_struct();
+        let expected = StructArray::new(
+            fields,
+            vec![
+                Arc::new(StringArray::new_null(3)),
+                Arc::new(Int32Array::from(vec![Some(4), None, Some(6)])),
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+            ],
+            Some(NullBuffer::from(vec![true, true, false])),
+        );
+        assert_eq!(array, &expected);
+    }
+}
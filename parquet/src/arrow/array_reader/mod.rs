@@ -38,9 +38,13 @@ mod fixed_len_byte_array;
 mod fixed_size_list_array;
 mod list_array;
 mod map_array;
+mod missing_fields;
 mod null_array;
 mod primitive_array;
+mod primitive_dictionary;
+mod row_number;
 mod struct_array;
+mod union_array;
 
 #[cfg(test)]
 mod test_util;
@@ -56,9 +60,14 @@ pub use fixed_len_byte_array::make_fixed_len_byte_array_reader;
 pub use fixed_size_list_array::FixedSizeListArrayReader;
 pub use list_array::ListArrayReader;
 pub use map_array::MapArrayReader;
+pub use missing_fields::MissingFieldsArrayReader;
 pub use null_array::NullArrayReader;
 pub use primitive_array::PrimitiveArrayReader;
+pub use primitive_dictionary::make_primitive_dictionary_reader;
+pub use row_number::RowNumberReader;
+pub(crate) use row_number::{check_row_number_field, row_group_ranges};
 pub use struct_array::StructArrayReader;
+pub use union_array::UnionArrayReader;
 
 /// Array reader reads parquet data into arrow array.
 pub trait ArrayReader: Send {
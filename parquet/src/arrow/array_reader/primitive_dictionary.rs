@@ -0,0 +1,711 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use arrow_array::{make_array, new_empty_array, ArrayRef};
+use arrow_buffer::{ArrowNativeType, Buffer};
+use arrow_data::ArrayDataBuilder;
+use arrow_schema::DataType as ArrowType;
+use bytes::Bytes;
+
+use crate::arrow::array_reader::primitive_array::{coerce_primitive_array, IntoBuffer};
+use crate::arrow::array_reader::{read_records, skip_records, ArrayReader};
+use crate::arrow::record_reader::buffer::ValuesBuffer;
+use crate::arrow::record_reader::GenericRecordReader;
+use crate::arrow::schema::parquet_to_arrow_field;
+use crate::basic::{Encoding, Type as PhysicalType};
+use crate::column::page::PageIterator;
+use crate::column::reader::decoder::{ColumnValueDecoder, ColumnValueDecoderImpl};
+use crate::data_type::{DataType, DoubleType, FloatType, Int32Type, Int64Type};
+use crate::encodings::decoding::{Decoder, PlainDecoder};
+use crate::encodings::rle::RleDecoder;
+use crate::errors::{ParquetError, Result};
+use crate::schema::types::ColumnDescPtr;
+use crate::util::bit_util::FromBytes;
+
+/// Returns an [`ArrayReader`] that decodes the provided `INT32`, `INT64`, `FLOAT` or
+/// `DOUBLE` column into a dictionary array
+///
+/// This will attempt to preserve any dictionary encoding present in the parquet data,
+/// using the values of the dictionary page as the dictionary of the returned arrays
+///
+/// It will be unable to preserve the dictionary encoding if:
+///
+/// * A single read spans across multiple column chunks
+/// * A column chunk contains non-dictionary encoded pages
+/// * The dictionary page contains more values than can be indexed by the key type
+///
+/// In these cases the values are decoded, and a new dictionary computed for the batch
+pub fn make_primitive_dictionary_reader(
+    pages: Box<dyn PageIterator>,
+    column_desc: ColumnDescPtr,
+    arrow_type: Option<ArrowType>,
+) -> Result<Box<dyn ArrayReader>> {
+    // Check if Arrow type is specified, else create it from Parquet type
+    let data_type = match arrow_type {
+        Some(t) => t,
+        None => parquet_to_arrow_field(column_desc.as_ref())?
+            .data_type()
+            .clone(),
+    };
+
+    if !matches!(data_type, ArrowType::Dictionary(_, _)) {
+        return Err(general_err!(
+            "invalid non-dictionary data type for primitive dictionary reader - {}",
+            data_type
+        ));
+    }
+
+    match column_desc.physical_type() {
+        PhysicalType::INT32 => make_reader::<Int32Type>(pages, column_desc, data_type),
+        PhysicalType::INT64 => make_reader::<Int64Type>(pages, column_desc, data_type),
+        PhysicalType::FLOAT => make_reader::<FloatType>(pages, column_desc, data_type),
+        PhysicalType::DOUBLE => make_reader::<DoubleType>(pages, column_desc, data_type),
+        physical_type => Err(general_err!(
+            "unsupported physical type for primitive dictionary reader - {}",
+            physical_type
+        )),
+    }
+}
+
+fn make_reader<T>(
+    pages: Box<dyn PageIterator>,
+    column_desc: ColumnDescPtr,
+    data_type: ArrowType,
+) -> Result<Box<dyn ArrayReader>>
+where
+    T: DataType,
+    T::T: Copy + Default + Sync,
+    Vec<T::T>: IntoBuffer,
+{
+    macro_rules! reader {
+        ($key_type:ty) => {
+            Ok(Box::new(PrimitiveDictionaryReader::<$key_type, T>::new(
+                pages,
+                data_type,
+                GenericRecordReader::new(column_desc),
+            )))
+        };
+    }
+
+    let ArrowType::Dictionary(key_type, _) = &data_type else {
+        unreachable!()
+    };
+    match key_type.as_ref() {
+        ArrowType::UInt8 => reader!(u8),
+        ArrowType::Int8 => reader!(i8),
+        ArrowType::UInt16 => reader!(u16),
+        ArrowType::Int16 => reader!(i16),
+        ArrowType::UInt32 => reader!(u32),
+        ArrowType::Int32 => reader!(i32),
+        ArrowType::UInt64 => reader!(u64),
+        ArrowType::Int64 => reader!(i64),
+        _ => Err(general_err!(
+            "unsupported data type for primitive dictionary reader - {}",
+            data_type
+        )),
+    }
+}
+
+/// An [`ArrayReader`] for dictionary encoded primitive columns
+///
+/// Will attempt to preserve any dictionary encoding present in the parquet data
+struct PrimitiveDictionaryReader<K: ArrowNativeType, T: DataType> {
+    data_type: ArrowType,
+    pages: Box<dyn PageIterator>,
+    def_levels_buffer: Option<Vec<i16>>,
+    rep_levels_buffer: Option<Vec<i16>>,
+    record_reader:
+        GenericRecordReader<PrimitiveDictionaryBuffer<K, T::T>, PrimitiveDictionaryDecoder<K, T>>,
+    /// The most recent parquet dictionary, and its values converted to the arrow value type
+    dictionary: Option<(Arc<[T::T]>, ArrayRef)>,
+}
+
+impl<K, T> PrimitiveDictionaryReader<K, T>
+where
+    K: FromBytes + Ord + ArrowNativeType,
+    T: DataType,
+    T::T: Copy + Default,
+    Vec<T::T>: IntoBuffer,
+{
+    fn new(
+        pages: Box<dyn PageIterator>,
+        data_type: ArrowType,
+        record_reader: GenericRecordReader<
+            PrimitiveDictionaryBuffer<K, T::T>,
+            PrimitiveDictionaryDecoder<K, T>,
+        >,
+    ) -> Self {
+        Self {
+            data_type,
+            pages,
+            def_levels_buffer: None,
+            rep_levels_buffer: None,
+            record_reader,
+            dictionary: None,
+        }
+    }
+
+    fn value_type(&self) -> &ArrowType {
+        match &self.data_type {
+            ArrowType::Dictionary(_, value_type) => value_type.as_ref(),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns `values` converted to the arrow value type, reusing the previous
+    /// conversion if the dictionary is unchanged
+    fn dictionary_values(&mut self, values: Arc<[T::T]>) -> Result<ArrayRef> {
+        if let Some((cached, array)) = &self.dictionary {
+            if Arc::ptr_eq(cached, &values) {
+                return Ok(Arc::clone(array));
+            }
+        }
+        let array =
+            coerce_primitive_array::<T>(values.to_vec(), values.len(), None, self.value_type())?;
+        self.dictionary = Some((values, Arc::clone(&array)));
+        Ok(array)
+    }
+}
+
+impl<K, T> ArrayReader for PrimitiveDictionaryReader<K, T>
+where
+    K: FromBytes + Ord + ArrowNativeType,
+    T: DataType,
+    T::T: Copy + Default + Sync,
+    Vec<T::T>: IntoBuffer,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        read_records(&mut self.record_reader, self.pages.as_mut(), batch_size)
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        if self.record_reader.num_values() == 0 {
+            return Ok(new_empty_array(&self.data_type));
+        }
+
+        let len = self.record_reader.num_values();
+        let buffer = self.record_reader.consume_record_data();
+        let null_buffer = self.record_reader.consume_bitmap_buffer();
+        let array = match buffer {
+            PrimitiveDictionaryBuffer::Dict { keys, values } => {
+                // Validate keys, as these are not checked when decoded
+                let min = K::from_usize(0).unwrap();
+                let max = K::from_usize(values.len()).unwrap();
+                if !keys
+                    .iter()
+                    .copied()
+                    .fold(true, |a, x| a && x >= min && x < max)
+                {
+                    return Err(general_err!(
+                        "dictionary key beyond bounds of dictionary: 0..{}",
+                        values.len()
+                    ));
+                }
+
+                let values = self.dictionary_values(values)?;
+                let builder = ArrayDataBuilder::new(self.data_type.clone())
+                    .len(keys.len())
+                    .add_buffer(Buffer::from_vec(keys))
+                    .add_child_data(values.to_data())
+                    .null_bit_buffer(null_buffer);
+
+                let data = match cfg!(debug_assertions) {
+                    true => builder.build().unwrap(),
+                    false => unsafe { builder.build_unchecked() },
+                };
+                make_array(data)
+            }
+            PrimitiveDictionaryBuffer::Values { values } => {
+                // This will compute a new dictionary
+                let array =
+                    coerce_primitive_array::<T>(values, len, null_buffer, self.value_type())?;
+                arrow_cast::cast(&array, &self.data_type)?
+            }
+        };
+
+        self.def_levels_buffer = self.record_reader.consume_def_levels();
+        self.rep_levels_buffer = self.record_reader.consume_rep_levels();
+        self.record_reader.reset();
+
+        Ok(array)
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        skip_records(&mut self.record_reader, self.pages.as_mut(), num_records)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.def_levels_buffer.as_deref()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.rep_levels_buffer.as_deref()
+    }
+}
+
+/// Primitive values that are potentially dictionary encoded
+enum PrimitiveDictionaryBuffer<K, V> {
+    Dict { keys: Vec<K>, values: Arc<[V]> },
+    Values { values: Vec<V> },
+}
+
+impl<K, V> Default for PrimitiveDictionaryBuffer<K, V> {
+    fn default() -> Self {
+        Self::Values {
+            values: Default::default(),
+        }
+    }
+}
+
+impl<K: ArrowNativeType, V: Copy + Default> PrimitiveDictionaryBuffer<K, V> {
+    /// Returns a mutable reference to a keys array
+    ///
+    /// Returns None if the dictionary needs to be recomputed
+    fn as_keys(&mut self, dictionary: &Arc<[V]>) -> Option<&mut Vec<K>> {
+        match self {
+            Self::Dict { keys, values } => {
+                if Arc::ptr_eq(values, dictionary) {
+                    Some(keys)
+                } else if keys.is_empty() {
+                    *values = Arc::clone(dictionary);
+                    Some(keys)
+                } else {
+                    None
+                }
+            }
+            Self::Values { values } if values.is_empty() => {
+                *self = Self::Dict {
+                    keys: Default::default(),
+                    values: Arc::clone(dictionary),
+                };
+                match self {
+                    Self::Dict { keys, .. } => Some(keys),
+                    _ => unreachable!(),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to a values array
+    ///
+    /// If this is currently dictionary encoded, this will convert from the
+    /// dictionary encoded representation
+    fn spill_values(&mut self) -> Result<&mut Vec<V>> {
+        if let Self::Dict { keys, values } = self {
+            // Null positions have default keys, which are only valid if the dictionary
+            // is not empty
+            let spilled = match values.is_empty() {
+                true => vec![V::default(); keys.len()],
+                false => lookup_keys(keys, values)?,
+            };
+            *self = Self::Values { values: spilled };
+        }
+        match self {
+            Self::Values { values } => Ok(values),
+            Self::Dict { .. } => unreachable!(),
+        }
+    }
+}
+
+impl<K: ArrowNativeType, V: Copy + Default> ValuesBuffer for PrimitiveDictionaryBuffer<K, V> {
+    fn pad_nulls(
+        &mut self,
+        read_offset: usize,
+        values_read: usize,
+        levels_read: usize,
+        valid_mask: &[u8],
+    ) {
+        match self {
+            Self::Dict { keys, .. } => {
+                keys.resize(read_offset + levels_read, K::default());
+                keys.pad_nulls(read_offset, values_read, levels_read, valid_mask)
+            }
+            Self::Values { values } => {
+                values.pad_nulls(read_offset, values_read, levels_read, valid_mask)
+            }
+        }
+    }
+}
+
+/// Returns the values of `dictionary` at the positions in `keys`
+fn lookup_keys<K: ArrowNativeType, V: Copy>(keys: &[K], dictionary: &[V]) -> Result<Vec<V>> {
+    keys.iter()
+        .map(|key| {
+            dictionary.get(key.as_usize()).copied().ok_or_else(|| {
+                general_err!(
+                    "dictionary key beyond bounds of dictionary: 0..{}",
+                    dictionary.len()
+                )
+            })
+        })
+        .collect()
+}
+
+/// If the data is dictionary encoded decode the key data directly, so that the dictionary
+/// encoding can be preserved. Otherwise fallback to decoding using [`ColumnValueDecoderImpl`]
+/// and compute a fresh dictionary in [`PrimitiveDictionaryReader::consume_batch`]
+enum MaybeDictionaryDecoder {
+    Dict {
+        decoder: RleDecoder,
+        /// This is a maximum as the null count is not always known, e.g. value data from
+        /// a v1 data page
+        max_remaining_values: usize,
+    },
+    Fallback,
+}
+
+/// A [`ColumnValueDecoder`] for dictionary encoded primitive columns
+struct PrimitiveDictionaryDecoder<K, T: DataType> {
+    /// The current dictionary
+    dict: Option<Arc<[T::T]>>,
+
+    /// The decoder of the current page
+    decoder: Option<MaybeDictionaryDecoder>,
+
+    /// Decodes pages that are not dictionary encoded
+    fallback: ColumnValueDecoderImpl<T>,
+
+    type_length: i32,
+
+    phantom: PhantomData<K>,
+}
+
+impl<K, T> ColumnValueDecoder for PrimitiveDictionaryDecoder<K, T>
+where
+    K: FromBytes + Ord + ArrowNativeType,
+    T: DataType,
+    T::T: Copy + Default,
+{
+    type Buffer = PrimitiveDictionaryBuffer<K, T::T>;
+
+    fn new(col: &ColumnDescPtr) -> Self {
+        Self {
+            dict: None,
+            decoder: None,
+            fallback: ColumnValueDecoderImpl::new(col),
+            type_length: col.type_length(),
+            phantom: Default::default(),
+        }
+    }
+
+    fn set_dict(
+        &mut self,
+        buf: Bytes,
+        num_values: u32,
+        encoding: Encoding,
+        _is_sorted: bool,
+    ) -> Result<()> {
+        if !matches!(
+            encoding,
+            Encoding::PLAIN | Encoding::RLE_DICTIONARY | Encoding::PLAIN_DICTIONARY
+        ) {
+            return Err(nyi_err!(
+                "Invalid/Unsupported encoding type for dictionary: {}",
+                encoding
+            ));
+        }
+
+        let len = num_values as usize;
+        let mut decoder = PlainDecoder::<T>::new(self.type_length);
+        decoder.set_data(buf, len)?;
+        let mut values = vec![T::T::default(); len];
+        let read = decoder.get(&mut values)?;
+        if read != len {
+            return Err(general_err!(
+                "expected {} dictionary values, got {}",
+                len,
+                read
+            ));
+        }
+
+        self.dict = Some(values.into());
+        Ok(())
+    }
+
+    fn set_data(
+        &mut self,
+        encoding: Encoding,
+        data: Bytes,
+        num_levels: usize,
+        num_values: Option<usize>,
+    ) -> Result<()> {
+        let decoder = match encoding {
+            Encoding::RLE_DICTIONARY | Encoding::PLAIN_DICTIONARY => {
+                let bit_width = data[0];
+                let mut decoder = RleDecoder::new(bit_width);
+                decoder.set_data(data.slice(1..));
+                MaybeDictionaryDecoder::Dict {
+                    decoder,
+                    max_remaining_values: num_values.unwrap_or(num_levels),
+                }
+            }
+            _ => {
+                self.fallback
+                    .set_data(encoding, data, num_levels, num_values)?;
+                MaybeDictionaryDecoder::Fallback
+            }
+        };
+
+        self.decoder = Some(decoder);
+        Ok(())
+    }
+
+    fn read(&mut self, out: &mut Self::Buffer, num_values: usize) -> Result<usize> {
+        match self.decoder.as_mut().expect("decoder set") {
+            MaybeDictionaryDecoder::Fallback => self.fallback.read(out.spill_values()?, num_values),
+            MaybeDictionaryDecoder::Dict {
+                decoder,
+                max_remaining_values,
+            } => {
+                let len = num_values.min(*max_remaining_values);
+
+                let dict = self
+                    .dict
+                    .as_ref()
+                    .ok_or_else(|| general_err!("missing dictionary page for column"))?;
+
+                if dict.is_empty() {
+                    return Ok(0); // All data must be NULL
+                }
+
+                let keys = match K::from_usize(dict.len()) {
+                    Some(_) => out.as_keys(dict),
+                    None => None,
+                };
+
+                match keys {
+                    Some(keys) => {
+                        // Happy path - can just copy keys
+                        // Keys will be validated on conversion to arrow
+                        let start = keys.len();
+                        keys.resize(start + len, K::default());
+                        let len = decoder.get_batch(&mut keys[start..])?;
+                        keys.truncate(start + len);
+                        *max_remaining_values -= len;
+                        Ok(len)
+                    }
+                    None => {
+                        // Sad path - need to recompute dictionary
+                        //
+                        // This either means we crossed into a new column chunk whilst
+                        // reading this batch, encountered non-dictionary encoded data,
+                        // or the dictionary is too large for the key type
+                        let values = out.spill_values()?;
+                        let mut keys = vec![0_u32; len];
+                        let len = decoder.get_batch(&mut keys)?;
+                        values.extend(lookup_keys(&keys[..len], dict)?);
+                        *max_remaining_values -= len;
+                        Ok(len)
+                    }
+                }
+            }
+        }
+    }
+
+    fn skip_values(&mut self, num_values: usize) -> Result<usize> {
+        match self.decoder.as_mut().expect("decoder set") {
+            MaybeDictionaryDecoder::Fallback => self.fallback.skip_values(num_values),
+            MaybeDictionaryDecoder::Dict {
+                decoder,
+                max_remaining_values,
+            } => {
+                let num_values = num_values.min(*max_remaining_values);
+                *max_remaining_values -= num_values;
+                decoder.skip(num_values)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::arrow_reader::{
+        ArrowReaderOptions, ParquetRecordBatchReaderBuilder, RowSelection, RowSelector,
+    };
+    use crate::arrow::ArrowWriter;
+    use crate::file::properties::WriterProperties;
+    use arrow::compute::cast;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int32Type as ArrowInt32, Int8Type as ArrowInt8};
+    use arrow_array::{
+        Array, Decimal128Array, DictionaryArray, Int32Array, Int64Array, RecordBatch,
+    };
+    use arrow_schema::{Field, Schema};
+
+    fn write_batch(batch: &RecordBatch, props: WriterProperties) -> Bytes {
+        let mut buffer = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(props)).unwrap();
+        writer.write(batch).unwrap();
+        writer.close().unwrap();
+        Bytes::from(buffer)
+    }
+
+    fn read_batches(data: Bytes, options: ArrowReaderOptions, batch_size: usize) -> Vec<ArrayRef> {
+        ParquetRecordBatchReaderBuilder::try_new_with_options(data, options)
+            .unwrap()
+            .with_batch_size(batch_size)
+            .build()
+            .unwrap()
+            .map(|batch| batch.unwrap().column(0).clone())
+            .collect()
+    }
+
+    fn concat_values(arrays: &[ArrayRef], value_type: &ArrowType) -> ArrayRef {
+        let arrays: Vec<_> = arrays
+            .iter()
+            .map(|a| cast(a, value_type).unwrap())
+            .collect();
+        let arrays: Vec<_> = arrays.iter().map(|a| a.as_ref()).collect();
+        arrow_select::concat::concat(&arrays).unwrap()
+    }
+
+    #[test]
+    fn test_dictionary_preservation() {
+        let keys = Int32Array::from_iter((0..200).map(|i| (i % 7 != 0).then_some(i % 20)));
+        let values = Int64Array::from_iter_values((0..20).map(|i| i * 1000));
+        let array = DictionaryArray::new(keys, Arc::new(values));
+        let expected = cast(&array, &ArrowType::Int64).unwrap();
+        let batch = RecordBatch::try_from_iter([("a", Arc::new(array) as ArrayRef)]).unwrap();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(100)
+            .set_data_page_row_count_limit(30)
+            .set_write_batch_size(30)
+            .build();
+        let data = write_batch(&batch, props);
+
+        let batches = read_batches(data.clone(), ArrowReaderOptions::new(), 10);
+        assert_eq!(batches.len(), 20);
+        for array in &batches {
+            assert_eq!(array.data_type(), batch.schema().field(0).data_type());
+            // The dictionary of each row group is preserved, rather than recomputed
+            let dictionary = array.as_dictionary::<ArrowInt32>();
+            assert_eq!(dictionary.values().len(), 20);
+        }
+        assert_eq!(&concat_values(&batches, &ArrowType::Int64), &expected);
+
+        // Batches spanning row groups compute a new dictionary
+        let batches = read_batches(data.clone(), ArrowReaderOptions::new(), 150);
+        assert_eq!(batches.len(), 2);
+        assert_eq!(&concat_values(&batches, &ArrowType::Int64), &expected);
+
+        // Skipped records are not decoded
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(45),
+            RowSelector::select(10),
+            RowSelector::skip(100),
+            RowSelector::select(45),
+        ]);
+        let batches: Vec<_> = ParquetRecordBatchReaderBuilder::try_new(data)
+            .unwrap()
+            .with_row_selection(selection)
+            .build()
+            .unwrap()
+            .map(|batch| batch.unwrap().column(0).clone())
+            .collect();
+        let expected_slices = [expected.slice(45, 10), expected.slice(155, 45)];
+        let expected_slices: Vec<_> = expected_slices.iter().map(|a| a.as_ref()).collect();
+        let expected = arrow_select::concat::concat(&expected_slices).unwrap();
+        assert_eq!(&concat_values(&batches, &ArrowType::Int64), &expected);
+    }
+
+    #[test]
+    fn test_dictionary_fallback() {
+        // Plain encoded pages once the dictionary page size limit is reached
+        let values = Int64Array::from_iter((0..1000).map(|i| (i % 11 != 0).then_some(i % 500)));
+        let array = cast(&values, &int64_dictionary()).unwrap();
+        let batch = RecordBatch::try_from_iter([("a", array)]).unwrap();
+        let props = WriterProperties::builder()
+            .set_dictionary_page_size_limit(1024)
+            .set_data_page_row_count_limit(100)
+            .set_write_batch_size(100)
+            .build();
+        let data = write_batch(&batch, props);
+
+        let batches = read_batches(data, ArrowReaderOptions::new(), 64);
+        for array in &batches {
+            assert_eq!(array.data_type(), &int64_dictionary());
+        }
+        let expected = Arc::new(values) as ArrayRef;
+        assert_eq!(&concat_values(&batches, &ArrowType::Int64), &expected);
+    }
+
+    fn int64_dictionary() -> ArrowType {
+        ArrowType::Dictionary(Box::new(ArrowType::UInt16), Box::new(ArrowType::Int64))
+    }
+
+    #[test]
+    fn test_dictionary_too_large_for_key_type() {
+        // The dictionary page contains 300 values, more than can be indexed by Int8
+        let values = Int32Array::from_iter_values((0..300).rev());
+        let batch =
+            RecordBatch::try_from_iter([("a", Arc::new(values.clone()) as ArrayRef)]).unwrap();
+        let data = write_batch(&batch, WriterProperties::default());
+
+        let data_type =
+            ArrowType::Dictionary(Box::new(ArrowType::Int8), Box::new(ArrowType::Int32));
+        let schema = Arc::new(Schema::new(vec![Field::new("a", data_type.clone(), false)]));
+        let options = ArrowReaderOptions::new().with_schema(schema);
+        let batches = read_batches(data, options, 100);
+        assert_eq!(batches.len(), 3);
+        for array in &batches {
+            let dictionary = array.as_dictionary::<ArrowInt8>();
+            assert_eq!(dictionary.values().len(), 100);
+        }
+        let expected = Arc::new(values) as ArrayRef;
+        assert_eq!(&concat_values(&batches, &ArrowType::Int32), &expected);
+    }
+
+    #[test]
+    fn test_dictionary_value_conversion() {
+        let values = Decimal128Array::from_iter((0..100).map(|i| (i % 3 != 0).then_some(i % 4)))
+            .with_precision_and_scale(9, 2)
+            .unwrap();
+        let batch =
+            RecordBatch::try_from_iter([("a", Arc::new(values.clone()) as ArrayRef)]).unwrap();
+        let data = write_batch(&batch, WriterProperties::default());
+
+        let value_type = ArrowType::Decimal128(9, 2);
+        let data_type = ArrowType::Dictionary(Box::new(ArrowType::Int16), Box::new(value_type));
+        let schema = Arc::new(Schema::new(vec![Field::new("a", data_type.clone(), true)]));
+        let options = ArrowReaderOptions::new().with_schema(schema);
+        let batches = read_batches(data, options, 100);
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].data_type(), &data_type);
+        let dictionary = batches[0].as_dictionary::<arrow_array::types::Int16Type>();
+        assert_eq!(dictionary.values().len(), 4);
+        assert_eq!(
+            cast(&batches[0], &ArrowType::Decimal128(9, 2))
+                .unwrap()
+                .as_ref(),
+            &values as &dyn Array
+        );
+    }
+}
@@ -0,0 +1,198 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::arrow::array_reader::ArrayReader;
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::ParquetMetaData;
+use arrow_array::{ArrayRef, Int64Array, UInt64Array};
+use arrow_schema::{DataType as ArrowType, Field, FieldRef};
+use std::any::Any;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// Returns the positions within the file of the rows of each of `row_groups`
+pub(crate) fn row_group_ranges(
+    metadata: &ParquetMetaData,
+    row_groups: &[usize],
+) -> Vec<Range<i64>> {
+    let mut first_row = 0;
+    let starts: Vec<i64> = metadata
+        .row_groups()
+        .iter()
+        .map(|rg| {
+            let start = first_row;
+            first_row += rg.num_rows();
+            start
+        })
+        .collect();
+
+    row_groups
+        .iter()
+        .map(|idx| {
+            let start = starts[*idx];
+            start..start + metadata.row_group(*idx).num_rows()
+        })
+        .collect()
+}
+
+/// Checks that `field` can be appended to `fields` as a row number column
+pub(crate) fn check_row_number_field(field: &Field, fields: &[FieldRef]) -> Result<()> {
+    if !matches!(field.data_type(), ArrowType::Int64 | ArrowType::UInt64) {
+        return Err(general_err!(
+            "row number column must be Int64 or UInt64, got {}",
+            field.data_type()
+        ));
+    }
+    if fields.iter().any(|f| f.name() == field.name()) {
+        return Err(general_err!(
+            "row number column \"{}\" conflicts with a column of the same name",
+            field.name()
+        ));
+    }
+    Ok(())
+}
+
+/// An [`ArrayReader`] that yields the position within the file of each row read
+pub struct RowNumberReader {
+    data_type: ArrowType,
+    /// The positions of the rows remaining to be read or skipped
+    ranges: VecDeque<Range<i64>>,
+    buffer: Vec<i64>,
+}
+
+impl RowNumberReader {
+    /// Create a new [`RowNumberReader`] returning `data_type` values, which
+    /// must be `Int64` or `UInt64`, for rows at the positions in `ranges`
+    pub fn new(data_type: ArrowType, ranges: impl IntoIterator<Item = Range<i64>>) -> Self {
+        Self {
+            data_type,
+            ranges: ranges.into_iter().collect(),
+            buffer: vec![],
+        }
+    }
+
+    /// Advances past up to `num_records` positions, passing each range consumed to `f`
+    fn advance(&mut self, num_records: usize, mut f: impl FnMut(Range<i64>)) -> usize {
+        let mut remaining = num_records as i64;
+        while remaining > 0 {
+            let Some(range) = self.ranges.front_mut() else {
+                break;
+            };
+            let len = remaining.min(range.end - range.start);
+            f(range.start..range.start + len);
+            range.start += len;
+            remaining -= len;
+            if range.is_empty() {
+                self.ranges.pop_front();
+            }
+        }
+        num_records - remaining as usize
+    }
+}
+
+impl ArrayReader for RowNumberReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        let mut buffer = std::mem::take(&mut self.buffer);
+        let read = self.advance(batch_size, |range| buffer.extend(range));
+        self.buffer = buffer;
+        Ok(read)
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        let values = std::mem::take(&mut self.buffer);
+        Ok(match self.data_type {
+            ArrowType::UInt64 => Arc::new(UInt64Array::from_iter_values(
+                values.into_iter().map(|v| v as u64),
+            )),
+            _ => Arc::new(Int64Array::from(values)),
+        })
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        Ok(self.advance(num_records, |_| {}))
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        None
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::{Int64Type, UInt64Type};
+    use arrow_schema::Fields;
+
+    #[test]
+    fn test_row_number_reader() {
+        let mut reader = RowNumberReader::new(ArrowType::Int64, [0..5, 10..13, 20..22]);
+
+        assert_eq!(reader.read_records(3).unwrap(), 3);
+        assert_eq!(reader.skip_records(4).unwrap(), 4);
+        assert_eq!(reader.read_records(2).unwrap(), 2);
+        let array = reader.consume_batch().unwrap();
+        assert_eq!(
+            array.as_primitive::<Int64Type>().values(),
+            &[0, 1, 2, 12, 20]
+        );
+
+        assert_eq!(reader.read_records(5).unwrap(), 1);
+        assert_eq!(reader.skip_records(5).unwrap(), 0);
+        let array = reader.consume_batch().unwrap();
+        assert_eq!(array.as_primitive::<Int64Type>().values(), &[21]);
+
+        let mut reader = RowNumberReader::new(ArrowType::UInt64, [7..9, 12..13]);
+        assert_eq!(reader.read_records(5).unwrap(), 3);
+        let array = reader.consume_batch().unwrap();
+        assert_eq!(array.as_primitive::<UInt64Type>().values(), &[7, 8, 12]);
+    }
+
+    #[test]
+    fn test_check_row_number_field() {
+        let fields = Fields::from(vec![Field::new("a", ArrowType::Int32, false)]);
+
+        check_row_number_field(&Field::new("row", ArrowType::UInt64, false), &fields).unwrap();
+
+        let err = check_row_number_field(&Field::new("row", ArrowType::Int32, false), &fields)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: row number column must be Int64 or UInt64, got Int32"
+        );
+
+        let err =
+            check_row_number_field(&Field::new("a", ArrowType::Int64, false), &fields).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: row number column \"a\" conflicts with a column of the same name"
+        );
+    }
+}
@@ -0,0 +1,187 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::arrow::array_reader::ArrayReader;
+use crate::errors::Result;
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int8Type;
+use arrow_array::{Array, ArrayRef, StructArray, UInt32Array, UnionArray};
+use arrow_buffer::ScalarBuffer;
+use arrow_schema::{DataType as ArrowType, UnionFields, UnionMode};
+use arrow_select::take::take;
+use std::any::Any;
+use std::sync::Arc;
+
+/// Implementation of union array reader.
+///
+/// Unions are stored in parquet as a struct of their type ids followed by a
+/// column for each child, which is null in rows where the child is not selected.
+/// This reads that struct with `struct_reader` and converts it to a union.
+pub struct UnionArrayReader {
+    struct_reader: Box<dyn ArrayReader>,
+    data_type: ArrowType,
+}
+
+impl UnionArrayReader {
+    /// Construct union array reader of `data_type`, which must be a union, from
+    /// a reader of the struct it is stored as
+    pub fn new(struct_reader: Box<dyn ArrayReader>, data_type: ArrowType) -> Self {
+        Self {
+            struct_reader,
+            data_type,
+        }
+    }
+}
+
+impl ArrayReader for UnionArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn read_records(&mut self, batch_size: usize) -> Result<usize> {
+        self.struct_reader.read_records(batch_size)
+    }
+
+    fn consume_batch(&mut self) -> Result<ArrayRef> {
+        let array = self.struct_reader.consume_batch()?;
+        let (fields, mode) = match &self.data_type {
+            ArrowType::Union(fields, mode) => (fields, *mode),
+            _ => unreachable!(),
+        };
+        Ok(Arc::new(struct_to_union(array.as_struct(), fields, mode)?))
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        self.struct_reader.skip_records(num_records)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.struct_reader.get_def_levels()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.struct_reader.get_rep_levels()
+    }
+}
+
+/// Converts the struct with which a union is stored to a union of `fields`
+///
+/// Rows with a null type id, such as those within a null parent, are given the
+/// first type id and a null value
+fn struct_to_union(
+    array: &StructArray,
+    fields: &UnionFields,
+    mode: UnionMode,
+) -> Result<UnionArray> {
+    let type_ids = array.column(0).as_primitive::<Int8Type>();
+    let default_id = fields.iter().next().map(|(id, _)| id).unwrap_or_default();
+    let ids: ScalarBuffer<i8> = type_ids.iter().map(|t| t.unwrap_or(default_id)).collect();
+    let children = &array.columns()[1..];
+
+    let union = match mode {
+        UnionMode::Sparse => UnionArray::try_new(fields.clone(), ids, None, children.to_vec())?,
+        UnionMode::Dense => {
+            // The index of each type id's child
+            let mut child_idx = [0; 128];
+            for (idx, (id, _)) in fields.iter().enumerate() {
+                child_idx[id as usize] = idx;
+            }
+
+            let mut indices = vec![vec![]; fields.len()];
+            let mut offsets = Vec::with_capacity(ids.len());
+            for (row, id) in ids.iter().enumerate() {
+                let child_indices = &mut indices[child_idx[*id as usize]];
+                offsets.push(child_indices.len() as i32);
+                child_indices.push(type_ids.is_valid(row).then_some(row as u32));
+            }
+
+            let children = children
+                .iter()
+                .zip(indices)
+                .map(|(child, indices)| take(child, &UInt32Array::from(indices), None))
+                .collect::<Result<_, _>>()?;
+            UnionArray::try_new(fields.clone(), ids, Some(offsets.into()), children)?
+        }
+    };
+    Ok(union)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::array_reader::test_util::InMemoryArrayReader;
+    use arrow_array::types::Int32Type;
+    use arrow_array::{Int32Array, Int8Array, StringArray};
+    use arrow_schema::{Field, Fields};
+
+    #[test]
+    fn test_union_array_reader() {
+        // Rows of a = 1, b = "x", null, a = null and b = "y"
+        let type_ids = Int8Array::from(vec![Some(0), Some(3), None, Some(0), Some(3)]);
+        let a = Int32Array::from(vec![Some(1), None, None, None, None]);
+        let b = StringArray::from(vec![None, Some("x"), None, None, Some("y")]);
+        let struct_fields = Fields::from(vec![
+            Field::new("type_id", ArrowType::Int8, true),
+            Field::new("a", ArrowType::Int32, true),
+            Field::new("b", ArrowType::Utf8, true),
+        ]);
+        let array = StructArray::new(
+            struct_fields.clone(),
+            vec![Arc::new(type_ids), Arc::new(a), Arc::new(b)],
+            None,
+        );
+
+        let union_fields = UnionFields::new(
+            [0, 3],
+            [
+                Field::new("a", ArrowType::Int32, true),
+                Field::new("b", ArrowType::Utf8, false),
+            ],
+        );
+
+        for mode in [UnionMode::Sparse, UnionMode::Dense] {
+            let struct_reader = InMemoryArrayReader::new(
+                ArrowType::Struct(struct_fields.clone()),
+                Arc::new(array.clone()),
+                None,
+                None,
+            );
+            let data_type = ArrowType::Union(union_fields.clone(), mode);
+            let mut reader = UnionArrayReader::new(Box::new(struct_reader), data_type.clone());
+
+            let union = reader.next_batch(5).unwrap();
+            assert_eq!(union.data_type(), &data_type);
+            let union = union.as_union();
+            assert_eq!(union.type_ids(), &[0, 3, 0, 0, 3]);
+
+            let value = |row: usize| union.value(row);
+            assert_eq!(value(0).as_primitive::<Int32Type>().value(0), 1);
+            assert_eq!(value(1).as_string::<i32>().value(0), "x");
+            assert!(value(2).is_null(0));
+            assert!(value(3).is_null(0));
+            assert_eq!(value(4).as_string::<i32>().value(0), "y");
+
+            if mode == UnionMode::Dense {
+                assert_eq!(union.offsets().unwrap(), &[0, 0, 1, 2, 1]);
+            }
+        }
+    }
+}
@@ -20,15 +20,20 @@
 use arrow_array::cast::AsArray;
 use arrow_array::Array;
 use arrow_array::{RecordBatch, RecordBatchReader};
-use arrow_schema::{ArrowError, DataType as ArrowType, Schema, SchemaRef};
+use arrow_schema::{ArrowError, DataType as ArrowType, FieldRef, Schema, SchemaRef, TimeUnit};
 pub use filter::{ArrowPredicate, ArrowPredicateFn, RowFilter};
+#[cfg(feature = "async")]
+pub(crate) use pruning::retain_row_groups;
+pub use pruning::{CompareOp, PruningPredicate};
 pub use selection::{RowSelection, RowSelector};
 use std::fmt::{Debug, Formatter};
 use std::sync::Arc;
 
 pub use crate::arrow::array_reader::RowGroups;
-use crate::arrow::array_reader::{ArrayReader, ArrayReaderBuilder};
-use crate::arrow::schema::{parquet_to_arrow_schema_and_fields, ParquetField};
+use crate::arrow::array_reader::{row_group_ranges, ArrayReader, ArrayReaderBuilder};
+use crate::arrow::schema::{
+    evolve_schema, parquet_to_arrow_schema_and_fields, statistics_schema, ParquetField,
+};
 use crate::arrow::{parquet_to_arrow_field_levels, FieldLevels, ProjectionMask};
 use crate::column::page::{PageIterator, PageReader};
 #[cfg(feature = "encryption")]
@@ -41,6 +46,7 @@ use crate::schema::types::SchemaDescriptor;
 pub(crate) use read_plan::{ReadPlan, ReadPlanBuilder};
 
 mod filter;
+mod pruning;
 mod read_plan;
 mod selection;
 pub mod statistics;
@@ -60,6 +66,7 @@ pub mod statistics;
 /// * Row group filtering: [`Self::with_row_groups`]
 /// * Range filtering: [`Self::with_row_selection`]
 /// * Row level filtering: [`Self::with_row_filter`]
+/// * Statistics based pruning: [`Self::with_pruning_predicate`]
 ///
 /// # Implementing Predicate Pushdown
 ///
@@ -107,11 +114,15 @@ pub struct ArrowReaderBuilder<T> {
 
     pub(crate) filter: Option<RowFilter>,
 
+    pub(crate) pruning_predicate: Option<PruningPredicate>,
+
     pub(crate) selection: Option<RowSelection>,
 
     pub(crate) limit: Option<usize>,
 
     pub(crate) offset: Option<usize>,
+
+    pub(crate) row_number_column: Option<FieldRef>,
 }
 
 impl<T: Debug> Debug for ArrowReaderBuilder<T> {
@@ -125,9 +136,11 @@ impl<T: Debug> Debug for ArrowReaderBuilder<T> {
             .field("row_groups", &self.row_groups)
             .field("projection", &self.projection)
             .field("filter", &self.filter)
+            .field("pruning_predicate", &self.pruning_predicate)
             .field("selection", &self.selection)
             .field("limit", &self.limit)
             .field("offset", &self.offset)
+            .field("row_number_column", &self.row_number_column)
             .finish()
     }
 }
@@ -143,9 +156,11 @@ impl<T> ArrowReaderBuilder<T> {
             row_groups: None,
             projection: ProjectionMask::all(),
             filter: None,
+            pruning_predicate: None,
             selection: None,
             limit: None,
             offset: None,
+            row_number_column: None,
         }
     }
 
@@ -269,6 +284,36 @@ impl<T> ArrowReaderBuilder<T> {
         }
     }
 
+    /// Provide a [`PruningPredicate`] to skip row groups and data pages
+    ///
+    /// The predicate is evaluated against the row group statistics, removing
+    /// row groups that can not contain matching rows from those selected by
+    /// [`Self::with_row_groups`]. If the [`PageIndex`] has been loaded (see
+    /// [`ArrowReaderOptions::with_page_index`]) it is then evaluated against
+    /// the data page statistics of the remaining row groups, skipping pages
+    /// that can not contain matching rows in addition to any
+    /// [`Self::with_row_selection`].
+    ///
+    /// When reading asynchronously with a [`ParquetRecordBatchStream`], the bloom
+    /// filters of the columns tested for equality (with [`PruningPredicate::eq`]
+    /// or [`PruningPredicate::in_list`]) are also fetched for the remaining row
+    /// groups before the first is read, skipping those that can not contain any
+    /// of the values. See [`ParquetRecordBatchStream::bloom_filter_pruned_row_groups`].
+    ///
+    /// This only avoids reading data that can not match, and so returned rows
+    /// may still not satisfy the predicate. Use [`Self::with_row_filter`] to
+    /// remove them.
+    ///
+    /// [`PageIndex`]: https://github.com/apache/parquet-format/blob/master/PageIndex.md
+    /// [`ParquetRecordBatchStream`]: crate::arrow::async_reader::ParquetRecordBatchStream
+    /// [`ParquetRecordBatchStream::bloom_filter_pruned_row_groups`]: crate::arrow::async_reader::ParquetRecordBatchStream::bloom_filter_pruned_row_groups
+    pub fn with_pruning_predicate(self, predicate: PruningPredicate) -> Self {
+        Self {
+            pruning_predicate: Some(predicate),
+            ..self
+        }
+    }
+
     /// Provide a limit to the number of rows to be read
     ///
     /// The limit will be applied after any [`Self::with_row_selection`] and [`Self::with_row_filter`]
@@ -296,6 +341,62 @@ impl<T> ArrowReaderBuilder<T> {
             ..self
         }
     }
+
+    /// Append a virtual column `field` to each [`RecordBatch`], containing the
+    /// position within the file of each row
+    ///
+    /// Positions are counted from the first row of the first row group in the
+    /// file, and so are unaffected by [`Self::with_row_groups`],
+    /// [`Self::with_row_selection`], [`Self::with_row_filter`],
+    /// [`Self::with_offset`] and [`Self::with_limit`]. This can be used, for
+    /// example, to apply positional deletes.
+    ///
+    /// The column is placed after the projected columns, and `field` must have
+    /// a data type of [`DataType::Int64`] or [`DataType::UInt64`] and a name
+    /// distinct from those of the projected columns, otherwise an error is
+    /// returned when building the reader.
+    ///
+    /// Note the column is not included in [`Self::schema`], which is the schema
+    /// of the parquet file.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use bytes::Bytes;
+    /// # use arrow_array::{Int32Array, RecordBatch};
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::UInt64Type;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// # use parquet::arrow::arrow_reader::{ParquetRecordBatchReaderBuilder, RowSelection, RowSelector};
+    /// # use parquet::arrow::ArrowWriter;
+    /// # let mut file: Vec<u8> = Vec::with_capacity(1024);
+    /// # let schema = Arc::new(Schema::new(vec![Field::new("i32", DataType::Int32, false)]));
+    /// # let mut writer = ArrowWriter::try_new(&mut file, schema.clone(), None).unwrap();
+    /// # let batch = RecordBatch::try_new(schema, vec![Arc::new(Int32Array::from(vec![1, 2, 3, 4]))]).unwrap();
+    /// # writer.write(&batch).unwrap();
+    /// # writer.close().unwrap();
+    /// # let file = Bytes::from(file);
+    /// let selection = RowSelection::from(vec![RowSelector::skip(1), RowSelector::select(2)]);
+    /// let mut reader = ParquetRecordBatchReaderBuilder::try_new(file)
+    ///     .unwrap()
+    ///     .with_row_selection(selection)
+    ///     .with_row_number_column(Field::new("row_number", DataType::UInt64, false))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let batch = reader.next().unwrap().unwrap();
+    /// let row_numbers = batch.column_by_name("row_number").unwrap();
+    /// assert_eq!(row_numbers.as_primitive::<UInt64Type>().values(), &[1, 2]);
+    /// ```
+    ///
+    /// [`DataType::Int64`]: arrow_schema::DataType::Int64
+    /// [`DataType::UInt64`]: arrow_schema::DataType::UInt64
+    pub fn with_row_number_column(self, field: impl Into<FieldRef>) -> Self {
+        Self {
+            row_number_column: Some(field.into()),
+            ..self
+        }
+    }
 }
 
 /// Options that control how metadata is read for a parquet file
@@ -311,6 +412,12 @@ pub struct ArrowReaderOptions {
     ///
     /// [ARROW_SCHEMA_META_KEY]: crate::arrow::ARROW_SCHEMA_META_KEY
     supplied_schema: Option<SchemaRef>,
+    /// If true, `supplied_schema` is a target schema into which the file is read,
+    /// see [`Self::with_target_schema`]
+    schema_evolution: bool,
+    /// If provided, the [`TimeUnit`] to read INT96 columns as,
+    /// see [`Self::with_coerce_int96`]
+    coerce_int96: Option<TimeUnit>,
     /// If true, attempt to read `OffsetIndex` and `ColumnIndex`
     pub(crate) page_index: bool,
     /// If encryption is enabled, the file decryption properties can be provided
@@ -357,7 +464,8 @@ impl ArrowReaderOptions {
     /// # Notes
     ///
     /// The provided schema must have the same number of columns as the parquet schema and
-    /// the column names must be the same.
+    /// the column names must be the same. To read a file written with a different schema
+    /// see [`Self::with_target_schema`].
     ///
     /// # Example
     /// ```
@@ -396,6 +504,86 @@ impl ArrowReaderOptions {
     pub fn with_schema(self, schema: SchemaRef) -> Self {
         Self {
             supplied_schema: Some(schema),
+            schema_evolution: false,
+            skip_arrow_metadata: true,
+            ..self
+        }
+    }
+
+    /// Read the parquet file into a target schema, such as the current schema of a
+    /// table whose schema has evolved since the file was written.
+    ///
+    /// Unlike [`Self::with_schema`], the columns of the target schema need not match
+    /// those of the file:
+    ///
+    /// * Fields with a field id in their metadata (see [`PARQUET_FIELD_ID_META_KEY`])
+    ///   are matched with the parquet field of the same id, regardless of their names,
+    ///   and other fields are matched with the parquet field of the same name. This
+    ///   applies to the fields of nested structs as well.
+    /// * Nullable fields that are not in the file are read as nulls.
+    /// * Columns of the file that are not in the target schema are not read.
+    /// * Values may be read as a wider type than that of the file: integers as a wider
+    ///   integer type (such as `Int32` as `Int64`), `Float32` as `Float64`, and decimals
+    ///   as decimals of the same scale with a greater precision.
+    /// * Union variants are matched in the same way as the fields of a struct, and
+    ///   variants that are not in the file are never selected.
+    ///
+    /// An error is returned when constructing the builder if a field of the target
+    /// schema can not be read from the file, such as a non-nullable field that is not
+    /// in the file, or a field whose type can not be read as the target type.
+    ///
+    /// The [`ProjectionMask`] of the builder selects the leaf columns of the file that
+    /// are read, and fields missing from the file are always read. To read a subset of
+    /// the fields, provide a target schema with just those fields.
+    ///
+    /// [`PARQUET_FIELD_ID_META_KEY`]: crate::arrow::PARQUET_FIELD_ID_META_KEY
+    ///
+    /// # Example
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use std::sync::Arc;
+    /// # use bytes::Bytes;
+    /// # use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::Int64Type;
+    /// # use arrow_schema::{DataType, Field, Schema};
+    /// # use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    /// # use parquet::arrow::{ArrowWriter, PARQUET_FIELD_ID_META_KEY};
+    /// let field_id = |id: i32| HashMap::from([(PARQUET_FIELD_ID_META_KEY.to_string(), id.to_string())]);
+    ///
+    /// // Write a file with a single Int32 column "a" with field id 1
+    /// let schema = Arc::new(Schema::new(vec![
+    ///     Field::new("a", DataType::Int32, false).with_metadata(field_id(1)),
+    /// ]));
+    /// let column = Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef;
+    /// let batch = RecordBatch::try_new(schema.clone(), vec![column]).unwrap();
+    /// let mut buffer = Vec::new();
+    /// let mut writer = ArrowWriter::try_new(&mut buffer, schema, None).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// writer.close().unwrap();
+    ///
+    /// // Read the file after "a" was renamed to "b" and widened to Int64, and a
+    /// // column "c" was added
+    /// let target_schema = Arc::new(Schema::new(vec![
+    ///     Field::new("c", DataType::Utf8, true).with_metadata(field_id(2)),
+    ///     Field::new("b", DataType::Int64, false).with_metadata(field_id(1)),
+    /// ]));
+    /// let options = ArrowReaderOptions::new().with_target_schema(target_schema.clone());
+    /// let mut reader =
+    ///     ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buffer), options)
+    ///         .unwrap()
+    ///         .build()
+    ///         .unwrap();
+    ///
+    /// let batch = reader.next().unwrap().unwrap();
+    /// assert_eq!(batch.schema(), target_schema);
+    /// assert_eq!(batch.column(0).null_count(), 3);
+    /// assert_eq!(batch.column(1).as_primitive::<Int64Type>().values(), &[1, 2, 3]);
+    /// ```
+    pub fn with_target_schema(self, schema: SchemaRef) -> Self {
+        Self {
+            supplied_schema: Some(schema),
+            schema_evolution: true,
             skip_arrow_metadata: true,
             ..self
         }
@@ -417,6 +605,63 @@ impl ArrowReaderOptions {
         Self { page_index, ..self }
     }
 
+    /// Read INT96 columns as timestamps of the given [`TimeUnit`] (defaults to `None`)
+    ///
+    /// INT96 is a deprecated physical type used by legacy writers such as Hive, Impala
+    /// and Spark to store nanosecond timestamps, and is by default read as
+    /// [`TimeUnit::Nanosecond`]. Nanosecond timestamps can only represent dates between
+    /// the years 1677 and 2262, and values outside this range wrap around. Reading them
+    /// with a coarser unit, such as [`TimeUnit::Microsecond`], avoids this overflow at
+    /// the cost of truncating the nanoseconds.
+    ///
+    /// Any timezone of the arrow schema embedded in the file is kept. This option is
+    /// ignored when a schema is provided with [`Self::with_schema`] or
+    /// [`Self::with_target_schema`], in which case INT96 columns are read as the unit
+    /// of the provided schema.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow_array::{Array, ArrayRef, RecordBatch, TimestampSecondArray};
+    /// # use arrow_array::cast::AsArray;
+    /// # use arrow_array::types::TimestampMicrosecondType;
+    /// # use arrow_schema::{DataType, TimeUnit};
+    /// # use bytes::Bytes;
+    /// # use parquet::arrow::ArrowWriter;
+    /// # use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+    /// # use parquet::file::properties::WriterProperties;
+    /// // The year 3000 overflows nanosecond timestamps
+    /// let seconds = 32_503_680_000;
+    /// let array = TimestampSecondArray::from(vec![seconds]);
+    /// let batch = RecordBatch::try_from_iter([("ts", Arc::new(array) as ArrayRef)]).unwrap();
+    ///
+    /// let props = WriterProperties::builder()
+    ///     .set_timestamps_as_int96(true)
+    ///     .build();
+    /// let mut buf = Vec::new();
+    /// let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+    /// writer.write(&batch).unwrap();
+    /// writer.close().unwrap();
+    ///
+    /// let options = ArrowReaderOptions::new()
+    ///     .with_skip_arrow_metadata(true)
+    ///     .with_coerce_int96(Some(TimeUnit::Microsecond));
+    /// let mut reader = ParquetRecordBatchReaderBuilder::try_new_with_options(Bytes::from(buf), options)
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// let batch = reader.next().unwrap().unwrap();
+    /// let ts = batch.column(0).as_primitive::<TimestampMicrosecondType>();
+    /// assert_eq!(ts.data_type(), &DataType::Timestamp(TimeUnit::Microsecond, None));
+    /// assert_eq!(ts.value(0), seconds * 1_000_000);
+    /// ```
+    pub fn with_coerce_int96(self, coerce_int96: Option<TimeUnit>) -> Self {
+        Self {
+            coerce_int96,
+            ..self
+        }
+    }
+
     /// Provide the file decryption properties to use when reading encrypted parquet files.
     ///
     /// If encryption is enabled and the file is encrypted, the `file_decryption_properties` must be provided.
@@ -500,6 +745,9 @@ impl ArrowReaderMetadata {
     /// See [`Self::load`] for more details.
     pub fn try_new(metadata: Arc<ParquetMetaData>, options: ArrowReaderOptions) -> Result<Self> {
         match options.supplied_schema {
+            Some(target_schema) if options.schema_evolution => {
+                Self::with_target_schema(metadata, target_schema)
+            }
             Some(supplied_schema) => Self::with_supplied_schema(metadata, supplied_schema.clone()),
             None => {
                 let kv_metadata = match options.skip_arrow_metadata {
@@ -511,6 +759,7 @@ impl ArrowReaderMetadata {
                     metadata.file_metadata().schema_descr(),
                     ProjectionMask::all(),
                     kv_metadata,
+                    options.coerce_int96,
                 )?;
 
                 Ok(Self {
@@ -590,6 +839,19 @@ impl ArrowReaderMetadata {
         })
     }
 
+    fn with_target_schema(
+        metadata: Arc<ParquetMetaData>,
+        target_schema: SchemaRef,
+    ) -> Result<Self> {
+        let parquet_schema = metadata.file_metadata().schema_descr();
+        let fields = evolve_schema(parquet_schema, target_schema.fields())?;
+        Ok(Self {
+            metadata,
+            schema: target_schema,
+            fields: Some(Arc::new(fields)),
+        })
+    }
+
     /// Returns a reference to the [`ParquetMetaData`] for this parquet file
     pub fn metadata(&self) -> &Arc<ParquetMetaData> {
         &self.metadata
@@ -716,6 +978,16 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             .row_groups
             .unwrap_or_else(|| (0..self.metadata.num_row_groups()).collect());
 
+        let (row_groups, selection) = match &self.pruning_predicate {
+            Some(predicate) => {
+                let parquet_schema = self.metadata.file_metadata().schema_descr();
+                let schema =
+                    statistics_schema(&self.schema, self.fields.as_deref(), parquet_schema);
+                predicate.prune(&self.metadata, &schema, row_groups, self.selection)?
+            }
+            None => (row_groups, self.selection),
+        };
+
         let reader = ReaderRowGroups {
             reader: Arc::new(self.input.0),
             metadata: self.metadata,
@@ -723,7 +995,7 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
         };
 
         let mut filter = self.filter;
-        let mut plan_builder = ReadPlanBuilder::new(batch_size).with_selection(self.selection);
+        let mut plan_builder = ReadPlanBuilder::new(batch_size).with_selection(selection);
 
         // Update selection based on any filters
         if let Some(filter) = filter.as_mut() {
@@ -740,8 +1012,13 @@ impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
             }
         }
 
-        let array_reader = ArrayReaderBuilder::new(&reader)
-            .build_array_reader(self.fields.as_deref(), &self.projection)?;
+        let mut array_reader_builder = ArrayReaderBuilder::new(&reader);
+        if let Some(field) = self.row_number_column {
+            let ranges = row_group_ranges(&reader.metadata, &reader.row_groups);
+            array_reader_builder = array_reader_builder.with_row_numbers(field, ranges);
+        }
+        let array_reader =
+            array_reader_builder.build_array_reader(self.fields.as_deref(), &self.projection)?;
 
         let read_plan = plan_builder
             .limited(reader.num_rows())
@@ -994,10 +1271,13 @@ mod tests {
         Float32Type, Float64Type, Time32MillisecondType, Time64MicrosecondType,
     };
     use arrow_array::*;
-    use arrow_buffer::{i256, ArrowNativeType, Buffer, IntervalDayTime};
+    use arrow_buffer::{
+        i256, ArrowNativeType, Buffer, IntervalDayTime, OffsetBuffer, ScalarBuffer,
+    };
     use arrow_data::{ArrayData, ArrayDataBuilder};
     use arrow_schema::{
         ArrowError, DataType as ArrowDataType, Field, Fields, Schema, SchemaRef, TimeUnit,
+        UnionFields,
     };
     use arrow_select::concat::concat_batches;
     use bytes::Bytes;
@@ -1008,10 +1288,10 @@ mod tests {
 
     use crate::arrow::arrow_reader::{
         ArrowPredicateFn, ArrowReaderBuilder, ArrowReaderOptions, ParquetRecordBatchReader,
-        ParquetRecordBatchReaderBuilder, RowFilter, RowSelection, RowSelector,
+        ParquetRecordBatchReaderBuilder, PruningPredicate, RowFilter, RowSelection, RowSelector,
     };
     use crate::arrow::schema::add_encoded_arrow_schema_to_metadata;
-    use crate::arrow::{ArrowWriter, ProjectionMask};
+    use crate::arrow::{ArrowWriter, ProjectionMask, PARQUET_FIELD_ID_META_KEY};
     use crate::basic::{ConvertedType, Encoding, Repetition, Type as PhysicalType};
     use crate::column::reader::decoder::REPETITION_LEVELS_BATCH_SIZE;
     use crate::data_type::{
@@ -1590,6 +1870,80 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_coerce_int96() {
+        // 3000-01-01T00:00:00 cannot be represented in nanoseconds
+        let values = vec![Some(32_503_680_000), None, Some(-1)];
+        let ts = TimestampSecondArray::from(values.clone()).with_timezone("+01:00");
+        let list = ListArray::new(
+            Arc::new(Field::new_list_field(ts.data_type().clone(), true)),
+            OffsetBuffer::from_lengths([2, 1]),
+            Arc::new(ts.clone()),
+            None,
+        );
+        let batch = RecordBatch::try_from_iter([
+            ("ts", Arc::new(ts.slice(0, 2)) as ArrayRef),
+            ("list", Arc::new(list) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_timestamps_as_int96(true)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let read = |options: ArrowReaderOptions| {
+            let mut reader =
+                ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+                    .unwrap()
+                    .build()
+                    .unwrap();
+            reader.next().unwrap().unwrap()
+        };
+
+        // The embedded arrow schema restores the original type
+        let read_batch = read(ArrowReaderOptions::new());
+        assert_eq!(read_batch, batch);
+
+        // Without it, INT96 is read as nanoseconds, which wrap around
+        let read_batch = read(ArrowReaderOptions::new().with_skip_arrow_metadata(true));
+        let ts = read_batch
+            .column(0)
+            .as_primitive::<types::TimestampNanosecondType>();
+        assert_eq!(ts.value(0), 32_503_680_000_i64.wrapping_mul(1_000_000_000));
+
+        let options = ArrowReaderOptions::new()
+            .with_skip_arrow_metadata(true)
+            .with_coerce_int96(Some(TimeUnit::Millisecond));
+        let read_batch = read(options);
+        let expected =
+            TimestampMillisecondArray::from_iter(values.iter().map(|v| v.map(|v| v * 1_000)));
+        let ts = read_batch
+            .column(0)
+            .as_primitive::<types::TimestampMillisecondType>();
+        assert_eq!(ts, &expected.slice(0, 2));
+        let list = read_batch.column(1).as_list::<i32>();
+        assert_eq!(
+            list.values()
+                .as_primitive::<types::TimestampMillisecondType>(),
+            &expected
+        );
+
+        // The timezone of the embedded arrow schema is kept
+        let options = ArrowReaderOptions::new().with_coerce_int96(Some(TimeUnit::Microsecond));
+        let read_batch = read(options);
+        let data_type = ArrowDataType::Timestamp(TimeUnit::Microsecond, Some("+01:00".into()));
+        assert_eq!(read_batch.column(0).data_type(), &data_type);
+        let ts = read_batch
+            .column(0)
+            .as_primitive::<types::TimestampMicrosecondType>();
+        assert_eq!(ts.value(0), 32_503_680_000_000_000);
+    }
+
     #[test]
     fn test_int96_from_spark_file_with_provided_schema() {
         // int96_from_spark.parquet was written based on Spark's microsecond timestamps which trade
@@ -3807,6 +4161,356 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_with_target_schema() {
+        let with_id = |field: Field, id: &str| {
+            field.with_metadata(HashMap::from([(
+                PARQUET_FIELD_ID_META_KEY.to_string(),
+                id.to_string(),
+            )]))
+        };
+
+        let s_fields = Fields::from(vec![
+            with_id(Field::new("x", ArrowDataType::Int32, true), "4"),
+            with_id(Field::new("y", ArrowDataType::Utf8, true), "5"),
+        ]);
+        let s = StructArray::new(
+            s_fields.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 2, 3])),
+                Arc::new(StringArray::from(vec!["a", "b", "c"])),
+            ],
+            Some(vec![true, false, true].into()),
+        );
+        let d = Decimal128Array::from(vec![100, 250, -5])
+            .with_precision_and_scale(9, 2)
+            .unwrap();
+        let schema = Schema::new(vec![
+            with_id(Field::new("a", ArrowDataType::Int32, true), "1"),
+            with_id(Field::new("b", ArrowDataType::Float32, false), "2"),
+            with_id(Field::new_struct("s", s_fields, true), "3"),
+            Field::new("d", d.data_type().clone(), true),
+            with_id(Field::new("old", ArrowDataType::Utf8, true), "7"),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])),
+                Arc::new(Float32Array::from(vec![0.5, 1.5, 2.5])),
+                Arc::new(s),
+                Arc::new(d),
+                Arc::new(StringArray::from(vec!["x", "y", "z"])),
+            ],
+        )
+        .unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        // Fields are reordered, renamed, widened, added and dropped
+        let st_fields = Fields::from(vec![
+            with_id(Field::new("z", ArrowDataType::Int32, true), "8"),
+            with_id(Field::new("yy", ArrowDataType::Utf8, true), "5"),
+        ]);
+        let target = Arc::new(Schema::new(vec![
+            with_id(Field::new("n", ArrowDataType::Utf8, true), "9"),
+            with_id(Field::new("renamed_a", ArrowDataType::Int64, true), "1"),
+            with_id(Field::new("bb", ArrowDataType::Float64, false), "2"),
+            with_id(Field::new_struct("st", st_fields.clone(), true), "3"),
+            Field::new("d", ArrowDataType::Decimal128(18, 2), true),
+        ]));
+
+        let options = ArrowReaderOptions::new().with_target_schema(target.clone());
+        let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(reader.schema(), target);
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(batches.len(), 1);
+
+        let st = StructArray::new(
+            st_fields,
+            vec![
+                Arc::new(Int32Array::new_null(3)),
+                Arc::new(StringArray::from(vec![Some("a"), None, Some("c")])),
+            ],
+            Some(vec![true, false, true].into()),
+        );
+        let expected = RecordBatch::try_new(
+            target.clone(),
+            vec![
+                Arc::new(StringArray::new_null(3)),
+                Arc::new(Int64Array::from(vec![Some(1), None, Some(3)])),
+                Arc::new(Float64Array::from(vec![0.5, 1.5, 2.5])),
+                Arc::new(st),
+                Arc::new(
+                    Decimal128Array::from(vec![100, 250, -5])
+                        .with_precision_and_scale(18, 2)
+                        .unwrap(),
+                ),
+            ],
+        )
+        .unwrap();
+        assert_eq!(batches[0], expected);
+
+        // Missing fields are read regardless of the projection
+        let options = ArrowReaderOptions::new().with_target_schema(target.clone());
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options).unwrap();
+        let projection = ProjectionMask::leaves(builder.parquet_schema(), [0]);
+        let mut reader = builder
+            .with_projection(projection)
+            .with_row_number_column(Field::new("row_number", ArrowDataType::UInt64, false))
+            .build()
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let names: Vec<_> = batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect();
+        assert_eq!(names, ["n", "renamed_a", "row_number"]);
+        assert_eq!(batch.column(0).null_count(), 3);
+        assert_eq!(
+            batch.column(2).as_primitive::<types::UInt64Type>().values(),
+            &[0, 1, 2]
+        );
+
+        // Reading only fields missing from the file yields a batch of nulls
+        let target = Arc::new(Schema::new(vec![Field::new(
+            "n",
+            ArrowDataType::Utf8,
+            true,
+        )]));
+        let options = ArrowReaderOptions::new().with_target_schema(target.clone());
+        let mut reader = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options)
+            .unwrap()
+            .build()
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.schema(), target);
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(batch.column(0).null_count(), 3);
+    }
+
+    #[test]
+    fn test_with_target_schema_nested() {
+        let element = Fields::from(vec![Field::new("a", ArrowDataType::Int32, true)]);
+        let mut list = ListBuilder::new(StructBuilder::from_fields(element, 3));
+        for values in [vec![1, 2], vec![], vec![3]] {
+            for v in values {
+                let s = list.values();
+                s.field_builder::<Int32Builder>(0).unwrap().append_value(v);
+                s.append(true);
+            }
+            list.append(true);
+        }
+        let list = list.finish();
+        let s = StructArray::from(vec![(
+            Arc::new(Field::new("y", ArrowDataType::Int32, true)),
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )]);
+        let file = write_parquet_from_iter(vec![
+            ("list", Arc::new(list) as ArrayRef),
+            ("s", Arc::new(s) as ArrayRef),
+        ]);
+
+        let element = Fields::from(vec![
+            Field::new("a", ArrowDataType::Int64, true),
+            Field::new("b", ArrowDataType::Utf8, true),
+        ]);
+        let item = Arc::new(Field::new_struct("item", element.clone(), true));
+        let target = Arc::new(Schema::new(vec![
+            Field::new("list", ArrowDataType::LargeList(item.clone()), true),
+            // A struct with none of its fields in the file is missing
+            Field::new_struct("s", vec![Field::new("x", ArrowDataType::Int32, true)], true),
+        ]));
+
+        let options = ArrowReaderOptions::new().with_target_schema(target.clone());
+        let mut reader = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options)
+            .unwrap()
+            .build()
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.schema(), target);
+
+        let list = batch.column(0).as_list::<i64>();
+        assert_eq!(list.value_offsets(), &[0, 2, 2, 3]);
+        let values = list.values().as_struct();
+        assert_eq!(values.fields(), &element);
+        assert_eq!(
+            values.column(0).as_primitive::<types::Int64Type>().values(),
+            &[1, 2, 3]
+        );
+        assert_eq!(values.column(1).null_count(), 3);
+        assert_eq!(batch.column(1).null_count(), 3);
+    }
+
+    #[test]
+    fn test_with_target_schema_union() {
+        let fields = UnionFields::new(
+            [1, 2],
+            [
+                Field::new("a", ArrowDataType::Int32, true),
+                Field::new("b", ArrowDataType::Float64, true),
+            ],
+        );
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let b = Float64Array::from(vec![None, Some(2.5), None]);
+        let children: Vec<ArrayRef> = vec![Arc::new(a), Arc::new(b)];
+        let type_ids = ScalarBuffer::from(vec![1_i8, 2, 1]);
+        let union = UnionArray::try_new(fields.clone(), type_ids, None, children).unwrap();
+        let file = write_parquet_from_iter(vec![("u", Arc::new(union.clone()) as ArrayRef)]);
+
+        let read = |target: SchemaRef| {
+            let options = ArrowReaderOptions::new().with_target_schema(target.clone());
+            let mut reader = ParquetRecordBatchReaderBuilder::try_new_with_options(
+                file.try_clone().unwrap(),
+                options,
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+            let batch = reader.next().unwrap().unwrap();
+            assert_eq!(batch.schema(), target);
+            batch
+        };
+
+        // The union is read as written
+        let target = Arc::new(Schema::new(vec![
+            Field::new("n", ArrowDataType::Utf8, true),
+            Field::new("u", union.data_type().clone(), false),
+        ]));
+        let batch = read(target);
+        assert_eq!(batch.column(0).null_count(), 3);
+        assert_eq!(batch.column(1).as_ref(), &union as &dyn Array);
+
+        // A variant is widened, and a variant not in the file is never selected
+        let target_fields = UnionFields::new(
+            [1, 2, 3],
+            [
+                Field::new("a", ArrowDataType::Int64, true),
+                Field::new("b", ArrowDataType::Float64, true),
+                Field::new("c", ArrowDataType::Utf8, true),
+            ],
+        );
+        let target = Arc::new(Schema::new(vec![Field::new(
+            "u",
+            ArrowDataType::Union(target_fields, arrow_schema::UnionMode::Sparse),
+            false,
+        )]));
+        let batch = read(target);
+        let read_union = batch.column(0).as_union();
+        assert_eq!(read_union.type_ids(), &[1, 2, 1]);
+        assert_eq!(
+            read_union.child(1).as_primitive::<types::Int64Type>(),
+            &Int64Array::from(vec![Some(1), None, Some(3)])
+        );
+        assert_eq!(read_union.child(3).null_count(), 3);
+    }
+
+    #[test]
+    fn test_with_target_schema_incompatible() {
+        let file = write_parquet_from_iter(vec![
+            ("a", Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef),
+            (
+                "b",
+                Arc::new(StringArray::from(vec![Some("x"), None])) as ArrayRef,
+            ),
+            (
+                "d",
+                Arc::new(
+                    Decimal128Array::from(vec![100, 200])
+                        .with_precision_and_scale(9, 2)
+                        .unwrap(),
+                ) as ArrayRef,
+            ),
+        ]);
+
+        let test = |fields: Vec<Field>, expected: &str| {
+            let options =
+                ArrowReaderOptions::new().with_target_schema(Arc::new(Schema::new(fields)));
+            let err = ParquetRecordBatchReaderBuilder::try_new_with_options(
+                file.try_clone().unwrap(),
+                options,
+            )
+            .unwrap_err();
+            assert_eq!(err.to_string(), expected);
+        };
+
+        test(
+            vec![Field::new("c", ArrowDataType::Int32, false)],
+            "Arrow: incompatible target schema, non-nullable field c is missing from the parquet file",
+        );
+        test(
+            vec![Field::new("a", ArrowDataType::Int32, true)],
+            "Arrow: incompatible target schema, cannot read field a of type Int64 as Int32",
+        );
+        test(
+            vec![Field::new("b", ArrowDataType::Float64, true)],
+            "Arrow: incompatible target schema, cannot read field b of type Utf8 as Float64",
+        );
+        test(
+            vec![Field::new("b", ArrowDataType::Utf8, false)],
+            "Arrow: incompatible target schema, field b is nullable in the parquet file",
+        );
+        test(
+            vec![Field::new("d", ArrowDataType::Decimal256(5, 2), true)],
+            "Arrow: incompatible target schema, cannot read field d of type Decimal128(9, 2) as Decimal256(5, 2)",
+        );
+        test(
+            vec![Field::new("d", ArrowDataType::Decimal128(9, 3), true)],
+            "Arrow: incompatible target schema, cannot read field d of type Decimal128(9, 2) as Decimal128(9, 3)",
+        );
+    }
+
+    #[test]
+    fn test_with_target_schema_pruning_predicate() {
+        let a = Int32Array::from_iter_values(0..100);
+        let b = StringArray::from_iter_values((0..100).map(|i| format!("{i:03}")));
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        // The statistics of "a" are found despite the columns being reordered
+        let target = Arc::new(Schema::new(vec![
+            Field::new("c", ArrowDataType::Int32, true),
+            Field::new("b", ArrowDataType::Utf8, true),
+            Field::new("a", ArrowDataType::Int32, true),
+        ]));
+        let options = ArrowReaderOptions::new().with_target_schema(target);
+        let predicate = PruningPredicate::lt("a", Int32Array::new_scalar(25));
+        let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options)
+            .unwrap()
+            .with_pruning_predicate(predicate)
+            .build()
+            .unwrap();
+        let a: Vec<i32> = reader
+            .flat_map(|batch| {
+                let batch = batch.unwrap();
+                let a = batch.column(2).as_primitive::<types::Int32Type>();
+                a.values().to_vec()
+            })
+            .collect();
+        assert_eq!(a, (0..30).collect::<Vec<_>>());
+    }
+
     #[test]
     fn test_empty_projection() {
         let testdata = arrow::util::test_util::parquet_test_data();
@@ -4648,4 +5352,369 @@ mod tests {
         assert_eq!(c0.len(), c1.len());
         c0.iter().zip(c1.iter()).for_each(|(l, r)| assert_eq!(l, r));
     }
+
+    #[test]
+    fn test_row_number_column() {
+        let values = Int64Array::from_iter_values(0..100);
+        let batch = RecordBatch::try_from_iter([("v", Arc::new(values) as ArrayRef)]).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .set_data_page_row_count_limit(4)
+            .set_write_batch_size(4)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let builder = || {
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options.clone())
+                .unwrap()
+                .with_batch_size(7)
+        };
+
+        // Returns the values of `v` and the row numbers read by `reader`
+        let read = |reader: ParquetRecordBatchReader| {
+            assert_eq!(reader.schema().field(1).name(), "row_number");
+            let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+            let batch = concat_batches(&batches[0].schema(), &batches).unwrap();
+            let v = batch
+                .column(0)
+                .as_primitive::<types::Int64Type>()
+                .values()
+                .to_vec();
+            let row_numbers = batch.column(1).as_primitive::<types::UInt64Type>().values();
+            let row_numbers: Vec<i64> = row_numbers.iter().map(|r| *r as i64).collect();
+            (v, row_numbers)
+        };
+        let field = Field::new("row_number", ArrowDataType::UInt64, false);
+
+        let reader = builder()
+            .with_row_groups(vec![1, 3, 4])
+            .with_row_number_column(field.clone())
+            .build()
+            .unwrap();
+        let (v, row_numbers) = read(reader);
+        assert_eq!(v, (10..20).chain(30..50).collect::<Vec<_>>());
+        assert_eq!(row_numbers, v);
+
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(3),
+            RowSelector::select(20),
+            RowSelector::skip(5),
+            RowSelector::select(12),
+        ]);
+        let filter = RowFilter::new(vec![Box::new(ArrowPredicateFn::new(
+            ProjectionMask::all(),
+            |batch: RecordBatch| {
+                let v = batch.column(0).as_primitive::<types::Int64Type>();
+                Ok(BooleanArray::from_unary(v, |v| v % 3 != 0))
+            },
+        ))]);
+        let reader = builder()
+            .with_row_groups(vec![0, 2, 5, 6])
+            .with_row_selection(selection)
+            .with_row_filter(filter)
+            .with_offset(2)
+            .with_limit(15)
+            .with_row_number_column(field)
+            .build()
+            .unwrap();
+        let (v, row_numbers) = read(reader);
+        assert_eq!(
+            v,
+            [7, 8, 20, 22, 23, 25, 26, 28, 29, 50, 52, 58, 59, 61, 62]
+        );
+        assert_eq!(row_numbers, v);
+    }
+
+    #[test]
+    fn test_row_number_column_without_columns() {
+        let values = Int32Array::from_iter_values(0..20);
+        let batch = RecordBatch::try_from_iter([("v", Arc::new(values) as ArrayRef)]).unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(6)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        let projection = ProjectionMask::leaves(builder.parquet_schema(), []);
+        let mut reader = builder
+            .with_projection(projection)
+            .with_row_groups(vec![1, 3])
+            .with_row_number_column(Field::new("pos", ArrowDataType::Int64, false))
+            .build()
+            .unwrap();
+
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.num_columns(), 1);
+        let row_numbers = batch.column(0).as_primitive::<types::Int64Type>();
+        assert_eq!(row_numbers.values(), &[6, 7, 8, 9, 10, 11, 18, 19]);
+        assert!(reader.next().is_none());
+
+        let err = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+            .unwrap()
+            .with_row_number_column(Field::new("v", ArrowDataType::Int64, false))
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: row number column \"v\" conflicts with a column of the same name"
+        );
+
+        let err = ParquetRecordBatchReaderBuilder::try_new(data)
+            .unwrap()
+            .with_row_number_column(Field::new("pos", ArrowDataType::Int32, false))
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: row number column must be Int64 or UInt64, got Int32"
+        );
+    }
+
+    #[test]
+    fn test_pruning_predicate() {
+        // 10 row groups of 10 rows, with pages of 4 rows, and "b" only null in row group 7
+        let a = Int32Array::from_iter_values(0..100);
+        let b = StringArray::from_iter((0..100).map(|i| (i / 10 != 7).then(|| format!("{i:03}"))));
+        let batch = RecordBatch::try_from_iter([
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .set_data_page_row_count_limit(4)
+            .set_write_batch_size(4)
+            .build();
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        // Returns the values of "a" read with `predicate`
+        let read = |page_index: bool, predicate: PruningPredicate| -> Vec<i32> {
+            let options = ArrowReaderOptions::new().with_page_index(page_index);
+            let reader =
+                ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options)
+                    .unwrap()
+                    .with_pruning_predicate(predicate)
+                    .build()
+                    .unwrap();
+            reader
+                .flat_map(|batch| {
+                    let batch = batch.unwrap();
+                    let a = batch.column(0).as_primitive::<types::Int32Type>();
+                    a.values().to_vec()
+                })
+                .collect()
+        };
+
+        let range = PruningPredicate::gt_eq("a", Int32Array::new_scalar(35))
+            .and(PruningPredicate::lt("a", Int32Array::new_scalar(52)));
+        assert_eq!(read(false, range.clone()), (30..60).collect::<Vec<_>>());
+        assert_eq!(read(true, range.clone()), (34..54).collect::<Vec<_>>());
+        assert_eq!(
+            read(true, !range),
+            (0..38).chain(50..100).collect::<Vec<_>>()
+        );
+
+        let values = Arc::new(Int32Array::from(vec![5, 93])) as ArrayRef;
+        let in_list = PruningPredicate::in_list("a", values);
+        assert_eq!(
+            read(false, in_list.clone()),
+            (0..10).chain(90..100).collect::<Vec<_>>()
+        );
+        assert_eq!(read(true, in_list), [4, 5, 6, 7, 90, 91, 92, 93]);
+
+        let is_null = PruningPredicate::is_null("b");
+        assert_eq!(read(false, is_null.clone()), (70..80).collect::<Vec<_>>());
+        let or = is_null.or(PruningPredicate::eq("b", StringArray::new_scalar("011")));
+        assert_eq!(read(true, or), (10..14).chain(70..80).collect::<Vec<_>>());
+
+        let none = PruningPredicate::gt("a", Int32Array::new_scalar(1000));
+        assert_eq!(read(true, none), Vec::<i32>::new());
+
+        // Pruning is applied to the selected row groups and row selection
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(5),
+            RowSelector::select(10),
+            RowSelector::skip(10),
+            RowSelector::select(3),
+        ]);
+        let predicate = PruningPredicate::gt_eq("a", Int32Array::new_scalar(57));
+        let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(
+            data.clone(),
+            ArrowReaderOptions::new().with_page_index(true),
+        )
+        .unwrap()
+        .with_row_groups(vec![2, 5, 6, 8])
+        .with_row_selection(selection)
+        .with_pruning_predicate(predicate)
+        .build()
+        .unwrap();
+        let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+        let batch = concat_batches(&batches[0].schema(), &batches).unwrap();
+        let a = batch.column(0).as_primitive::<types::Int32Type>();
+        assert_eq!(a.values(), &[54, 65, 66, 67]);
+
+        let predicate = PruningPredicate::eq("a", Int64Array::new_scalar(1));
+        let err = ParquetRecordBatchReaderBuilder::try_new(data.clone())
+            .unwrap()
+            .with_pruning_predicate(predicate)
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Cannot compare column of type Int32 with value of type Int64"
+        );
+    }
+
+    #[test]
+    fn test_geospatial_pruning() {
+        use crate::geospatial::BoundingBox;
+
+        // Little-endian WKB of an XY point
+        let point = |x: f64, y: f64| {
+            let mut wkb = vec![1, 1, 0, 0, 0];
+            wkb.extend_from_slice(&x.to_le_bytes());
+            wkb.extend_from_slice(&y.to_le_bytes());
+            ByteArray::from(wkb)
+        };
+
+        let schema = "message schema { REQUIRED BYTE_ARRAY geo (GEOMETRY); }";
+        let schema = Arc::new(parse_message_type(schema).unwrap());
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = SerializedFileWriter::new(&mut buf, schema, Default::default()).unwrap();
+
+        // Row groups of points in [0, 1], [10, 11] and [20, 21]
+        for i in 0..3 {
+            let offset = i as f64 * 10.;
+            let values = [point(offset, offset), point(offset + 1., offset + 1.)];
+            let mut row_group_writer = writer.next_row_group().unwrap();
+            let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+            col_writer
+                .typed::<ByteArrayType>()
+                .write_batch(&values, None, None)
+                .unwrap();
+            col_writer.close().unwrap();
+            row_group_writer.close().unwrap();
+        }
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        let stats = builder.metadata().row_group(1).column(0).geo_statistics();
+        let stats = stats.unwrap();
+        assert_eq!(stats.bbox(), Some(&BoundingBox::new(10., 11., 10., 11.)));
+        assert_eq!(stats.geospatial_types(), Some([1].as_slice()));
+
+        let read = |predicate: PruningPredicate| -> usize {
+            ParquetRecordBatchReaderBuilder::try_new(data.clone())
+                .unwrap()
+                .with_pruning_predicate(predicate)
+                .build()
+                .unwrap()
+                .map(|batch| batch.unwrap().num_rows())
+                .sum()
+        };
+
+        let bbox = BoundingBox::new(0.5, 10.5, -5., 5.);
+        assert_eq!(read(PruningPredicate::intersects("geo", bbox)), 2);
+        assert_eq!(read(!PruningPredicate::intersects("geo", bbox)), 6);
+        let bbox = BoundingBox::new(0.5, 10.5, 0.5, 10.5);
+        assert_eq!(read(PruningPredicate::intersects("geo", bbox)), 4);
+    }
+
+    #[test]
+    fn test_union_projection() {
+        let fields = UnionFields::new(
+            [1, 2],
+            [
+                Field::new("a", ArrowDataType::Int32, true),
+                Field::new("b", ArrowDataType::Float64, true),
+            ],
+        );
+        let a = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let b = Float64Array::from(vec![None, Some(2.5), None]);
+        let children: Vec<ArrayRef> = vec![Arc::new(a), Arc::new(b)];
+        let type_ids = ScalarBuffer::from(vec![1_i8, 2, 1]);
+        let union = UnionArray::try_new(fields, type_ids, None, children).unwrap();
+        let batch = RecordBatch::try_from_iter([("u", Arc::new(union) as ArrayRef)]).unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let mut writer = ArrowWriter::try_new(&mut buf, batch.schema(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        assert_eq!(builder.schema().as_ref(), batch.schema().as_ref());
+        let read = builder.build().unwrap().next().unwrap().unwrap();
+        assert_eq!(read, batch);
+
+        // The type id and first child of the union
+        let builder = ParquetRecordBatchReaderBuilder::try_new(data.clone()).unwrap();
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), [0, 1]);
+        let file_metadata = builder.metadata().file_metadata();
+        let err = crate::arrow::parquet_to_arrow_schema_by_columns(
+            file_metadata.schema_descr(),
+            mask.clone(),
+            file_metadata.key_value_metadata(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: partial projection of union u is not supported"
+        );
+        let err = builder.with_projection(mask).build().err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: partial projection of UnionArray is not supported"
+        );
+
+        // Without the embedded arrow schema the union is read as a struct
+        let options = ArrowReaderOptions::new().with_skip_arrow_metadata(true);
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new_with_options(data.clone(), options).unwrap();
+        let expected = ArrowDataType::Struct(Fields::from(vec![
+            Field::new("type_id", ArrowDataType::Int8, false),
+            Field::new("a", ArrowDataType::Int32, true),
+            Field::new("b", ArrowDataType::Float64, true),
+        ]));
+        assert_eq!(builder.schema().field(0).data_type(), &expected);
+        let read = builder.build().unwrap().next().unwrap().unwrap();
+        assert_eq!(read.column(0).data_type(), &expected);
+
+        // Any of whose columns can be projected
+        let options = ArrowReaderOptions::new().with_skip_arrow_metadata(true);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(data, options).unwrap();
+        let mask = ProjectionMask::leaves(builder.parquet_schema(), [0, 1]);
+        let mut reader = builder.with_projection(mask).build().unwrap();
+        let expected = ArrowDataType::Struct(Fields::from(vec![
+            Field::new("type_id", ArrowDataType::Int8, false),
+            Field::new("a", ArrowDataType::Int32, true),
+        ]));
+        assert_eq!(reader.schema().field(0).data_type(), &expected);
+        let read = reader.next().unwrap().unwrap();
+        let s = read.column(0).as_struct();
+        assert_eq!(
+            s.column(0).as_primitive::<types::Int8Type>().values(),
+            &[1, 2, 1]
+        );
+    }
 }
@@ -0,0 +1,904 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`PruningPredicate`] for skipping row groups and data pages using statistics
+//! and bloom filters
+
+use crate::arrow::arrow_reader::statistics::StatisticsConverter;
+use crate::arrow::arrow_reader::{RowSelection, RowSelector};
+use crate::basic::Type as PhysicalType;
+use crate::bloom_filter::Sbbf;
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::ParquetMetaData;
+use crate::geospatial::BoundingBox;
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    Date32Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type, UInt16Type,
+    UInt32Type, UInt64Type, UInt8Type,
+};
+use arrow_array::{Array, ArrayRef, ArrowPrimitiveType, BooleanArray, Scalar, UInt64Array};
+use arrow_buffer::BooleanBuffer;
+use arrow_ord::cmp;
+use arrow_schema::{DataType, Schema};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A comparison operator of a [`PruningPredicate::Compare`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    /// `column = value`
+    Eq,
+    /// `column != value`
+    NotEq,
+    /// `column < value`
+    Lt,
+    /// `column <= value`
+    LtEq,
+    /// `column > value`
+    Gt,
+    /// `column >= value`
+    GtEq,
+}
+
+impl CompareOp {
+    /// Returns the operator matching exactly the rows this operator does not
+    fn negate(self) -> Self {
+        match self {
+            Self::Eq => Self::NotEq,
+            Self::NotEq => Self::Eq,
+            Self::Lt => Self::GtEq,
+            Self::LtEq => Self::Gt,
+            Self::Gt => Self::LtEq,
+            Self::GtEq => Self::Lt,
+        }
+    }
+}
+
+/// A predicate on the columns of a parquet file, used to skip row groups and
+/// data pages that can not contain any matching rows
+///
+/// A [`PruningPredicate`] is evaluated against the row group statistics and, if
+/// loaded, the [`PageIndex`] of the file, and can be provided to
+/// [`ArrowReaderBuilder::with_pruning_predicate`]. Unlike a [`RowFilter`] it does
+/// not decode any data, and rows that do not match the predicate may still be
+/// returned. Where statistics are missing a row group or page is never skipped.
+///
+/// Columns are identified by the name of a top-level field of the arrow schema,
+/// and values must be of the same type as that field (or the value type of a
+/// dictionary field). Note that, as the statistics of floating point columns do
+/// not include `NaN`, comparisons assume that `NaN` does not match any predicate.
+///
+/// Columns of [`GEOMETRY`] values can be tested for intersection with a
+/// [`BoundingBox`] using [`PruningPredicate::intersects`], which prunes row groups
+/// using their [`GeospatialStatistics`].
+///
+/// # Example
+/// ```
+/// # use arrow_array::{Int32Array, StringArray};
+/// # use parquet::arrow::arrow_reader::PruningPredicate;
+/// // a >= 10 AND (b IS NULL OR b = 'foo')
+/// let predicate = PruningPredicate::gt_eq("a", Int32Array::new_scalar(10)).and(
+///     PruningPredicate::is_null("b").or(PruningPredicate::eq("b", StringArray::new_scalar("foo"))),
+/// );
+/// ```
+///
+/// [`PageIndex`]: https://github.com/apache/parquet-format/blob/master/PageIndex.md
+/// [`ArrowReaderBuilder::with_pruning_predicate`]: crate::arrow::arrow_reader::ArrowReaderBuilder::with_pruning_predicate
+/// [`RowFilter`]: crate::arrow::arrow_reader::RowFilter
+/// [`GEOMETRY`]: crate::basic::LogicalType::Geometry
+/// [`GeospatialStatistics`]: crate::geospatial::GeospatialStatistics
+#[derive(Debug, Clone)]
+pub enum PruningPredicate {
+    /// Compares `column` against `value`, an array containing a single value
+    Compare {
+        /// The name of the column
+        column: String,
+        /// The comparison operator
+        op: CompareOp,
+        /// The value to compare against
+        value: ArrayRef,
+    },
+    /// Tests if `column` is equal to any of `values`
+    InList {
+        /// The name of the column
+        column: String,
+        /// The values to compare against
+        values: ArrayRef,
+        /// If true, tests if `column` is equal to none of `values`
+        negated: bool,
+    },
+    /// Tests if `column` is null
+    IsNull {
+        /// The name of the column
+        column: String,
+        /// If true, tests if `column` is not null
+        negated: bool,
+    },
+    /// Tests if the geometry of `column` may intersect `bbox`
+    Intersects {
+        /// The name of the column
+        column: String,
+        /// The bounding box to test against
+        bbox: BoundingBox,
+    },
+    /// Both predicates are true
+    And(Box<PruningPredicate>, Box<PruningPredicate>),
+    /// Either predicate is true
+    Or(Box<PruningPredicate>, Box<PruningPredicate>),
+    /// The predicate is false
+    Not(Box<PruningPredicate>),
+}
+
+impl PruningPredicate {
+    fn compare<T: Array + 'static>(
+        column: impl Into<String>,
+        op: CompareOp,
+        value: Scalar<T>,
+    ) -> Self {
+        Self::Compare {
+            column: column.into(),
+            op,
+            value: Arc::new(value.into_inner()),
+        }
+    }
+
+    /// Create a predicate for `column = value`
+    pub fn eq<T: Array + 'static>(column: impl Into<String>, value: Scalar<T>) -> Self {
+        Self::compare(column, CompareOp::Eq, value)
+    }
+
+    /// Create a predicate for `column != value`
+    pub fn not_eq<T: Array + 'static>(column: impl Into<String>, value: Scalar<T>) -> Self {
+        Self::compare(column, CompareOp::NotEq, value)
+    }
+
+    /// Create a predicate for `column < value`
+    pub fn lt<T: Array + 'static>(column: impl Into<String>, value: Scalar<T>) -> Self {
+        Self::compare(column, CompareOp::Lt, value)
+    }
+
+    /// Create a predicate for `column <= value`
+    pub fn lt_eq<T: Array + 'static>(column: impl Into<String>, value: Scalar<T>) -> Self {
+        Self::compare(column, CompareOp::LtEq, value)
+    }
+
+    /// Create a predicate for `column > value`
+    pub fn gt<T: Array + 'static>(column: impl Into<String>, value: Scalar<T>) -> Self {
+        Self::compare(column, CompareOp::Gt, value)
+    }
+
+    /// Create a predicate for `column >= value`
+    pub fn gt_eq<T: Array + 'static>(column: impl Into<String>, value: Scalar<T>) -> Self {
+        Self::compare(column, CompareOp::GtEq, value)
+    }
+
+    /// Create a predicate for `column IN (values)`
+    pub fn in_list(column: impl Into<String>, values: ArrayRef) -> Self {
+        Self::InList {
+            column: column.into(),
+            values,
+            negated: false,
+        }
+    }
+
+    /// Create a predicate for `column NOT IN (values)`
+    pub fn not_in_list(column: impl Into<String>, values: ArrayRef) -> Self {
+        Self::InList {
+            column: column.into(),
+            values,
+            negated: true,
+        }
+    }
+
+    /// Create a predicate for `column IS NULL`
+    pub fn is_null(column: impl Into<String>) -> Self {
+        Self::IsNull {
+            column: column.into(),
+            negated: false,
+        }
+    }
+
+    /// Create a predicate for `column IS NOT NULL`
+    pub fn is_not_null(column: impl Into<String>) -> Self {
+        Self::IsNull {
+            column: column.into(),
+            negated: true,
+        }
+    }
+
+    /// Create a predicate for the geometry of `column` intersecting `bbox`
+    pub fn intersects(column: impl Into<String>, bbox: BoundingBox) -> Self {
+        Self::Intersects {
+            column: column.into(),
+            bbox,
+        }
+    }
+
+    /// Create a predicate for `self AND other`
+    pub fn and(self, other: Self) -> Self {
+        Self::And(Box::new(self), Box::new(other))
+    }
+
+    /// Create a predicate for `self OR other`
+    pub fn or(self, other: Self) -> Self {
+        Self::Or(Box::new(self), Box::new(other))
+    }
+
+    /// Applies this predicate to the `row_groups` to read and their `selection`,
+    /// returning the row groups that may contain matching rows and a selection
+    /// skipping any data pages that do not
+    pub(crate) fn prune(
+        &self,
+        metadata: &ParquetMetaData,
+        schema: &Schema,
+        row_groups: Vec<usize>,
+        selection: Option<RowSelection>,
+    ) -> Result<(Vec<usize>, Option<RowSelection>)> {
+        let pruner = RowGroupPruner {
+            metadata,
+            schema,
+            row_groups: &row_groups,
+        };
+        let keep = self.evaluate(&pruner, false)?;
+        let (kept, selection) = retain_row_groups(metadata, &row_groups, selection, &keep);
+
+        let pruner = PagePruner::try_new(metadata, schema, &kept);
+        let page_selection = match pruner {
+            Some(pruner) => Some(self.evaluate(&pruner, false)?),
+            None => None,
+        };
+        let selection = match (selection, page_selection) {
+            (selection, Some(pages)) if pages.skipped_row_count() > 0 => match selection {
+                Some(selection) => Some(selection.intersection(&pages)),
+                None => Some(pages),
+            },
+            (selection, _) => selection,
+        };
+        Ok((kept, selection))
+    }
+
+    /// Returns the parquet leaf columns that this predicate tests for equality,
+    /// whose bloom filters may be used to prune row groups
+    #[cfg_attr(not(feature = "async"), allow(dead_code))]
+    pub(crate) fn bloom_filter_columns(
+        &self,
+        metadata: &ParquetMetaData,
+        schema: &Schema,
+    ) -> Result<Vec<usize>> {
+        let mut columns = self.evaluate(&BloomFilterColumns { metadata, schema }, false)?;
+        columns.sort_unstable();
+        columns.dedup();
+        Ok(columns)
+    }
+
+    /// Returns, for each of `row_groups`, false if the bloom filters in `filters`,
+    /// keyed by row group and parquet leaf column, rule out any matching rows
+    #[cfg_attr(not(feature = "async"), allow(dead_code))]
+    pub(crate) fn prune_bloom_filters(
+        &self,
+        metadata: &ParquetMetaData,
+        schema: &Schema,
+        row_groups: &[usize],
+        filters: &HashMap<(usize, usize), Sbbf>,
+    ) -> Result<BooleanBuffer> {
+        let pruner = BloomFilterPruner {
+            metadata,
+            schema,
+            row_groups,
+            filters,
+        };
+        self.evaluate(&pruner, false)
+    }
+
+    /// Evaluates this predicate, negated if `negated` is true, using `pruner`
+    ///
+    /// Negations are pushed down to the leaves of the predicate, where they can
+    /// be evaluated against the statistics
+    fn evaluate<P: Pruner>(&self, pruner: &P, negated: bool) -> Result<P::Output> {
+        match self {
+            Self::Compare { column, op, value } => {
+                let op = if negated { op.negate() } else { *op };
+                pruner.leaf(column, &Leaf::Compare(op, value))
+            }
+            Self::InList {
+                column,
+                values,
+                negated: n,
+            } => pruner.leaf(column, &Leaf::InList(values, *n != negated)),
+            Self::IsNull { column, negated: n } => {
+                pruner.leaf(column, &Leaf::IsNull(*n != negated))
+            }
+            Self::Intersects { column, bbox } => {
+                pruner.leaf(column, &Leaf::Intersects(bbox, negated))
+            }
+            Self::And(a, b) | Self::Or(a, b) => {
+                let a = a.evaluate(pruner, negated)?;
+                let b = b.evaluate(pruner, negated)?;
+                // NOT (a AND b) is (NOT a) OR (NOT b), and vice versa
+                Ok(match matches!(self, Self::And(_, _)) != negated {
+                    true => pruner.and(a, b),
+                    false => pruner.or(a, b),
+                })
+            }
+            Self::Not(p) => p.evaluate(pruner, !negated),
+        }
+    }
+}
+
+impl std::ops::Not for PruningPredicate {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::Not(Box::new(self))
+    }
+}
+
+/// Removes the row groups of `row_groups` for which `keep` is false, along with
+/// their rows in `selection`
+///
+/// The selection of each remaining row group is padded with skipped rows to the
+/// number of rows in the row group, so that it can be intersected with other
+/// selections of the remaining row groups
+pub(crate) fn retain_row_groups(
+    metadata: &ParquetMetaData,
+    row_groups: &[usize],
+    mut selection: Option<RowSelection>,
+    keep: &BooleanBuffer,
+) -> (Vec<usize>, Option<RowSelection>) {
+    let mut kept = Vec::with_capacity(row_groups.len());
+    let mut kept_selectors = vec![];
+    for (idx, keep) in row_groups.iter().zip(keep.iter()) {
+        let num_rows = metadata.row_group(*idx).num_rows() as usize;
+        let row_group_selection = selection.as_mut().map(|s| s.split_off(num_rows));
+        if keep {
+            kept.push(*idx);
+            if let Some(s) = row_group_selection {
+                let selected = s.row_count() + s.skipped_row_count();
+                kept_selectors.extend(s.iter().cloned());
+                kept_selectors.push(RowSelector::skip(num_rows - selected));
+            }
+        }
+    }
+    let selection = selection.map(|_| RowSelection::from(kept_selectors));
+    (kept, selection)
+}
+
+/// A predicate on a single column, with any negation applied
+enum Leaf<'a> {
+    Compare(CompareOp, &'a ArrayRef),
+    InList(&'a ArrayRef, bool),
+    IsNull(bool),
+    Intersects(&'a BoundingBox, bool),
+}
+
+/// The statistics of a column for a set of row groups or data pages
+struct Statistics {
+    mins: ArrayRef,
+    maxes: ArrayRef,
+    null_counts: UInt64Array,
+    row_counts: Option<UInt64Array>,
+}
+
+impl Statistics {
+    /// Returns, for each row group or page, false if it can not contain rows matching `leaf`
+    fn may_match(&self, leaf: &Leaf<'_>) -> Result<BooleanBuffer> {
+        match leaf {
+            Leaf::IsNull(false) => Ok(BooleanBuffer::collect_bool(self.len(), |i| {
+                self.null_counts.is_null(i) || self.null_counts.value(i) > 0
+            })),
+            Leaf::IsNull(true) => Ok(self.has_non_null()),
+            Leaf::Compare(op, value) => {
+                Ok(&self.compare(*op, self.check_value(value)?)? & &self.has_non_null())
+            }
+            Leaf::InList(values, negated) => {
+                self.check_value(values)?;
+                let op = if *negated {
+                    CompareOp::NotEq
+                } else {
+                    CompareOp::Eq
+                };
+                let mut result = BooleanBuffer::new_unset(self.len());
+                if *negated {
+                    result = !&result;
+                }
+                for i in 0..values.len() {
+                    let matches = self.compare(op, &values.slice(i, 1))?;
+                    result = match negated {
+                        true => &result & &matches,
+                        false => &result | &matches,
+                    };
+                }
+                Ok(&result & &self.has_non_null())
+            }
+            // Bounding boxes are only available in the row group statistics
+            Leaf::Intersects(_, _) => Ok(BooleanBuffer::new_set(self.len())),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.null_counts.len()
+    }
+
+    /// Returns false for each row group or page known to contain only nulls
+    fn has_non_null(&self) -> BooleanBuffer {
+        BooleanBuffer::collect_bool(self.len(), |i| match &self.row_counts {
+            Some(rows) if self.null_counts.is_valid(i) && rows.is_valid(i) => {
+                self.null_counts.value(i) < rows.value(i)
+            }
+            _ => true,
+        })
+    }
+
+    /// Returns `value` if it has the same type as the statistics
+    fn check_value<'a>(&self, value: &'a ArrayRef) -> Result<&'a ArrayRef> {
+        match value.data_type() == self.mins.data_type() {
+            true => Ok(value),
+            false => Err(arrow_err!(
+                "Cannot compare column of type {} with value of type {}",
+                self.mins.data_type(),
+                value.data_type()
+            )),
+        }
+    }
+
+    /// Returns, for each row group or page, false if no value `v` between the
+    /// minimum and maximum can satisfy `v op value`
+    fn compare(&self, op: CompareOp, value: &ArrayRef) -> Result<BooleanBuffer> {
+        let value = Scalar::new(value);
+        let (mins, maxes) = (&self.mins, &self.maxes);
+        Ok(match op {
+            CompareOp::Eq => {
+                &or_unknown(cmp::lt_eq(mins, &value)?) & &or_unknown(cmp::gt_eq(maxes, &value)?)
+            }
+            CompareOp::NotEq => {
+                &or_unknown(cmp::neq(mins, &value)?) | &or_unknown(cmp::neq(maxes, &value)?)
+            }
+            CompareOp::Lt => or_unknown(cmp::lt(mins, &value)?),
+            CompareOp::LtEq => or_unknown(cmp::lt_eq(mins, &value)?),
+            CompareOp::Gt => or_unknown(cmp::gt(maxes, &value)?),
+            CompareOp::GtEq => or_unknown(cmp::gt_eq(maxes, &value)?),
+        })
+    }
+}
+
+/// Returns the values of `a`, treating nulls, where statistics are unknown, as true
+fn or_unknown(a: BooleanArray) -> BooleanBuffer {
+    match a.nulls() {
+        Some(nulls) => a.values() | &!nulls.inner(),
+        None => a.values().clone(),
+    }
+}
+
+/// Evaluates a [`PruningPredicate`] for a set of row groups or data pages
+trait Pruner {
+    type Output;
+
+    /// Evaluates `leaf` for `column`
+    fn leaf(&self, column: &str, leaf: &Leaf<'_>) -> Result<Self::Output>;
+
+    fn and(&self, a: Self::Output, b: Self::Output) -> Self::Output;
+
+    fn or(&self, a: Self::Output, b: Self::Output) -> Self::Output;
+}
+
+/// A [`Pruner`] evaluating the row group statistics of `row_groups`
+struct RowGroupPruner<'a> {
+    metadata: &'a ParquetMetaData,
+    schema: &'a Schema,
+    row_groups: &'a [usize],
+}
+
+impl Pruner for RowGroupPruner<'_> {
+    type Output = BooleanBuffer;
+
+    fn leaf(&self, column: &str, leaf: &Leaf<'_>) -> Result<BooleanBuffer> {
+        let parquet_schema = self.metadata.file_metadata().schema_descr();
+        let converter = StatisticsConverter::try_new(column, self.schema, parquet_schema)?
+            .with_missing_null_counts_as_zero(false);
+        let row_groups = || self.row_groups.iter().map(|i| self.metadata.row_group(*i));
+
+        if let Leaf::Intersects(bbox, negated) = leaf {
+            let column_index = converter.parquet_column_index();
+            return Ok(row_groups()
+                .map(|rg| {
+                    let stats = column_index.and_then(|i| rg.column(i).geo_statistics());
+                    match stats.and_then(|s| s.bbox()) {
+                        Some(b) => *negated || b.intersects(bbox),
+                        None => true,
+                    }
+                })
+                .collect());
+        }
+
+        let statistics = Statistics {
+            mins: converter.row_group_mins(row_groups())?,
+            maxes: converter.row_group_maxes(row_groups())?,
+            null_counts: converter.row_group_null_counts(row_groups())?,
+            row_counts: converter.row_group_row_counts(row_groups())?,
+        };
+        statistics.may_match(leaf)
+    }
+
+    fn and(&self, a: BooleanBuffer, b: BooleanBuffer) -> BooleanBuffer {
+        &a & &b
+    }
+
+    fn or(&self, a: BooleanBuffer, b: BooleanBuffer) -> BooleanBuffer {
+        &a | &b
+    }
+}
+
+/// A [`Pruner`] evaluating the page index of `row_groups`
+struct PagePruner<'a> {
+    metadata: &'a ParquetMetaData,
+    schema: &'a Schema,
+    row_groups: &'a [usize],
+    /// The total number of rows in `row_groups`
+    num_rows: usize,
+}
+
+impl<'a> PagePruner<'a> {
+    /// Returns a [`PagePruner`], or `None` if the page index is not available
+    /// for all `row_groups`
+    fn try_new(
+        metadata: &'a ParquetMetaData,
+        schema: &'a Schema,
+        row_groups: &'a [usize],
+    ) -> Option<Self> {
+        let column_index = metadata.column_index()?;
+        let offset_index = metadata.offset_index()?;
+        let loaded = row_groups
+            .iter()
+            .all(|i| !column_index[*i].is_empty() && !offset_index[*i].is_empty());
+
+        (loaded && !row_groups.is_empty()).then(|| Self {
+            metadata,
+            schema,
+            row_groups,
+            num_rows: row_groups
+                .iter()
+                .map(|i| metadata.row_group(*i).num_rows() as usize)
+                .sum(),
+        })
+    }
+}
+
+impl Pruner for PagePruner<'_> {
+    type Output = RowSelection;
+
+    fn leaf(&self, column: &str, leaf: &Leaf<'_>) -> Result<RowSelection> {
+        let parquet_schema = self.metadata.file_metadata().schema_descr();
+        let converter = StatisticsConverter::try_new(column, self.schema, parquet_schema)?
+            .with_missing_null_counts_as_zero(false);
+        let column_index = self.metadata.column_index().unwrap();
+        let offset_index = self.metadata.offset_index().unwrap();
+
+        let row_counts = match converter.parquet_column_index() {
+            Some(_) => converter.data_page_row_counts(
+                offset_index,
+                self.metadata.row_groups(),
+                self.row_groups,
+            )?,
+            None => None,
+        };
+        let Some(row_counts) = row_counts else {
+            return Ok(RowSelection::from(vec![RowSelector::select(self.num_rows)]));
+        };
+
+        let statistics = Statistics {
+            mins: converter.data_page_mins(column_index, offset_index, self.row_groups)?,
+            maxes: converter.data_page_maxes(column_index, offset_index, self.row_groups)?,
+            null_counts: converter.data_page_null_counts(
+                column_index,
+                offset_index,
+                self.row_groups,
+            )?,
+            row_counts: Some(row_counts.clone()),
+        };
+        let may_match = statistics.may_match(leaf)?;
+
+        Ok(row_counts
+            .values()
+            .iter()
+            .zip(may_match.iter())
+            .map(|(rows, may_match)| match may_match {
+                true => RowSelector::select(*rows as usize),
+                false => RowSelector::skip(*rows as usize),
+            })
+            .collect())
+    }
+
+    fn and(&self, a: RowSelection, b: RowSelection) -> RowSelection {
+        a.intersection(&b)
+    }
+
+    fn or(&self, a: RowSelection, b: RowSelection) -> RowSelection {
+        a.union(&b)
+    }
+}
+
+/// A [`Pruner`] collecting the parquet leaf columns tested for equality
+#[cfg_attr(not(feature = "async"), allow(dead_code))]
+struct BloomFilterColumns<'a> {
+    metadata: &'a ParquetMetaData,
+    schema: &'a Schema,
+}
+
+impl Pruner for BloomFilterColumns<'_> {
+    type Output = Vec<usize>;
+
+    fn leaf(&self, column: &str, leaf: &Leaf<'_>) -> Result<Vec<usize>> {
+        if !matches!(
+            leaf,
+            Leaf::Compare(CompareOp::Eq, _) | Leaf::InList(_, false)
+        ) {
+            return Ok(vec![]);
+        }
+        let parquet_schema = self.metadata.file_metadata().schema_descr();
+        let converter = StatisticsConverter::try_new(column, self.schema, parquet_schema)?;
+        Ok(converter.parquet_column_index().into_iter().collect())
+    }
+
+    fn and(&self, mut a: Vec<usize>, b: Vec<usize>) -> Vec<usize> {
+        a.extend(b);
+        a
+    }
+
+    fn or(&self, a: Vec<usize>, b: Vec<usize>) -> Vec<usize> {
+        self.and(a, b)
+    }
+}
+
+/// A [`Pruner`] probing the bloom filters of `row_groups` for equality predicates
+#[cfg_attr(not(feature = "async"), allow(dead_code))]
+struct BloomFilterPruner<'a> {
+    metadata: &'a ParquetMetaData,
+    schema: &'a Schema,
+    row_groups: &'a [usize],
+    filters: &'a HashMap<(usize, usize), Sbbf>,
+}
+
+impl Pruner for BloomFilterPruner<'_> {
+    type Output = BooleanBuffer;
+
+    fn leaf(&self, column: &str, leaf: &Leaf<'_>) -> Result<BooleanBuffer> {
+        let all = || BooleanBuffer::new_set(self.row_groups.len());
+        let values = match leaf {
+            Leaf::Compare(CompareOp::Eq, value) => value,
+            Leaf::InList(values, false) => values,
+            _ => return Ok(all()),
+        };
+
+        let parquet_schema = self.metadata.file_metadata().schema_descr();
+        let converter = StatisticsConverter::try_new(column, self.schema, parquet_schema)?;
+        let Some(idx) = converter.parquet_column_index() else {
+            return Ok(all());
+        };
+        let physical_type = parquet_schema.column(idx).physical_type();
+        let Some(values) = bloom_filter_values(values.as_ref(), physical_type) else {
+            return Ok(all());
+        };
+
+        Ok(BooleanBuffer::collect_bool(
+            self.row_groups.len(),
+            |i| match self.filters.get(&(self.row_groups[i], idx)) {
+                Some(filter) => values.iter().any(|v| filter.check(v)),
+                None => true,
+            },
+        ))
+    }
+
+    fn and(&self, a: BooleanBuffer, b: BooleanBuffer) -> BooleanBuffer {
+        &a & &b
+    }
+
+    fn or(&self, a: BooleanBuffer, b: BooleanBuffer) -> BooleanBuffer {
+        &a | &b
+    }
+}
+
+/// Returns the non-null `values` as the bytes hashed into the bloom filter of a
+/// column of `physical_type`, or `None` if they can not be probed
+#[cfg_attr(not(feature = "async"), allow(dead_code))]
+fn bloom_filter_values(values: &dyn Array, physical_type: PhysicalType) -> Option<Vec<Vec<u8>>> {
+    fn primitive<T: ArrowPrimitiveType, const N: usize>(
+        values: &dyn Array,
+        f: impl Fn(T::Native) -> [u8; N],
+    ) -> Vec<Vec<u8>> {
+        let values = values.as_primitive::<T>();
+        values.iter().flatten().map(|v| f(v).to_vec()).collect()
+    }
+
+    fn bytes<'a>(values: impl Iterator<Item = Option<&'a [u8]>>) -> Vec<Vec<u8>> {
+        values.flatten().map(|v| v.to_vec()).collect()
+    }
+
+    // Values are hashed as the native bytes of their physical type, with unsigned
+    // integers reinterpreted as signed integers of the same width
+    Some(match (physical_type, values.data_type()) {
+        (PhysicalType::INT32, DataType::Int8) => {
+            primitive::<Int8Type, 4>(values, |v| (v as i32).to_ne_bytes())
+        }
+        (PhysicalType::INT32, DataType::Int16) => {
+            primitive::<Int16Type, 4>(values, |v| (v as i32).to_ne_bytes())
+        }
+        (PhysicalType::INT32, DataType::Int32) => {
+            primitive::<Int32Type, 4>(values, i32::to_ne_bytes)
+        }
+        (PhysicalType::INT32, DataType::UInt8) => {
+            primitive::<UInt8Type, 4>(values, |v| (v as i32).to_ne_bytes())
+        }
+        (PhysicalType::INT32, DataType::UInt16) => {
+            primitive::<UInt16Type, 4>(values, |v| (v as i32).to_ne_bytes())
+        }
+        (PhysicalType::INT32, DataType::UInt32) => {
+            primitive::<UInt32Type, 4>(values, |v| (v as i32).to_ne_bytes())
+        }
+        (PhysicalType::INT32, DataType::Date32) => {
+            primitive::<Date32Type, 4>(values, i32::to_ne_bytes)
+        }
+        (PhysicalType::INT64, DataType::Int64) => {
+            primitive::<Int64Type, 8>(values, i64::to_ne_bytes)
+        }
+        (PhysicalType::INT64, DataType::UInt64) => {
+            primitive::<UInt64Type, 8>(values, |v| (v as i64).to_ne_bytes())
+        }
+        (PhysicalType::FLOAT, DataType::Float32) => {
+            primitive::<Float32Type, 4>(values, f32::to_ne_bytes)
+        }
+        (PhysicalType::DOUBLE, DataType::Float64) => {
+            primitive::<Float64Type, 8>(values, f64::to_ne_bytes)
+        }
+        (PhysicalType::BOOLEAN, DataType::Boolean) => {
+            let values = values.as_boolean();
+            values.iter().flatten().map(|v| vec![v as u8]).collect()
+        }
+        (PhysicalType::BYTE_ARRAY, DataType::Utf8) => bytes(
+            values
+                .as_string::<i32>()
+                .iter()
+                .map(|v| v.map(str::as_bytes)),
+        ),
+        (PhysicalType::BYTE_ARRAY, DataType::LargeUtf8) => bytes(
+            values
+                .as_string::<i64>()
+                .iter()
+                .map(|v| v.map(str::as_bytes)),
+        ),
+        (PhysicalType::BYTE_ARRAY, DataType::Utf8View) => {
+            bytes(values.as_string_view().iter().map(|v| v.map(str::as_bytes)))
+        }
+        (PhysicalType::BYTE_ARRAY, DataType::Binary) => bytes(values.as_binary::<i32>().iter()),
+        (PhysicalType::BYTE_ARRAY, DataType::LargeBinary) => {
+            bytes(values.as_binary::<i64>().iter())
+        }
+        (PhysicalType::BYTE_ARRAY, DataType::BinaryView) => bytes(values.as_binary_view().iter()),
+        (PhysicalType::FIXED_LEN_BYTE_ARRAY, DataType::FixedSizeBinary(_)) => {
+            bytes(values.as_fixed_size_binary().iter())
+        }
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::{Int32Array, Int8Array, StringArray, StringViewArray, UInt32Array};
+
+    fn statistics() -> Statistics {
+        // Four containers of 10 rows with values in [0, 9], [10, 19], unknown and all nulls
+        Statistics {
+            mins: Arc::new(Int32Array::from(vec![Some(0), Some(10), None, None])),
+            maxes: Arc::new(Int32Array::from(vec![Some(9), Some(19), None, None])),
+            null_counts: UInt64Array::from(vec![Some(0), Some(2), None, Some(10)]),
+            row_counts: Some(UInt64Array::from(vec![10, 10, 10, 10])),
+        }
+    }
+
+    fn may_match(leaf: Leaf<'_>) -> Vec<bool> {
+        statistics().may_match(&leaf).unwrap().iter().collect()
+    }
+
+    #[test]
+    fn test_compare() {
+        let value: ArrayRef = Arc::new(Int32Array::from(vec![9]));
+        let cases = [
+            (CompareOp::Eq, [true, false, true, false]),
+            (CompareOp::NotEq, [true, true, true, false]),
+            (CompareOp::Lt, [true, false, true, false]),
+            (CompareOp::LtEq, [true, false, true, false]),
+            (CompareOp::Gt, [false, true, true, false]),
+            (CompareOp::GtEq, [true, true, true, false]),
+        ];
+        for (op, expected) in cases {
+            assert_eq!(may_match(Leaf::Compare(op, &value)), expected, "{op:?}");
+        }
+
+        let value: ArrayRef = Arc::new(Int32Array::from(vec![10]));
+        let eq = may_match(Leaf::Compare(CompareOp::Eq, &value));
+        assert_eq!(eq, [false, true, true, false]);
+        let lt = may_match(Leaf::Compare(CompareOp::Lt, &value));
+        assert_eq!(lt, [true, false, true, false]);
+
+        let statistics = Statistics {
+            mins: Arc::new(Int32Array::from(vec![5])),
+            maxes: Arc::new(Int32Array::from(vec![5])),
+            null_counts: UInt64Array::from(vec![0]),
+            row_counts: None,
+        };
+        let value: ArrayRef = Arc::new(Int32Array::from(vec![5]));
+        let neq = statistics.may_match(&Leaf::Compare(CompareOp::NotEq, &value));
+        assert_eq!(neq.unwrap().iter().collect::<Vec<_>>(), [false]);
+    }
+
+    #[test]
+    fn test_in_list_and_is_null() {
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![3, 25]));
+        assert_eq!(
+            may_match(Leaf::InList(&values, false)),
+            [true, false, true, false]
+        );
+        assert_eq!(
+            may_match(Leaf::InList(&values, true)),
+            [true, true, true, false]
+        );
+
+        assert_eq!(may_match(Leaf::IsNull(false)), [false, true, true, true]);
+        assert_eq!(may_match(Leaf::IsNull(true)), [true, true, true, false]);
+    }
+
+    #[test]
+    fn test_type_mismatch() {
+        let value: ArrayRef = Arc::new(StringArray::from(vec!["a"]));
+        let err = statistics()
+            .may_match(&Leaf::Compare(CompareOp::Eq, &value))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Cannot compare column of type Int32 with value of type Utf8"
+        );
+    }
+
+    #[test]
+    fn test_bloom_filter_values() {
+        let mut filter = Sbbf::new_with_ndv_fpp(10, 0.01).unwrap();
+        filter.insert(&-5_i32);
+        filter.insert(&(u32::MAX as i32));
+        filter.insert(&i64::MAX);
+        filter.insert("foo");
+
+        let probe = |values: &dyn Array, physical_type| -> Vec<bool> {
+            let values = bloom_filter_values(values, physical_type).unwrap();
+            values.iter().map(|v| filter.check(v)).collect()
+        };
+
+        let values = Int8Array::from(vec![Some(-5), None, Some(3)]);
+        assert_eq!(probe(&values, PhysicalType::INT32), vec![true, false]);
+
+        let values = UInt32Array::from(vec![u32::MAX, 5]);
+        assert_eq!(probe(&values, PhysicalType::INT32), vec![true, false]);
+
+        let values = UInt64Array::from(vec![i64::MAX as u64]);
+        assert_eq!(probe(&values, PhysicalType::INT64), vec![true]);
+
+        let values = StringViewArray::from(vec!["foo", "bar"]);
+        assert_eq!(probe(&values, PhysicalType::BYTE_ARRAY), vec![true, false]);
+
+        let values = Int32Array::from(vec![-5]);
+        assert!(bloom_filter_values(&values, PhysicalType::INT64).is_none());
+    }
+}
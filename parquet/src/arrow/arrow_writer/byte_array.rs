@@ -19,10 +19,12 @@ use crate::basic::Encoding;
 use crate::bloom_filter::Sbbf;
 use crate::column::writer::encoder::{ColumnValueEncoder, DataPageValues, DictionaryPage};
 use crate::data_type::{AsBytes, ByteArray, Int32Type};
+use crate::distinct_count::HyperLogLog;
 use crate::encodings::encoding::{DeltaBitPackEncoder, Encoder};
 use crate::encodings::rle::RleEncoder;
 use crate::errors::{ParquetError, Result};
 use crate::file::properties::{EnabledStatistics, WriterProperties, WriterVersion};
+use crate::geospatial::{GeospatialAccumulator, GeospatialStatistics};
 use crate::schema::types::ColumnDescPtr;
 use crate::util::bit_util::num_required_bits;
 use crate::util::interner::{Interner, Storage};
@@ -421,6 +423,8 @@ pub struct ByteArrayEncoder {
     min_value: Option<ByteArray>,
     max_value: Option<ByteArray>,
     bloom_filter: Option<Sbbf>,
+    distinct_count_sketch: Option<HyperLogLog>,
+    geo_stats_accumulator: Option<GeospatialAccumulator>,
 }
 
 impl ColumnValueEncoder for ByteArrayEncoder {
@@ -430,6 +434,14 @@ impl ColumnValueEncoder for ByteArrayEncoder {
         self.bloom_filter.take()
     }
 
+    fn flush_distinct_count_sketch(&mut self) -> Option<HyperLogLog> {
+        self.distinct_count_sketch.take()
+    }
+
+    fn flush_geospatial_statistics(&mut self) -> Option<Box<GeospatialStatistics>> {
+        self.geo_stats_accumulator.take()?.finish().map(Box::new)
+    }
+
     fn try_new(descr: &ColumnDescPtr, props: &WriterProperties) -> Result<Self>
     where
         Self: Sized,
@@ -445,12 +457,23 @@ impl ColumnValueEncoder for ByteArrayEncoder {
             .map(|props| Sbbf::new_with_ndv_fpp(props.ndv, props.fpp))
             .transpose()?;
 
+        let distinct_count_sketch = props
+            .distinct_count_enabled(descr.path())
+            .then(|| HyperLogLog::try_new(props.distinct_count_precision()))
+            .transpose()?;
+
         let statistics_enabled = props.statistics_enabled(descr.path());
 
+        let geo_stats_accumulator = (statistics_enabled != EnabledStatistics::None)
+            .then(|| GeospatialAccumulator::try_new(descr))
+            .flatten();
+
         Ok(Self {
             fallback,
             statistics_enabled,
             bloom_filter,
+            distinct_count_sketch,
+            geo_stats_accumulator,
             dict_encoder: dictionary,
             min_value: None,
             max_value: None,
@@ -491,10 +514,16 @@ impl ColumnValueEncoder for ByteArrayEncoder {
             .map(|bf| bf.estimated_memory_size())
             .unwrap_or_default();
 
+        let sketch_size = self
+            .distinct_count_sketch
+            .as_ref()
+            .map(|sketch| sketch.estimated_memory_size())
+            .unwrap_or_default();
+
         let stats_size = self.min_value.as_ref().map(|v| v.len()).unwrap_or_default()
             + self.max_value.as_ref().map(|v| v.len()).unwrap_or_default();
 
-        encoder_size + bloom_filter_size + stats_size
+        encoder_size + bloom_filter_size + sketch_size + stats_size
     }
 
     fn estimated_dict_page_size(&self) -> Option<usize> {
@@ -566,6 +595,18 @@ where
         }
     }
 
+    if let Some(sketch) = &mut encoder.distinct_count_sketch {
+        for idx in indices {
+            sketch.insert(values.value(*idx).as_ref());
+        }
+    }
+
+    if let Some(accumulator) = &mut encoder.geo_stats_accumulator {
+        for idx in indices {
+            accumulator.update(values.value(*idx).as_ref());
+        }
+    }
+
     match &mut encoder.dict_encoder {
         Some(dict_encoder) => dict_encoder.encode(values, indices),
         None => encoder.fallback.encode(values, indices),
@@ -0,0 +1,514 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Content-defined chunking of leaf columns into data pages, see [`ContentDefinedChunker`]
+
+use crate::arrow::arrow_writer::gearhash::{GEARHASH_TABLES, NUM_GEARHASH_TABLES};
+use crate::arrow::arrow_writer::levels::ArrayLevels;
+use crate::errors::{ParquetError, Result};
+use crate::file::properties::CdcOptions;
+use arrow_array::cast::AsArray;
+use arrow_array::{Array, BinaryViewArray, BooleanArray, FixedSizeBinaryArray, StringViewArray};
+use arrow_buffer::Buffer;
+use arrow_schema::DataType;
+
+/// A range of the levels and values of a leaf column to write to a data page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Chunk {
+    /// The offset of the first level
+    pub level_offset: usize,
+    /// The number of levels
+    pub num_levels: usize,
+    /// The offset of the first non-null value
+    pub value_offset: usize,
+    /// The number of non-null values
+    pub num_values: usize,
+}
+
+/// Splits the levels and values of a leaf column into chunks at positions determined by
+/// their content, so that the same data produces the same chunks regardless of the
+/// data written before it
+///
+/// A gear hash is rolled over the bytes of the definition and repetition levels and of
+/// the non-null values. Once a chunk has reached the minimum size, a boundary is placed
+/// at the start of the next record after the hash has matched the mask with each of the
+/// [`NUM_GEARHASH_TABLES`] tables in turn, which makes the chunk sizes more evenly
+/// distributed than a single match would. A boundary is also forced once a chunk
+/// reaches the maximum size.
+///
+/// The state is kept across calls to [`Self::get_chunks`], so the chunks of a column
+/// written in multiple batches do not depend on the batch boundaries.
+#[derive(Debug)]
+pub(crate) struct ContentDefinedChunker {
+    min_chunk_size: usize,
+    max_chunk_size: usize,
+    /// The rolling hash matches when all the bits in the mask are zero
+    rolling_hash_mask: u64,
+    rolling_hash: u64,
+    /// Whether the rolling hash has matched since the last call to `need_new_chunk`
+    has_matched: bool,
+    /// The index of the gear hash table in use
+    nth_run: usize,
+    /// The number of bytes rolled since the last boundary
+    chunk_size: usize,
+}
+
+impl ContentDefinedChunker {
+    /// Creates a new chunker from the given [`CdcOptions`]
+    pub fn try_new(options: &CdcOptions) -> Result<Self> {
+        Ok(Self {
+            min_chunk_size: options.min_chunk_size,
+            max_chunk_size: options.max_chunk_size,
+            rolling_hash_mask: calculate_mask(options)?,
+            rolling_hash: 0,
+            has_matched: false,
+            nth_run: 0,
+            chunk_size: 0,
+        })
+    }
+
+    /// Updates the rolling hash with `bytes`
+    fn roll(&mut self, bytes: &[u8]) {
+        self.chunk_size += bytes.len();
+        if self.chunk_size < self.min_chunk_size {
+            return;
+        }
+        let table = &GEARHASH_TABLES[self.nth_run];
+        for byte in bytes {
+            self.rolling_hash = (self.rolling_hash << 1).wrapping_add(table[*byte as usize]);
+            self.has_matched |= self.rolling_hash & self.rolling_hash_mask == 0;
+        }
+    }
+
+    /// Returns true if a boundary should be placed before the next record
+    fn need_new_chunk(&mut self) -> bool {
+        if self.has_matched {
+            self.has_matched = false;
+            self.nth_run += 1;
+            if self.nth_run >= NUM_GEARHASH_TABLES {
+                self.nth_run = 0;
+                self.chunk_size = 0;
+                return true;
+            }
+        }
+        if self.chunk_size >= self.max_chunk_size {
+            self.chunk_size = 0;
+            return true;
+        }
+        false
+    }
+
+    /// Returns the chunks of `levels`
+    ///
+    /// Each chunk except the last ends at a boundary, while the last chunk should be
+    /// continued by the data of the next call. The first chunk is empty if a boundary
+    /// is placed before the first record.
+    pub fn get_chunks(&mut self, levels: &ArrayLevels) -> Result<Vec<Chunk>> {
+        let def_levels = levels.def_levels();
+        let rep_levels = levels.rep_levels();
+        let max_def_level = levels.max_def_level();
+        let indices = levels.non_null_indices();
+        let num_levels = match def_levels {
+            Some(def_levels) => def_levels.len(),
+            None => indices.len(),
+        };
+
+        let values = LeafValues::try_new(levels.array().as_ref())?;
+
+        let mut chunks = vec![];
+        let mut prev_level_offset = 0;
+        let mut prev_value_offset = 0;
+        let mut value_offset = 0;
+        for level_offset in 0..num_levels {
+            let def_level = def_levels.map(|levels| levels[level_offset]);
+            let rep_level = rep_levels.map(|levels| levels[level_offset]);
+            if let Some(def_level) = def_level {
+                self.roll(&def_level.to_le_bytes());
+            }
+            if let Some(rep_level) = rep_level {
+                self.roll(&rep_level.to_le_bytes());
+            }
+
+            let is_value = def_level.is_none_or(|level| level == max_def_level);
+            if is_value {
+                values.roll(self, indices[value_offset]);
+            }
+
+            // Boundaries can only be placed at the start of a record
+            if rep_level.is_none_or(|level| level == 0) && self.need_new_chunk() {
+                let num_levels = level_offset - prev_level_offset;
+                if num_levels > 0 || level_offset == 0 {
+                    chunks.push(Chunk {
+                        level_offset: prev_level_offset,
+                        num_levels,
+                        value_offset: prev_value_offset,
+                        num_values: value_offset - prev_value_offset,
+                    });
+                    prev_level_offset = level_offset;
+                    prev_value_offset = value_offset;
+                }
+            }
+
+            if is_value {
+                value_offset += 1;
+            }
+        }
+
+        if num_levels > prev_level_offset {
+            chunks.push(Chunk {
+                level_offset: prev_level_offset,
+                num_levels: num_levels - prev_level_offset,
+                value_offset: prev_value_offset,
+                num_values: value_offset - prev_value_offset,
+            });
+        }
+        Ok(chunks)
+    }
+}
+
+/// Calculates the mask of the rolling hash that results in chunks of the average of
+/// the minimum and maximum sizes
+fn calculate_mask(options: &CdcOptions) -> Result<u64> {
+    if options.max_chunk_size <= options.min_chunk_size {
+        return Err(general_err!(
+            "Content-defined chunking max_chunk_size ({}) must be greater than min_chunk_size ({})",
+            options.max_chunk_size,
+            options.min_chunk_size
+        ));
+    }
+
+    let avg_chunk_size = (options.min_chunk_size + options.max_chunk_size) / 2;
+    // No boundary is placed in the first `min_chunk_size` bytes of a chunk, and the hash
+    // must match once with each table, so the target size of each match is smaller
+    let target_size = (avg_chunk_size - options.min_chunk_size) / NUM_GEARHASH_TABLES;
+    let mask_bits = (usize::BITS - target_size.leading_zeros()).saturating_sub(1) as i64;
+
+    // Increasing the norm level decreases the number of bits to match, making a match
+    // more likely and chunk sizes closer to the minimum size
+    let effective_bits = mask_bits - options.norm_level as i64;
+    if !(1..=63).contains(&effective_bits) {
+        return Err(general_err!(
+            "Content-defined chunking requires between 1 and 63 mask bits, got {} for min_chunk_size {}, max_chunk_size {} and norm_level {}",
+            effective_bits,
+            options.min_chunk_size,
+            options.max_chunk_size,
+            options.norm_level
+        ));
+    }
+    Ok(u64::MAX << (64 - effective_bits))
+}
+
+/// The values of a leaf array, as bytes to roll into the hash
+enum LeafValues<'a> {
+    /// Arrays with no values to roll, such as [`DataType::Null`]
+    Empty,
+    Boolean(&'a BooleanArray),
+    /// Values of a fixed width, starting at the offset of the array
+    Fixed {
+        data: Buffer,
+        width: usize,
+    },
+    Offsets32 {
+        offsets: &'a [i32],
+        data: &'a [u8],
+    },
+    Offsets64 {
+        offsets: &'a [i64],
+        data: &'a [u8],
+    },
+    BinaryView(&'a BinaryViewArray),
+    Utf8View(&'a StringViewArray),
+    FixedSizeBinary(&'a FixedSizeBinaryArray),
+    /// The values of a dictionary, indexed by its keys
+    Dictionary {
+        keys: Vec<usize>,
+        values: Box<LeafValues<'a>>,
+    },
+}
+
+impl<'a> LeafValues<'a> {
+    /// Creates the [`LeafValues`] of `array`
+    fn try_new(array: &'a dyn Array) -> Result<Self> {
+        Ok(match array.data_type() {
+            DataType::Null => Self::Empty,
+            DataType::Boolean => Self::Boolean(array.as_boolean()),
+            DataType::Binary => {
+                let array = array.as_binary::<i32>();
+                Self::Offsets32 {
+                    offsets: array.value_offsets(),
+                    data: array.value_data(),
+                }
+            }
+            DataType::Utf8 => {
+                let array = array.as_string::<i32>();
+                Self::Offsets32 {
+                    offsets: array.value_offsets(),
+                    data: array.value_data(),
+                }
+            }
+            DataType::LargeBinary => {
+                let array = array.as_binary::<i64>();
+                Self::Offsets64 {
+                    offsets: array.value_offsets(),
+                    data: array.value_data(),
+                }
+            }
+            DataType::LargeUtf8 => {
+                let array = array.as_string::<i64>();
+                Self::Offsets64 {
+                    offsets: array.value_offsets(),
+                    data: array.value_data(),
+                }
+            }
+            DataType::BinaryView => Self::BinaryView(array.as_binary_view()),
+            DataType::Utf8View => Self::Utf8View(array.as_string_view()),
+            DataType::FixedSizeBinary(_) => Self::FixedSizeBinary(array.as_fixed_size_binary()),
+            // The values are rolled rather than the keys, so that the chunks do not depend
+            // on the dictionary the values were encoded with
+            DataType::Dictionary(_, _) => {
+                let dictionary = array.as_any_dictionary();
+                match dictionary.values().is_empty() {
+                    // All keys are null
+                    true => Self::Empty,
+                    false => Self::Dictionary {
+                        keys: dictionary.normalized_keys(),
+                        values: Box::new(Self::try_new(dictionary.values().as_ref())?),
+                    },
+                }
+            }
+            data_type => match data_type.primitive_width() {
+                Some(width) => {
+                    let data = array.to_data();
+                    Self::Fixed {
+                        data: data.buffers()[0].slice(data.offset() * width),
+                        width,
+                    }
+                }
+                None => {
+                    return Err(nyi_err!(
+                        "Content-defined chunking of {} columns",
+                        data_type
+                    ))
+                }
+            },
+        })
+    }
+
+    /// Rolls the bytes of the value at `index` into the hash of `chunker`
+    fn roll(&self, chunker: &mut ContentDefinedChunker, index: usize) {
+        match self {
+            Self::Empty => {}
+            Self::Boolean(array) => chunker.roll(&[array.value(index) as u8]),
+            Self::Fixed { data, width } => chunker.roll(&data[index * width..(index + 1) * width]),
+            Self::Offsets32 { offsets, data } => {
+                chunker.roll(&data[offsets[index] as usize..offsets[index + 1] as usize])
+            }
+            Self::Offsets64 { offsets, data } => {
+                chunker.roll(&data[offsets[index] as usize..offsets[index + 1] as usize])
+            }
+            Self::BinaryView(array) => chunker.roll(array.value(index)),
+            Self::Utf8View(array) => chunker.roll(array.value(index).as_bytes()),
+            Self::FixedSizeBinary(array) => chunker.roll(array.value(index)),
+            Self::Dictionary { keys, values } => values.roll(chunker, keys[index]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrow::arrow_writer::levels::calculate_array_levels;
+    use arrow_array::builder::{Int64Builder, ListBuilder};
+    use arrow_array::{ArrayRef, Int64Array};
+    use arrow_schema::Field;
+    use rand::{Rng, SeedableRng};
+    use std::sync::Arc;
+
+    fn cdc_options(min_chunk_size: usize, max_chunk_size: usize) -> CdcOptions {
+        CdcOptions {
+            min_chunk_size,
+            max_chunk_size,
+            norm_level: 0,
+        }
+    }
+
+    fn random_values(len: usize) -> Vec<i64> {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        (0..len).map(|_| rng.random()).collect()
+    }
+
+    fn get_chunks(chunker: &mut ContentDefinedChunker, array: ArrayRef) -> Vec<Chunk> {
+        let field = Field::new("a", array.data_type().clone(), true);
+        let mut levels = calculate_array_levels(&array, &field).unwrap();
+        chunker.get_chunks(&levels.remove(0)).unwrap()
+    }
+
+    #[test]
+    fn test_calculate_mask() {
+        // avg 640 KiB, target (640 - 256) KiB / 8 = 48 KiB, requiring 15 bits
+        let mask = calculate_mask(&CdcOptions::default()).unwrap();
+        assert_eq!(mask, u64::MAX << 49);
+
+        let options = CdcOptions {
+            norm_level: 2,
+            ..Default::default()
+        };
+        assert_eq!(calculate_mask(&options).unwrap(), u64::MAX << 51);
+
+        let err = calculate_mask(&cdc_options(1024, 1024)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Content-defined chunking max_chunk_size (1024) must be greater than min_chunk_size (1024)"
+        );
+
+        let options = CdcOptions {
+            norm_level: 15,
+            ..Default::default()
+        };
+        let err = calculate_mask(&options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Content-defined chunking requires between 1 and 63 mask bits, got 0 for min_chunk_size 262144, max_chunk_size 1048576 and norm_level 15"
+        );
+    }
+
+    #[test]
+    fn test_chunk_sizes() {
+        let mut chunker = ContentDefinedChunker::try_new(&cdc_options(1024, 4096)).unwrap();
+        let array = Arc::new(Int64Array::from(random_values(100_000)));
+        let chunks = get_chunks(&mut chunker, array);
+        assert!(chunks.len() > 100, "{}", chunks.len());
+
+        let mut level_offset = 0;
+        for (idx, chunk) in chunks.iter().enumerate() {
+            assert_eq!(chunk.level_offset, level_offset);
+            assert_eq!(chunk.value_offset, level_offset);
+            assert_eq!(chunk.num_values, chunk.num_levels);
+            level_offset += chunk.num_levels;
+
+            if idx != chunks.len() - 1 {
+                // Each value rolls its 8 bytes and a 2 byte definition level
+                let size = chunk.num_levels * 10;
+                assert!((1024..=4096 + 10).contains(&size), "{size}");
+            }
+        }
+        assert_eq!(level_offset, 100_000);
+    }
+
+    #[test]
+    fn test_chunks_independent_of_batches() {
+        let values = Int64Array::from(random_values(10_000));
+        let mut chunker = ContentDefinedChunker::try_new(&cdc_options(256, 1024)).unwrap();
+        let expected = get_chunks(&mut chunker, Arc::new(values.clone()));
+
+        // The same boundaries are found when written in multiple batches
+        let mut chunker = ContentDefinedChunker::try_new(&cdc_options(256, 1024)).unwrap();
+        let mut boundaries = vec![];
+        for offset in (0..values.len()).step_by(777) {
+            let len = 777.min(values.len() - offset);
+            let chunks = get_chunks(&mut chunker, Arc::new(values.slice(offset, len)));
+            let (last, chunks) = chunks.split_last().unwrap();
+            boundaries.extend(
+                chunks
+                    .iter()
+                    .map(|c| offset + c.level_offset + c.num_levels),
+            );
+            assert_eq!(last.level_offset + last.num_levels, len);
+        }
+        let expected_boundaries: Vec<_> = expected[..expected.len() - 1]
+            .iter()
+            .map(|c| c.level_offset + c.num_levels)
+            .collect();
+        assert_eq!(boundaries, expected_boundaries);
+    }
+
+    #[test]
+    fn test_chunks_after_insertion() {
+        let values = random_values(50_000);
+        let mut inserted = values.clone();
+        inserted.splice(20_000..20_000, [1, 2, 3, 4, 5]);
+
+        let boundaries = |values: Vec<i64>| {
+            let mut chunker = ContentDefinedChunker::try_new(&cdc_options(1024, 4096)).unwrap();
+            let chunks = get_chunks(&mut chunker, Arc::new(Int64Array::from(values)));
+            chunks
+                .iter()
+                .map(|c| c.level_offset + c.num_levels)
+                .collect::<Vec<_>>()
+        };
+        let original = boundaries(values);
+        let inserted = boundaries(inserted);
+
+        // All boundaries before the insertion are unchanged, and all but a few of those
+        // after it are shifted by the number of inserted values
+        let (before, after): (Vec<_>, Vec<_>) = original.iter().partition(|b| **b <= 20_000);
+        assert!(before.iter().all(|b| inserted.contains(b)));
+        let shifted = after
+            .iter()
+            .filter(|b| inserted.contains(&(**b + 5)))
+            .count();
+        assert!(shifted + 2 >= after.len(), "{shifted} of {}", after.len());
+    }
+
+    #[test]
+    fn test_nested_chunks() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        let mut builder = ListBuilder::new(Int64Builder::new());
+        for _ in 0..5_000 {
+            if rng.random_bool(0.1) {
+                builder.append_null();
+                continue;
+            }
+            for _ in 0..rng.random_range(0..5) {
+                match rng.random_bool(0.2) {
+                    true => builder.values().append_null(),
+                    false => builder.values().append_value(rng.random()),
+                }
+            }
+            builder.append(true);
+        }
+        let array: ArrayRef = Arc::new(builder.finish());
+        let field = Field::new("a", array.data_type().clone(), true);
+        let levels = calculate_array_levels(&array, &field).unwrap().remove(0);
+
+        let mut chunker = ContentDefinedChunker::try_new(&cdc_options(512, 2048)).unwrap();
+        let chunks = chunker.get_chunks(&levels).unwrap();
+        assert!(chunks.len() > 10, "{}", chunks.len());
+
+        let def_levels = levels.def_levels().unwrap();
+        let rep_levels = levels.rep_levels().unwrap();
+        let mut level_offset = 0;
+        let mut value_offset = 0;
+        for chunk in &chunks {
+            assert_eq!(chunk.level_offset, level_offset);
+            assert_eq!(chunk.value_offset, value_offset);
+            // Chunks start at the start of a record
+            assert_eq!(rep_levels[level_offset], 0);
+
+            let range = level_offset..level_offset + chunk.num_levels;
+            let num_values = def_levels[range]
+                .iter()
+                .filter(|l| **l == levels.max_def_level())
+                .count();
+            assert_eq!(chunk.num_values, num_values);
+            level_offset += chunk.num_levels;
+            value_offset += chunk.num_values;
+        }
+        assert_eq!(level_offset, def_levels.len());
+        assert_eq!(value_offset, levels.non_null_indices().len());
+    }
+}
@@ -0,0 +1,182 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Execution of column encoding tasks on other threads, see [`EncodingExecutor`]
+
+use std::panic::AssertUnwindSafe;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+/// A unit of column encoding work submitted to an [`EncodingExecutor`]
+pub type EncodingTask = Box<dyn FnOnce() + Send + 'static>;
+
+/// Runs the column encoding tasks of an [`ArrowWriter`] concurrently
+///
+/// Implementations may run tasks on any thread and in any order. The writer waits for
+/// the results of the tasks it submits, so an implementation that runs no tasks will
+/// cause the writer to block indefinitely, and one that drops tasks without running
+/// them will cause the writer to return an error.
+///
+/// [`ThreadPoolExecutor`] runs tasks on a fixed set of threads, while applications with
+/// an existing thread pool, such as rayon or tokio, can implement this trait to use it
+/// instead. For example, for rayon:
+///
+/// ```ignore
+/// #[derive(Debug)]
+/// struct RayonExecutor;
+///
+/// impl EncodingExecutor for RayonExecutor {
+///     fn spawn(&self, task: EncodingTask) {
+///         rayon::spawn(task)
+///     }
+/// }
+/// ```
+///
+/// [`ArrowWriter`]: crate::arrow::arrow_writer::ArrowWriter
+pub trait EncodingExecutor: Send + Sync + std::fmt::Debug {
+    /// Runs `task`, potentially on another thread
+    fn spawn(&self, task: EncodingTask);
+}
+
+/// An [`EncodingExecutor`] that runs tasks on a fixed number of threads
+///
+/// The threads are started on creation, and are stopped once all queued tasks have
+/// completed after the executor is dropped.
+#[derive(Debug)]
+pub struct ThreadPoolExecutor {
+    sender: Option<Sender<EncodingTask>>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl ThreadPoolExecutor {
+    /// Creates an executor that runs tasks on `num_threads` threads
+    ///
+    /// # Panics
+    ///
+    /// If `num_threads` is 0
+    pub fn new(num_threads: usize) -> Self {
+        assert!(num_threads > 0, "num_threads must be greater than 0");
+        let (sender, receiver) = channel::<EncodingTask>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let threads = (0..num_threads)
+            .map(|i| {
+                let receiver = Arc::clone(&receiver);
+                std::thread::Builder::new()
+                    .name(format!("parquet-encoder-{i}"))
+                    .spawn(move || run_tasks(&receiver))
+                    .expect("failed to spawn encoding thread")
+            })
+            .collect();
+        Self {
+            sender: Some(sender),
+            threads,
+        }
+    }
+
+    /// Returns the number of threads running tasks
+    pub fn num_threads(&self) -> usize {
+        self.threads.len()
+    }
+}
+
+/// Runs tasks received from `receiver` until all senders are dropped
+fn run_tasks(receiver: &Mutex<Receiver<EncodingTask>>) {
+    loop {
+        // Release the lock before running the task, so other threads can receive tasks
+        let task = match receiver.lock() {
+            Ok(receiver) => receiver.recv(),
+            Err(_) => return,
+        };
+        match task {
+            // A panicking task drops its result sender, which the writer reports as an
+            // error, so the thread can continue running other tasks
+            Ok(task) => {
+                let _ = std::panic::catch_unwind(AssertUnwindSafe(task));
+            }
+            Err(_) => return,
+        }
+    }
+}
+
+impl EncodingExecutor for ThreadPoolExecutor {
+    fn spawn(&self, task: EncodingTask) {
+        if let Some(sender) = &self.sender {
+            // Sending only fails if all threads have exited, in which case the dropped
+            // task is reported as an error by the writer
+            let _ = sender.send(task);
+        }
+    }
+}
+
+impl Drop for ThreadPoolExecutor {
+    fn drop(&mut self) {
+        drop(self.sender.take());
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_thread_pool_executor() {
+        let executor = ThreadPoolExecutor::new(3);
+        assert_eq!(executor.num_threads(), 3);
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let (tx, rx) = channel();
+        for _ in 0..10 {
+            let count = Arc::clone(&count);
+            let tx = tx.clone();
+            executor.spawn(Box::new(move || {
+                count.fetch_add(1, Ordering::SeqCst);
+                tx.send(()).unwrap();
+            }));
+        }
+        drop(tx);
+        assert_eq!(rx.iter().count(), 10);
+        assert_eq!(count.load(Ordering::SeqCst), 10);
+    }
+
+    #[test]
+    fn test_thread_pool_executor_panic() {
+        let executor = ThreadPoolExecutor::new(1);
+
+        let (tx, rx) = channel::<()>();
+        executor.spawn(Box::new(move || {
+            let _tx = tx;
+            panic!("task failed");
+        }));
+        assert!(rx.recv().is_err());
+
+        // The thread keeps running tasks after a task panics
+        let (tx, rx) = channel();
+        executor.spawn(Box::new(move || tx.send(1).unwrap()));
+        assert_eq!(rx.recv().unwrap(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "num_threads must be greater than 0")]
+    fn test_thread_pool_executor_no_threads() {
+        ThreadPoolExecutor::new(0);
+    }
+}
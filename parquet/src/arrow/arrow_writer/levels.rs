@@ -40,12 +40,16 @@
 //!
 //! \[1\] [parquet-format#nested-encoding](https://github.com/apache/parquet-format#nested-encoding)
 
+use crate::arrow::schema::union_struct_fields;
 use crate::errors::{ParquetError, Result};
 use arrow_array::cast::AsArray;
-use arrow_array::{Array, ArrayRef, OffsetSizeTrait};
+use arrow_array::{
+    Array, ArrayRef, Int8Array, OffsetSizeTrait, StructArray, UInt32Array, UnionArray,
+};
 use arrow_buffer::bit_iterator::BitIndexIterator;
 use arrow_buffer::{NullBuffer, OffsetBuffer};
-use arrow_schema::{DataType, Field};
+use arrow_schema::{DataType, Field, UnionFields};
+use arrow_select::take::take;
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -94,6 +98,28 @@ fn is_leaf(data_type: &DataType) -> bool {
     )
 }
 
+/// Returns the [`StructArray`] with which `array` is written to parquet
+///
+/// See [`union_struct_fields`]
+fn union_to_struct(array: &UnionArray, fields: &UnionFields) -> Result<StructArray> {
+    let type_ids = array.type_ids();
+    let mut columns: Vec<ArrayRef> = Vec::with_capacity(fields.len() + 1);
+    columns.push(Arc::new(Int8Array::new(type_ids.clone(), None)));
+    for (type_id, _) in fields.iter() {
+        let indices: UInt32Array = type_ids
+            .iter()
+            .enumerate()
+            .map(|(idx, t)| (*t == type_id).then(|| array.value_offset(idx) as u32))
+            .collect();
+        columns.push(take(array.child(type_id), &indices, None)?);
+    }
+    Ok(StructArray::try_new(
+        union_struct_fields(fields),
+        columns,
+        None,
+    )?)
+}
+
 /// The definition and repetition level of an array within a potentially nested hierarchy
 #[derive(Debug, Default, Clone, Copy)]
 struct LevelContext {
@@ -157,7 +183,11 @@ impl LevelInfoBuilder {
                 Ok(Self::Primitive(levels))
             }
             DataType::Struct(children) => {
-                let array = array.as_struct();
+                // Arrays such as `VariantArray` wrap a `StructArray` in another type
+                let array = match array.as_struct_opt() {
+                    Some(array) => array.clone(),
+                    None => StructArray::from(array.to_data()),
+                };
                 let def_level = match is_nullable {
                     true => parent_ctx.def_level + 1,
                     false => parent_ctx.def_level,
@@ -220,6 +250,11 @@ impl LevelInfoBuilder {
                     _ => unreachable!(),
                 })
             }
+            DataType::Union(fields, _) => {
+                let array = Arc::new(union_to_struct(array.as_union(), fields)?) as ArrayRef;
+                let field = field.clone().with_data_type(array.data_type().clone());
+                Self::try_new(&field, parent_ctx, &array)
+            }
             d => Err(nyi_err!("Datatype {} is not yet supported", d)),
         }
     }
@@ -622,6 +657,49 @@ impl ArrayLevels {
     pub fn non_null_indices(&self) -> &[usize] {
         &self.non_null_indices
     }
+
+    pub fn max_def_level(&self) -> i16 {
+        self.max_def_level
+    }
+
+    /// Returns the `num_levels` levels starting at `level_offset`, which must contain
+    /// the `num_values` non-null values starting at `value_offset`
+    ///
+    /// The array is sliced to the range of the non-null values, so that writing the
+    /// returned levels only processes the values they contain.
+    pub fn slice(
+        &self,
+        level_offset: usize,
+        num_levels: usize,
+        value_offset: usize,
+        num_values: usize,
+    ) -> Self {
+        let level_range = level_offset..level_offset + num_levels;
+        let indices = &self.non_null_indices[value_offset..value_offset + num_values];
+        let (array_offset, array_len) = match (indices.first(), indices.last()) {
+            (Some(first), Some(last)) => (*first, last - first + 1),
+            _ => (0, 0),
+        };
+
+        Self {
+            def_levels: self
+                .def_levels
+                .as_ref()
+                .map(|levels| levels[level_range.clone()].to_vec()),
+            rep_levels: self
+                .rep_levels
+                .as_ref()
+                .map(|levels| levels[level_range].to_vec()),
+            non_null_indices: indices.iter().map(|i| i - array_offset).collect(),
+            max_def_level: self.max_def_level,
+            max_rep_level: self.max_rep_level,
+            array: self.array.slice(array_offset, array_len),
+            logical_nulls: self
+                .logical_nulls
+                .as_ref()
+                .map(|nulls| nulls.slice(array_offset, array_len)),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -18,9 +18,11 @@
 //! Contains writer which writes arrow data into parquet data.
 
 use bytes::Bytes;
+use std::collections::VecDeque;
 use std::io::{Read, Write};
 use std::iter::Peekable;
 use std::slice::Iter;
+use std::sync::mpsc::{channel, Receiver};
 use std::sync::{Arc, Mutex};
 use std::vec::IntoIter;
 use thrift::protocol::TCompactOutputProtocol;
@@ -28,9 +30,13 @@ use thrift::protocol::TCompactOutputProtocol;
 use arrow_array::cast::AsArray;
 use arrow_array::types::*;
 use arrow_array::{ArrayRef, RecordBatch, RecordBatchWriter};
-use arrow_schema::{ArrowError, DataType as ArrowDataType, Field, IntervalUnit, SchemaRef};
+use arrow_schema::{
+    ArrowError, DataType as ArrowDataType, Field, IntervalUnit, SchemaRef, TimeUnit,
+};
 
-use super::schema::{add_encoded_arrow_schema_to_metadata, decimal_length_from_precision};
+use super::schema::{
+    add_encoded_arrow_schema_to_metadata, decimal_length_from_precision, union_struct_fields,
+};
 
 use crate::arrow::arrow_writer::byte_array::ByteArrayEncoder;
 use crate::arrow::ArrowSchemaConverter;
@@ -40,7 +46,7 @@ use crate::column::writer::encoder::ColumnValueEncoder;
 use crate::column::writer::{
     get_column_writer, ColumnCloseResult, ColumnWriter, GenericColumnWriter,
 };
-use crate::data_type::{ByteArray, FixedLenByteArray};
+use crate::data_type::{ByteArray, FixedLenByteArray, Int96};
 #[cfg(feature = "encryption")]
 use crate::encryption::encrypt::FileEncryptor;
 use crate::errors::{ParquetError, Result};
@@ -50,11 +56,17 @@ use crate::file::reader::{ChunkReader, Length};
 use crate::file::writer::{SerializedFileWriter, SerializedRowGroupWriter};
 use crate::schema::types::{ColumnDescPtr, SchemaDescriptor};
 use crate::thrift::TSerializable;
+use chunker::ContentDefinedChunker;
 use levels::{calculate_array_levels, ArrayLevels};
 
 mod byte_array;
+mod chunker;
+mod executor;
+mod gearhash;
 mod levels;
 
+pub use executor::{EncodingExecutor, EncodingTask, ThreadPoolExecutor};
+
 /// Encodes [`RecordBatch`] to parquet
 ///
 /// Writes Arrow `RecordBatch`es to a Parquet writer. Multiple [`RecordBatch`] will be encoded
@@ -114,6 +126,34 @@ mod levels;
 /// }
 /// ```
 ///
+/// # Concurrent Encoding
+///
+/// By default, columns are encoded on the thread calling [`Self::write`]. An
+/// [`EncodingExecutor`], such as a [`ThreadPoolExecutor`], can be configured with
+/// [`ArrowWriterOptions::with_encoding_executor`] to instead encode the leaf columns
+/// of each [`RecordBatch`] concurrently. Each call to [`Self::write`] waits for its
+/// columns to be encoded, so the written bytes are identical to those written without
+/// an executor, and [`Self::memory_size`] and [`Self::in_progress_size`] remain accurate.
+///
+/// Completed row groups can additionally be closed concurrently with the encoding of
+/// later row groups, see [`ArrowWriterOptions::with_max_concurrent_row_groups`].
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow_array::{ArrayRef, Int64Array, RecordBatch};
+/// # use parquet::arrow::arrow_writer::{ArrowWriter, ArrowWriterOptions, ThreadPoolExecutor};
+/// let col = Arc::new(Int64Array::from_iter_values([1, 2, 3])) as ArrayRef;
+/// let to_write = RecordBatch::try_from_iter([("a", col.clone()), ("b", col)]).unwrap();
+///
+/// let options = ArrowWriterOptions::new()
+///     .with_encoding_executor(Arc::new(ThreadPoolExecutor::new(2)));
+/// let mut buffer = Vec::new();
+/// let mut writer =
+///     ArrowWriter::try_new_with_options(&mut buffer, to_write.schema(), options).unwrap();
+/// writer.write(&to_write).unwrap();
+/// writer.close().unwrap();
+/// ```
+///
 /// ## Type Support
 ///
 /// The writer supports writing all Arrow [`DataType`]s that have a direct mapping to
@@ -145,6 +185,19 @@ pub struct ArrowWriter<W: Write> {
 
     /// The length of arrays to write to each row group
     max_row_group_size: usize,
+
+    /// Executes column encoding tasks, if encoding concurrently
+    encoding_executor: Option<Arc<dyn EncodingExecutor>>,
+
+    /// The maximum number of row groups being encoded at once
+    max_concurrent_row_groups: usize,
+
+    /// Row groups being closed by the [`EncodingExecutor`], in the order they will be written
+    pending_row_groups: VecDeque<PendingRowGroup>,
+
+    /// Set if concurrently encoding columns failed, leaving the writer in an
+    /// inconsistent state
+    poisoned: bool,
 }
 
 impl<W: Write + Send> std::fmt::Debug for ArrowWriter<W> {
@@ -165,7 +218,7 @@ impl<W: Write + Send> ArrowWriter<W> {
     ///
     /// The writer will fail if:
     ///  * a `SerializedFileWriter` cannot be created from the ParquetWriter
-    ///  * the Arrow schema contains unsupported datatypes such as Unions
+    ///  * the Arrow schema contains unsupported datatypes such as RunEndEncoded
     pub fn try_new(
         writer: W,
         arrow_schema: SchemaRef,
@@ -179,14 +232,16 @@ impl<W: Write + Send> ArrowWriter<W> {
     ///
     /// The writer will fail if:
     ///  * a `SerializedFileWriter` cannot be created from the ParquetWriter
-    ///  * the Arrow schema contains unsupported datatypes such as Unions
+    ///  * the Arrow schema contains unsupported datatypes such as RunEndEncoded
     pub fn try_new_with_options(
         writer: W,
         arrow_schema: SchemaRef,
         options: ArrowWriterOptions,
     ) -> Result<Self> {
         let mut props = options.properties;
-        let mut converter = ArrowSchemaConverter::new().with_coerce_types(props.coerce_types());
+        let mut converter = ArrowSchemaConverter::new()
+            .with_coerce_types(props.coerce_types())
+            .with_timestamps_as_int96(props.timestamps_as_int96());
         if let Some(schema_root) = &options.schema_root {
             converter = converter.schema_root(schema_root);
         }
@@ -209,6 +264,10 @@ impl<W: Write + Send> ArrowWriter<W> {
             arrow_schema,
             row_group_writer_factory,
             max_row_group_size,
+            encoding_executor: options.encoding_executor,
+            max_concurrent_row_groups: options.max_concurrent_row_groups,
+            pending_row_groups: VecDeque::new(),
+            poisoned: false,
         })
     }
 
@@ -220,12 +279,15 @@ impl<W: Write + Send> ArrowWriter<W> {
     /// Estimated memory usage, in bytes, of this `ArrowWriter`
     ///
     /// This estimate is formed bu summing the values of
-    /// [`ArrowColumnWriter::memory_size`] all in progress columns.
+    /// [`ArrowColumnWriter::memory_size`] all in progress columns, and of any row groups
+    /// that are still being closed concurrently.
     pub fn memory_size(&self) -> usize {
-        match &self.in_progress {
+        let in_progress = match &self.in_progress {
             Some(in_progress) => in_progress.writers.iter().map(|x| x.memory_size()).sum(),
             None => 0,
-        }
+        };
+        let pending: usize = self.pending_row_groups.iter().map(|x| x.memory_size).sum();
+        in_progress + pending
     }
 
     /// Anticipated encoded size of the in progress row group.
@@ -266,6 +328,7 @@ impl<W: Write + Send> ArrowWriter<W> {
     ///
     /// This will fail if the `batch`'s schema does not match the writer's schema.
     pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        self.check_poisoned()?;
         if batch.num_rows() == 0 {
             return Ok(());
         }
@@ -276,7 +339,7 @@ impl<W: Write + Send> ArrowWriter<W> {
                 self.writer.schema_descr(),
                 self.writer.properties(),
                 &self.arrow_schema,
-                self.writer.flushed_row_groups().len(),
+                self.writer.flushed_row_groups().len() + self.pending_row_groups.len(),
             )?),
         };
 
@@ -289,7 +352,19 @@ impl<W: Write + Send> ArrowWriter<W> {
             return self.write(&b);
         }
 
-        in_progress.write(batch)?;
+        match &self.encoding_executor {
+            Some(executor) => {
+                let leaves = in_progress.batch_leaves(batch)?;
+                // A column writer is lost or left partially written if encoding fails
+                if let Err(e) =
+                    in_progress.write_concurrent(leaves, batch.num_rows(), executor.as_ref())
+                {
+                    self.poisoned = true;
+                    return Err(e);
+                }
+            }
+            None => in_progress.write(batch)?,
+        }
 
         if in_progress.buffered_rows >= self.max_row_group_size {
             self.flush()?
@@ -301,19 +376,61 @@ impl<W: Write + Send> ArrowWriter<W> {
     ///
     /// It's safe to use this method to write data to the underlying writer,
     /// because it will ensure that the buffering and byte‐counting layers are used.
+    ///
+    /// Any row groups still being closed concurrently are written first.
     pub fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.write_pending_row_groups(0)?;
         self.writer.write_all(buf)
     }
 
     /// Flushes all buffered rows into a new row group
+    ///
+    /// If [`ArrowWriterOptions::with_max_concurrent_row_groups`] is greater than 1, the
+    /// row group may still be being closed, and is written to the underlying writer once
+    /// complete, by a later call to this method, or by [`Self::finish`].
     pub fn flush(&mut self) -> Result<()> {
+        self.check_poisoned()?;
         let in_progress = match self.in_progress.take() {
             Some(in_progress) => in_progress,
             None => return Ok(()),
         };
 
+        match self.encoding_executor.clone() {
+            Some(executor) => {
+                let pending = in_progress.close_concurrent(executor.as_ref());
+                self.pending_row_groups.push_back(pending);
+                self.write_pending_row_groups(self.max_concurrent_row_groups - 1)
+            }
+            None => self.write_row_group(in_progress.close()?),
+        }
+    }
+
+    /// Writes the oldest row groups being closed concurrently, until at most
+    /// `max_pending` remain
+    fn write_pending_row_groups(&mut self, max_pending: usize) -> Result<()> {
+        while self.pending_row_groups.len() > max_pending {
+            let pending = self.pending_row_groups.pop_front().unwrap();
+            let chunks = pending.wait().inspect_err(|_| self.poisoned = true)?;
+            self.write_row_group(chunks)?;
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a previous failure to encode columns concurrently has left
+    /// this writer in an inconsistent state
+    fn check_poisoned(&self) -> Result<()> {
+        match self.poisoned {
+            true => Err(general_err!(
+                "ArrowWriter is poisoned by a previous failure to encode columns"
+            )),
+            false => Ok(()),
+        }
+    }
+
+    /// Appends the closed column chunks of a row group to the underlying writer
+    fn write_row_group(&mut self, chunks: Vec<ArrowColumnChunk>) -> Result<()> {
         let mut row_group_writer = self.writer.next_row_group()?;
-        for chunk in in_progress.close()? {
+        for chunk in chunks {
             chunk.append_to_row_group(&mut row_group_writer)?;
         }
         row_group_writer.close()?;
@@ -347,6 +464,7 @@ impl<W: Write + Send> ArrowWriter<W> {
     /// Flushes any outstanding data and returns the underlying writer.
     pub fn into_inner(mut self) -> Result<W> {
         self.flush()?;
+        self.write_pending_row_groups(0)?;
         self.writer.into_inner()
     }
 
@@ -357,6 +475,7 @@ impl<W: Write + Send> ArrowWriter<W> {
     /// Attempting to write after calling finish will result in an error
     pub fn finish(&mut self) -> Result<crate::format::FileMetaData> {
         self.flush()?;
+        self.write_pending_row_groups(0)?;
         self.writer.finish()
     }
 
@@ -380,11 +499,25 @@ impl<W: Write + Send> RecordBatchWriter for ArrowWriter<W> {
 /// Arrow-specific configuration settings for writing parquet files.
 ///
 /// See [`ArrowWriter`] for how to configure the writer.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct ArrowWriterOptions {
     properties: WriterProperties,
     skip_arrow_metadata: bool,
     schema_root: Option<String>,
+    encoding_executor: Option<Arc<dyn EncodingExecutor>>,
+    max_concurrent_row_groups: usize,
+}
+
+impl Default for ArrowWriterOptions {
+    fn default() -> Self {
+        Self {
+            properties: WriterProperties::default(),
+            skip_arrow_metadata: false,
+            schema_root: None,
+            encoding_executor: None,
+            max_concurrent_row_groups: 1,
+        }
+    }
 }
 
 impl ArrowWriterOptions {
@@ -418,6 +551,39 @@ impl ArrowWriterOptions {
             ..self
         }
     }
+
+    /// Sets the [`EncodingExecutor`] used to encode leaf columns concurrently
+    /// (defaults to `None`, encoding columns on the writing thread)
+    ///
+    /// See [`ArrowWriter`] for more details.
+    pub fn with_encoding_executor(self, encoding_executor: Arc<dyn EncodingExecutor>) -> Self {
+        Self {
+            encoding_executor: Some(encoding_executor),
+            ..self
+        }
+    }
+
+    /// Sets the maximum number of row groups encoded at once (defaults to `1`)
+    ///
+    /// When greater than 1, a completed row group is closed by the [`EncodingExecutor`]
+    /// whilst the writer continues encoding the next row group, with up to
+    /// `max_concurrent_row_groups - 1` completed row groups buffered in memory at once.
+    /// Row groups are always written in order. This has no effect unless an executor is
+    /// set with [`Self::with_encoding_executor`].
+    ///
+    /// # Panics
+    ///
+    /// If `max_concurrent_row_groups` is 0
+    pub fn with_max_concurrent_row_groups(self, max_concurrent_row_groups: usize) -> Self {
+        assert!(
+            max_concurrent_row_groups > 0,
+            "max_concurrent_row_groups must be greater than 0"
+        );
+        Self {
+            max_concurrent_row_groups,
+            ..self
+        }
+    }
 }
 
 /// A single column chunk produced by [`ArrowColumnWriter`]
@@ -614,6 +780,7 @@ impl ArrowColumnChunk {
 /// let props = Arc::new(WriterProperties::default());
 /// let parquet_schema = ArrowSchemaConverter::new()
 ///   .with_coerce_types(props.coerce_types())
+///   .with_timestamps_as_int96(props.timestamps_as_int96())
 ///   .convert(&schema)
 ///   .unwrap();
 ///
@@ -684,6 +851,7 @@ impl ArrowColumnChunk {
 pub struct ArrowColumnWriter {
     writer: ArrowColumnWriterImpl,
     chunk: SharedColumnChunk,
+    chunker: Option<ContentDefinedChunker>,
 }
 
 impl std::fmt::Debug for ArrowColumnWriter {
@@ -697,15 +865,58 @@ enum ArrowColumnWriterImpl {
     Column(ColumnWriter<'static>),
 }
 
+impl ArrowColumnWriterImpl {
+    fn write(&mut self, levels: &ArrayLevels) -> Result<()> {
+        match self {
+            ArrowColumnWriterImpl::Column(c) => {
+                write_leaf(c, levels)?;
+            }
+            ArrowColumnWriterImpl::ByteArray(c) => {
+                write_primitive(c, levels.array().as_ref(), levels)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn flush_data_page(&mut self) -> Result<()> {
+        match self {
+            ArrowColumnWriterImpl::Column(c) => c.flush_data_page(),
+            ArrowColumnWriterImpl::ByteArray(c) => c.flush_data_page(),
+        }
+    }
+}
+
 impl ArrowColumnWriter {
     /// Write an [`ArrowLeafColumn`]
+    ///
+    /// If content-defined chunking is enabled, see
+    /// [`WriterPropertiesBuilder::set_content_defined_chunking`], a data page is
+    /// completed at each chunk boundary found in the column.
+    ///
+    /// [`WriterPropertiesBuilder::set_content_defined_chunking`]: crate::file::properties::WriterPropertiesBuilder::set_content_defined_chunking
     pub fn write(&mut self, col: &ArrowLeafColumn) -> Result<()> {
-        match &mut self.writer {
-            ArrowColumnWriterImpl::Column(c) => {
-                write_leaf(c, &col.0)?;
+        let chunks = match &mut self.chunker {
+            Some(chunker) => chunker.get_chunks(&col.0)?,
+            None => return self.writer.write(&col.0),
+        };
+        if chunks.len() <= 1 {
+            return self.writer.write(&col.0);
+        }
+
+        let last = chunks.len() - 1;
+        for (idx, chunk) in chunks.into_iter().enumerate() {
+            if chunk.num_levels > 0 {
+                let levels = col.0.slice(
+                    chunk.level_offset,
+                    chunk.num_levels,
+                    chunk.value_offset,
+                    chunk.num_values,
+                );
+                self.writer.write(&levels)?;
             }
-            ArrowColumnWriterImpl::ByteArray(c) => {
-                write_primitive(c, col.0.array().as_ref(), &col.0)?;
+            // The last chunk is continued by the next write
+            if idx != last {
+                self.writer.flush_data_page()?;
             }
         }
         Ok(())
@@ -781,12 +992,103 @@ impl ArrowRowGroupWriter {
         Ok(())
     }
 
+    /// Returns the leaf columns of `batch`, one for each column writer
+    fn batch_leaves(&self, batch: &RecordBatch) -> Result<Vec<ArrowLeafColumn>> {
+        let mut leaves = Vec::with_capacity(self.writers.len());
+        for (field, column) in self.schema.fields().iter().zip(batch.columns()) {
+            leaves.extend(compute_leaves(field.as_ref(), column)?);
+        }
+        if leaves.len() != self.writers.len() {
+            return Err(general_err!(
+                "Row group writer has {} columns, expected {}",
+                self.writers.len(),
+                leaves.len()
+            ));
+        }
+        Ok(leaves)
+    }
+
+    /// Writes the `leaves` of `num_rows` rows, encoding each as a separate task of `executor`
+    fn write_concurrent(
+        &mut self,
+        leaves: Vec<ArrowLeafColumn>,
+        num_rows: usize,
+        executor: &dyn EncodingExecutor,
+    ) -> Result<()> {
+        self.buffered_rows += num_rows;
+
+        // Move each column writer into the task encoding it, and restore them in order
+        // once all tasks have completed
+        let num_columns = self.writers.len();
+        let (sender, receiver) = channel();
+        let writers = std::mem::take(&mut self.writers);
+        for (idx, (mut writer, leaf)) in writers.into_iter().zip(leaves).enumerate() {
+            let sender = sender.clone();
+            executor.spawn(Box::new(move || {
+                let result = writer.write(&leaf);
+                let _ = sender.send((idx, writer, result));
+            }));
+        }
+        drop(sender);
+
+        let mut completed: Vec<_> = receiver.iter().collect();
+        if completed.len() != num_columns {
+            // The writers of any failed tasks are lost, so this row group cannot be completed
+            return Err(general_err!("Column encoding task failed to complete"));
+        }
+        completed.sort_unstable_by_key(|(idx, _, _)| *idx);
+        let mut result = Ok(());
+        for (_, writer, r) in completed {
+            self.writers.push(writer);
+            result = result.and(r);
+        }
+        result
+    }
+
     fn close(self) -> Result<Vec<ArrowColumnChunk>> {
         self.writers
             .into_iter()
             .map(|writer| writer.close())
             .collect()
     }
+
+    /// Closes each column writer as a separate task of `executor`
+    fn close_concurrent(self, executor: &dyn EncodingExecutor) -> PendingRowGroup {
+        let memory_size = self.writers.iter().map(|x| x.memory_size()).sum();
+        let num_columns = self.writers.len();
+        let (sender, receiver) = channel();
+        for (idx, writer) in self.writers.into_iter().enumerate() {
+            let sender = sender.clone();
+            executor.spawn(Box::new(move || {
+                let _ = sender.send((idx, writer.close()));
+            }));
+        }
+        PendingRowGroup {
+            receiver,
+            num_columns,
+            memory_size,
+        }
+    }
+}
+
+/// A row group whose columns are being closed by an [`EncodingExecutor`]
+struct PendingRowGroup {
+    receiver: Receiver<(usize, Result<ArrowColumnChunk>)>,
+    num_columns: usize,
+    /// The memory usage of the column writers when the row group was completed
+    memory_size: usize,
+}
+
+impl PendingRowGroup {
+    /// Waits for all columns to be closed, returning the column chunks in order
+    fn wait(self) -> Result<Vec<ArrowColumnChunk>> {
+        let mut chunks: Vec<_> = self.receiver.iter().collect();
+        if chunks.len() != self.num_columns {
+            return Err(general_err!("Column encoding task failed to complete"));
+        }
+        chunks.sort_unstable_by_key(|(idx, _)| *idx);
+        chunks.into_iter().map(|(_, chunk)| chunk).collect()
+    }
 }
 
 struct ArrowRowGroupWriterFactory {
@@ -946,6 +1248,13 @@ impl ArrowColumnWriterFactory {
         leaves: &mut Iter<'_, ColumnDescPtr>,
         out: &mut Vec<ArrowColumnWriter>,
     ) -> Result<()> {
+        let chunker = || {
+            props
+                .content_defined_chunking()
+                .map(ContentDefinedChunker::try_new)
+                .transpose()
+        };
+
         let col = |desc: &ColumnDescPtr| -> Result<ArrowColumnWriter> {
             let page_writer = self.create_page_writer(desc, out.len())?;
             let chunk = page_writer.buffer.clone();
@@ -953,6 +1262,7 @@ impl ArrowColumnWriterFactory {
             Ok(ArrowColumnWriter {
                 chunk,
                 writer: ArrowColumnWriterImpl::Column(writer),
+                chunker: chunker()?,
             })
         };
 
@@ -963,6 +1273,7 @@ impl ArrowColumnWriterFactory {
             Ok(ArrowColumnWriter {
                 chunk,
                 writer: ArrowColumnWriterImpl::ByteArray(writer),
+                chunker: chunker()?,
             })
         };
 
@@ -987,6 +1298,11 @@ impl ArrowColumnWriterFactory {
                     self.get_arrow_column_writer(field.data_type(), props, leaves, out)?
                 }
             }
+            ArrowDataType::Union(fields, _) => {
+                for field in union_struct_fields(fields).iter() {
+                    self.get_arrow_column_writer(field.data_type(), props, leaves, out)?
+                }
+            }
             ArrowDataType::Map(f, _) => match f.data_type() {
                 ArrowDataType::Struct(f) => {
                     self.get_arrow_column_writer(f[0].data_type(), props, leaves, out)?;
@@ -1150,8 +1466,47 @@ fn write_leaf(writer: &mut ColumnWriter<'_>, levels: &ArrayLevels) -> Result<usi
                 }
             }
         }
-        ColumnWriter::Int96ColumnWriter(ref mut _typed) => {
-            unreachable!("Currently unreachable because data type not supported")
+        ColumnWriter::Int96ColumnWriter(ref mut typed) => {
+            let array = match column.data_type() {
+                ArrowDataType::Dictionary(_, value_type) => arrow_cast::cast(column, value_type)?,
+                _ => Arc::clone(levels.array()),
+            };
+            let values: Vec<Int96> = match array.data_type() {
+                ArrowDataType::Timestamp(TimeUnit::Second, _) => {
+                    let array = array.as_primitive::<TimestampSecondType>();
+                    array
+                        .values()
+                        .iter()
+                        .map(|v| Int96::from_seconds(*v))
+                        .collect()
+                }
+                ArrowDataType::Timestamp(TimeUnit::Millisecond, _) => {
+                    let array = array.as_primitive::<TimestampMillisecondType>();
+                    array
+                        .values()
+                        .iter()
+                        .map(|v| Int96::from_millis(*v))
+                        .collect()
+                }
+                ArrowDataType::Timestamp(TimeUnit::Microsecond, _) => {
+                    let array = array.as_primitive::<TimestampMicrosecondType>();
+                    array
+                        .values()
+                        .iter()
+                        .map(|v| Int96::from_micros(*v))
+                        .collect()
+                }
+                ArrowDataType::Timestamp(TimeUnit::Nanosecond, _) => {
+                    let array = array.as_primitive::<TimestampNanosecondType>();
+                    array
+                        .values()
+                        .iter()
+                        .map(|v| Int96::from_nanos(*v))
+                        .collect()
+                }
+                _ => unreachable!("INT96 must be a timestamp."),
+            };
+            write_primitive(typed, &values, levels)
         }
         ColumnWriter::FloatColumnWriter(ref mut typed) => {
             let array = column.as_primitive::<Float32Type>();
@@ -1353,16 +1708,21 @@ mod tests {
     use arrow::util::pretty::pretty_format_batches;
     use arrow::{array::*, buffer::Buffer};
     use arrow_buffer::{i256, IntervalDayTime, IntervalMonthDayNano, NullBuffer};
-    use arrow_schema::Fields;
+    use arrow_buffer::{OffsetBuffer, ScalarBuffer};
+    use arrow_schema::{Fields, UnionFields, UnionMode};
     use half::f16;
     use num::{FromPrimitive, ToPrimitive};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
 
     use crate::basic::Encoding;
     use crate::data_type::AsBytes;
+    use crate::distinct_count::read_distinct_count_sketch;
     use crate::file::metadata::{ColumnChunkMetaData, ParquetMetaData, ParquetMetaDataReader};
     use crate::file::page_index::index::Index;
     use crate::file::properties::{
-        BloomFilterPosition, EnabledStatistics, ReaderProperties, WriterVersion,
+        BloomFilterPosition, CdcOptions, EnabledStatistics, ReaderProperties, WriterVersion,
+        DEFAULT_DISTINCT_COUNT_PRECISION,
     };
     use crate::file::serialized_reader::ReadOptionsBuilder;
     use crate::file::{
@@ -2506,6 +2866,106 @@ mod tests {
         one_column_roundtrip(values, false);
     }
 
+    #[test]
+    fn timestamp_int96_roundtrip() {
+        use crate::arrow::arrow_reader::ArrowReaderOptions;
+
+        let values = vec![
+            Some(0),
+            None,
+            Some(-1),
+            Some(1_700_000_000),
+            Some(-20_000_000_000),
+        ];
+        let seconds = TimestampSecondArray::from(values.clone()).with_timezone("+01:00");
+        let millis =
+            TimestampMillisecondArray::from_iter(values.iter().map(|v| v.map(|v| v * 1_000 + 1)));
+        let micros = TimestampMicrosecondArray::from_iter(
+            values.iter().map(|v| v.map(|v| v * 1_000_000 - 1)),
+        );
+        let nanos = TimestampNanosecondArray::from(vec![
+            Some(i64::MIN),
+            Some(-1),
+            None,
+            Some(1),
+            Some(i64::MAX),
+        ]);
+        let dict = DictionaryArray::new(
+            Int32Array::from(vec![0, 1, 0, 1, 1]),
+            Arc::new(TimestampMillisecondArray::from(vec![-5, 5])),
+        );
+        let batch = RecordBatch::try_from_iter([
+            ("seconds", Arc::new(seconds) as ArrayRef),
+            ("millis", Arc::new(millis) as ArrayRef),
+            ("micros", Arc::new(micros) as ArrayRef),
+            ("nanos", Arc::new(nanos) as ArrayRef),
+            ("dict", Arc::new(dict) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_timestamps_as_int96(true)
+            .build();
+        let file = roundtrip_opts(&batch, props);
+
+        let reader = SerializedFileReader::new(file.try_clone().unwrap()).unwrap();
+        let schema = reader.metadata().file_metadata().schema_descr();
+        for column in schema.columns() {
+            assert_eq!(column.physical_type(), crate::basic::Type::INT96);
+        }
+
+        // The statistics are ordered by time
+        let row_group = reader.metadata().row_group(0);
+        let expected = [
+            (
+                Int96::from_seconds(-20_000_000_000),
+                Int96::from_seconds(1_700_000_000),
+            ),
+            (
+                Int96::from_millis(-20_000_000_000_000 + 1),
+                Int96::from_millis(1_700_000_000_000 + 1),
+            ),
+            (
+                Int96::from_micros(-20_000_000_000_000_000 - 1),
+                Int96::from_micros(1_700_000_000_000_000 - 1),
+            ),
+            (Int96::from_nanos(i64::MIN), Int96::from_nanos(i64::MAX)),
+            (Int96::from_millis(-5), Int96::from_millis(5)),
+        ];
+        for (column, (min, max)) in row_group.columns().iter().zip(expected) {
+            match column.statistics().unwrap() {
+                Statistics::Int96(stats) => {
+                    assert_eq!(stats.min_opt(), Some(&min));
+                    assert_eq!(stats.max_opt(), Some(&max));
+                }
+                s => panic!("expected Int96 statistics, got {s:?}"),
+            }
+        }
+
+        // Coercing INT96 to the unit of a column keeps its timezone and dictionary encoding
+        for (idx, field) in batch.schema().fields().iter().enumerate() {
+            let unit = match field.data_type() {
+                DataType::Timestamp(unit, _) => *unit,
+                DataType::Dictionary(_, value) => match value.as_ref() {
+                    DataType::Timestamp(unit, _) => *unit,
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            };
+            let options = ArrowReaderOptions::new().with_coerce_int96(Some(unit));
+            let mut reader = ParquetRecordBatchReaderBuilder::try_new_with_options(
+                file.try_clone().unwrap(),
+                options,
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+            let read = reader.next().unwrap().unwrap();
+            assert_eq!(read.schema().field(idx), field.as_ref());
+            assert_eq!(read.column(idx), batch.column(idx));
+        }
+    }
+
     #[test]
     fn date32_single_column() {
         required_and_optional::<Date32Array, _>(0..SMALL_SIZE as i32);
@@ -2835,6 +3295,210 @@ mod tests {
         one_column_roundtrip(values, false);
     }
 
+    #[test]
+    fn union_roundtrip() {
+        let fields = UnionFields::new(
+            [0, 5],
+            [
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, false),
+            ],
+        );
+        let type_ids = ScalarBuffer::from(vec![0_i8, 5, 5, 0, 0, 5, 0]);
+
+        // The children of a sparse union are null where not selected
+        let a = Int32Array::from(vec![Some(1), None, None, None, Some(5), None, Some(7)]);
+        let b = StringArray::from(vec![
+            None,
+            Some("b"),
+            Some("c"),
+            None,
+            None,
+            Some("f"),
+            None,
+        ]);
+        let children: Vec<ArrayRef> = vec![Arc::new(a), Arc::new(b)];
+        let sparse = UnionArray::try_new(fields.clone(), type_ids.clone(), None, children).unwrap();
+
+        let a = Int32Array::from(vec![Some(1), None, Some(5), Some(7)]);
+        let b = StringArray::from(vec!["b", "c", "f"]);
+        let offsets = ScalarBuffer::from(vec![0, 0, 1, 1, 2, 2, 3]);
+        let children: Vec<ArrayRef> = vec![Arc::new(a), Arc::new(b)];
+        let dense = UnionArray::try_new(fields, type_ids, Some(offsets), children).unwrap();
+        let dense: ArrayRef = Arc::new(dense);
+
+        // A list of unions, and a union within a nullable struct
+        let list_field = Arc::new(Field::new_list_field(dense.data_type().clone(), true));
+        let offsets = OffsetBuffer::new(vec![0, 2, 2, 3, 3, 5, 6, 7].into());
+        let nulls = NullBuffer::from(vec![true, false, true, true, true, true, true]);
+        let list = ListArray::new(list_field, offsets, dense.clone(), Some(nulls));
+
+        let union_field = Arc::new(Field::new("u", dense.data_type().clone(), true));
+        let nulls = NullBuffer::from(vec![true, false, true, true, false, true, true]);
+        let s = StructArray::new(vec![union_field].into(), vec![dense.clone()], Some(nulls));
+
+        let batch = RecordBatch::try_from_iter([
+            ("sparse", Arc::new(sparse) as ArrayRef),
+            ("dense", dense),
+            ("list", Arc::new(list) as ArrayRef),
+            ("struct", Arc::new(s) as ArrayRef),
+        ])
+        .unwrap();
+        roundtrip(batch, Some(SMALL_SIZE / 2));
+    }
+
+    #[test]
+    fn union_type_id_conflict() {
+        let fields = UnionFields::new([0], [Field::new("type_id", DataType::Int32, false)]);
+        let schema = Schema::new(vec![Field::new(
+            "u",
+            DataType::Union(fields, UnionMode::Sparse),
+            false,
+        )]);
+        let err = ArrowWriter::try_new(vec![], Arc::new(schema), None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: union child \"type_id\" conflicts with the type id column"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "variant_experimental")]
+    fn variant_roundtrip() {
+        use crate::arrow::arrow_reader::ArrowReaderOptions;
+        use arrow_schema::extension::ExtensionType;
+        use parquet_variant::Variant;
+        use parquet_variant_compute::{VariantArray, VariantArrayBuilder, VariantType};
+
+        let mut builder = VariantArrayBuilder::new(3);
+        builder.append_variant(Variant::from(42));
+        builder.append_null();
+        builder.append_variant(Variant::from("hello"));
+        let array = builder.build();
+
+        let schema = Arc::new(Schema::new(vec![array.field("v")]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap();
+
+        let mut file = tempfile::tempfile().unwrap();
+        let mut writer = ArrowWriter::try_new(&mut file, schema.clone(), None).unwrap();
+        writer.write(&batch).unwrap();
+        let metadata = writer.close().unwrap();
+        let variant = &metadata.schema[1];
+        assert_eq!(
+            variant.logical_type,
+            Some(crate::format::LogicalType::VARIANT(Default::default()))
+        );
+
+        for skip_arrow_metadata in [false, true] {
+            let options = ArrowReaderOptions::new().with_skip_arrow_metadata(skip_arrow_metadata);
+            let mut reader = ParquetRecordBatchReaderBuilder::try_new_with_options(
+                file.try_clone().unwrap(),
+                options,
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+
+            let read = reader.next().unwrap().unwrap();
+            let field = read.schema_ref().field(0);
+            assert_eq!(field.extension_type_name(), Some(VariantType::NAME));
+
+            let array = VariantArray::try_new(read.column(0).clone()).unwrap();
+            assert_eq!(array.value(0), Variant::from(42));
+            assert!(array.is_null(1));
+            assert_eq!(array.value(2), Variant::from("hello"));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "variant_experimental")]
+    fn variant_shredded_roundtrip() {
+        use crate::arrow::arrow_reader::ArrowReaderOptions;
+        use arrow_schema::extension::ExtensionType;
+        use parquet_variant_compute::VariantType;
+
+        // A variant object shredded with a field "a" of type Int64
+        let binary =
+            |values: Vec<Option<&[u8]>>| Arc::new(BinaryViewArray::from(values)) as ArrayRef;
+        let a_fields = Fields::from(vec![
+            Field::new("value", DataType::BinaryView, true),
+            Field::new("typed_value", DataType::Int64, true),
+        ]);
+        let a = StructArray::new(
+            a_fields.clone(),
+            vec![
+                binary(vec![None, Some(b"\x0c\x01"), None]),
+                Arc::new(Int64Array::from(vec![Some(1), None, Some(3)])),
+            ],
+            None,
+        );
+        let typed_value_fields =
+            Fields::from(vec![Field::new("a", DataType::Struct(a_fields), false)]);
+        let typed_value = StructArray::new(
+            typed_value_fields.clone(),
+            vec![Arc::new(a)],
+            Some(vec![true, true, false].into()),
+        );
+        let variant_fields = Fields::from(vec![
+            Field::new("metadata", DataType::BinaryView, false),
+            Field::new("value", DataType::BinaryView, true),
+            Field::new("typed_value", DataType::Struct(typed_value_fields), true),
+        ]);
+        let metadata: &[u8] = b"\x01\x01\x00\x01a";
+        let variant = StructArray::new(
+            variant_fields.clone(),
+            vec![
+                binary(vec![Some(metadata); 3]),
+                binary(vec![None, None, Some(b"\x00")]),
+                Arc::new(typed_value),
+            ],
+            None,
+        );
+
+        let field = Field::new("v", DataType::Struct(variant_fields), true)
+            .with_extension_type(VariantType);
+        let schema = Arc::new(Schema::new(vec![field]));
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(variant)]).unwrap();
+
+        let mut file = tempfile::tempfile().unwrap();
+        let mut writer = ArrowWriter::try_new(&mut file, schema.clone(), None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        for skip_arrow_metadata in [false, true] {
+            let options = ArrowReaderOptions::new().with_skip_arrow_metadata(skip_arrow_metadata);
+            let mut reader = ParquetRecordBatchReaderBuilder::try_new_with_options(
+                file.try_clone().unwrap(),
+                options,
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+
+            let read = reader.next().unwrap().unwrap();
+            let field = read.schema_ref().field(0);
+            assert_eq!(field.extension_type_name(), Some(VariantType::NAME));
+            assert_eq!(read.column(0), batch.column(0));
+        }
+
+        // Fields other than those of a variant are rejected
+        let fields = Fields::from(vec![
+            Field::new("metadata", DataType::BinaryView, false),
+            Field::new("other", DataType::BinaryView, true),
+        ]);
+        let mut field = Field::new("v", DataType::Struct(fields), true);
+        field.set_metadata(std::collections::HashMap::from([(
+            "ARROW:extension:name".to_string(),
+            VariantType::NAME.to_string(),
+        )]));
+        let schema = Arc::new(Schema::new(vec![field]));
+        let err = ArrowWriter::try_new(Vec::new(), schema, None).unwrap_err();
+        assert!(
+            err.to_string().contains("VariantType data type mismatch"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn list_and_map_coerced_names() {
         // Create map and list with non-Parquet naming
@@ -3733,6 +4397,164 @@ mod tests {
         }
     }
 
+    fn concurrent_encoding_batch() -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("int32", DataType::Int32, true),
+            Field::new("utf8", DataType::Utf8, true),
+            Field::new(
+                "dict",
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+                true,
+            ),
+            Field::new(
+                "list",
+                DataType::List(Arc::new(Field::new_list_field(DataType::Int64, true))),
+                true,
+            ),
+            Field::new(
+                "struct",
+                DataType::Struct(Fields::from(vec![
+                    Field::new("a", DataType::Float64, true),
+                    Field::new("b", DataType::Binary, false),
+                ])),
+                true,
+            ),
+        ]));
+        arrow::util::data_gen::create_random_batch(schema, 1000, 0.2, 0.5).unwrap()
+    }
+
+    fn write_with_options(batch: &RecordBatch, options: ArrowWriterOptions) -> Vec<u8> {
+        let mut writer =
+            ArrowWriter::try_new_with_options(Vec::new(), batch.schema(), options).unwrap();
+        for offset in (0..batch.num_rows()).step_by(150) {
+            let len = 150.min(batch.num_rows() - offset);
+            writer.write(&batch.slice(offset, len)).unwrap();
+        }
+        writer.into_inner().unwrap()
+    }
+
+    #[test]
+    fn test_arrow_writer_concurrent_encoding() {
+        let batch = concurrent_encoding_batch();
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(400)
+            .set_data_page_row_count_limit(50)
+            .set_write_batch_size(50)
+            .build();
+        let options = ArrowWriterOptions::new().with_properties(props);
+
+        let expected = write_with_options(&batch, options.clone());
+        let executor = Arc::new(ThreadPoolExecutor::new(3));
+        for max_concurrent_row_groups in [1, 2, 5] {
+            let options = options
+                .clone()
+                .with_encoding_executor(executor.clone())
+                .with_max_concurrent_row_groups(max_concurrent_row_groups);
+            let actual = write_with_options(&batch, options);
+            assert_eq!(actual, expected, "{max_concurrent_row_groups}");
+        }
+
+        let reader = ParquetRecordBatchReader::try_new(Bytes::from(expected), 1000).unwrap();
+        let read = reader.collect::<ArrowResult<Vec<_>>>().unwrap();
+        assert_eq!(read, vec![batch]);
+    }
+
+    #[test]
+    fn test_arrow_writer_concurrent_encoding_memory_size() {
+        let batch = concurrent_encoding_batch();
+        let options = ArrowWriterOptions::new()
+            .with_encoding_executor(Arc::new(ThreadPoolExecutor::new(2)))
+            .with_max_concurrent_row_groups(2);
+        let mut sequential = ArrowWriter::try_new(Vec::new(), batch.schema(), None).unwrap();
+        let mut concurrent =
+            ArrowWriter::try_new_with_options(Vec::new(), batch.schema(), options).unwrap();
+
+        for writer in [&mut sequential, &mut concurrent] {
+            writer.write(&batch).unwrap();
+        }
+        assert_eq!(concurrent.memory_size(), sequential.memory_size());
+        assert_eq!(concurrent.in_progress_size(), sequential.in_progress_size());
+        assert_eq!(concurrent.in_progress_rows(), sequential.in_progress_rows());
+
+        // The flushed row group is retained until closed, and included in memory_size
+        let memory_size = concurrent.memory_size();
+        concurrent.flush().unwrap();
+        assert_eq!(concurrent.memory_size(), memory_size);
+        assert_eq!(concurrent.in_progress_size(), 0);
+        assert_eq!(concurrent.in_progress_rows(), 0);
+        assert!(concurrent.flushed_row_groups().is_empty());
+
+        sequential.flush().unwrap();
+        assert_eq!(sequential.memory_size(), 0);
+        assert_eq!(sequential.flushed_row_groups().len(), 1);
+
+        let concurrent = concurrent.into_inner().unwrap();
+        assert_eq!(concurrent, sequential.into_inner().unwrap());
+    }
+
+    #[test]
+    fn test_arrow_writer_concurrent_encoding_failed_task() {
+        /// An executor that drops tasks without running them
+        #[derive(Debug)]
+        struct DroppingExecutor;
+
+        impl EncodingExecutor for DroppingExecutor {
+            fn spawn(&self, _task: EncodingTask) {}
+        }
+
+        let batch = concurrent_encoding_batch();
+        let options = ArrowWriterOptions::new().with_encoding_executor(Arc::new(DroppingExecutor));
+        let mut writer =
+            ArrowWriter::try_new_with_options(Vec::new(), batch.schema(), options).unwrap();
+        let err = writer.write(&batch).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Column encoding task failed to complete"
+        );
+
+        // The column writers of the failed tasks are lost, so the writer is unusable
+        let expected =
+            "Parquet error: ArrowWriter is poisoned by a previous failure to encode columns";
+        let err = writer.write(&batch).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+        let err = writer.close().unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[test]
+    fn test_arrow_writer_concurrent_encoding_invalid_batch() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Float64, false)]));
+        let valid = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Float64Array::from(vec![1., 2.])) as _],
+        )
+        .unwrap();
+        let invalid = RecordBatch::try_from_iter([(
+            "a",
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )])
+        .unwrap();
+
+        let options =
+            ArrowWriterOptions::new().with_encoding_executor(Arc::new(ThreadPoolExecutor::new(2)));
+        let mut writer = ArrowWriter::try_new_with_options(Vec::new(), schema, options).unwrap();
+        writer.write(&valid).unwrap();
+
+        // The batch is rejected before encoding, so the writer remains usable
+        let err = writer.write(&invalid).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Arrow: Incompatible type. Field 'a' has type Float64, array has type Int32"
+        );
+        writer.write(&valid).unwrap();
+        let data = Bytes::from(writer.into_inner().unwrap());
+
+        let reader = ParquetRecordBatchReader::try_new(data, 1024).unwrap();
+        let read = reader.collect::<ArrowResult<Vec<_>>>().unwrap();
+        let expected = arrow_select::concat::concat_batches(&valid.schema(), [&valid, &valid]);
+        assert_eq!(read, vec![expected.unwrap()]);
+    }
+
     #[test]
     fn mismatched_schemas() {
         let batch_schema = Schema::new(vec![Field::new("count", DataType::Int32, false)]);
@@ -4006,4 +4828,179 @@ mod tests {
         assert_eq!(get_dict_page_size(col0_meta), 1024 * 1024);
         assert_eq!(get_dict_page_size(col1_meta), 1024 * 1024 * 4);
     }
+
+    /// Returns the data pages of the first column of `data`
+    fn first_column_data_pages(data: &Bytes) -> Vec<Bytes> {
+        let metadata = ParquetMetaDataReader::new().parse_and_finish(data).unwrap();
+        let mut pages = vec![];
+        for row_group in metadata.row_groups() {
+            let reader =
+                SerializedPageReader::new(Arc::new(data.clone()), row_group.column(0), 0, None)
+                    .unwrap();
+            for page in reader {
+                let page = page.unwrap();
+                if !matches!(page, Page::DictionaryPage { .. }) {
+                    pages.push(page.buffer().clone());
+                }
+            }
+        }
+        pages
+    }
+
+    #[test]
+    fn test_arrow_writer_content_defined_chunking() {
+        let batch = concurrent_encoding_batch();
+        let props = WriterProperties::builder()
+            .set_content_defined_chunking(Some(CdcOptions {
+                min_chunk_size: 256,
+                max_chunk_size: 1024,
+                norm_level: 0,
+            }))
+            .build();
+        let options = ArrowWriterOptions::new().with_properties(props);
+        let data = write_with_options(&batch, options.clone());
+
+        // The page boundaries do not depend on how the data is split into batches
+        let mut writer =
+            ArrowWriter::try_new_with_options(Vec::new(), batch.schema(), options).unwrap();
+        writer.write(&batch).unwrap();
+        assert_eq!(writer.into_inner().unwrap(), data);
+
+        let data = Bytes::from(data);
+        assert!(first_column_data_pages(&data).len() > 1);
+
+        let reader = ParquetRecordBatchReader::try_new(data, 1000).unwrap();
+        let read = reader.collect::<ArrowResult<Vec<_>>>().unwrap();
+        assert_eq!(read, vec![batch]);
+    }
+
+    #[test]
+    fn test_arrow_writer_content_defined_chunking_insertion() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let values: Vec<i64> = (0..100_000).map(|_| rng.random()).collect();
+        let mut inserted = values.clone();
+        inserted.splice(50_000..50_000, 0..10);
+
+        let write = |values: &[i64], props: WriterProperties| {
+            let array = Arc::new(Int64Array::from(values.to_vec())) as ArrayRef;
+            let batch = RecordBatch::try_from_iter([("a", array)]).unwrap();
+            let mut writer = ArrowWriter::try_new(Vec::new(), batch.schema(), Some(props)).unwrap();
+            for offset in (0..batch.num_rows()).step_by(1000) {
+                let len = 1000.min(batch.num_rows() - offset);
+                writer.write(&batch.slice(offset, len)).unwrap();
+            }
+            first_column_data_pages(&Bytes::from(writer.into_inner().unwrap()))
+        };
+        let shared = |a: &[Bytes], b: &[Bytes]| b.iter().filter(|page| a.contains(page)).count();
+
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_content_defined_chunking(Some(CdcOptions {
+                min_chunk_size: 4 * 1024,
+                max_chunk_size: 16 * 1024,
+                norm_level: 0,
+            }))
+            .build();
+        let original = write(&values, props.clone());
+        let modified = write(&inserted, props);
+        assert!(original.len() > 50, "{}", original.len());
+        // Only the pages around the insertion differ
+        let num_shared = shared(&original, &modified);
+        assert!(num_shared + 2 >= modified.len(), "{num_shared}");
+
+        // Without content-defined chunking, all pages after the insertion differ
+        let props = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .set_data_page_size_limit(8 * 1024)
+            .build();
+        let original = write(&values, props.clone());
+        let modified = write(&inserted, props);
+        let num_shared = shared(&original, &modified);
+        assert!(num_shared <= modified.len() / 2 + 1, "{num_shared}");
+    }
+
+    #[test]
+    fn test_arrow_writer_content_defined_chunking_dictionary() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let indices: Vec<i32> = (0..20_000).map(|_| rng.random_range(0..1000)).collect();
+
+        // The same values, encoded with a dictionary in a different order and with
+        // an unused entry
+        let values = StringArray::from_iter_values((0..1000).map(|v| format!("value{v}")));
+        let reversed = StringArray::from_iter_values(
+            std::iter::once("unused".to_string())
+                .chain((0..1000).rev().map(|v| format!("value{v}"))),
+        );
+        let a = DictionaryArray::new(Int32Array::from(indices.clone()), Arc::new(values));
+        let b = DictionaryArray::new(
+            Int32Array::from_iter_values(indices.iter().map(|i| 1000 - i)),
+            Arc::new(reversed),
+        );
+
+        let props = WriterProperties::builder()
+            .set_content_defined_chunking(Some(CdcOptions {
+                min_chunk_size: 1024,
+                max_chunk_size: 4096,
+                norm_level: 0,
+            }))
+            .build();
+        let write = |array: DictionaryArray<Int32Type>| {
+            let batch = RecordBatch::try_from_iter([("a", Arc::new(array) as ArrayRef)]).unwrap();
+            let options = ArrowWriterOptions::new().with_properties(props.clone());
+            first_column_data_pages(&Bytes::from(write_with_options(&batch, options)))
+        };
+        let a = write(a);
+        assert!(a.len() > 10, "{}", a.len());
+        assert_eq!(a, write(b));
+    }
+
+    #[test]
+    fn test_arrow_writer_distinct_count() {
+        let ints = Int32Array::from_iter((0..10_000).map(|v| (v % 7 != 0).then_some(v % 500)));
+        let strings = StringArray::from_iter_values((0..10_000).map(|v| format!("s{}", v % 50)));
+        let batch = RecordBatch::try_from_iter([
+            ("i", Arc::new(ints) as ArrayRef),
+            ("s", Arc::new(strings) as ArrayRef),
+        ])
+        .unwrap();
+
+        let props = WriterProperties::builder()
+            .set_column_distinct_count_enabled(ColumnPath::from("i"), true)
+            .set_column_distinct_count_enabled(ColumnPath::from("s"), true)
+            .set_write_distinct_count_sketches(true)
+            .set_max_row_group_size(2_500)
+            .build();
+        let mut writer = ArrowWriter::try_new(Vec::new(), batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        let data = Bytes::from(writer.into_inner().unwrap());
+
+        let metadata = ParquetMetaDataReader::new()
+            .parse_and_finish(&data)
+            .unwrap();
+        assert_eq!(metadata.num_row_groups(), 4);
+        for row_group in metadata.row_groups() {
+            let stats = row_group.column(0).statistics().unwrap();
+            let estimate = stats.distinct_count_opt().unwrap();
+            assert!((490..=510).contains(&estimate), "{estimate}");
+            let stats = row_group.column(1).statistics().unwrap();
+            let estimate = stats.distinct_count_opt().unwrap();
+            assert!((45..=55).contains(&estimate), "{estimate}");
+        }
+
+        // The sketches of the row groups are merged
+        let file_metadata = metadata.file_metadata();
+        let sketch = read_distinct_count_sketch(file_metadata, &ColumnPath::from("i"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(sketch.precision(), DEFAULT_DISTINCT_COUNT_PRECISION);
+        let estimate = sketch.estimate();
+        assert!((490..=510).contains(&estimate), "{estimate}");
+        let sketch = read_distinct_count_sketch(file_metadata, &ColumnPath::from("s"))
+            .unwrap()
+            .unwrap();
+        let estimate = sketch.estimate();
+        assert!((45..=55).contains(&estimate), "{estimate}");
+        let sketch = read_distinct_count_sketch(file_metadata, &ColumnPath::from("x")).unwrap();
+        assert!(sketch.is_none());
+    }
 }
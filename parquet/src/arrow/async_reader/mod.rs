@@ -21,7 +21,7 @@
 //!
 //! See example on [`ParquetRecordBatchStreamBuilder::new`]
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Formatter;
 use std::io::SeekFrom;
 use std::ops::Range;
@@ -36,14 +36,17 @@ use futures::stream::Stream;
 use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
 
 use arrow_array::RecordBatch;
-use arrow_schema::{DataType, Fields, Schema, SchemaRef};
+use arrow_buffer::BooleanBuffer;
+use arrow_schema::{FieldRef, Fields, Schema, SchemaRef};
 
-use crate::arrow::array_reader::{ArrayReaderBuilder, RowGroups};
+use crate::arrow::array_reader::{
+    check_row_number_field, row_group_ranges, ArrayReaderBuilder, RowGroups,
+};
 use crate::arrow::arrow_reader::{
-    ArrowReaderBuilder, ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReader,
-    RowFilter, RowSelection,
+    retain_row_groups, ArrowReaderBuilder, ArrowReaderMetadata, ArrowReaderOptions,
+    ParquetRecordBatchReader, PruningPredicate, RowFilter, RowSelection,
 };
-use crate::arrow::ProjectionMask;
+use crate::arrow::{parquet_to_arrow_field_levels, ProjectionMask};
 
 use crate::bloom_filter::{
     chunk_read_bloom_filter_header_and_offset, Sbbf, SBBF_HEADER_SIZE_ESTIMATE,
@@ -62,7 +65,7 @@ pub use metadata::*;
 mod store;
 
 use crate::arrow::arrow_reader::ReadPlanBuilder;
-use crate::arrow::schema::ParquetField;
+use crate::arrow::schema::{statistics_schema, ParquetField};
 #[cfg(feature = "object_store")]
 pub use store::*;
 
@@ -422,61 +425,14 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
         row_group_idx: usize,
         column_idx: usize,
     ) -> Result<Option<Sbbf>> {
-        let metadata = self.metadata.row_group(row_group_idx);
-        let column_metadata = metadata.column(column_idx);
-
-        let offset: u64 = if let Some(offset) = column_metadata.bloom_filter_offset() {
-            offset
-                .try_into()
-                .map_err(|_| ParquetError::General("Bloom filter offset is invalid".to_string()))?
-        } else {
-            return Ok(None);
-        };
-
-        let buffer = match column_metadata.bloom_filter_length() {
-            Some(length) => self.input.0.get_bytes(offset..offset + length as u64),
-            None => self
-                .input
-                .0
-                .get_bytes(offset..offset + SBBF_HEADER_SIZE_ESTIMATE as u64),
-        }
+        let mut filters = fetch_bloom_filters(
+            &mut self.input.0,
+            &self.metadata,
+            &[row_group_idx],
+            &[column_idx],
+        )
         .await?;
-
-        let (header, bitset_offset) =
-            chunk_read_bloom_filter_header_and_offset(offset, buffer.clone())?;
-
-        match header.algorithm {
-            BloomFilterAlgorithm::BLOCK(_) => {
-                // this match exists to future proof the singleton algorithm enum
-            }
-        }
-        match header.compression {
-            BloomFilterCompression::UNCOMPRESSED(_) => {
-                // this match exists to future proof the singleton compression enum
-            }
-        }
-        match header.hash {
-            BloomFilterHash::XXHASH(_) => {
-                // this match exists to future proof the singleton hash enum
-            }
-        }
-
-        let bitset = match column_metadata.bloom_filter_length() {
-            Some(_) => buffer.slice(
-                (TryInto::<usize>::try_into(bitset_offset).unwrap()
-                    - TryInto::<usize>::try_into(offset).unwrap())..,
-            ),
-            None => {
-                let bitset_length: u64 = header.num_bytes.try_into().map_err(|_| {
-                    ParquetError::General("Bloom filter length is invalid".to_string())
-                })?;
-                self.input
-                    .0
-                    .get_bytes(bitset_offset..bitset_offset + bitset_length)
-                    .await?
-            }
-        };
-        Ok(Some(Sbbf::new(&bitset)))
+        Ok(filters.remove(&(row_group_idx, column_idx)))
     }
 
     /// Build a new [`ParquetRecordBatchStream`]
@@ -494,11 +450,42 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
                         num_row_groups
                     ));
                 }
-                row_groups.into()
+                row_groups
             }
             None => (0..self.metadata.row_groups().len()).collect(),
         };
 
+        let parquet_schema = self.metadata.file_metadata().schema_descr();
+        let statistics_schema =
+            statistics_schema(&self.schema, self.fields.as_deref(), parquet_schema);
+        let (row_groups, selection) = match &self.pruning_predicate {
+            Some(predicate) => predicate.prune(
+                &self.metadata,
+                &statistics_schema,
+                row_groups,
+                self.selection,
+            )?,
+            None => (row_groups, self.selection),
+        };
+
+        // Only probe bloom filters if any of the remaining row groups have them
+        let bloom_filter_pruning = match self.pruning_predicate {
+            Some(predicate) => {
+                let columns = predicate.bloom_filter_columns(&self.metadata, &statistics_schema)?;
+                let has_bloom_filters = row_groups.iter().any(|idx| {
+                    let row_group = self.metadata.row_group(*idx);
+                    let column = |c: &usize| row_group.column(*c).bloom_filter_offset();
+                    columns.iter().any(|c| column(c).is_some())
+                });
+                has_bloom_filters.then(|| BloomFilterPruning {
+                    predicate,
+                    schema: statistics_schema,
+                    columns,
+                })
+            }
+            None => None,
+        };
+
         // Try to avoid allocate large buffer
         let batch_size = self
             .batch_size
@@ -510,32 +497,148 @@ impl<T: AsyncFileReader + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
             fields: self.fields,
             limit: self.limit,
             offset: self.offset,
+            row_number_column: self.row_number_column,
         };
 
         // Ensure schema of ParquetRecordBatchStream respects projection, and does
         // not store metadata (same as for ParquetRecordBatchReader and emitted RecordBatches)
-        let projected_fields = match reader_factory.fields.as_deref().map(|pf| &pf.arrow_type) {
-            Some(DataType::Struct(fields)) => {
-                fields.filter_leaves(|idx, _| self.projection.leaf_included(idx))
+        //
+        // The projection is applied to the parquet schema, as some arrow types, such as
+        // unions, are stored with additional leaf columns
+        let projected_fields = match &reader_factory.fields {
+            Some(_) => {
+                parquet_to_arrow_field_levels(
+                    self.metadata.file_metadata().schema_descr(),
+                    self.projection.clone(),
+                    Some(self.schema.fields()),
+                )?
+                .fields
             }
             None => Fields::empty(),
-            _ => unreachable!("Must be Struct for root type"),
+        };
+        let projected_fields = match &reader_factory.row_number_column {
+            Some(field) => {
+                check_row_number_field(field, &projected_fields)?;
+                projected_fields.iter().chain([field]).cloned().collect()
+            }
+            None => projected_fields,
         };
         let schema = Arc::new(Schema::new(projected_fields));
 
         Ok(ParquetRecordBatchStream {
             metadata: self.metadata,
             batch_size,
-            row_groups,
+            row_groups: row_groups.into(),
             projection: self.projection,
-            selection: self.selection,
+            selection,
             schema,
             reader_factory: Some(reader_factory),
+            bloom_filter_pruning,
+            bloom_filter_pruned_row_groups: 0,
             state: StreamState::Init,
         })
     }
 }
 
+/// Fetches the bloom filters of `columns` in each of `row_groups`, keyed by row
+/// group and column, omitting any column chunks without a bloom filter
+///
+/// All bloom filters are requested with a single call to
+/// [`AsyncFileReader::get_byte_ranges`], allowing the reads to be coalesced. If
+/// the length of a bloom filter is not recorded in the metadata only its header
+/// is requested, and its bitset is fetched by a second call.
+async fn fetch_bloom_filters<T: AsyncFileReader>(
+    input: &mut T,
+    metadata: &ParquetMetaData,
+    row_groups: &[usize],
+    columns: &[usize],
+) -> Result<HashMap<(usize, usize), Sbbf>> {
+    let mut keys = vec![];
+    let mut ranges = vec![];
+    for row_group_idx in row_groups {
+        for column_idx in columns {
+            let column_metadata = metadata.row_group(*row_group_idx).column(*column_idx);
+            let Some(offset) = column_metadata.bloom_filter_offset() else {
+                continue;
+            };
+            let offset: u64 = offset
+                .try_into()
+                .map_err(|_| general_err!("Bloom filter offset is invalid"))?;
+            let length = match column_metadata.bloom_filter_length() {
+                Some(length) => length as u64,
+                None => SBBF_HEADER_SIZE_ESTIMATE as u64,
+            };
+            keys.push((*row_group_idx, *column_idx));
+            ranges.push(offset..offset + length);
+        }
+    }
+    let mut filters = HashMap::with_capacity(keys.len());
+    if ranges.is_empty() {
+        return Ok(filters);
+    }
+
+    let buffers = input.get_byte_ranges(ranges.clone()).await?;
+    let mut bitset_keys = vec![];
+    let mut bitset_ranges = vec![];
+    for ((key, range), buffer) in keys.into_iter().zip(ranges).zip(buffers) {
+        let (header, bitset_offset) =
+            chunk_read_bloom_filter_header_and_offset(range.start, buffer.clone())?;
+
+        match header.algorithm {
+            BloomFilterAlgorithm::BLOCK(_) => {
+                // this match exists to future proof the singleton algorithm enum
+            }
+        }
+        match header.compression {
+            BloomFilterCompression::UNCOMPRESSED(_) => {
+                // this match exists to future proof the singleton compression enum
+            }
+        }
+        match header.hash {
+            BloomFilterHash::XXHASH(_) => {
+                // this match exists to future proof the singleton hash enum
+            }
+        }
+
+        let bitset_length: u64 = header
+            .num_bytes
+            .try_into()
+            .map_err(|_| general_err!("Bloom filter length is invalid"))?;
+        let bitset = (bitset_offset - range.start) as usize..;
+        match buffer.get(bitset.clone()) {
+            Some(bitset) if bitset.len() as u64 >= bitset_length => {
+                filters.insert(key, Sbbf::new(&bitset[..bitset_length as usize]));
+            }
+            _ => {
+                bitset_keys.push(key);
+                bitset_ranges.push(bitset_offset..bitset_offset + bitset_length);
+            }
+        }
+    }
+
+    if !bitset_ranges.is_empty() {
+        let bitsets = input.get_byte_ranges(bitset_ranges).await?;
+        for (key, bitset) in bitset_keys.into_iter().zip(bitsets) {
+            filters.insert(key, Sbbf::new(&bitset));
+        }
+    }
+    Ok(filters)
+}
+
+/// A [`PruningPredicate`] to evaluate against the bloom filters of the row groups
+/// of a [`ParquetRecordBatchStream`] before the first is read
+struct BloomFilterPruning {
+    predicate: PruningPredicate,
+    /// The arrow schema of the file
+    schema: SchemaRef,
+    /// The parquet leaf columns tested for equality by `predicate`
+    columns: Vec<usize>,
+}
+
+/// Returns a [`ReaderFactory`] and, for each row group probed, whether its bloom
+/// filters allow it to contain rows matching a [`BloomFilterPruning`]
+type PruneResult<T> = Result<(ReaderFactory<T>, BooleanBuffer)>;
+
 /// Returns a [`ReaderFactory`] and an optional [`ParquetRecordBatchReader`] for the next row group
 ///
 /// Note: If all rows are filtered out in the row group (e.g by filters, limit or
@@ -560,12 +663,39 @@ struct ReaderFactory<T> {
 
     /// Offset to apply to the next
     offset: Option<usize>,
+
+    /// Optional virtual column of row positions within the file
+    row_number_column: Option<FieldRef>,
 }
 
 impl<T> ReaderFactory<T>
 where
     T: AsyncFileReader + Send,
 {
+    /// Fetches the bloom filters of `row_groups` and evaluates `pruning` against them
+    ///
+    /// Note: this captures self so that the resulting future has a static lifetime
+    async fn prune_bloom_filters(
+        mut self,
+        pruning: BloomFilterPruning,
+        row_groups: Vec<usize>,
+    ) -> PruneResult<T> {
+        let filters = fetch_bloom_filters(
+            &mut self.input,
+            &self.metadata,
+            &row_groups,
+            &pruning.columns,
+        )
+        .await?;
+        let keep = pruning.predicate.prune_bloom_filters(
+            &self.metadata,
+            &pruning.schema,
+            &row_groups,
+            &filters,
+        )?;
+        Ok((self, keep))
+    }
+
     /// Reads the next row group with the provided `selection`, `projection` and `batch_size`
     ///
     /// Updates the `limit` and `offset` of the reader factory
@@ -661,8 +791,13 @@ where
 
         let plan = plan_builder.build();
 
-        let array_reader = ArrayReaderBuilder::new(&row_group)
-            .build_array_reader(self.fields.as_deref(), &projection)?;
+        let mut array_reader_builder = ArrayReaderBuilder::new(&row_group);
+        if let Some(field) = &self.row_number_column {
+            let ranges = row_group_ranges(&self.metadata, &[row_group_idx]);
+            array_reader_builder = array_reader_builder.with_row_numbers(field.clone(), ranges);
+        }
+        let array_reader =
+            array_reader_builder.build_array_reader(self.fields.as_deref(), &projection)?;
 
         let reader = ParquetRecordBatchReader::new(array_reader, plan);
 
@@ -677,6 +812,8 @@ enum StreamState<T> {
     Decoding(ParquetRecordBatchReader),
     /// Reading data from input
     Reading(BoxFuture<'static, ReadResult<T>>),
+    /// Pruning row groups using their bloom filters
+    Pruning(BoxFuture<'static, PruneResult<T>>),
     /// Error
     Error,
 }
@@ -687,6 +824,7 @@ impl<T> std::fmt::Debug for StreamState<T> {
             StreamState::Init => write!(f, "StreamState::Init"),
             StreamState::Decoding(_) => write!(f, "StreamState::Decoding"),
             StreamState::Reading(_) => write!(f, "StreamState::Reading"),
+            StreamState::Pruning(_) => write!(f, "StreamState::Pruning"),
             StreamState::Error => write!(f, "StreamState::Error"),
         }
     }
@@ -725,6 +863,12 @@ pub struct ParquetRecordBatchStream<T> {
     /// This is an option so it can be moved into a future
     reader_factory: Option<ReaderFactory<T>>,
 
+    /// Bloom filter pruning yet to be applied to `row_groups`
+    bloom_filter_pruning: Option<BloomFilterPruning>,
+
+    /// The number of row groups skipped by bloom filter pruning
+    bloom_filter_pruned_row_groups: usize,
+
     state: StreamState<T>,
 }
 
@@ -748,6 +892,28 @@ impl<T> ParquetRecordBatchStream<T> {
     pub fn schema(&self) -> &SchemaRef {
         &self.schema
     }
+
+    /// Returns the number of row groups skipped because their bloom filters
+    /// rule out the equality predicates of the [`PruningPredicate`]
+    ///
+    /// Bloom filters are fetched and evaluated before the first row group is
+    /// read, and so this returns 0 until then.
+    ///
+    /// See [`ParquetRecordBatchStreamBuilder::with_pruning_predicate`]
+    pub fn bloom_filter_pruned_row_groups(&self) -> usize {
+        self.bloom_filter_pruned_row_groups
+    }
+
+    /// Removes the row groups for which `keep` is false, as returned by
+    /// [`ReaderFactory::prune_bloom_filters`] for the remaining row groups
+    fn retain_row_groups(&mut self, keep: &BooleanBuffer) {
+        let row_groups: Vec<usize> = self.row_groups.drain(..).collect();
+        let (kept, selection) =
+            retain_row_groups(&self.metadata, &row_groups, self.selection.take(), keep);
+        self.bloom_filter_pruned_row_groups += row_groups.len() - kept.len();
+        self.row_groups = kept.into();
+        self.selection = selection;
+    }
 }
 
 impl<T> ParquetRecordBatchStream<T>
@@ -770,12 +936,26 @@ where
     pub async fn next_row_group(&mut self) -> Result<Option<ParquetRecordBatchReader>> {
         loop {
             match &mut self.state {
-                StreamState::Decoding(_) | StreamState::Reading(_) => {
+                StreamState::Decoding(_) | StreamState::Reading(_) | StreamState::Pruning(_) => {
                     return Err(ParquetError::General(
                         "Cannot combine the use of next_row_group with the Stream API".to_string(),
                     ))
                 }
                 StreamState::Init => {
+                    if let Some(pruning) = self.bloom_filter_pruning.take() {
+                        let reader_factory =
+                            self.reader_factory.take().expect("lost reader factory");
+                        let row_groups = self.row_groups.iter().copied().collect();
+                        let (reader_factory, keep) = reader_factory
+                            .prune_bloom_filters(pruning, row_groups)
+                            .await
+                            .inspect_err(|_| {
+                                self.state = StreamState::Error;
+                            })?;
+                        self.reader_factory = Some(reader_factory);
+                        self.retain_row_groups(&keep);
+                    }
+
                     let row_group_idx = match self.row_groups.pop_front() {
                         Some(idx) => idx,
                         None => return Ok(None),
@@ -833,6 +1013,14 @@ where
                     None => self.state = StreamState::Init,
                 },
                 StreamState::Init => {
+                    if let Some(pruning) = self.bloom_filter_pruning.take() {
+                        let reader = self.reader_factory.take().expect("lost reader factory");
+                        let row_groups = self.row_groups.iter().copied().collect();
+                        let fut = reader.prune_bloom_filters(pruning, row_groups).boxed();
+                        self.state = StreamState::Pruning(fut);
+                        continue;
+                    }
+
                     let row_group_idx = match self.row_groups.pop_front() {
                         Some(idx) => idx,
                         None => return Poll::Ready(None),
@@ -870,6 +1058,17 @@ where
                         return Poll::Ready(Some(Err(e)));
                     }
                 },
+                StreamState::Pruning(f) => match ready!(f.poll_unpin(cx)) {
+                    Ok((reader_factory, keep)) => {
+                        self.reader_factory = Some(reader_factory);
+                        self.retain_row_groups(&keep);
+                        self.state = StreamState::Init;
+                    }
+                    Err(e) => {
+                        self.state = StreamState::Error;
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                },
                 StreamState::Error => return Poll::Ready(None), // Ends the stream as error happens.
             }
         }
@@ -1107,7 +1306,7 @@ impl PageIterator for ColumnChunkIterator {}
 mod tests {
     use super::*;
     use crate::arrow::arrow_reader::{
-        ArrowPredicateFn, ParquetRecordBatchReaderBuilder, RowSelector,
+        ArrowPredicateFn, ParquetRecordBatchReaderBuilder, PruningPredicate, RowSelector,
     };
     use crate::arrow::arrow_reader::{ArrowReaderMetadata, ArrowReaderOptions};
     use crate::arrow::schema::parquet_to_arrow_schema_and_fields;
@@ -1118,10 +1317,10 @@ mod tests {
     use arrow::error::Result as ArrowResult;
     use arrow_array::builder::{ListBuilder, StringBuilder};
     use arrow_array::cast::AsArray;
-    use arrow_array::types::Int32Type;
+    use arrow_array::types::{Int32Type, Int64Type};
     use arrow_array::{
-        Array, ArrayRef, Int32Array, Int8Array, RecordBatchReader, Scalar, StringArray,
-        StructArray, UInt64Array,
+        Array, ArrayRef, BooleanArray, Int32Array, Int8Array, RecordBatchReader, Scalar,
+        StringArray, StructArray, UInt64Array,
     };
     use arrow_schema::{DataType, Field, Schema};
     use futures::{StreamExt, TryStreamExt};
@@ -1869,6 +2068,7 @@ mod tests {
             metadata.file_metadata().schema_descr(),
             ProjectionMask::all(),
             None,
+            None,
         )
         .unwrap();
 
@@ -1883,6 +2083,7 @@ mod tests {
             filter: None,
             limit: None,
             offset: None,
+            row_number_column: None,
         };
 
         let mut skip = true;
@@ -2386,4 +2587,173 @@ mod tests {
         let result = reader.try_collect::<Vec<_>>().await.unwrap();
         assert_eq!(result.len(), 1);
     }
+
+    #[tokio::test]
+    async fn test_row_number_column() {
+        let c = Int32Array::from_iter_values(0..30);
+        let data = RecordBatch::try_from_iter([("c", Arc::new(c) as ArrayRef)]).unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+
+        let test = TestReader::new(buf.into());
+        let builder = ParquetRecordBatchStreamBuilder::new(test).await.unwrap();
+        let filter = ArrowPredicateFn::new(ProjectionMask::all(), |batch: RecordBatch| {
+            let c = batch.column(0).as_primitive::<Int32Type>();
+            Ok(BooleanArray::from_unary(c, |c| c % 2 == 0))
+        });
+        let stream = builder
+            .with_row_groups(vec![0, 2])
+            .with_row_filter(RowFilter::new(vec![Box::new(filter)]))
+            .with_offset(1)
+            .with_limit(6)
+            .with_row_number_column(Field::new("row_number", DataType::Int64, false))
+            .build()
+            .unwrap();
+
+        let expected = Schema::new(vec![
+            Field::new("c", DataType::Int32, false),
+            Field::new("row_number", DataType::Int64, false),
+        ]);
+        assert_eq!(stream.schema().as_ref(), &expected);
+
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        let mut c: Vec<i32> = vec![];
+        let mut row_numbers: Vec<i64> = vec![];
+        for batch in &batches {
+            assert_eq!(batch.schema().as_ref(), &expected);
+            c.extend(batch.column(0).as_primitive::<Int32Type>().values());
+            row_numbers.extend(batch.column(1).as_primitive::<Int64Type>().values());
+        }
+        assert_eq!(c, [2, 4, 6, 8, 20, 22]);
+        assert_eq!(row_numbers, [2, 4, 6, 8, 20, 22]);
+    }
+
+    #[tokio::test]
+    async fn test_pruning_predicate() {
+        let c = Int32Array::from_iter_values(0..40);
+        let data = RecordBatch::try_from_iter([("c", Arc::new(c) as ArrayRef)]).unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .set_data_page_row_count_limit(5)
+            .set_write_batch_size(5)
+            .set_dictionary_enabled(false)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+
+        let test = TestReader::new(buf.into());
+        let requests = test.requests.clone();
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let builder = ParquetRecordBatchStreamBuilder::new_with_options(test, options)
+            .await
+            .unwrap();
+        let metadata = builder.metadata().clone();
+        requests.lock().unwrap().clear();
+
+        let predicate = PruningPredicate::gt_eq("c", Int32Array::new_scalar(17))
+            .and(PruningPredicate::lt_eq("c", Int32Array::new_scalar(21)));
+        let stream = builder.with_pruning_predicate(predicate).build().unwrap();
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+
+        let c: Vec<i32> = batches
+            .iter()
+            .flat_map(|b| b.column(0).as_primitive::<Int32Type>().values().to_vec())
+            .collect();
+        assert_eq!(c, [15, 16, 17, 18, 19, 20, 21, 22, 23, 24]);
+
+        // Only the second page of row group 1 and first page of row group 2 are fetched
+        let pages: Vec<_> = [(1, 1), (2, 0)]
+            .iter()
+            .map(|(rg, page)| {
+                let location = &metadata.offset_index().unwrap()[*rg][0].page_locations[*page];
+                location.offset as usize
+                    ..(location.offset + location.compressed_page_size as i64) as usize
+            })
+            .collect();
+        assert_eq!(requests.lock().unwrap().as_slice(), pages.as_slice());
+    }
+
+    #[tokio::test]
+    async fn test_bloom_filter_pruning() {
+        // Every row group has the same minimum and maximum, so only the bloom
+        // filters can distinguish them
+        let c = (0..5).flat_map(|rg| [0, 100].into_iter().chain(rg * 10 + 1..rg * 10 + 9));
+        let c = Int32Array::from_iter_values(c);
+        let data = RecordBatch::try_from_iter([("c", Arc::new(c) as ArrayRef)]).unwrap();
+
+        let mut buf = Vec::with_capacity(1024);
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(10)
+            .set_bloom_filter_enabled(true)
+            .build();
+        let mut writer = ArrowWriter::try_new(&mut buf, data.schema(), Some(props)).unwrap();
+        writer.write(&data).unwrap();
+        writer.close().unwrap();
+        let data = Bytes::from(buf);
+
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![13, 47]));
+        let builder = ParquetRecordBatchStreamBuilder::new(TestReader::new(data.clone()))
+            .await
+            .unwrap();
+        let mut stream = builder
+            .with_pruning_predicate(PruningPredicate::in_list("c", values))
+            .build()
+            .unwrap();
+        assert_eq!(stream.bloom_filter_pruned_row_groups(), 0);
+
+        let mut c: Vec<i32> = vec![];
+        while let Some(batch) = stream.next().await {
+            c.extend(
+                batch
+                    .unwrap()
+                    .column(0)
+                    .as_primitive::<Int32Type>()
+                    .values(),
+            );
+        }
+        assert_eq!(
+            c,
+            [0, 100, 11, 12, 13, 14, 15, 16, 17, 18, 0, 100, 41, 42, 43, 44, 45, 46, 47, 48]
+        );
+        assert_eq!(stream.bloom_filter_pruned_row_groups(), 3);
+
+        // Only row groups with a bloom filter containing the value are read, with
+        // the selection of the remaining row groups preserved
+        let builder = ParquetRecordBatchStreamBuilder::new(TestReader::new(data.clone()))
+            .await
+            .unwrap();
+        let selection = RowSelection::from(vec![RowSelector::skip(35), RowSelector::select(3)]);
+        let mut stream = builder
+            .with_row_selection(selection)
+            .with_pruning_predicate(PruningPredicate::eq("c", Int32Array::new_scalar(33)))
+            .build()
+            .unwrap();
+        let reader = stream.next_row_group().await.unwrap().unwrap();
+        let batch = reader.collect::<Result<Vec<_>, _>>().unwrap().remove(0);
+        assert_eq!(
+            batch.column(0).as_primitive::<Int32Type>().values(),
+            &[34, 35, 36]
+        );
+        assert!(stream.next_row_group().await.unwrap().is_none());
+        assert_eq!(stream.bloom_filter_pruned_row_groups(), 4);
+
+        // Negated predicates can not use bloom filters
+        let builder = ParquetRecordBatchStreamBuilder::new(TestReader::new(data))
+            .await
+            .unwrap();
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![33]));
+        let predicate = PruningPredicate::not_in_list("c", values);
+        let stream = builder.with_pruning_predicate(predicate).build().unwrap();
+        let batches: Vec<_> = stream.try_collect().await.unwrap();
+        assert_eq!(batches.iter().map(|b| b.num_rows()).sum::<usize>(), 50);
+    }
 }
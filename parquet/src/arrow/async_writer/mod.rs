@@ -148,6 +148,18 @@ impl<T: AsyncWrite + Unpin + Send> AsyncFileWriter for T {
 /// }
 /// # }
 /// ```
+///
+/// ## Concurrent Encoding
+///
+/// Columns can be encoded concurrently by configuring an [`EncodingExecutor`] with
+/// [`ArrowWriterOptions::with_encoding_executor`], see [ArrowWriter] for more information.
+/// Note that [`Self::write`], [`Self::flush`] and [`Self::finish`] block the thread
+/// polling them whilst waiting for the columns to be encoded, preventing it from running
+/// other tasks of the async runtime. The executor should therefore not run tasks on the
+/// threads of the async runtime. To avoid blocking the runtime, instead write with an
+/// [`ArrowWriter`] within [`tokio::task::spawn_blocking`].
+///
+/// [`EncodingExecutor`]: crate::arrow::arrow_writer::EncodingExecutor
 pub struct AsyncArrowWriter<W> {
     /// Underlying sync writer
     sync_writer: ArrowWriter<Vec<u8>>,
@@ -299,6 +311,7 @@ mod tests {
     use tokio::pin;
 
     use crate::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
+    use crate::arrow::arrow_writer::ThreadPoolExecutor;
 
     use super::*;
 
@@ -365,6 +378,42 @@ mod tests {
         assert_eq!(sync_buffer, async_buffer);
     }
 
+    #[tokio::test]
+    async fn test_async_writer_concurrent_encoding() {
+        let a = Arc::new(Int64Array::from_iter_values(0..1000)) as ArrayRef;
+        let b = Arc::new(Int32Array::from_iter(
+            (0..1000).map(|x| (x % 3 != 0).then_some(x)),
+        ));
+        let to_write = RecordBatch::try_from_iter([("a", a), ("b", b as ArrayRef)]).unwrap();
+
+        let write_props = WriterProperties::builder()
+            .set_max_row_group_size(300)
+            .build();
+        let options = ArrowWriterOptions::new()
+            .with_properties(write_props.clone())
+            .with_encoding_executor(Arc::new(ThreadPoolExecutor::new(2)))
+            .with_max_concurrent_row_groups(2);
+
+        let mut async_buffer = Vec::new();
+        let mut async_writer =
+            AsyncArrowWriter::try_new_with_options(&mut async_buffer, to_write.schema(), options)
+                .unwrap();
+
+        let mut sync_buffer = Vec::new();
+        let mut sync_writer =
+            ArrowWriter::try_new(&mut sync_buffer, to_write.schema(), Some(write_props)).unwrap();
+        for offset in (0..1000).step_by(100) {
+            let batch = to_write.slice(offset, 100);
+            async_writer.write(&batch).await.unwrap();
+            sync_writer.write(&batch).unwrap();
+        }
+        sync_writer.close().unwrap();
+        let metadata = async_writer.close().await.unwrap();
+
+        assert_eq!(metadata.row_groups.len(), 4);
+        assert_eq!(sync_buffer, async_buffer);
+    }
+
     struct TestAsyncSink {
         sink: Vec<u8>,
         min_accept_bytes: usize,
@@ -246,6 +246,15 @@ pub const PARQUET_FIELD_ID_META_KEY: &str = "PARQUET:field_id";
 /// For non-nested schemas, i.e. those containing only primitive columns, the root
 /// and leaves are the same
 ///
+/// An arrow `Union` is stored as a group of its type ids followed by a column for
+/// each child. When reading with the embedded arrow schema, a [`ProjectionMask`]
+/// must include either all or none of the leaves of such a group, otherwise
+/// reading returns an error. Without the embedded arrow schema, see
+/// [`ArrowReaderOptions::with_skip_arrow_metadata`], the group is read as a struct
+/// and any of its leaves can be projected.
+///
+/// [`ArrowReaderOptions::with_skip_arrow_metadata`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_skip_arrow_metadata
+///
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ProjectionMask {
     /// If `Some`, a leaf column should be included if the value at
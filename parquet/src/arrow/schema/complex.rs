@@ -19,12 +19,17 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use crate::arrow::schema::primitive::convert_primitive;
+use crate::arrow::schema::union_struct_fields;
 use crate::arrow::{ProjectionMask, PARQUET_FIELD_ID_META_KEY};
-use crate::basic::{ConvertedType, Repetition};
+use crate::basic::{ConvertedType, LogicalType, Repetition, Type as PhysicalType};
 use crate::errors::ParquetError;
 use crate::errors::Result;
 use crate::schema::types::{SchemaDescriptor, Type, TypePtr};
-use arrow_schema::{DataType, Field, Fields, SchemaBuilder};
+#[cfg(feature = "variant_experimental")]
+use arrow_schema::extension::ExtensionType;
+use arrow_schema::{DataType, Field, Fields, SchemaBuilder, TimeUnit};
+#[cfg(feature = "variant_experimental")]
+use parquet_variant_compute::VariantType;
 
 fn get_repetition(t: &Type) -> Repetition {
     let info = t.get_basic_info();
@@ -74,7 +79,7 @@ impl ParquetField {
     /// Returns a list of [`ParquetField`] children if this is a group type
     pub fn children(&self) -> Option<&[Self]> {
         match &self.field_type {
-            ParquetFieldType::Primitive { .. } => None,
+            ParquetFieldType::Primitive { .. } | ParquetFieldType::Missing => None,
             ParquetFieldType::Group { children } => Some(children),
         }
     }
@@ -91,6 +96,11 @@ pub enum ParquetFieldType {
     Group {
         children: Vec<ParquetField>,
     },
+    /// A field of the arrow schema that is not present in the parquet file,
+    /// which is read as nulls (see [`ArrowReaderOptions::with_target_schema`])
+    ///
+    /// [`ArrowReaderOptions::with_target_schema`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_target_schema
+    Missing,
 }
 
 /// Encodes the context of the parent of the field currently under consideration
@@ -99,6 +109,8 @@ struct VisitorContext {
     def_level: i16,
     /// An optional [`DataType`] sourced from the embedded arrow schema
     data_type: Option<DataType>,
+    /// Whether the field is nested within a `VARIANT` group
+    in_variant: bool,
 }
 
 impl VisitorContext {
@@ -124,6 +136,9 @@ struct Visitor {
 
     /// Mask of columns to include
     mask: ProjectionMask,
+
+    /// The [`TimeUnit`] to read INT96 columns as, if not nanoseconds
+    coerce_int96: Option<TimeUnit>,
 }
 
 impl Visitor {
@@ -142,7 +157,22 @@ impl Visitor {
         let repetition = get_repetition(primitive_type);
         let (def_level, rep_level, nullable) = context.levels(repetition);
 
-        let arrow_type = convert_primitive(primitive_type, context.data_type)?;
+        let hint = match (self.coerce_int96, primitive_type.get_physical_type()) {
+            // Keep the timezone and dictionary encoding of the embedded arrow schema, if any
+            (Some(unit), PhysicalType::INT96) => match context.data_type {
+                Some(DataType::Timestamp(_, tz)) => Some(DataType::Timestamp(unit, tz)),
+                Some(DataType::Dictionary(key, value)) => match *value {
+                    DataType::Timestamp(_, tz) => Some(DataType::Dictionary(
+                        key,
+                        Box::new(DataType::Timestamp(unit, tz)),
+                    )),
+                    _ => Some(DataType::Timestamp(unit, None)),
+                },
+                _ => Some(DataType::Timestamp(unit, None)),
+            },
+            _ => context.data_type,
+        };
+        let arrow_type = convert_primitive(primitive_type, hint)?;
 
         let primitive_field = ParquetField {
             rep_level,
@@ -172,8 +202,20 @@ impl Visitor {
 
         let parquet_fields = struct_type.get_fields();
 
+        // A union is stored as a struct, see `union_struct_fields`
+        let union_fields = match &context.data_type {
+            Some(DataType::Union(fields, mode)) => {
+                Some((fields, *mode, DataType::Struct(union_struct_fields(fields))))
+            }
+            _ => None,
+        };
+        let data_type = match &union_fields {
+            Some((_, _, data_type)) => Some(data_type),
+            None => context.data_type.as_ref(),
+        };
+
         // Extract the arrow fields
-        let arrow_fields = match &context.data_type {
+        let arrow_fields = match data_type {
             Some(DataType::Struct(fields)) => {
                 if fields.len() != parquet_fields.len() {
                     return Err(arrow_err!(
@@ -195,6 +237,8 @@ impl Visitor {
 
         let mut child_fields = SchemaBuilder::with_capacity(parquet_fields.len());
         let mut children = Vec::with_capacity(parquet_fields.len());
+        let in_variant = context.in_variant
+            || struct_type.get_basic_info().logical_type() == Some(LogicalType::Variant);
 
         // Perform a DFS of children
         for (idx, parquet_field) in parquet_fields.iter().enumerate() {
@@ -210,6 +254,9 @@ impl Visitor {
                     }
                     Some(field.data_type().clone())
                 }
+                #[cfg(feature = "variant_experimental")]
+                None => variant_child_hint(in_variant, parquet_field),
+                #[cfg(not(feature = "variant_experimental"))]
                 None => None,
             };
 
@@ -218,6 +265,7 @@ impl Visitor {
                 rep_level,
                 def_level,
                 data_type,
+                in_variant,
             };
 
             if let Some(child) = self.dispatch(parquet_field, child_ctx)? {
@@ -232,11 +280,34 @@ impl Visitor {
             return Ok(None);
         }
 
+        let child_fields = child_fields.finish().fields;
+        let arrow_type = match union_fields {
+            Some(_) if children.len() != parquet_fields.len() => {
+                return Err(arrow_err!(
+                    "partial projection of union {} is not supported",
+                    struct_type.name()
+                ))
+            }
+            Some((fields, mode, _)) => {
+                let fields = fields
+                    .iter()
+                    .zip(child_fields.iter().skip(1))
+                    .map(|((id, f), c)| {
+                        (
+                            id,
+                            Arc::new(f.as_ref().clone().with_data_type(c.data_type().clone())),
+                        )
+                    });
+                DataType::Union(fields.collect(), mode)
+            }
+            _ => DataType::Struct(child_fields),
+        };
+
         let struct_field = ParquetField {
             rep_level,
             def_level,
             nullable,
-            arrow_type: DataType::Struct(child_fields.finish().fields),
+            arrow_type,
             field_type: ParquetFieldType::Group { children },
         };
 
@@ -335,6 +406,7 @@ impl Visitor {
                 rep_level,
                 def_level,
                 data_type: arrow_key.map(|x| x.data_type().clone()),
+                in_variant: context.in_variant,
             };
 
             self.dispatch(map_key, context)?
@@ -345,6 +417,7 @@ impl Visitor {
                 rep_level,
                 def_level,
                 data_type: arrow_value.map(|x| x.data_type().clone()),
+                in_variant: context.in_variant,
             };
 
             self.dispatch(map_value, context)?
@@ -441,6 +514,7 @@ impl Visitor {
                 rep_level: context.rep_level,
                 def_level,
                 data_type: arrow_field.map(|f| f.data_type().clone()),
+                in_variant: context.in_variant,
             };
 
             return match self.visit_primitive(repeated_field, context) {
@@ -472,6 +546,7 @@ impl Visitor {
                 rep_level: context.rep_level,
                 def_level,
                 data_type: arrow_field.map(|f| f.data_type().clone()),
+                in_variant: context.in_variant,
             };
 
             return match self.visit_struct(repeated_field, context) {
@@ -492,6 +567,7 @@ impl Visitor {
             def_level,
             rep_level,
             data_type: arrow_field.map(|f| f.data_type().clone()),
+            in_variant: context.in_variant,
         };
 
         match self.dispatch(item_type, new_context) {
@@ -549,7 +625,8 @@ fn convert_field(parquet_type: &Type, field: &ParquetField, arrow_hint: Option<&
     let data_type = field.arrow_type.clone();
     let nullable = field.nullable;
 
-    match arrow_hint {
+    #[allow(unused_mut)]
+    let mut ret = match arrow_hint {
         Some(hint) => {
             // If the inferred type is a dictionary, preserve dictionary metadata
             #[allow(deprecated)]
@@ -577,28 +654,57 @@ fn convert_field(parquet_type: &Type, field: &ParquetField, arrow_hint: Option<&
             }
             ret
         }
+    };
+
+    // Tag VARIANT groups with the extension type of `VariantArray`, unless
+    // projected such that they lack the required `metadata` column
+    #[cfg(feature = "variant_experimental")]
+    if parquet_type.get_basic_info().logical_type() == Some(LogicalType::Variant)
+        && VariantType.supports_data_type(ret.data_type()).is_ok()
+    {
+        ret = ret.with_extension_type(VariantType);
     }
+    ret
+}
+
+/// Returns the arrow type with which to read `field`, a child of a group nested
+/// within a `VARIANT` group if `in_variant`, in the absence of an embedded arrow schema
+///
+/// The binary `metadata` and `value` columns of a `VARIANT` group, including the
+/// `value` columns of its shredded `typed_value`, are read as `BinaryView`, as
+/// required by `VariantArray`
+#[cfg(feature = "variant_experimental")]
+fn variant_child_hint(in_variant: bool, field: &Type) -> Option<DataType> {
+    let is_binary = field.is_primitive() && field.get_physical_type() == PhysicalType::BYTE_ARRAY;
+    (in_variant && is_binary && matches!(field.name(), "metadata" | "value"))
+        .then_some(DataType::BinaryView)
 }
 
 /// Computes the [`ParquetField`] for the provided [`SchemaDescriptor`] with `leaf_columns` listing
 /// the indexes of leaf columns to project, and `embedded_arrow_schema` the optional
 /// [`Fields`] embedded in the parquet metadata
 ///
+/// INT96 columns are read as timestamps of the [`TimeUnit`] `coerce_int96` if provided,
+/// otherwise as nanoseconds
+///
 /// Note: This does not support out of order column projection
 pub fn convert_schema(
     schema: &SchemaDescriptor,
     mask: ProjectionMask,
     embedded_arrow_schema: Option<&Fields>,
+    coerce_int96: Option<TimeUnit>,
 ) -> Result<Option<ParquetField>> {
     let mut visitor = Visitor {
         next_col_idx: 0,
         mask,
+        coerce_int96,
     };
 
     let context = VisitorContext {
         rep_level: 0,
         def_level: 0,
         data_type: embedded_arrow_schema.map(|fields| DataType::Struct(fields.clone())),
+        in_variant: false,
     };
 
     visitor.dispatch(&schema.root_schema_ptr(), context)
@@ -609,12 +715,14 @@ pub fn convert_type(parquet_type: &TypePtr) -> Result<ParquetField> {
     let mut visitor = Visitor {
         next_col_idx: 0,
         mask: ProjectionMask::all(),
+        coerce_int96: None,
     };
 
     let context = VisitorContext {
         rep_level: 0,
         def_level: 0,
         data_type: None,
+        in_variant: false,
     };
 
     Ok(visitor.dispatch(parquet_type, context)?.unwrap())
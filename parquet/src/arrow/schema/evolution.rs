@@ -0,0 +1,273 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reading a parquet file into a target arrow schema, which may have evolved
+//! since the file was written (see [`ArrowReaderOptions::with_target_schema`])
+//!
+//! [`ArrowReaderOptions::with_target_schema`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_target_schema
+
+use std::sync::Arc;
+
+use arrow_schema::{DataType, Field, FieldRef, Fields, Schema, SchemaRef};
+
+use super::complex::{convert_schema, ParquetField, ParquetFieldType};
+use super::field_id;
+use super::primitive::convert_primitive;
+use super::union_struct_fields;
+use crate::arrow::ProjectionMask;
+use crate::errors::{ParquetError, Result};
+use crate::schema::types::SchemaDescriptor;
+
+/// Computes the [`ParquetField`] for reading the file with the parquet `schema`
+/// as the `target` fields
+///
+/// Fields of `target` with a field id are matched with the parquet field with the
+/// same id, and other fields with the parquet field of the same name. Parquet
+/// fields without a match are not read, and nullable fields of `target` without a
+/// match are read as nulls.
+pub(crate) fn evolve_schema(schema: &SchemaDescriptor, target: &Fields) -> Result<ParquetField> {
+    let root = match convert_schema(schema, ProjectionMask::all(), None, None)? {
+        Some(root) => root,
+        None => ParquetField {
+            rep_level: 0,
+            def_level: 0,
+            nullable: false,
+            arrow_type: DataType::Struct(Fields::empty()),
+            field_type: ParquetFieldType::Group { children: vec![] },
+        },
+    };
+
+    let children = evolve_children(&root, target, None)?;
+    Ok(ParquetField {
+        arrow_type: DataType::Struct(target.clone()),
+        field_type: ParquetFieldType::Group { children },
+        ..root
+    })
+}
+
+/// Returns the fields of the struct `file` to read as the `target` fields, with
+/// [`ParquetFieldType::Missing`] for those not in the file
+fn evolve_children(
+    file: &ParquetField,
+    target: &Fields,
+    path: Option<&str>,
+) -> Result<Vec<ParquetField>> {
+    let (file_fields, file_children) = match (&file.arrow_type, file.children()) {
+        (DataType::Struct(fields), Some(children)) => (fields, children),
+        _ => unreachable!(),
+    };
+
+    target
+        .iter()
+        .map(|target| {
+            let path = match path {
+                Some(path) => format!("{path}.{}", target.name()),
+                None => target.name().clone(),
+            };
+            let idx = match field_id(target) {
+                Some(id) => file_fields.iter().position(|f| field_id(f) == Some(id)),
+                None => file_fields.iter().position(|f| f.name() == target.name()),
+            };
+            let child = match idx {
+                Some(idx) => evolve_field(&file_children[idx], target, &path)?,
+                None => None,
+            };
+
+            match child {
+                Some(child) => Ok(child),
+                None if target.is_nullable() => Ok(ParquetField {
+                    rep_level: file.rep_level,
+                    def_level: file.def_level,
+                    nullable: true,
+                    arrow_type: target.data_type().clone(),
+                    field_type: ParquetFieldType::Missing,
+                }),
+                None => Err(arrow_err!(
+                    "incompatible target schema, non-nullable field {} is missing from the parquet file",
+                    path
+                )),
+            }
+        })
+        .collect()
+}
+
+/// Returns the [`ParquetField`] for reading `file` as `target`, or `None` if none of
+/// the children of `target` are in the file
+fn evolve_field(file: &ParquetField, target: &Field, path: &str) -> Result<Option<ParquetField>> {
+    if file.nullable && !target.is_nullable() {
+        return Err(arrow_err!(
+            "incompatible target schema, field {} is nullable in the parquet file",
+            path
+        ));
+    }
+
+    let incompatible = || {
+        arrow_err!(
+            "incompatible target schema, cannot read field {} of type {} as {}",
+            path,
+            file.arrow_type,
+            target.data_type()
+        )
+    };
+
+    let field_type = match (&file.field_type, &file.arrow_type, target.data_type()) {
+        (ParquetFieldType::Primitive { primitive_type, .. }, _, target_type) => {
+            let data_type = convert_primitive(primitive_type, Some(target_type.clone()))?;
+            let value_type = match target_type {
+                DataType::Dictionary(_, value) => value.as_ref(),
+                _ => target_type,
+            };
+            let compatible = match (&file.arrow_type, value_type) {
+                // A Decimal256 hint is applied regardless of its precision and scale, so
+                // must be checked against the decimal type of the file
+                (DataType::Decimal128(_, _), DataType::Decimal256(_, _)) => {
+                    is_widening(&file.arrow_type, value_type)
+                }
+                _ => &data_type == target_type || is_widening(&data_type, target_type),
+            };
+            if !compatible {
+                return Err(incompatible());
+            }
+            file.field_type.clone()
+        }
+        (ParquetFieldType::Group { .. }, DataType::Struct(_), DataType::Struct(fields)) => {
+            let children = evolve_children(file, fields, Some(path))?;
+            let missing = |c: &ParquetField| matches!(c.field_type, ParquetFieldType::Missing);
+            if !children.is_empty() && children.iter().all(missing) {
+                return Ok(None);
+            }
+            ParquetFieldType::Group { children }
+        }
+        // A union is stored as a struct, see `union_struct_fields`
+        (ParquetFieldType::Group { .. }, DataType::Struct(_), DataType::Union(fields, _)) => {
+            let children = evolve_children(file, &union_struct_fields(fields), Some(path))?;
+            ParquetFieldType::Group { children }
+        }
+        (
+            ParquetFieldType::Group { children },
+            DataType::List(_) | DataType::LargeList(_) | DataType::FixedSizeList(_, _),
+            DataType::List(f) | DataType::LargeList(f) | DataType::FixedSizeList(f, _),
+        ) => {
+            let path = format!("{path}.{}", f.name());
+            match evolve_field(&children[0], f, &path)? {
+                Some(child) => ParquetFieldType::Group {
+                    children: vec![child],
+                },
+                None => return Ok(None),
+            }
+        }
+        (ParquetFieldType::Group { children }, DataType::Map(_, _), DataType::Map(f, _)) => {
+            let entries = match f.data_type() {
+                DataType::Struct(entries) if entries.len() == 2 => entries,
+                _ => return Err(incompatible()),
+            };
+            // The key is always read as non-nullable (#5630)
+            let key = entries[0].as_ref().clone().with_nullable(true);
+            let key = evolve_field(&children[0], &key, &format!("{path}.{}", key.name()))?;
+            let value_path = format!("{path}.{}", entries[1].name());
+            let value = evolve_field(&children[1], &entries[1], &value_path)?;
+            match (key, value) {
+                (Some(key), Some(value)) => ParquetFieldType::Group {
+                    children: vec![key, value],
+                },
+                _ => return Ok(None),
+            }
+        }
+        _ => return Err(incompatible()),
+    };
+
+    Ok(Some(ParquetField {
+        rep_level: file.rep_level,
+        def_level: file.def_level,
+        nullable: file.nullable,
+        arrow_type: target.data_type().clone(),
+        field_type,
+    }))
+}
+
+/// Returns true if values of type `from` can be losslessly read as `to`
+fn is_widening(from: &DataType, to: &DataType) -> bool {
+    match (from, to) {
+        (DataType::Int8, DataType::Int16 | DataType::Int32 | DataType::Int64) => true,
+        (DataType::Int16, DataType::Int32 | DataType::Int64) => true,
+        (DataType::Int32, DataType::Int64) => true,
+        (DataType::Float32, DataType::Float64) => true,
+        (
+            DataType::Decimal128(p1, s1),
+            DataType::Decimal128(p2, s2) | DataType::Decimal256(p2, s2),
+        )
+        | (DataType::Decimal256(p1, s1), DataType::Decimal256(p2, s2)) => s1 == s2 && p1 <= p2,
+        _ => false,
+    }
+}
+
+/// Returns `schema`, the schema of `fields`, with its fields at the positions of the
+/// corresponding root columns of `parquet_schema`
+///
+/// [`StatisticsConverter`] locates the parquet column of a field by its position, whereas
+/// the fields of a target schema may be in a different order from the columns of the file.
+/// Fields missing from the file are moved after the root columns, and root columns not read
+/// are given an empty name.
+///
+/// [`StatisticsConverter`]: crate::arrow::arrow_reader::statistics::StatisticsConverter
+pub(crate) fn statistics_schema(
+    schema: &SchemaRef,
+    fields: Option<&ParquetField>,
+    parquet_schema: &SchemaDescriptor,
+) -> SchemaRef {
+    let Some(children) = fields.and_then(|f| f.children()) else {
+        return schema.clone();
+    };
+
+    let num_roots = parquet_schema.root_schema().get_fields().len();
+    let mut roots: Vec<Option<FieldRef>> = vec![None; num_roots];
+    let mut missing = vec![];
+    for (field, child) in schema.fields().iter().zip(children) {
+        match first_leaf(child) {
+            Some(col_idx) => {
+                roots[parquet_schema.get_column_root_idx(col_idx)] = Some(field.clone())
+            }
+            None => missing.push(field.clone()),
+        }
+    }
+
+    let aligned = missing.is_empty()
+        && roots.len() == schema.fields().len()
+        && roots
+            .iter()
+            .zip(schema.fields())
+            .all(|(a, b)| a.as_ref() == Some(b));
+    if aligned {
+        return schema.clone();
+    }
+
+    let fields: Fields = roots
+        .into_iter()
+        .map(|f| f.unwrap_or_else(|| Arc::new(Field::new("", DataType::Null, true))))
+        .chain(missing)
+        .collect();
+    Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone()))
+}
+
+/// Returns the index of the first leaf column of `field` in the file, if any
+fn first_leaf(field: &ParquetField) -> Option<usize> {
+    match &field.field_type {
+        ParquetFieldType::Primitive { col_idx, .. } => Some(*col_idx),
+        ParquetFieldType::Group { children } => children.iter().find_map(first_leaf),
+        ParquetFieldType::Missing => None,
+    }
+}
@@ -23,9 +23,13 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use arrow_ipc::writer;
+#[cfg(feature = "variant_experimental")]
+use arrow_schema::extension::ExtensionType;
 #[cfg(feature = "arrow_canonical_extension_types")]
 use arrow_schema::extension::{Json, Uuid};
-use arrow_schema::{DataType, Field, Fields, Schema, TimeUnit};
+use arrow_schema::{DataType, Field, Fields, Schema, TimeUnit, UnionFields};
+#[cfg(feature = "variant_experimental")]
+use parquet_variant_compute::VariantType;
 
 use crate::basic::{
     ConvertedType, LogicalType, Repetition, TimeUnit as ParquetTimeUnit, Type as PhysicalType,
@@ -35,10 +39,12 @@ use crate::file::{metadata::KeyValue, properties::WriterProperties};
 use crate::schema::types::{ColumnDescriptor, SchemaDescriptor, Type};
 
 mod complex;
+mod evolution;
 mod primitive;
 
 use crate::arrow::ProjectionMask;
 pub(crate) use complex::{ParquetField, ParquetFieldType};
+pub(crate) use evolution::{evolve_schema, statistics_schema};
 
 use super::PARQUET_FIELD_ID_META_KEY;
 
@@ -60,14 +66,16 @@ pub fn parquet_to_arrow_schema_by_columns(
     mask: ProjectionMask,
     key_value_metadata: Option<&Vec<KeyValue>>,
 ) -> Result<Schema> {
-    Ok(parquet_to_arrow_schema_and_fields(parquet_schema, mask, key_value_metadata)?.0)
+    Ok(parquet_to_arrow_schema_and_fields(parquet_schema, mask, key_value_metadata, None)?.0)
 }
 
-/// Extracts the arrow metadata
+/// Extracts the arrow metadata, reading INT96 columns as timestamps of the [`TimeUnit`]
+/// `coerce_int96` if provided
 pub(crate) fn parquet_to_arrow_schema_and_fields(
     parquet_schema: &SchemaDescriptor,
     mask: ProjectionMask,
     key_value_metadata: Option<&Vec<KeyValue>>,
+    coerce_int96: Option<TimeUnit>,
 ) -> Result<(Schema, Option<ParquetField>)> {
     let mut metadata = parse_key_value_metadata(key_value_metadata).unwrap_or_default();
     let maybe_schema = metadata
@@ -83,7 +91,7 @@ pub(crate) fn parquet_to_arrow_schema_and_fields(
     }
 
     let hint = maybe_schema.as_ref().map(|s| s.fields());
-    let field_levels = parquet_to_arrow_field_levels(parquet_schema, mask, hint)?;
+    let field_levels = convert_field_levels(parquet_schema, mask, hint, coerce_int96)?;
     let schema = Schema::new_with_metadata(field_levels.fields, metadata);
     Ok((schema, field_levels.levels))
 }
@@ -126,7 +134,16 @@ pub fn parquet_to_arrow_field_levels(
     mask: ProjectionMask,
     hint: Option<&Fields>,
 ) -> Result<FieldLevels> {
-    match complex::convert_schema(schema, mask, hint)? {
+    convert_field_levels(schema, mask, hint, None)
+}
+
+fn convert_field_levels(
+    schema: &SchemaDescriptor,
+    mask: ProjectionMask,
+    hint: Option<&Fields>,
+    coerce_int96: Option<TimeUnit>,
+) -> Result<FieldLevels> {
+    match complex::convert_schema(schema, mask, hint, coerce_int96)? {
         Some(field) => match &field.arrow_type {
             DataType::Struct(fields) => Ok(FieldLevels {
                 fields: fields.clone(),
@@ -284,6 +301,10 @@ pub struct ArrowSchemaConverter<'a> {
     ///
     /// See docs on [Self::with_coerce_types]`
     coerce_types: bool,
+    /// Should timestamps be written as INT96?
+    ///
+    /// See docs on [Self::with_timestamps_as_int96]
+    timestamps_as_int96: bool,
 }
 
 impl Default for ArrowSchemaConverter<'_> {
@@ -298,6 +319,7 @@ impl<'a> ArrowSchemaConverter<'a> {
         Self {
             schema_root: "arrow_schema",
             coerce_types: false,
+            timestamps_as_int96: false,
         }
     }
 
@@ -336,6 +358,22 @@ impl<'a> ArrowSchemaConverter<'a> {
         self
     }
 
+    /// Should Arrow timestamps be converted to the deprecated INT96 physical type
+    /// (default `false`).
+    ///
+    /// INT96 timestamps have nanosecond precision and no logical type, and are
+    /// only supported for compatibility with legacy readers such as older versions
+    /// of Hive and Impala. The unit and timezone of the Arrow timestamp are preserved
+    /// only in the embedded Arrow schema, and are otherwise read as nanoseconds in
+    /// UTC. Dates outside the years 1677 to 2262 can be read by choosing a coarser
+    /// unit with [`ArrowReaderOptions::with_coerce_int96`].
+    ///
+    /// [`ArrowReaderOptions::with_coerce_int96`]: crate::arrow::arrow_reader::ArrowReaderOptions::with_coerce_int96
+    pub fn with_timestamps_as_int96(mut self, timestamps_as_int96: bool) -> Self {
+        self.timestamps_as_int96 = timestamps_as_int96;
+        self
+    }
+
     /// Set the root schema element name (defaults to `"arrow_schema"`).
     pub fn schema_root(mut self, schema_root: &'a str) -> Self {
         self.schema_root = schema_root;
@@ -349,7 +387,7 @@ impl<'a> ArrowSchemaConverter<'a> {
         let fields = schema
             .fields()
             .iter()
-            .map(|field| arrow_to_parquet_type(field, self.coerce_types).map(Arc::new))
+            .map(|field| arrow_to_parquet_type(field, self).map(Arc::new))
             .collect::<Result<_>>()?;
         let group = Type::group_type_builder(self.schema_root)
             .with_fields(fields)
@@ -425,8 +463,29 @@ pub fn decimal_length_from_precision(precision: u8) -> usize {
     (((10.0_f64.powi(precision as i32) + 1.0).log2() + 1.0) / 8.0).ceil() as usize
 }
 
+/// The name of the column containing the type ids of a union
+const UNION_TYPE_ID_FIELD_NAME: &str = "type_id";
+
+/// Returns the fields of the struct with which a union of `fields` is stored in parquet
+///
+/// This is a non-nullable `Int8` column of the type id of each row, followed by a
+/// nullable column for each child of the union, which is null in any row where
+/// that child is not selected. The union is restored when reading from the arrow
+/// schema embedded in the file, without which it is read as this struct. Reading
+/// the union requires projecting either all or none of these columns.
+pub(crate) fn union_struct_fields(fields: &UnionFields) -> Fields {
+    let type_id = Field::new(UNION_TYPE_ID_FIELD_NAME, DataType::Int8, false);
+    std::iter::once(Arc::new(type_id))
+        .chain(
+            fields
+                .iter()
+                .map(|(_, f)| Arc::new(f.as_ref().clone().with_nullable(true))),
+        )
+        .collect()
+}
+
 /// Convert an arrow field to a parquet `Type`
-fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
+fn arrow_to_parquet_type(field: &Field, converter: &ArrowSchemaConverter<'_>) -> Result<Type> {
     const PARQUET_LIST_ELEMENT_NAME: &str = "element";
     const PARQUET_MAP_STRUCT_NAME: &str = "key_value";
     const PARQUET_KEY_FIELD_NAME: &str = "key";
@@ -520,6 +579,12 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
             .with_repetition(repetition)
             .with_id(id)
             .build(),
+        DataType::Timestamp(_, _) if converter.timestamps_as_int96 => {
+            Type::primitive_type_builder(name, PhysicalType::INT96)
+                .with_repetition(repetition)
+                .with_id(id)
+                .build()
+        }
         DataType::Timestamp(TimeUnit::Second, _) => {
             // Cannot represent seconds in LogicalType
             Type::primitive_type_builder(name, PhysicalType::INT64)
@@ -549,7 +614,7 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
             .with_id(id)
             .build(),
         DataType::Date64 => {
-            if coerce_types {
+            if converter.coerce_types {
                 Type::primitive_type_builder(name, PhysicalType::INT32)
                     .with_logical_type(Some(LogicalType::Date))
                     .with_repetition(repetition)
@@ -694,12 +759,12 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
             .with_id(id)
             .build(),
         DataType::List(f) | DataType::FixedSizeList(f, _) | DataType::LargeList(f) => {
-            let field_ref = if coerce_types && f.name() != PARQUET_LIST_ELEMENT_NAME {
+            let field_ref = if converter.coerce_types && f.name() != PARQUET_LIST_ELEMENT_NAME {
                 // Ensure proper naming per the Parquet specification
                 let ff = f.as_ref().clone().with_name(PARQUET_LIST_ELEMENT_NAME);
-                Arc::new(arrow_to_parquet_type(&ff, coerce_types)?)
+                Arc::new(arrow_to_parquet_type(&ff, converter)?)
             } else {
-                Arc::new(arrow_to_parquet_type(f, coerce_types)?)
+                Arc::new(arrow_to_parquet_type(f, converter)?)
             };
 
             Type::group_type_builder(name)
@@ -724,10 +789,27 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
             // recursively convert children to types/nodes
             let fields = fields
                 .iter()
-                .map(|f| arrow_to_parquet_type(f, coerce_types).map(Arc::new))
+                .map(|f| arrow_to_parquet_type(f, converter).map(Arc::new))
                 .collect::<Result<_>>()?;
             Type::group_type_builder(name)
                 .with_fields(fields)
+                .with_logical_type({
+                    #[cfg(feature = "variant_experimental")]
+                    {
+                        // Use the Variant logical type if the VariantType
+                        // extension type is set on this field, returning an
+                        // error if its fields are not those of a variant
+                        match field.extension_type_name() {
+                            Some(VariantType::NAME) => {
+                                field.try_extension_type::<VariantType>()?;
+                                Some(LogicalType::Variant)
+                            }
+                            _ => None,
+                        }
+                    }
+                    #[cfg(not(feature = "variant_experimental"))]
+                    None
+                })
                 .with_repetition(repetition)
                 .with_id(id)
                 .build()
@@ -735,7 +817,7 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
         DataType::Map(field, _) => {
             if let DataType::Struct(struct_fields) = field.data_type() {
                 // If coercing then set inner struct name to "key_value"
-                let map_struct_name = if coerce_types {
+                let map_struct_name = if converter.coerce_types {
                     PARQUET_MAP_STRUCT_NAME
                 } else {
                     field.name()
@@ -743,11 +825,11 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
 
                 // If coercing then ensure struct fields are named "key" and "value"
                 let fix_map_field = |name: &str, fld: &Arc<Field>| -> Result<Arc<Type>> {
-                    if coerce_types && fld.name() != name {
+                    if converter.coerce_types && fld.name() != name {
                         let f = fld.as_ref().clone().with_name(name);
-                        Ok(Arc::new(arrow_to_parquet_type(&f, coerce_types)?))
+                        Ok(Arc::new(arrow_to_parquet_type(&f, converter)?))
                     } else {
-                        Ok(Arc::new(arrow_to_parquet_type(fld, coerce_types)?))
+                        Ok(Arc::new(arrow_to_parquet_type(fld, converter)?))
                     }
                 };
                 let key_field = fix_map_field(PARQUET_KEY_FIELD_NAME, &struct_fields[0])?;
@@ -770,11 +852,23 @@ fn arrow_to_parquet_type(field: &Field, coerce_types: bool) -> Result<Type> {
                 ))
             }
         }
-        DataType::Union(_, _) => unimplemented!("See ARROW-8817."),
+        DataType::Union(fields, _) => {
+            if let Some((_, f)) = fields
+                .iter()
+                .find(|(_, f)| f.name() == UNION_TYPE_ID_FIELD_NAME)
+            {
+                return Err(arrow_err!(
+                    "union child \"{}\" conflicts with the type id column",
+                    f.name()
+                ));
+            }
+            let data_type = DataType::Struct(union_struct_fields(fields));
+            arrow_to_parquet_type(&field.clone().with_data_type(data_type), converter)
+        }
         DataType::Dictionary(_, ref value) => {
             // Dictionary encoding not handled at the schema level
             let dict_field = field.clone().with_data_type(value.as_ref().clone());
-            arrow_to_parquet_type(&dict_field, coerce_types)
+            arrow_to_parquet_type(&dict_field, converter)
         }
         DataType::RunEndEncoded(_, _) => Err(arrow_err!(
             "Converting RunEndEncodedType to parquet not supported",
@@ -2270,6 +2364,47 @@ mod tests {
         //     Json::default()
         // );
 
+        Ok(())
+    }
+    #[test]
+    #[cfg(feature = "variant_experimental")]
+    fn arrow_variant_to_parquet_variant() -> Result<()> {
+        // A variant shredded with an Int64 typed_value
+        let variant_fields = Fields::from(vec![
+            Field::new("metadata", DataType::Binary, false),
+            Field::new("value", DataType::Binary, true),
+            Field::new("typed_value", DataType::Int64, true),
+        ]);
+        let arrow_schema = Schema::new(vec![Field::new(
+            "variant",
+            DataType::Struct(variant_fields),
+            true,
+        )
+        .with_extension_type(VariantType)]);
+
+        let parquet_schema = ArrowSchemaConverter::new().convert(&arrow_schema)?;
+        let variant = &parquet_schema.root_schema().get_fields()[0];
+        assert_eq!(
+            variant.get_basic_info().logical_type(),
+            Some(LogicalType::Variant)
+        );
+
+        // The binary columns are read as BinaryView, as required by VariantArray
+        let arrow_schema = parquet_to_arrow_schema(&parquet_schema, None)?;
+        let field = arrow_schema.field(0);
+        assert_eq!(field.try_extension_type::<VariantType>()?, VariantType);
+        let expected = Fields::from(vec![
+            Field::new("metadata", DataType::BinaryView, false),
+            Field::new("value", DataType::BinaryView, true),
+            Field::new("typed_value", DataType::Int64, true),
+        ]);
+        assert_eq!(field.data_type(), &DataType::Struct(expected));
+
+        // A projection without the metadata column is not tagged
+        let mask = ProjectionMask::leaves(&parquet_schema, [1, 2]);
+        let arrow_schema = parquet_to_arrow_schema_by_columns(&parquet_schema, mask, None)?;
+        assert_eq!(arrow_schema.field(0).extension_type_name(), None);
+
         Ok(())
     }
 }
@@ -28,8 +28,8 @@ use crate::errors::{ParquetError, Result};
 
 // Re-export crate::format types used in this module
 pub use crate::format::{
-    BsonType, DateType, DecimalType, EnumType, IntType, JsonType, ListType, MapType, NullType,
-    StringType, TimeType, TimeUnit, TimestampType, UUIDType,
+    BsonType, DateType, DecimalType, EnumType, GeographyType, GeometryType, IntType, JsonType,
+    ListType, MapType, NullType, StringType, TimeType, TimeUnit, TimestampType, UUIDType,
 };
 
 // ----------------------------------------------------------------------
@@ -231,9 +231,40 @@ pub enum LogicalType {
     /// A Variant value.
     Variant,
     /// A geospatial feature in the Well-Known Binary (WKB) format with linear/planar edges interpolation.
-    Geometry,
+    Geometry {
+        /// The coordinate reference system, `OGC:CRS84` if not set.
+        crs: Option<String>,
+    },
     /// A geospatial feature in the WKB format with an explicit (non-linear/non-planar) edges interpolation.
-    Geography,
+    Geography {
+        /// The coordinate reference system, `OGC:CRS84` if not set.
+        crs: Option<String>,
+        /// The edge interpolation algorithm, [`EdgeInterpolationAlgorithm::SPHERICAL`] if not set.
+        algorithm: Option<EdgeInterpolationAlgorithm>,
+    },
+}
+
+// ----------------------------------------------------------------------
+// Mirrors `parquet::EdgeInterpolationAlgorithm`
+
+/// The algorithm used to interpolate the edges between the vertices of a
+/// [`LogicalType::Geography`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[allow(non_camel_case_types)]
+pub enum EdgeInterpolationAlgorithm {
+    /// Edges are interpolated as geodesics on a sphere.
+    #[default]
+    SPHERICAL,
+    /// Vincenty's formulae for geodesics on an ellipsoid.
+    VINCENTY,
+    /// Thomas' formulae for geodesics on an ellipsoid.
+    THOMAS,
+    /// Andoyer-Lambert formulae for geodesics on an ellipsoid.
+    ANDOYER,
+    /// Karney's algorithm for geodesics on an ellipsoid.
+    KARNEY,
+    /// An algorithm not known to this version of the crate.
+    UNKNOWN(i32),
 }
 
 // ----------------------------------------------------------------------
@@ -584,9 +615,9 @@ impl ColumnOrder {
                 LogicalType::Unknown => SortOrder::UNDEFINED,
                 LogicalType::Uuid => SortOrder::UNSIGNED,
                 LogicalType::Float16 => SortOrder::SIGNED,
-                LogicalType::Variant | LogicalType::Geometry | LogicalType::Geography => {
-                    SortOrder::UNDEFINED
-                }
+                LogicalType::Variant
+                | LogicalType::Geometry { .. }
+                | LogicalType::Geography { .. } => SortOrder::UNDEFINED,
             },
             // Fall back to converted type
             None => Self::get_converted_sort_order(converted_type, physical_type),
@@ -690,6 +721,12 @@ impl fmt::Display for Compression {
     }
 }
 
+impl fmt::Display for EdgeInterpolationAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
 impl fmt::Display for PageType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{self:?}")
@@ -850,8 +887,11 @@ impl From<parquet::LogicalType> for LogicalType {
             parquet::LogicalType::UUID(_) => LogicalType::Uuid,
             parquet::LogicalType::FLOAT16(_) => LogicalType::Float16,
             parquet::LogicalType::VARIANT(_) => LogicalType::Variant,
-            parquet::LogicalType::GEOMETRY(_) => LogicalType::Geometry,
-            parquet::LogicalType::GEOGRAPHY(_) => LogicalType::Geography,
+            parquet::LogicalType::GEOMETRY(t) => LogicalType::Geometry { crs: t.crs },
+            parquet::LogicalType::GEOGRAPHY(t) => LogicalType::Geography {
+                crs: t.crs,
+                algorithm: t.algorithm.map(EdgeInterpolationAlgorithm::from),
+            },
         }
     }
 }
@@ -894,8 +934,13 @@ impl From<LogicalType> for parquet::LogicalType {
             LogicalType::Uuid => parquet::LogicalType::UUID(Default::default()),
             LogicalType::Float16 => parquet::LogicalType::FLOAT16(Default::default()),
             LogicalType::Variant => parquet::LogicalType::VARIANT(Default::default()),
-            LogicalType::Geometry => parquet::LogicalType::GEOMETRY(Default::default()),
-            LogicalType::Geography => parquet::LogicalType::GEOGRAPHY(Default::default()),
+            LogicalType::Geometry { crs } => parquet::LogicalType::GEOMETRY(GeometryType { crs }),
+            LogicalType::Geography { crs, algorithm } => {
+                parquet::LogicalType::GEOGRAPHY(GeographyType {
+                    crs,
+                    algorithm: algorithm.map(Into::into),
+                })
+            }
         }
     }
 }
@@ -948,8 +993,8 @@ impl From<Option<LogicalType>> for ConvertedType {
                 LogicalType::Uuid
                 | LogicalType::Float16
                 | LogicalType::Variant
-                | LogicalType::Geometry
-                | LogicalType::Geography
+                | LogicalType::Geometry { .. }
+                | LogicalType::Geography { .. }
                 | LogicalType::Unknown => ConvertedType::NONE,
             },
             None => ConvertedType::NONE,
@@ -1069,6 +1114,35 @@ impl From<Compression> for parquet::CompressionCodec {
     }
 }
 
+// ----------------------------------------------------------------------
+// parquet::EdgeInterpolationAlgorithm <=> EdgeInterpolationAlgorithm conversion
+
+impl From<parquet::EdgeInterpolationAlgorithm> for EdgeInterpolationAlgorithm {
+    fn from(value: parquet::EdgeInterpolationAlgorithm) -> Self {
+        match value {
+            parquet::EdgeInterpolationAlgorithm::SPHERICAL => EdgeInterpolationAlgorithm::SPHERICAL,
+            parquet::EdgeInterpolationAlgorithm::VINCENTY => EdgeInterpolationAlgorithm::VINCENTY,
+            parquet::EdgeInterpolationAlgorithm::THOMAS => EdgeInterpolationAlgorithm::THOMAS,
+            parquet::EdgeInterpolationAlgorithm::ANDOYER => EdgeInterpolationAlgorithm::ANDOYER,
+            parquet::EdgeInterpolationAlgorithm::KARNEY => EdgeInterpolationAlgorithm::KARNEY,
+            other => EdgeInterpolationAlgorithm::UNKNOWN(other.0),
+        }
+    }
+}
+
+impl From<EdgeInterpolationAlgorithm> for parquet::EdgeInterpolationAlgorithm {
+    fn from(value: EdgeInterpolationAlgorithm) -> Self {
+        match value {
+            EdgeInterpolationAlgorithm::SPHERICAL => parquet::EdgeInterpolationAlgorithm::SPHERICAL,
+            EdgeInterpolationAlgorithm::VINCENTY => parquet::EdgeInterpolationAlgorithm::VINCENTY,
+            EdgeInterpolationAlgorithm::THOMAS => parquet::EdgeInterpolationAlgorithm::THOMAS,
+            EdgeInterpolationAlgorithm::ANDOYER => parquet::EdgeInterpolationAlgorithm::ANDOYER,
+            EdgeInterpolationAlgorithm::KARNEY => parquet::EdgeInterpolationAlgorithm::KARNEY,
+            EdgeInterpolationAlgorithm::UNKNOWN(v) => parquet::EdgeInterpolationAlgorithm(v),
+        }
+    }
+}
+
 // ----------------------------------------------------------------------
 // parquet::PageType <=> PageType conversion
 
@@ -1164,6 +1238,24 @@ impl str::FromStr for ConvertedType {
     }
 }
 
+impl str::FromStr for EdgeInterpolationAlgorithm {
+    type Err = ParquetError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "SPHERICAL" => Ok(EdgeInterpolationAlgorithm::SPHERICAL),
+            "VINCENTY" => Ok(EdgeInterpolationAlgorithm::VINCENTY),
+            "THOMAS" => Ok(EdgeInterpolationAlgorithm::THOMAS),
+            "ANDOYER" => Ok(EdgeInterpolationAlgorithm::ANDOYER),
+            "KARNEY" => Ok(EdgeInterpolationAlgorithm::KARNEY),
+            other => Err(general_err!(
+                "Invalid parquet edge interpolation algorithm {}",
+                other
+            )),
+        }
+    }
+}
+
 impl str::FromStr for LogicalType {
     type Err = ParquetError;
 
@@ -1199,6 +1291,11 @@ impl str::FromStr for LogicalType {
                 "Interval parquet logical type not yet supported"
             )),
             "FLOAT16" => Ok(LogicalType::Float16),
+            "GEOMETRY" => Ok(LogicalType::Geometry { crs: None }),
+            "GEOGRAPHY" => Ok(LogicalType::Geography {
+                crs: None,
+                algorithm: None,
+            }),
             other => Err(general_err!("Invalid parquet logical type {}", other)),
         }
     }
@@ -2120,6 +2217,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_geospatial_logical_types() {
+        let geometry = LogicalType::Geometry {
+            crs: Some("OGC:CRS83".to_string()),
+        };
+        let thrift = parquet::LogicalType::from(geometry.clone());
+        assert_eq!(
+            thrift,
+            parquet::LogicalType::GEOMETRY(GeometryType::new("OGC:CRS83".to_string()))
+        );
+        assert_eq!(LogicalType::from(thrift), geometry);
+
+        let geography = LogicalType::Geography {
+            crs: None,
+            algorithm: Some(EdgeInterpolationAlgorithm::KARNEY),
+        };
+        let thrift = parquet::LogicalType::from(geography.clone());
+        assert_eq!(
+            thrift,
+            parquet::LogicalType::GEOGRAPHY(GeographyType::new(
+                None,
+                parquet::EdgeInterpolationAlgorithm::KARNEY
+            ))
+        );
+        assert_eq!(LogicalType::from(thrift), geography);
+
+        // Unknown algorithms are preserved
+        let thrift = parquet::LogicalType::GEOGRAPHY(GeographyType::new(
+            None,
+            parquet::EdgeInterpolationAlgorithm(42),
+        ));
+        let geography = LogicalType::from(thrift.clone());
+        assert_eq!(
+            geography,
+            LogicalType::Geography {
+                crs: None,
+                algorithm: Some(EdgeInterpolationAlgorithm::UNKNOWN(42)),
+            }
+        );
+        assert_eq!(parquet::LogicalType::from(geography), thrift);
+
+        assert_eq!(EdgeInterpolationAlgorithm::VINCENTY.to_string(), "VINCENTY");
+        assert_eq!(
+            "THOMAS".parse::<EdgeInterpolationAlgorithm>().unwrap(),
+            EdgeInterpolationAlgorithm::THOMAS
+        );
+        assert!("FLAT".parse::<EdgeInterpolationAlgorithm>().is_err());
+    }
+
     #[test]
     fn test_display_sort_order() {
         assert_eq!(SortOrder::SIGNED.to_string(), "SIGNED");
@@ -2235,7 +2381,15 @@ mod tests {
         check_sort_order(signed, SortOrder::SIGNED);
 
         // Undefined comparison
-        let undefined = vec![LogicalType::List, LogicalType::Map];
+        let undefined = vec![
+            LogicalType::List,
+            LogicalType::Map,
+            LogicalType::Geometry { crs: None },
+            LogicalType::Geography {
+                crs: None,
+                algorithm: None,
+            },
+        ];
         check_sort_order(undefined, SortOrder::UNDEFINED);
     }
 
@@ -94,14 +94,11 @@ impl Args {
             for rg in metadata.row_groups() {
                 let mut rg_out = writer.next_row_group()?;
                 for column in rg.columns() {
-                    let result = ColumnCloseResult {
-                        bytes_written: column.compressed_size() as _,
-                        rows_written: rg.num_rows() as _,
-                        metadata: column.clone(),
-                        bloom_filter: None,
-                        column_index: None,
-                        offset_index: None,
-                    };
+                    let result = ColumnCloseResult::new(
+                        column.compressed_size() as _,
+                        rg.num_rows() as _,
+                        column.clone(),
+                    );
                     rg_out.append_column(&input, result)?;
                 }
                 rg_out.close()?;
@@ -145,6 +145,8 @@ impl Page {
 pub struct CompressedPage {
     compressed_page: Page,
     uncompressed_size: usize,
+    #[cfg(feature = "crc")]
+    write_checksum: bool,
 }
 
 impl CompressedPage {
@@ -154,9 +156,21 @@ impl CompressedPage {
         Self {
             compressed_page,
             uncompressed_size,
+            #[cfg(feature = "crc")]
+            write_checksum: false,
         }
     }
 
+    /// Sets whether the CRC32 checksum of the page data is written in the page header.
+    ///
+    /// The checksum is computed when the header is created, so covers any later changes
+    /// to the page data such as encryption.
+    #[cfg(feature = "crc")]
+    pub(crate) fn with_checksum(mut self, write_checksum: bool) -> Self {
+        self.write_checksum = write_checksum;
+        self
+    }
+
     /// Returns page type.
     pub fn page_type(&self) -> PageType {
         self.compressed_page.page_type()
@@ -207,8 +221,7 @@ impl CompressedPage {
             type_: page_type.into(),
             uncompressed_page_size: uncompressed_size as i32,
             compressed_page_size: compressed_size as i32,
-            // TODO: Add support for crc checksum
-            crc: None,
+            crc: self.checksum(),
             data_page_header: None,
             index_page_header: None,
             dictionary_page_header: None,
@@ -264,6 +277,18 @@ impl CompressedPage {
         page_header
     }
 
+    /// Returns the CRC32 checksum of the page data, if it should be written
+    #[cfg(feature = "crc")]
+    fn checksum(&self) -> Option<i32> {
+        self.write_checksum
+            .then(|| crc32fast::hash(self.data()) as i32)
+    }
+
+    #[cfg(not(feature = "crc"))]
+    fn checksum(&self) -> Option<i32> {
+        None
+    }
+
     /// Update the compressed buffer for a page.
     /// This might be required when encrypting page data for example.
     /// The size of uncompressed data must not change.
@@ -490,5 +515,20 @@ mod tests {
         assert_eq!(cpage.num_values(), 10);
         assert_eq!(cpage.encoding(), Encoding::PLAIN);
         assert_eq!(cpage.data(), &[0, 1, 2]);
+        assert_eq!(cpage.to_thrift_header().crc, None);
+    }
+
+    #[test]
+    #[cfg(feature = "crc")]
+    fn test_compressed_page_checksum() {
+        let dict_page = Page::DictionaryPage {
+            buf: Bytes::from_static(b"123456789"),
+            num_values: 1,
+            encoding: Encoding::PLAIN,
+            is_sorted: false,
+        };
+        let cpage = CompressedPage::new(dict_page, 9).with_checksum(true);
+        // The standard CRC32 check value
+        assert_eq!(cpage.to_thrift_header().crc, Some(0xCBF43926_u32 as i32));
     }
 }
@@ -32,6 +32,7 @@ use std::sync::Arc;
 pub(crate) struct PageEncryptor {
     file_encryptor: Arc<FileEncryptor>,
     block_encryptor: Box<dyn BlockEncryptor>,
+    data_encryptor: Box<dyn BlockEncryptor>,
     row_group_index: usize,
     column_index: usize,
     page_index: usize,
@@ -48,9 +49,11 @@ impl PageEncryptor {
         match file_encryptor {
             Some(file_encryptor) if file_encryptor.is_column_encrypted(column_path) => {
                 let block_encryptor = file_encryptor.get_column_encryptor(column_path)?;
+                let data_encryptor = file_encryptor.get_column_data_encryptor(column_path)?;
                 Ok(Some(Self {
                     file_encryptor: file_encryptor.clone(),
                     block_encryptor,
+                    data_encryptor,
                     row_group_index,
                     column_index,
                     page_index: 0,
@@ -78,7 +81,7 @@ impl PageEncryptor {
             self.column_index,
             Some(self.page_index),
         )?;
-        let encrypted_buffer = self.block_encryptor.encrypt(page.data(), &aad)?;
+        let encrypted_buffer = self.data_encryptor.encrypt(page.data(), &aad)?;
 
         Ok(encrypted_buffer)
     }
@@ -24,10 +24,12 @@ use crate::column::writer::{
     compare_greater, fallback_encoding, has_dictionary_support, is_nan, update_max, update_min,
 };
 use crate::data_type::private::ParquetValueType;
-use crate::data_type::DataType;
+use crate::data_type::{AsBytes, DataType};
+use crate::distinct_count::HyperLogLog;
 use crate::encodings::encoding::{get_encoder, DictEncoder, Encoder};
 use crate::errors::{ParquetError, Result};
 use crate::file::properties::{EnabledStatistics, WriterProperties};
+use crate::geospatial::{GeospatialAccumulator, GeospatialStatistics};
 use crate::schema::types::{ColumnDescPtr, ColumnDescriptor};
 
 /// A collection of [`ParquetValueType`] encoded by a [`ColumnValueEncoder`]
@@ -121,6 +123,18 @@ pub trait ColumnValueEncoder {
     /// will *not* be tracked by the bloom filter as it is empty since. This should be called once
     /// near the end of encoding.
     fn flush_bloom_filter(&mut self) -> Option<Sbbf>;
+
+    /// Flushes the sketch of the distinct values if enabled and returns it, otherwise returns
+    /// `None`. This should be called once near the end of encoding.
+    fn flush_distinct_count_sketch(&mut self) -> Option<HyperLogLog>;
+
+    /// Flushes the geospatial statistics of a [`GEOMETRY`] or [`GEOGRAPHY`] column if
+    /// statistics are enabled and returns them, otherwise returns `None`. This should be
+    /// called once near the end of encoding.
+    ///
+    /// [`GEOMETRY`]: crate::basic::LogicalType::Geometry
+    /// [`GEOGRAPHY`]: crate::basic::LogicalType::Geography
+    fn flush_geospatial_statistics(&mut self) -> Option<Box<GeospatialStatistics>>;
 }
 
 pub struct ColumnValueEncoderImpl<T: DataType> {
@@ -132,7 +146,9 @@ pub struct ColumnValueEncoderImpl<T: DataType> {
     min_value: Option<T::T>,
     max_value: Option<T::T>,
     bloom_filter: Option<Sbbf>,
+    distinct_count_sketch: Option<HyperLogLog>,
     variable_length_bytes: Option<i64>,
+    geo_stats_accumulator: Option<GeospatialAccumulator>,
 }
 
 impl<T: DataType> ColumnValueEncoderImpl<T> {
@@ -165,6 +181,18 @@ impl<T: DataType> ColumnValueEncoderImpl<T> {
             }
         }
 
+        if let Some(sketch) = &mut self.distinct_count_sketch {
+            for value in slice {
+                sketch.insert(value);
+            }
+        }
+
+        if let Some(accumulator) = &mut self.geo_stats_accumulator {
+            for value in slice {
+                accumulator.update(value.as_bytes());
+            }
+        }
+
         match &mut self.dict_encoder {
             Some(encoder) => encoder.put(slice),
             _ => self.encoder.put(slice),
@@ -181,6 +209,14 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
         self.bloom_filter.take()
     }
 
+    fn flush_distinct_count_sketch(&mut self) -> Option<HyperLogLog> {
+        self.distinct_count_sketch.take()
+    }
+
+    fn flush_geospatial_statistics(&mut self) -> Option<Box<GeospatialStatistics>> {
+        self.geo_stats_accumulator.take()?.finish().map(Box::new)
+    }
+
     fn try_new(descr: &ColumnDescPtr, props: &WriterProperties) -> Result<Self> {
         let dict_supported = props.dictionary_enabled(descr.path())
             && has_dictionary_support(T::get_physical_type(), props);
@@ -201,6 +237,15 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
             .map(|props| Sbbf::new_with_ndv_fpp(props.ndv, props.fpp))
             .transpose()?;
 
+        let distinct_count_sketch = props
+            .distinct_count_enabled(descr.path())
+            .then(|| HyperLogLog::try_new(props.distinct_count_precision()))
+            .transpose()?;
+
+        let geo_stats_accumulator = (statistics_enabled != EnabledStatistics::None)
+            .then(|| GeospatialAccumulator::try_new(descr))
+            .flatten();
+
         Ok(Self {
             encoder,
             dict_encoder,
@@ -208,9 +253,11 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
             num_values: 0,
             statistics_enabled,
             bloom_filter,
+            distinct_count_sketch,
             min_value: None,
             max_value: None,
             variable_length_bytes: None,
+            geo_stats_accumulator,
         })
     }
 
@@ -257,7 +304,13 @@ impl<T: DataType> ColumnValueEncoder for ColumnValueEncoderImpl<T> {
             .map(|bf| bf.estimated_memory_size())
             .unwrap_or_default();
 
-        encoder_size + dict_encoder_size + bloom_filter_size
+        let sketch_size = self
+            .distinct_count_sketch
+            .as_ref()
+            .map(|sketch| sketch.estimated_memory_size())
+            .unwrap_or_default();
+
+        encoder_size + dict_encoder_size + bloom_filter_size + sketch_size
     }
 
     fn estimated_dict_page_size(&self) -> Option<usize> {
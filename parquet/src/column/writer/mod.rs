@@ -21,6 +21,7 @@ use bytes::Bytes;
 use half::f16;
 
 use crate::bloom_filter::Sbbf;
+use crate::distinct_count::HyperLogLog;
 use crate::format::{BoundaryOrder, ColumnIndex, OffsetIndex};
 use std::collections::{BTreeSet, VecDeque};
 use std::str;
@@ -96,6 +97,12 @@ impl ColumnWriter<'_> {
         downcast_writer!(self, typed, typed.get_estimated_total_bytes())
     }
 
+    /// Writes any buffered values to a new data page
+    #[cfg(feature = "arrow")]
+    pub(crate) fn flush_data_page(&mut self) -> Result<()> {
+        downcast_writer!(self, typed, typed.flush_data_page())
+    }
+
     /// Close this [`ColumnWriter`]
     pub fn close(self) -> Result<ColumnCloseResult> {
         downcast_writer!(self, typed, typed.close())
@@ -174,7 +181,11 @@ pub fn get_typed_column_writer_mut<'a, 'b: 'a, T: DataType>(
 }
 
 /// Metadata returned by [`GenericColumnWriter::close`]
+///
+/// This is marked `#[non_exhaustive]` so that fields can be added without breaking
+/// changes, use [`Self::new`] to create an instance.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct ColumnCloseResult {
     /// The total number of bytes written
     pub bytes_written: u64,
@@ -184,12 +195,33 @@ pub struct ColumnCloseResult {
     pub metadata: ColumnChunkMetaData,
     /// Optional bloom filter for this column
     pub bloom_filter: Option<Sbbf>,
+    /// Optional sketch of the distinct values of this column, see
+    /// [`WriterPropertiesBuilder::set_distinct_count_enabled`]
+    ///
+    /// [`WriterPropertiesBuilder::set_distinct_count_enabled`]: crate::file::properties::WriterPropertiesBuilder::set_distinct_count_enabled
+    pub distinct_count_sketch: Option<HyperLogLog>,
     /// Optional column index, for filtering
     pub column_index: Option<ColumnIndex>,
     /// Optional offset index, identifying page locations
     pub offset_index: Option<OffsetIndex>,
 }
 
+impl ColumnCloseResult {
+    /// Creates a new [`ColumnCloseResult`] for a column chunk described by `metadata`,
+    /// without a bloom filter, distinct count sketch, column index or offset index
+    pub fn new(bytes_written: u64, rows_written: u64, metadata: ColumnChunkMetaData) -> Self {
+        Self {
+            bytes_written,
+            rows_written,
+            metadata,
+            bloom_filter: None,
+            distinct_count_sketch: None,
+            column_index: None,
+            offset_index: None,
+        }
+    }
+}
+
 // Metrics per page
 #[derive(Default)]
 struct PageMetrics {
@@ -554,6 +586,18 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
         self.column_metrics.total_bytes_written as usize + self.encoder.estimated_memory_size()
     }
 
+    /// Writes any buffered values to a new data page, regardless of its size.
+    ///
+    /// Used to place page boundaries at positions chosen by the caller, such as for
+    /// content-defined chunking.
+    #[cfg(feature = "arrow")]
+    pub(crate) fn flush_data_page(&mut self) -> Result<()> {
+        if self.page_metrics.num_buffered_values > 0 {
+            self.add_data_page()?;
+        }
+        Ok(())
+    }
+
     /// Returns total number of bytes written by this column writer so far.
     /// This value is also returned when column writer is closed.
     ///
@@ -600,6 +644,15 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             self.write_dictionary_page()?;
         }
         self.flush_data_pages()?;
+
+        // A distinct count provided by the caller is exact, so takes precedence
+        let distinct_count_sketch = self.encoder.flush_distinct_count_sketch();
+        if let Some(sketch) = &distinct_count_sketch {
+            self.column_metrics
+                .column_distinct_count
+                .get_or_insert_with(|| sketch.estimate());
+        }
+
         let metadata = self.build_column_metadata()?;
         self.page_writer.close()?;
 
@@ -626,6 +679,7 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             bytes_written: self.column_metrics.total_bytes_written,
             rows_written: self.column_metrics.total_rows_written,
             bloom_filter: self.encoder.flush_bloom_filter(),
+            distinct_count_sketch,
             metadata,
             column_index,
             offset_index,
@@ -1195,6 +1249,10 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
 
             let statistics = self.truncate_statistics(statistics);
 
+            if let Some(geo_statistics) = self.encoder.flush_geospatial_statistics() {
+                builder = builder.set_geo_statistics(geo_statistics);
+            }
+
             builder = builder
                 .set_statistics(statistics)
                 .set_unencoded_byte_array_data_bytes(self.column_metrics.variable_length_bytes)
@@ -1250,6 +1308,8 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
                 });
             }
         }
+        #[cfg(feature = "crc")]
+        let page = page.with_checksum(self.props.write_page_checksum());
         let page_spec = self.page_writer.write_page(page)?;
         // update offset index
         // compressed_size = header_size + compressed_data_size
@@ -1293,6 +1353,8 @@ impl<'a, E: ColumnValueEncoder> GenericColumnWriter<'a, E> {
             encoding: compressed_page.encoding(),
             count: 1,
         });
+        #[cfg(feature = "crc")]
+        let compressed_page = compressed_page.with_checksum(self.props.write_page_checksum());
         let page_spec = self.page_writer.write_page(compressed_page)?;
         self.update_metrics_for_page(page_spec);
         // For the directory page, don't need to update column/offset index.
@@ -1426,6 +1488,12 @@ fn compare_greater<T: ParquetValueType>(descr: &ColumnDescriptor, a: &T, b: &T)
                 return compare_greater_f16(a.as_bytes(), b.as_bytes());
             }
         }
+        Type::INT96 => {
+            // The derived ordering of `Int96` compares the low word of the nanoseconds first
+            let a = a.as_any().downcast_ref::<Int96>().unwrap();
+            let b = b.as_any().downcast_ref::<Int96>().unwrap();
+            return a.data_as_days_and_nanos() > b.data_as_days_and_nanos();
+        }
 
         _ => {}
     }
@@ -2221,6 +2289,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_column_writer_distinct_count() {
+        let props = Arc::new(
+            WriterProperties::builder()
+                .set_distinct_count_enabled(true)
+                .build(),
+        );
+        let page_writer = get_test_page_writer();
+        let mut writer = get_test_column_writer::<Int32Type>(page_writer, 1, 0, props.clone());
+        let values: Vec<i32> = (0..10_000).map(|v| v % 100).collect();
+        let def_levels: Vec<i16> = (0..12_000).map(|i| (i % 6 != 0) as i16).collect();
+        writer
+            .write_batch(&values, Some(&def_levels), None)
+            .unwrap();
+
+        let r = writer.close().unwrap();
+        let stats = r.metadata.statistics().unwrap();
+        assert_eq!(stats.distinct_count_opt(), Some(100));
+        assert_eq!(stats.null_count_opt(), Some(2_000));
+        assert_eq!(r.distinct_count_sketch.unwrap().estimate(), 100);
+
+        // A precalculated distinct count takes precedence over the estimate
+        let page_writer = get_test_page_writer();
+        let mut writer = get_test_column_writer::<Int32Type>(page_writer, 0, 0, props);
+        writer
+            .write_batch_with_statistics(&[1, 2, 3, 4], None, None, Some(&1), Some(&4), Some(5))
+            .unwrap();
+        let r = writer.close().unwrap();
+        let stats = r.metadata.statistics().unwrap();
+        assert_eq!(stats.distinct_count_opt(), Some(5));
+        assert_eq!(r.distinct_count_sketch.unwrap().estimate(), 4);
+
+        // The sketch is not created unless enabled
+        let page_writer = get_test_page_writer();
+        let props = Default::default();
+        let mut writer = get_test_column_writer::<Int32Type>(page_writer, 0, 0, props);
+        writer.write_batch(&[1, 2, 3, 4], None, None).unwrap();
+        let r = writer.close().unwrap();
+        assert!(r
+            .metadata
+            .statistics()
+            .unwrap()
+            .distinct_count_opt()
+            .is_none());
+        assert!(r.distinct_count_sketch.is_none());
+    }
+
     #[test]
     fn test_mixed_precomputed_statistics() {
         let mut buf = Vec::with_capacity(100);
@@ -2527,9 +2642,10 @@ mod tests {
 
         let stats = statistics_roundtrip::<Int96Type>(&input);
         assert!(!stats.is_min_max_backwards_compatible());
+        // Ordered by the julian day, and then by the nanoseconds within the day
         if let Statistics::Int96(stats) = stats {
-            assert_eq!(stats.min_opt().unwrap(), &Int96::from(vec![0, 20, 30]));
-            assert_eq!(stats.max_opt().unwrap(), &Int96::from(vec![3, 20, 10]));
+            assert_eq!(stats.min_opt().unwrap(), &Int96::from(vec![3, 20, 10]));
+            assert_eq!(stats.max_opt().unwrap(), &Int96::from(vec![2, 20, 30]));
         } else {
             panic!("expecting Statistics::Int96, got {stats:?}");
         }
@@ -118,8 +118,55 @@ impl Int96 {
             .wrapping_add(nanos)
     }
 
+    /// Creates an INT96 from the number of SECONDS since EPOCH
+    ///
+    /// Will wrap around on overflow
+    #[inline]
+    pub fn from_seconds(seconds: i64) -> Self {
+        Self::from_days_and_nanos(
+            seconds.div_euclid(SECONDS_IN_DAY),
+            seconds.rem_euclid(SECONDS_IN_DAY) * NANOSECONDS,
+        )
+    }
+
+    /// Creates an INT96 from the number of MILLISECONDS since EPOCH
+    #[inline]
+    pub fn from_millis(millis: i64) -> Self {
+        Self::from_days_and_nanos(
+            millis.div_euclid(MILLISECONDS_IN_DAY),
+            millis.rem_euclid(MILLISECONDS_IN_DAY) * (NANOSECONDS / MILLISECONDS),
+        )
+    }
+
+    /// Creates an INT96 from the number of MICROSECONDS since EPOCH
+    #[inline]
+    pub fn from_micros(micros: i64) -> Self {
+        Self::from_days_and_nanos(
+            micros.div_euclid(MICROSECONDS_IN_DAY),
+            micros.rem_euclid(MICROSECONDS_IN_DAY) * (NANOSECONDS / MICROSECONDS),
+        )
+    }
+
+    /// Creates an INT96 from the number of NANOSECONDS since EPOCH
     #[inline]
-    fn data_as_days_and_nanos(&self) -> (i32, i64) {
+    pub fn from_nanos(nanos: i64) -> Self {
+        Self::from_days_and_nanos(
+            nanos.div_euclid(NANOSECONDS_IN_DAY),
+            nanos.rem_euclid(NANOSECONDS_IN_DAY),
+        )
+    }
+
+    #[inline]
+    fn from_days_and_nanos(days: i64, nanos: i64) -> Self {
+        let day = days.wrapping_add(JULIAN_DAY_OF_EPOCH) as u32;
+        Self {
+            value: [nanos as u32, (nanos >> 32) as u32, day],
+        }
+    }
+
+    /// Returns the julian day and the nanoseconds within the day
+    #[inline]
+    pub(crate) fn data_as_days_and_nanos(&self) -> (i32, i64) {
         let day = self.data()[2] as i32;
         let nanos = ((self.data()[1] as i64) << 32) + self.data()[0] as i64;
         (day, nanos)
@@ -1340,6 +1387,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_int96_from_timestamp() {
+        for seconds in [
+            0,
+            1,
+            -1,
+            86_399,
+            86_400,
+            -86_401,
+            1_700_000_000,
+            -20_000_000_000,
+        ] {
+            assert_eq!(Int96::from_seconds(seconds).to_seconds(), seconds);
+            let millis = seconds * 1_000 + 999;
+            assert_eq!(Int96::from_millis(millis).to_millis(), millis);
+            let micros = seconds * 1_000_000 - 1;
+            assert_eq!(Int96::from_micros(micros).to_micros(), micros);
+        }
+        for nanos in [0, 1, -1, i64::MAX, i64::MIN] {
+            assert_eq!(Int96::from_nanos(nanos).to_nanos(), nanos);
+        }
+
+        // 1970-01-01 is julian day 2_440_588
+        assert_eq!(Int96::from_nanos(1).data(), &[1, 0, 2_440_588]);
+        assert_eq!(
+            Int96::from_nanos(-1).data(),
+            &[(NANOSECONDS_IN_DAY - 1) as u32, 20116, 2_440_587]
+        );
+    }
+
     #[test]
     fn test_byte_array_from() {
         assert_eq!(ByteArray::from(b"ABC".to_vec()).data(), b"ABC");
@@ -0,0 +1,338 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Approximate distinct counts of column values, computed with a [HyperLogLog] sketch.
+//!
+//! When enabled with [`WriterPropertiesBuilder::set_distinct_count_enabled`], the column
+//! writer inserts each non-null value of a column chunk into a [`HyperLogLog`] sketch,
+//! and writes its estimate as the distinct count of the column chunk [`Statistics`].
+//!
+//! Distinct counts can't be summed across column chunks, as the same value may occur in
+//! several of them, but sketches can be merged. With
+//! [`WriterPropertiesBuilder::set_write_distinct_count_sketches`], the sketches of all the
+//! row groups of a column are merged and stored in the key value metadata of the file,
+//! under the key [`DISTINCT_COUNT_SKETCH_KEY_PREFIX`] followed by the column path. These
+//! can be read with [`read_distinct_count_sketch`] and merged with
+//! [`HyperLogLog::merge`] to estimate the distinct count of a column across files.
+//!
+//! The relative standard error of the estimate is about `1.04 / sqrt(2^precision)`,
+//! which is 1.6% for the default precision of [`DEFAULT_DISTINCT_COUNT_PRECISION`],
+//! while the sketch uses `2^precision` bytes of memory.
+//!
+//! [HyperLogLog]: https://algo.inria.fr/flajolet/Publications/FlFuGaMe07.pdf
+//! [`WriterPropertiesBuilder::set_distinct_count_enabled`]: crate::file::properties::WriterPropertiesBuilder::set_distinct_count_enabled
+//! [`WriterPropertiesBuilder::set_write_distinct_count_sketches`]: crate::file::properties::WriterPropertiesBuilder::set_write_distinct_count_sketches
+//! [`Statistics`]: crate::file::statistics::Statistics
+//! [`DEFAULT_DISTINCT_COUNT_PRECISION`]: crate::file::properties::DEFAULT_DISTINCT_COUNT_PRECISION
+
+use crate::data_type::AsBytes;
+use crate::errors::{ParquetError, Result};
+#[cfg(feature = "base64")]
+use crate::file::metadata::FileMetaData;
+#[cfg(feature = "base64")]
+use crate::schema::types::ColumnPath;
+use twox_hash::XxHash64;
+
+/// The minimum precision of a [`HyperLogLog`]
+pub const MIN_PRECISION: u8 = 4;
+
+/// The maximum precision of a [`HyperLogLog`]
+pub const MAX_PRECISION: u8 = 18;
+
+/// The prefix of the key value metadata keys of serialized sketches, which is followed
+/// by the path of the column, see [`read_distinct_count_sketch`]
+pub const DISTINCT_COUNT_SKETCH_KEY_PREFIX: &str = "parquet.distinct_count_sketch.";
+
+/// The version of the serialized format of [`HyperLogLog::to_bytes`]
+const SERIALIZATION_VERSION: u8 = 1;
+
+/// A HyperLogLog sketch for estimating the number of distinct values
+///
+/// Values are hashed with xxHash64, like for bloom filters, so a sketch only
+/// distinguishes values by their plain encoded bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    precision: u8,
+    /// The maximum rank of the hashes with each index, with `2^precision` registers
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    /// Creates an empty sketch with `2^precision` registers
+    ///
+    /// Returns an error if `precision` is not between [`MIN_PRECISION`] and
+    /// [`MAX_PRECISION`]
+    pub fn try_new(precision: u8) -> Result<Self> {
+        if !(MIN_PRECISION..=MAX_PRECISION).contains(&precision) {
+            return Err(general_err!(
+                "HyperLogLog precision must be between {} and {}, got {}",
+                MIN_PRECISION,
+                MAX_PRECISION,
+                precision
+            ));
+        }
+        Ok(Self {
+            precision,
+            registers: vec![0; 1 << precision],
+        })
+    }
+
+    /// Returns the precision of this sketch
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Inserts a value into this sketch
+    pub fn insert<T: AsBytes + ?Sized>(&mut self, value: &T) {
+        self.insert_hash(XxHash64::oneshot(0, value.as_bytes()))
+    }
+
+    /// Inserts the 64-bit hash of a value into this sketch
+    pub fn insert_hash(&mut self, hash: u64) {
+        let precision = self.precision as u32;
+        let index = (hash >> (64 - precision)) as usize;
+        // The position of the first set bit of the remaining bits, capped at 64 - precision + 1
+        let rank = ((hash << precision) | (1 << (precision - 1))).leading_zeros() + 1;
+        let register = &mut self.registers[index];
+        *register = (*register).max(rank as u8);
+    }
+
+    /// Returns the estimated number of distinct values inserted into this sketch
+    pub fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1. + 1.079 / m),
+        };
+
+        let mut sum = 0.;
+        let mut zeros = 0;
+        for register in &self.registers {
+            sum += 1. / (1_u64 << register) as f64;
+            zeros += (*register == 0) as usize;
+        }
+        let estimate = alpha * m * m / sum;
+
+        // Use linear counting for small cardinalities, where the estimate is biased.
+        // No correction is needed for large cardinalities, as the hashes are 64 bits
+        if estimate <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+
+    /// Merges `other` into this sketch, so that it estimates the number of distinct
+    /// values inserted into either
+    ///
+    /// Returns an error if the sketches have different precisions
+    pub fn merge(&mut self, other: &Self) -> Result<()> {
+        if self.precision != other.precision {
+            return Err(general_err!(
+                "Cannot merge HyperLogLog with precision {} into HyperLogLog with precision {}",
+                other.precision,
+                self.precision
+            ));
+        }
+        for (register, other) in self.registers.iter_mut().zip(&other.registers) {
+            *register = (*register).max(*other);
+        }
+        Ok(())
+    }
+
+    /// Serializes this sketch, see [`Self::try_from_bytes`]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(2 + self.registers.len());
+        bytes.push(SERIALIZATION_VERSION);
+        bytes.push(self.precision);
+        bytes.extend_from_slice(&self.registers);
+        bytes
+    }
+
+    /// Deserializes a sketch serialized by [`Self::to_bytes`]
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self> {
+        let (version, precision, registers) = match bytes {
+            [version, precision, registers @ ..] => (*version, *precision, registers),
+            _ => return Err(general_err!("HyperLogLog sketch is too short")),
+        };
+        if version != SERIALIZATION_VERSION {
+            return Err(general_err!(
+                "Unsupported HyperLogLog sketch version {}",
+                version
+            ));
+        }
+
+        let mut sketch = Self::try_new(precision)?;
+        if registers.len() != sketch.registers.len() {
+            return Err(general_err!(
+                "Expected {} HyperLogLog registers, got {}",
+                sketch.registers.len(),
+                registers.len()
+            ));
+        }
+        let max_rank = 64 - precision + 1;
+        if let Some(rank) = registers.iter().find(|rank| **rank > max_rank) {
+            return Err(general_err!("Invalid HyperLogLog register value {}", rank));
+        }
+        sketch.registers.copy_from_slice(registers);
+        Ok(sketch)
+    }
+
+    /// Returns the estimated memory usage of this sketch
+    pub(crate) fn estimated_memory_size(&self) -> usize {
+        self.registers.capacity()
+    }
+}
+
+/// Returns the key value metadata key of the sketch of the column `column_path`
+#[cfg(feature = "base64")]
+pub(crate) fn distinct_count_sketch_key(column_path: &ColumnPath) -> String {
+    format!("{DISTINCT_COUNT_SKETCH_KEY_PREFIX}{}", column_path.string())
+}
+
+/// Returns the base64 encoded serialized `sketch`, for storing in key value metadata
+#[cfg(feature = "base64")]
+pub(crate) fn encode_distinct_count_sketch(sketch: &HyperLogLog) -> String {
+    use base64::Engine;
+    base64::prelude::BASE64_STANDARD.encode(sketch.to_bytes())
+}
+
+/// Reads the sketch of the column `column_path` from the key value metadata of a file
+/// written with [`WriterPropertiesBuilder::set_write_distinct_count_sketches`]
+///
+/// Returns `None` if the file has no sketch for the column.
+///
+/// [`WriterPropertiesBuilder::set_write_distinct_count_sketches`]: crate::file::properties::WriterPropertiesBuilder::set_write_distinct_count_sketches
+#[cfg(feature = "base64")]
+pub fn read_distinct_count_sketch(
+    file_metadata: &FileMetaData,
+    column_path: &ColumnPath,
+) -> Result<Option<HyperLogLog>> {
+    use base64::Engine;
+
+    let key = distinct_count_sketch_key(column_path);
+    let value = file_metadata
+        .key_value_metadata()
+        .and_then(|kv| kv.iter().find(|kv| kv.key == key))
+        .and_then(|kv| kv.value.as_ref());
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    let bytes = base64::prelude::BASE64_STANDARD
+        .decode(value)
+        .map_err(|e| general_err!("Invalid base64 in HyperLogLog sketch: {}", e))?;
+    HyperLogLog::try_from_bytes(&bytes).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_estimate(sketch: &HyperLogLog, expected: u64, tolerance: f64) {
+        let estimate = sketch.estimate();
+        let error = (estimate as f64 - expected as f64).abs() / expected as f64;
+        assert!(error <= tolerance, "{estimate} != {expected}");
+    }
+
+    #[test]
+    fn test_estimate() {
+        let mut sketch = HyperLogLog::try_new(12).unwrap();
+        assert_eq!(sketch.estimate(), 0);
+
+        for value in 0..10_i64 {
+            sketch.insert(&value);
+            sketch.insert(&value);
+        }
+        assert_eq!(sketch.estimate(), 10);
+
+        for value in 0..1_000_000_i64 {
+            sketch.insert(&value);
+        }
+        // 3 standard errors
+        assert_estimate(&sketch, 1_000_000, 0.05);
+
+        let mut sketch = HyperLogLog::try_new(8).unwrap();
+        for value in 0..1_000_i64 {
+            sketch.insert(&value);
+        }
+        assert_estimate(&sketch, 1_000, 0.2);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut all = HyperLogLog::try_new(10).unwrap();
+        let mut a = HyperLogLog::try_new(10).unwrap();
+        let mut b = HyperLogLog::try_new(10).unwrap();
+        for value in 0..10_000_i32 {
+            all.insert(&value);
+            match value % 3 {
+                0 => a.insert(&value),
+                1 => b.insert(&value),
+                _ => {
+                    a.insert(&value);
+                    b.insert(&value);
+                }
+            }
+        }
+        a.merge(&b).unwrap();
+        assert_eq!(a, all);
+
+        let err = a.merge(&HyperLogLog::try_new(11).unwrap()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Cannot merge HyperLogLog with precision 11 into HyperLogLog with precision 10"
+        );
+    }
+
+    #[test]
+    fn test_serialization() {
+        let mut sketch = HyperLogLog::try_new(MIN_PRECISION).unwrap();
+        for value in ["a", "b", "c"] {
+            sketch.insert(value);
+        }
+        let bytes = sketch.to_bytes();
+        assert_eq!(bytes.len(), 18);
+        assert_eq!(&bytes[..2], &[1, 4]);
+        assert_eq!(HyperLogLog::try_from_bytes(&bytes).unwrap(), sketch);
+
+        let err = HyperLogLog::try_from_bytes(&bytes[..10]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Expected 16 HyperLogLog registers, got 8"
+        );
+        let err = HyperLogLog::try_from_bytes(&[2, 4]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Unsupported HyperLogLog sketch version 2"
+        );
+        let err = HyperLogLog::try_from_bytes(&[1, 19]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: HyperLogLog precision must be between 4 and 18, got 19"
+        );
+        let mut invalid = bytes.clone();
+        invalid[2] = 62;
+        let err = HyperLogLog::try_from_bytes(&invalid).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid HyperLogLog register value 62"
+        );
+    }
+}
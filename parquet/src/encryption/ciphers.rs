@@ -18,7 +18,7 @@
 use crate::errors::ParquetError;
 use crate::errors::ParquetError::General;
 use crate::errors::Result;
-use ring::aead::{Aad, LessSafeKey, NonceSequence, UnboundKey, AES_128_GCM};
+use ring::aead::{Aad, LessSafeKey, Nonce, NonceSequence, UnboundKey, AES_128_GCM};
 use ring::rand::{SecureRandom, SystemRandom};
 use std::fmt::Debug;
 
@@ -26,6 +26,7 @@ const RIGHT_TWELVE: u128 = 0x0000_0000_ffff_ffff_ffff_ffff_ffff_ffff;
 pub(crate) const NONCE_LEN: usize = 12;
 pub(crate) const TAG_LEN: usize = 16;
 pub(crate) const SIZE_LEN: usize = 4;
+const BLOCK_LEN: usize = 16;
 
 pub(crate) trait BlockDecryptor: Debug + Send + Sync {
     fn decrypt(&self, length_and_ciphertext: &[u8], aad: &[u8]) -> Result<Vec<u8>>;
@@ -40,12 +41,8 @@ pub(crate) struct RingGcmBlockDecryptor {
 
 impl RingGcmBlockDecryptor {
     pub(crate) fn new(key_bytes: &[u8]) -> Result<Self> {
-        // todo support other key sizes
-        let key = UnboundKey::new(&AES_128_GCM, key_bytes)
-            .map_err(|_| General("Failed to create AES key".to_string()))?;
-
         Ok(Self {
-            key: LessSafeKey::new(key),
+            key: aes_key(key_bytes)?,
         })
     }
 }
@@ -80,6 +77,72 @@ impl BlockDecryptor for RingGcmBlockDecryptor {
     }
 }
 
+/// Applies the AES-CTR keystream of `nonce`, with a counter starting at 1, to `data`
+///
+/// As ring does not expose AES-CTR this uses AES-GCM, which with a 12 byte nonce
+/// encrypts using the CTR keystream starting at counter 2, and whose tag for an
+/// empty message and AAD is the keystream block of counter 1. Counters are only
+/// incremented in their lower 32 bits, so `data` is limited to 64GB.
+fn apply_ctr_keystream(key: &LessSafeKey, nonce: &[u8], data: &mut [u8]) -> Result<()> {
+    let first_block = key.seal_in_place_separate_tag(
+        Nonce::try_assume_unique_for_key(nonce)?,
+        Aad::empty(),
+        &mut [],
+    )?;
+    let (head, tail) = data.split_at_mut(data.len().min(BLOCK_LEN));
+    head.iter_mut()
+        .zip(first_block.as_ref())
+        .for_each(|(b, k)| *b ^= k);
+    // The tag of the remaining blocks is not used
+    let _ = key.seal_in_place_separate_tag(
+        Nonce::try_assume_unique_for_key(nonce)?,
+        Aad::empty(),
+        tail,
+    )?;
+    Ok(())
+}
+
+/// Creates an AES key for use with ring
+fn aes_key(key_bytes: &[u8]) -> Result<LessSafeKey> {
+    // todo support other key sizes
+    let key = UnboundKey::new(&AES_128_GCM, key_bytes)
+        .map_err(|_| General("Failed to create AES key".to_string()))?;
+    Ok(LessSafeKey::new(key))
+}
+
+/// Decrypts the data and dictionary pages of files encrypted with AES_GCM_CTR_V1
+#[derive(Debug, Clone)]
+pub(crate) struct RingCtrBlockDecryptor {
+    key: LessSafeKey,
+}
+
+impl RingCtrBlockDecryptor {
+    pub(crate) fn new(key_bytes: &[u8]) -> Result<Self> {
+        Ok(Self {
+            key: aes_key(key_bytes)?,
+        })
+    }
+}
+
+impl BlockDecryptor for RingCtrBlockDecryptor {
+    fn decrypt(&self, length_and_ciphertext: &[u8], _aad: &[u8]) -> Result<Vec<u8>> {
+        // Format is: [ciphertext size, nonce, ciphertext], without an authentication tag
+        if length_and_ciphertext.len() < SIZE_LEN + NONCE_LEN {
+            return Err(general_err!("Ciphertext too short"));
+        }
+        let nonce = &length_and_ciphertext[SIZE_LEN..SIZE_LEN + NONCE_LEN];
+        let mut result = length_and_ciphertext[SIZE_LEN + NONCE_LEN..].to_vec();
+        apply_ctr_keystream(&self.key, nonce, &mut result)?;
+        Ok(result)
+    }
+
+    fn compute_plaintext_tag(&self, _aad: &[u8], _plaintext: &[u8]) -> Result<Vec<u8>> {
+        Err(general_err!(
+            "AES-CTR does not compute authentication tags, footers are signed with AES-GCM"
+        ))
+    }
+}
+
 pub(crate) trait BlockEncryptor: Debug + Send + Sync {
     fn encrypt(&mut self, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>>;
 }
@@ -178,6 +241,42 @@ impl BlockEncryptor for RingGcmBlockEncryptor {
     }
 }
 
+/// Encrypts the data and dictionary pages of files encrypted with AES_GCM_CTR_V1
+#[derive(Debug, Clone)]
+pub(crate) struct RingCtrBlockEncryptor {
+    key: LessSafeKey,
+    nonce_sequence: CounterNonce,
+}
+
+impl RingCtrBlockEncryptor {
+    /// Create a new `RingCtrBlockEncryptor` with a given key and random nonce.
+    pub(crate) fn new(key_bytes: &[u8]) -> Result<Self> {
+        let rng = SystemRandom::new();
+        Ok(Self {
+            key: aes_key(key_bytes)?,
+            nonce_sequence: CounterNonce::new(&rng)?,
+        })
+    }
+}
+
+impl BlockEncryptor for RingCtrBlockEncryptor {
+    fn encrypt(&mut self, plaintext: &[u8], _aad: &[u8]) -> Result<Vec<u8>> {
+        // Format is: [ciphertext size, nonce, ciphertext]
+        let ciphertext_length: u32 = (NONCE_LEN + plaintext.len())
+            .try_into()
+            .map_err(|err| General(format!("Plaintext data too long. {err:?}")))?;
+        let mut ciphertext = Vec::with_capacity(SIZE_LEN + ciphertext_length as usize);
+        ciphertext.extend(ciphertext_length.to_le_bytes());
+
+        let nonce = *self.nonce_sequence.advance()?.as_ref();
+        ciphertext.extend(nonce);
+        ciphertext.extend(plaintext);
+
+        apply_ctr_keystream(&self.key, &nonce, &mut ciphertext[SIZE_LEN + NONCE_LEN..])?;
+        Ok(ciphertext)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -196,4 +295,35 @@ mod tests {
 
         assert_eq!(plaintext, decrypted.as_slice());
     }
+
+    #[test]
+    fn test_ctr_round_trip() {
+        let key = [0u8; 16];
+        let mut encryptor = RingCtrBlockEncryptor::new(&key).unwrap();
+        let decryptor = RingCtrBlockDecryptor::new(&key).unwrap();
+
+        for len in [1, 5, 16, 33] {
+            let plaintext = vec![42; len];
+            let ciphertext = encryptor.encrypt(&plaintext, b"aad").unwrap();
+            assert_eq!(ciphertext.len(), SIZE_LEN + NONCE_LEN + len);
+            assert_ne!(&ciphertext[SIZE_LEN + NONCE_LEN..], plaintext.as_slice());
+            let decrypted = decryptor.decrypt(&ciphertext, b"aad").unwrap();
+            assert_eq!(plaintext, decrypted);
+        }
+    }
+
+    #[test]
+    fn test_ctr_keystream() {
+        // AES-128-CTR with the counter block of the nonce followed by 0x00000001
+        let key = aes_key(b"0123456789012345").unwrap();
+        let nonce: Vec<u8> = (0..12).collect();
+        let mut data = b"The quick brown fox jumps over the lazy dog".to_vec();
+        apply_ctr_keystream(&key, &nonce, &mut data).unwrap();
+        let expected = [
+            192, 213, 76, 241, 137, 37, 20, 237, 85, 139, 23, 214, 106, 192, 148, 41, 198, 155,
+            100, 248, 81, 111, 131, 217, 206, 222, 195, 41, 155, 236, 250, 162, 67, 47, 99, 38, 43,
+            151, 54, 182, 115, 181, 75,
+        ];
+        assert_eq!(data, expected);
+    }
 }
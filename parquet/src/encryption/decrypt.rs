@@ -17,7 +17,10 @@
 
 //! Configuration and utilities for decryption of files using Parquet Modular Encryption
 
-use crate::encryption::ciphers::{BlockDecryptor, RingGcmBlockDecryptor, TAG_LEN};
+use crate::encryption::ciphers::{
+    BlockDecryptor, RingCtrBlockDecryptor, RingGcmBlockDecryptor, TAG_LEN,
+};
+use crate::encryption::encrypt::EncryptionAlgorithm;
 use crate::encryption::modules::{create_footer_aad, create_module_aad, ModuleType};
 use crate::errors::{ParquetError, Result};
 use crate::file::column_crypto_metadata::ColumnCryptoMetaData;
@@ -143,8 +146,7 @@ impl CryptoContext {
     ) -> Result<Self> {
         let (data_decryptor, metadata_decryptor) = match column_crypto_metadata {
             ColumnCryptoMetaData::EncryptionWithFooterKey => {
-                // TODO: In GCM-CTR mode will this need to be a non-GCM decryptor?
-                let data_decryptor = file_decryptor.get_footer_decryptor()?;
+                let data_decryptor = file_decryptor.get_footer_data_decryptor()?;
                 let metadata_decryptor = file_decryptor.get_footer_decryptor()?;
                 (data_decryptor, metadata_decryptor)
             }
@@ -536,19 +538,24 @@ impl DecryptionPropertiesBuilderWithRetriever {
 
 #[derive(Clone, Debug)]
 pub(crate) struct FileDecryptor {
+    algorithm: EncryptionAlgorithm,
     decryption_properties: FileDecryptionProperties,
     footer_decryptor: Arc<dyn BlockDecryptor>,
+    footer_data_decryptor: Arc<dyn BlockDecryptor>,
     file_aad: Vec<u8>,
 }
 
 impl PartialEq for FileDecryptor {
     fn eq(&self, other: &Self) -> bool {
-        self.decryption_properties == other.decryption_properties && self.file_aad == other.file_aad
+        self.algorithm == other.algorithm
+            && self.decryption_properties == other.decryption_properties
+            && self.file_aad == other.file_aad
     }
 }
 
 impl FileDecryptor {
     pub(crate) fn new(
+        algorithm: EncryptionAlgorithm,
         decryption_properties: &FileDecryptionProperties,
         footer_key_metadata: Option<&[u8]>,
         aad_file_unique: Vec<u8>,
@@ -562,9 +569,16 @@ impl FileDecryptor {
                 e.to_string().replace("Parquet error: ", "")
             )
         })?;
+        let footer_decryptor: Arc<dyn BlockDecryptor> = Arc::new(footer_decryptor);
+        let footer_data_decryptor = match algorithm {
+            EncryptionAlgorithm::AesGcmV1 => footer_decryptor.clone(),
+            EncryptionAlgorithm::AesGcmCtrV1 => Arc::new(RingCtrBlockDecryptor::new(&footer_key)?),
+        };
 
         Ok(Self {
-            footer_decryptor: Arc::new(footer_decryptor),
+            algorithm,
+            footer_decryptor,
+            footer_data_decryptor,
             decryption_properties: decryption_properties.clone(),
             file_aad,
         })
@@ -574,6 +588,12 @@ impl FileDecryptor {
         Ok(self.footer_decryptor.clone())
     }
 
+    /// Get the decryptor for the data and dictionary pages of columns encrypted
+    /// with the footer key
+    pub(crate) fn get_footer_data_decryptor(&self) -> Result<Arc<dyn BlockDecryptor>> {
+        Ok(self.footer_data_decryptor.clone())
+    }
+
     /// Verify the signature of the footer
     pub(crate) fn verify_plaintext_footer_signature(&self, plaintext_footer: &[u8]) -> Result<()> {
         // Plaintext footer format is: [plaintext metadata, nonce, authentication tag]
@@ -601,7 +621,10 @@ impl FileDecryptor {
         let column_key = self
             .decryption_properties
             .column_key(column_name, key_metadata)?;
-        Ok(Arc::new(RingGcmBlockDecryptor::new(&column_key)?))
+        Ok(match self.algorithm {
+            EncryptionAlgorithm::AesGcmV1 => Arc::new(RingGcmBlockDecryptor::new(&column_key)?),
+            EncryptionAlgorithm::AesGcmCtrV1 => Arc::new(RingCtrBlockDecryptor::new(&column_key)?),
+        })
     }
 
     pub(crate) fn get_column_metadata_decryptor(
@@ -609,8 +632,10 @@ impl FileDecryptor {
         column_name: &str,
         key_metadata: Option<&[u8]>,
     ) -> Result<Arc<dyn BlockDecryptor>> {
-        // Once GCM CTR mode is implemented, data and metadata decryptors may be different
-        self.get_column_data_decryptor(column_name, key_metadata)
+        let column_key = self
+            .decryption_properties
+            .column_key(column_name, key_metadata)?;
+        Ok(Arc::new(RingGcmBlockDecryptor::new(&column_key)?))
     }
 
     pub(crate) fn file_aad(&self) -> &Vec<u8> {
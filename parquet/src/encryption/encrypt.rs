@@ -18,7 +18,7 @@
 //! Configuration and utilities for Parquet Modular Encryption
 
 use crate::encryption::ciphers::{
-    BlockEncryptor, RingGcmBlockEncryptor, NONCE_LEN, SIZE_LEN, TAG_LEN,
+    BlockEncryptor, RingCtrBlockEncryptor, RingGcmBlockEncryptor, NONCE_LEN, SIZE_LEN, TAG_LEN,
 };
 use crate::errors::{ParquetError, Result};
 use crate::file::column_crypto_metadata::{ColumnCryptoMetaData, EncryptionWithColumnKey};
@@ -29,6 +29,20 @@ use std::collections::{HashMap, HashSet};
 use std::io::Write;
 use thrift::protocol::TCompactOutputProtocol;
 
+/// The algorithm used to encrypt a Parquet file
+///
+/// See the [specification](https://github.com/apache/parquet-format/blob/master/Encryption.md#42-encryption-algorithms)
+/// for more details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EncryptionAlgorithm {
+    /// AES-GCM, encrypting and authenticating all modules
+    #[default]
+    AesGcmV1,
+    /// AES-GCM for metadata and page headers, and AES-CTR for the data and dictionary
+    /// pages, which are faster to encrypt but not authenticated
+    AesGcmCtrV1,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 struct EncryptionKey {
     key: Vec<u8>,
@@ -90,7 +104,17 @@ impl EncryptionKey {
 ///     .build()?;
 /// # Ok::<(), parquet::errors::ParquetError>(())
 /// ```
+///
+/// Use the AES_GCM_CTR_V1 algorithm, which encrypts page data with AES-CTR:
+/// ```
+/// # use parquet::encryption::encrypt::{EncryptionAlgorithm, FileEncryptionProperties};
+/// let file_encryption_properties = FileEncryptionProperties::builder(b"0123456789012345".into())
+///     .with_encryption_algorithm(EncryptionAlgorithm::AesGcmCtrV1)
+///     .build()?;
+/// # Ok::<(), parquet::errors::ParquetError>(())
+/// ```
 pub struct FileEncryptionProperties {
+    algorithm: EncryptionAlgorithm,
     encrypt_footer: bool,
     footer_key: EncryptionKey,
     column_keys: HashMap<String, EncryptionKey>,
@@ -104,6 +128,11 @@ impl FileEncryptionProperties {
         EncryptionPropertiesBuilder::new(footer_key)
     }
 
+    /// The algorithm used to encrypt the file
+    pub fn encryption_algorithm(&self) -> EncryptionAlgorithm {
+        self.algorithm
+    }
+
     /// Should the footer be encrypted
     pub fn encrypt_footer(&self) -> bool {
         self.encrypt_footer
@@ -181,6 +210,7 @@ impl FileEncryptionProperties {
 ///
 /// See [`FileEncryptionProperties`] for example usage.
 pub struct EncryptionPropertiesBuilder {
+    algorithm: EncryptionAlgorithm,
     encrypt_footer: bool,
     footer_key: EncryptionKey,
     column_keys: HashMap<String, EncryptionKey>,
@@ -192,6 +222,7 @@ impl EncryptionPropertiesBuilder {
     /// Create a new [`EncryptionPropertiesBuilder`] with the given footer encryption key
     pub fn new(footer_key: Vec<u8>) -> EncryptionPropertiesBuilder {
         Self {
+            algorithm: EncryptionAlgorithm::default(),
             footer_key: EncryptionKey::new(footer_key),
             column_keys: HashMap::default(),
             aad_prefix: None,
@@ -200,6 +231,12 @@ impl EncryptionPropertiesBuilder {
         }
     }
 
+    /// Set the algorithm used to encrypt the file. Defaults to [`EncryptionAlgorithm::AesGcmV1`].
+    pub fn with_encryption_algorithm(mut self, algorithm: EncryptionAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
     /// Set if the footer should be stored in plaintext (not encrypted). Defaults to false.
     pub fn with_plaintext_footer(mut self, plaintext_footer: bool) -> Self {
         self.encrypt_footer = !plaintext_footer;
@@ -277,6 +314,7 @@ impl EncryptionPropertiesBuilder {
     /// Build the encryption properties
     pub fn build(self) -> Result<FileEncryptionProperties> {
         Ok(FileEncryptionProperties {
+            algorithm: self.algorithm,
             encrypt_footer: self.encrypt_footer,
             footer_key: self.footer_key,
             column_keys: self.column_keys,
@@ -348,18 +386,37 @@ impl FileEncryptor {
         )?))
     }
 
-    /// Get the encryptor for a column.
+    /// Get the encryptor for the metadata and page headers of a column.
     /// Will return an error if the column is not an encrypted column.
     pub(crate) fn get_column_encryptor(
         &self,
         column_path: &str,
     ) -> Result<Box<dyn BlockEncryptor>> {
+        let key = self.column_key(column_path)?;
+        Ok(Box::new(RingGcmBlockEncryptor::new(key)?))
+    }
+
+    /// Get the encryptor for the data and dictionary pages of a column.
+    /// Will return an error if the column is not an encrypted column.
+    pub(crate) fn get_column_data_encryptor(
+        &self,
+        column_path: &str,
+    ) -> Result<Box<dyn BlockEncryptor>> {
+        let key = self.column_key(column_path)?;
+        Ok(match self.properties.algorithm {
+            EncryptionAlgorithm::AesGcmV1 => Box::new(RingGcmBlockEncryptor::new(key)?),
+            EncryptionAlgorithm::AesGcmCtrV1 => Box::new(RingCtrBlockEncryptor::new(key)?),
+        })
+    }
+
+    /// Get the key used to encrypt a column
+    fn column_key(&self, column_path: &str) -> Result<&[u8]> {
         if self.properties.column_keys.is_empty() {
-            return self.get_footer_encryptor();
+            return Ok(&self.properties.footer_key.key);
         }
         match self.properties.column_keys.get(column_path) {
             None => Err(general_err!("Column '{}' is not encrypted", column_path)),
-            Some(column_key) => Ok(Box::new(RingGcmBlockEncryptor::new(column_key.key())?)),
+            Some(column_key) => Ok(column_key.key()),
         }
     }
 }
@@ -0,0 +1,101 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::errors::Result;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A thread-safe cache whose entries expire a given lifetime after they were inserted
+#[derive(Debug)]
+pub(crate) struct ExpiringCache<K, V> {
+    entries: Mutex<HashMap<K, (V, Instant)>>,
+}
+
+impl<K, V> Default for ExpiringCache<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> ExpiringCache<K, V> {
+    /// Returns the value cached for `key` if inserted less than `lifetime` ago, or
+    /// otherwise inserts and returns the value created by `f`
+    ///
+    /// Entries never expire if `lifetime` is `None`
+    pub(crate) fn get_or_try_insert(
+        &self,
+        key: K,
+        lifetime: Option<Duration>,
+        f: impl FnOnce() -> Result<V>,
+    ) -> Result<V> {
+        let mut entries = self.entries.lock().unwrap();
+        if let Some((value, inserted)) = entries.get(&key) {
+            if lifetime.is_none_or(|lifetime| inserted.elapsed() < lifetime) {
+                return Ok(value.clone());
+            }
+        }
+        let value = f()?;
+        entries.insert(key, (value.clone(), Instant::now()));
+        Ok(value)
+    }
+
+    /// Removes the entries for which `f` returns false
+    pub(crate) fn retain(&self, mut f: impl FnMut(&K) -> bool) {
+        self.entries.lock().unwrap().retain(|key, _| f(key));
+    }
+
+    /// Returns the number of entries, including expired ones
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ParquetError;
+
+    #[test]
+    fn test_expiring_cache() {
+        let cache = ExpiringCache::default();
+        let value = cache.get_or_try_insert("a", None, || Ok(1)).unwrap();
+        assert_eq!(value, 1);
+        let value = cache.get_or_try_insert("a", None, || Ok(2)).unwrap();
+        assert_eq!(value, 1);
+
+        // An expired entry is replaced
+        let value = cache
+            .get_or_try_insert("a", Some(Duration::ZERO), || Ok(3))
+            .unwrap();
+        assert_eq!(value, 3);
+
+        // Errors are not cached
+        let err = cache
+            .get_or_try_insert("b", None, || Err(general_err!("failed")))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "Parquet error: failed");
+        assert_eq!(cache.len(), 1);
+
+        cache.retain(|key| *key != "a");
+        assert_eq!(cache.len(), 0);
+    }
+}
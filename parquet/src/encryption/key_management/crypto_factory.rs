@@ -0,0 +1,677 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The [`CryptoFactory`] creating file encryption and decryption properties with keys
+//! wrapped by a KMS
+
+use crate::encryption::decrypt::{FileDecryptionProperties, KeyRetriever};
+use crate::encryption::encrypt::{EncryptionAlgorithm, FileEncryptionProperties};
+use crate::encryption::key_management::cache::ExpiringCache;
+use crate::encryption::key_management::key_encryption::{
+    decrypt_key_locally, encrypt_key_locally, random_bytes, KEK_ID_LEN, KEY_LEN,
+};
+use crate::encryption::key_management::key_material::KeyMaterial;
+use crate::encryption::key_management::kms::{KmsClient, KmsClientFactory, KmsConnectionConfig};
+use crate::errors::{ParquetError, Result};
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Formatter;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// The default lifetime of cached KMS clients and key encryption keys
+const DEFAULT_CACHE_LIFETIME: Duration = Duration::from_secs(600);
+
+/// Configuration of how a file is encrypted with keys wrapped by a KMS
+///
+/// Each file is encrypted with newly generated data encryption keys, which are wrapped
+/// with the master keys identified in this configuration.
+#[derive(Debug, Clone)]
+pub struct EncryptionConfiguration {
+    footer_key_id: String,
+    column_key_ids: HashMap<String, Vec<String>>,
+    encryption_algorithm: EncryptionAlgorithm,
+    plaintext_footer: bool,
+    double_wrapping: bool,
+    cache_lifetime: Option<Duration>,
+}
+
+impl EncryptionConfiguration {
+    /// Returns a new builder of a configuration with the footer wrapped by the master key
+    /// `footer_key_id`
+    pub fn builder(footer_key_id: String) -> EncryptionConfigurationBuilder {
+        EncryptionConfigurationBuilder::new(footer_key_id)
+    }
+
+    /// Returns the identifier of the master key of the footer key
+    pub fn footer_key_id(&self) -> &str {
+        &self.footer_key_id
+    }
+
+    /// Returns the paths of the encrypted columns, keyed by the identifier of the master
+    /// key of their column keys
+    pub fn column_key_ids(&self) -> &HashMap<String, Vec<String>> {
+        &self.column_key_ids
+    }
+
+    /// Returns the algorithm used to encrypt the file
+    pub fn encryption_algorithm(&self) -> EncryptionAlgorithm {
+        self.encryption_algorithm
+    }
+
+    /// Returns whether the footer is written in plaintext
+    pub fn plaintext_footer(&self) -> bool {
+        self.plaintext_footer
+    }
+
+    /// Returns whether data encryption keys are wrapped with key encryption keys
+    pub fn double_wrapping(&self) -> bool {
+        self.double_wrapping
+    }
+
+    /// Returns how long KMS clients and key encryption keys are cached, if they expire
+    pub fn cache_lifetime(&self) -> Option<Duration> {
+        self.cache_lifetime
+    }
+}
+
+/// Builder for [`EncryptionConfiguration`]
+#[derive(Debug)]
+pub struct EncryptionConfigurationBuilder {
+    footer_key_id: String,
+    column_key_ids: HashMap<String, Vec<String>>,
+    encryption_algorithm: EncryptionAlgorithm,
+    plaintext_footer: bool,
+    double_wrapping: bool,
+    cache_lifetime: Option<Duration>,
+}
+
+impl EncryptionConfigurationBuilder {
+    /// Returns a new builder of a configuration with the footer wrapped by the master key
+    /// `footer_key_id`
+    pub fn new(footer_key_id: String) -> Self {
+        Self {
+            footer_key_id,
+            column_key_ids: HashMap::new(),
+            encryption_algorithm: EncryptionAlgorithm::default(),
+            plaintext_footer: false,
+            double_wrapping: true,
+            cache_lifetime: Some(DEFAULT_CACHE_LIFETIME),
+        }
+    }
+
+    /// Encrypts the columns in `column_paths` with keys wrapped by the master key
+    /// `master_key_id`
+    ///
+    /// If no column keys are set, all columns are encrypted with the footer key.
+    pub fn with_column_keys(mut self, master_key_id: String, column_paths: Vec<String>) -> Self {
+        self.column_key_ids
+            .entry(master_key_id)
+            .or_default()
+            .extend(column_paths);
+        self
+    }
+
+    /// Sets the algorithm used to encrypt the file, AES_GCM_V1 by default
+    pub fn with_encryption_algorithm(mut self, algorithm: EncryptionAlgorithm) -> Self {
+        self.encryption_algorithm = algorithm;
+        self
+    }
+
+    /// Sets whether the footer is written in plaintext, false by default
+    pub fn with_plaintext_footer(mut self, plaintext_footer: bool) -> Self {
+        self.plaintext_footer = plaintext_footer;
+        self
+    }
+
+    /// Sets whether data encryption keys are wrapped locally with key encryption keys,
+    /// which are in turn wrapped by the KMS, true by default
+    ///
+    /// Double wrapping reduces the number of calls to the KMS, as a key encryption key is
+    /// reused for all data encryption keys of a master key while it is cached.
+    pub fn with_double_wrapping(mut self, double_wrapping: bool) -> Self {
+        self.double_wrapping = double_wrapping;
+        self
+    }
+
+    /// Sets how long KMS clients and key encryption keys are cached, 10 minutes by
+    /// default, or `None` to never expire them
+    pub fn with_cache_lifetime(mut self, cache_lifetime: Option<Duration>) -> Self {
+        self.cache_lifetime = cache_lifetime;
+        self
+    }
+
+    /// Finalizes the configuration, checking that no column has multiple master keys
+    pub fn build(self) -> Result<EncryptionConfiguration> {
+        let mut columns = HashSet::new();
+        for column_path in self.column_key_ids.values().flatten() {
+            if !columns.insert(column_path) {
+                return Err(general_err!(
+                    "Multiple master keys set for column {}",
+                    column_path
+                ));
+            }
+        }
+
+        Ok(EncryptionConfiguration {
+            footer_key_id: self.footer_key_id,
+            column_key_ids: self.column_key_ids,
+            encryption_algorithm: self.encryption_algorithm,
+            plaintext_footer: self.plaintext_footer,
+            double_wrapping: self.double_wrapping,
+            cache_lifetime: self.cache_lifetime,
+        })
+    }
+}
+
+/// Configuration of how files encrypted with keys wrapped by a KMS are decrypted
+#[derive(Debug, Clone)]
+pub struct DecryptionConfiguration {
+    cache_lifetime: Option<Duration>,
+}
+
+impl Default for DecryptionConfiguration {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl DecryptionConfiguration {
+    /// Returns a new builder of a [`DecryptionConfiguration`]
+    pub fn builder() -> DecryptionConfigurationBuilder {
+        DecryptionConfigurationBuilder::default()
+    }
+
+    /// Returns how long KMS clients and key encryption keys are cached, if they expire
+    pub fn cache_lifetime(&self) -> Option<Duration> {
+        self.cache_lifetime
+    }
+}
+
+/// Builder for [`DecryptionConfiguration`]
+#[derive(Debug)]
+pub struct DecryptionConfigurationBuilder {
+    cache_lifetime: Option<Duration>,
+}
+
+impl Default for DecryptionConfigurationBuilder {
+    fn default() -> Self {
+        Self {
+            cache_lifetime: Some(DEFAULT_CACHE_LIFETIME),
+        }
+    }
+}
+
+impl DecryptionConfigurationBuilder {
+    /// Sets how long KMS clients and key encryption keys are cached, 10 minutes by
+    /// default, or `None` to never expire them
+    pub fn with_cache_lifetime(mut self, cache_lifetime: Option<Duration>) -> Self {
+        self.cache_lifetime = cache_lifetime;
+        self
+    }
+
+    /// Finalizes the configuration
+    pub fn build(self) -> DecryptionConfiguration {
+        DecryptionConfiguration {
+            cache_lifetime: self.cache_lifetime,
+        }
+    }
+}
+
+/// A key encryption key used to wrap data encryption keys locally
+#[derive(Debug)]
+struct KeyEncryptionKey {
+    id: Vec<u8>,
+    encoded_id: String,
+    key: Vec<u8>,
+    wrapped_key: String,
+}
+
+/// The KMS client factory and caches shared by a [`CryptoFactory`] and the key
+/// retrievers of the decryption properties it creates
+struct KeyToolkit {
+    kms_client_factory: Arc<dyn KmsClientFactory>,
+    /// KMS clients keyed by access token, KMS instance ID and URL
+    kms_clients: ExpiringCache<(String, String, String), Arc<dyn KmsClient>>,
+    /// Key encryption keys for writing keyed by access token and master key ID
+    write_keks: ExpiringCache<(String, String), Arc<KeyEncryptionKey>>,
+    /// Unwrapped key encryption keys keyed by access token and encoded key ID
+    read_keks: ExpiringCache<(String, String), Vec<u8>>,
+}
+
+impl KeyToolkit {
+    fn kms_client(
+        &self,
+        config: &KmsConnectionConfig,
+        cache_lifetime: Option<Duration>,
+    ) -> Result<Arc<dyn KmsClient>> {
+        let key = (
+            config.key_access_token(),
+            config.kms_instance_id().to_string(),
+            config.kms_instance_url().to_string(),
+        );
+        self.kms_clients.get_or_try_insert(key, cache_lifetime, || {
+            self.kms_client_factory.create_client(config)
+        })
+    }
+
+    fn retain_tokens(&self, f: impl Fn(&str) -> bool) {
+        self.kms_clients.retain(|(token, _, _)| f(token));
+        self.write_keks.retain(|(token, _)| f(token));
+        self.read_keks.retain(|(token, _)| f(token));
+    }
+}
+
+/// Creates [`FileEncryptionProperties`] and [`FileDecryptionProperties`] using keys
+/// wrapped by a Key Management Server (KMS)
+///
+/// This implements the key management tools of parquet-mr: a random data encryption key
+/// is generated for the footer and each encrypted column of a file, and stored in the key
+/// metadata wrapped by a master key in the KMS, along with the JSON key material needed
+/// to unwrap it. Files are therefore interoperable with the crypto factories of parquet-mr
+/// and PyArrow.
+///
+/// Only 128 bit data encryption keys are supported.
+///
+/// See the [module level documentation](crate::encryption::key_management) for an example.
+#[derive(Clone)]
+pub struct CryptoFactory {
+    toolkit: Arc<KeyToolkit>,
+}
+
+impl std::fmt::Debug for CryptoFactory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CryptoFactory {{ }}")
+    }
+}
+
+impl CryptoFactory {
+    /// Creates a factory using KMS clients created by `kms_client_factory`
+    pub fn new(kms_client_factory: impl KmsClientFactory + 'static) -> Self {
+        Self {
+            toolkit: Arc::new(KeyToolkit {
+                kms_client_factory: Arc::new(kms_client_factory),
+                kms_clients: ExpiringCache::default(),
+                write_keks: ExpiringCache::default(),
+                read_keks: ExpiringCache::default(),
+            }),
+        }
+    }
+
+    /// Returns the properties to encrypt a file with newly generated data encryption keys,
+    /// wrapped by the master keys set in `config`
+    pub fn file_encryption_properties(
+        &self,
+        kms_config: &KmsConnectionConfig,
+        config: &EncryptionConfiguration,
+    ) -> Result<FileEncryptionProperties> {
+        let wrapper = KeyWrapper {
+            toolkit: &self.toolkit,
+            kms_client: self.toolkit.kms_client(kms_config, config.cache_lifetime)?,
+            kms_config,
+            config,
+        };
+
+        let footer_key = random_bytes(KEY_LEN)?;
+        let footer_key_metadata = wrapper.wrap(&footer_key, &config.footer_key_id, true)?;
+        let mut builder = FileEncryptionProperties::builder(footer_key)
+            .with_footer_key_metadata(footer_key_metadata)
+            .with_encryption_algorithm(config.encryption_algorithm)
+            .with_plaintext_footer(config.plaintext_footer);
+
+        for (master_key_id, column_paths) in &config.column_key_ids {
+            for column_path in column_paths {
+                let key = random_bytes(KEY_LEN)?;
+                let key_metadata = wrapper.wrap(&key, master_key_id, false)?;
+                builder = builder.with_column_key_and_metadata(column_path, key, key_metadata);
+            }
+        }
+        builder.build()
+    }
+
+    /// Returns the properties to decrypt files encrypted by a [`CryptoFactory`], unwrapping
+    /// their keys with the KMS as they are read
+    pub fn file_decryption_properties(
+        &self,
+        kms_config: &KmsConnectionConfig,
+        config: &DecryptionConfiguration,
+    ) -> Result<FileDecryptionProperties> {
+        let key_retriever = KeyUnwrapper {
+            toolkit: Arc::clone(&self.toolkit),
+            kms_config: Mutex::new(kms_config.clone()),
+            cache_lifetime: config.cache_lifetime,
+        };
+        FileDecryptionProperties::with_key_retriever(Arc::new(key_retriever)).build()
+    }
+
+    /// Removes the cached KMS clients and key encryption keys of an access token, such as
+    /// after it was revoked
+    pub fn remove_cache_entries_for_token(&self, access_token: &str) {
+        self.toolkit.retain_tokens(|token| token != access_token);
+    }
+
+    /// Removes all cached KMS clients and key encryption keys
+    pub fn remove_cache_entries_for_all_tokens(&self) {
+        self.toolkit.retain_tokens(|_| false);
+    }
+}
+
+/// Wraps the data encryption keys of a file, returning their key metadata
+struct KeyWrapper<'a> {
+    toolkit: &'a KeyToolkit,
+    kms_client: Arc<dyn KmsClient>,
+    kms_config: &'a KmsConnectionConfig,
+    config: &'a EncryptionConfiguration,
+}
+
+impl KeyWrapper<'_> {
+    fn wrap(&self, key: &[u8], master_key_id: &str, is_footer_key: bool) -> Result<Vec<u8>> {
+        let material = match self.config.double_wrapping {
+            true => {
+                let cache_key = (
+                    self.kms_config.key_access_token(),
+                    master_key_id.to_string(),
+                );
+                let kek = self.toolkit.write_keks.get_or_try_insert(
+                    cache_key,
+                    self.config.cache_lifetime,
+                    || {
+                        let key = random_bytes(KEY_LEN)?;
+                        let id = random_bytes(KEK_ID_LEN)?;
+                        Ok(Arc::new(KeyEncryptionKey {
+                            wrapped_key: self.kms_client.wrap_key(&key, master_key_id)?,
+                            encoded_id: BASE64_STANDARD.encode(&id),
+                            id,
+                            key,
+                        }))
+                    },
+                )?;
+                let wrapped_dek = encrypt_key_locally(key, &kek.key, &kek.id)?;
+                KeyMaterial::new(master_key_id.to_string(), wrapped_dek)
+                    .with_double_wrapping(kek.encoded_id.clone(), kek.wrapped_key.clone())
+            }
+            false => {
+                let wrapped_dek = self.kms_client.wrap_key(key, master_key_id)?;
+                KeyMaterial::new(master_key_id.to_string(), wrapped_dek)
+            }
+        };
+
+        let material = match is_footer_key {
+            true => material.with_footer_key(
+                self.kms_config.kms_instance_id().to_string(),
+                self.kms_config.kms_instance_url().to_string(),
+            ),
+            false => material,
+        };
+        material.serialize()
+    }
+}
+
+/// A [`KeyRetriever`] unwrapping keys from their JSON key material
+struct KeyUnwrapper {
+    toolkit: Arc<KeyToolkit>,
+    /// The KMS connection, updated with the KMS instance of the footer key material
+    kms_config: Mutex<KmsConnectionConfig>,
+    cache_lifetime: Option<Duration>,
+}
+
+impl KeyRetriever for KeyUnwrapper {
+    fn retrieve_key(&self, key_metadata: &[u8]) -> Result<Vec<u8>> {
+        let material = KeyMaterial::deserialize(key_metadata)?;
+        let kms_config = {
+            let mut kms_config = self.kms_config.lock().unwrap();
+            if material.is_footer_key {
+                *kms_config = kms_config.with_key_material_defaults(
+                    material.kms_instance_id.as_deref(),
+                    material.kms_instance_url.as_deref(),
+                );
+            }
+            kms_config.clone()
+        };
+        let kms_client = self.toolkit.kms_client(&kms_config, self.cache_lifetime)?;
+
+        match (material.kek_id, material.wrapped_kek) {
+            (Some(kek_id), Some(wrapped_kek)) if material.double_wrapping => {
+                let kek_id_bytes = BASE64_STANDARD
+                    .decode(&kek_id)
+                    .map_err(|e| general_err!("Invalid key encryption key ID: {}", e))?;
+                let cache_key = (kms_config.key_access_token(), kek_id);
+                let kek = self.toolkit.read_keks.get_or_try_insert(
+                    cache_key,
+                    self.cache_lifetime,
+                    || kms_client.unwrap_key(&wrapped_kek, &material.master_key_id),
+                )?;
+                decrypt_key_locally(&material.wrapped_dek, &kek, &kek_id_bytes)
+            }
+            _ => kms_client.unwrap_key(&material.wrapped_dek, &material.master_key_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::key_management::test_kms::InMemoryKms;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A KMS client counting the calls to wrap and unwrap keys
+    struct CountingKms {
+        kms: InMemoryKms,
+        calls: Arc<AtomicUsize>,
+    }
+
+    impl KmsClient for CountingKms {
+        fn wrap_key(&self, key_bytes: &[u8], master_key_identifier: &str) -> Result<String> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            self.kms.wrap_key(key_bytes, master_key_identifier)
+        }
+
+        fn unwrap_key(&self, wrapped_key: &str, master_key_identifier: &str) -> Result<Vec<u8>> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            self.kms.unwrap_key(wrapped_key, master_key_identifier)
+        }
+    }
+
+    fn crypto_factory(calls: Arc<AtomicUsize>) -> CryptoFactory {
+        let kms = InMemoryKms::new(HashMap::from([
+            ("kf".to_string(), b"0123456789012345".to_vec()),
+            ("kc1".to_string(), b"1234567890123450".to_vec()),
+            ("kc2".to_string(), b"1234567890123451".to_vec()),
+        ]));
+        CryptoFactory::new(move |_: &KmsConnectionConfig| {
+            Ok(Arc::new(CountingKms {
+                kms: kms.clone(),
+                calls: Arc::clone(&calls),
+            }) as Arc<dyn KmsClient>)
+        })
+    }
+
+    fn encryption_config(double_wrapping: bool) -> EncryptionConfiguration {
+        EncryptionConfiguration::builder("kf".to_string())
+            .with_column_keys("kc1".to_string(), vec!["x".to_string(), "y".to_string()])
+            .with_column_keys("kc2".to_string(), vec!["z".to_string()])
+            .with_double_wrapping(double_wrapping)
+            .build()
+            .unwrap()
+    }
+
+    fn check_keys_roundtrip(double_wrapping: bool) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let factory = crypto_factory(Arc::clone(&calls));
+        let kms_config = KmsConnectionConfig::default();
+        let config = encryption_config(double_wrapping);
+
+        let encryption_properties = factory
+            .file_encryption_properties(&kms_config, &config)
+            .unwrap();
+        let footer_key_metadata = encryption_properties.footer_key_metadata().unwrap();
+        let material = KeyMaterial::deserialize(footer_key_metadata).unwrap();
+        assert!(material.is_footer_key);
+        assert_eq!(material.master_key_id, "kf");
+        assert_eq!(material.double_wrapping, double_wrapping);
+        assert_eq!(material.kms_instance_id.as_deref(), Some("DEFAULT"));
+        // With double wrapping, the KMS only wraps one key encryption key per master key
+        assert_eq!(
+            calls.load(Ordering::Relaxed),
+            if double_wrapping { 3 } else { 4 }
+        );
+
+        // Use a separate factory so key encryption keys are not cached
+        let factory = crypto_factory(Arc::clone(&calls));
+        let decryption_properties = factory
+            .file_decryption_properties(&kms_config, &DecryptionConfiguration::default())
+            .unwrap();
+        let footer_key = decryption_properties
+            .footer_key(Some(footer_key_metadata))
+            .unwrap();
+        assert_eq!(footer_key.as_ref(), encryption_properties.footer_key());
+
+        let (column_names, column_keys, key_metadata) = encryption_properties.column_keys();
+        assert_eq!(column_names.len(), 3);
+        for ((column_name, key), metadata) in column_names.iter().zip(column_keys).zip(key_metadata)
+        {
+            let material = KeyMaterial::deserialize(&metadata).unwrap();
+            assert!(!material.is_footer_key);
+            let expected_master_key = if column_name == "z" { "kc2" } else { "kc1" };
+            assert_eq!(material.master_key_id, expected_master_key);
+
+            let retrieved = decryption_properties
+                .column_key(column_name, Some(&metadata))
+                .unwrap();
+            assert_eq!(retrieved.as_ref(), &key);
+        }
+    }
+
+    #[test]
+    fn test_keys_roundtrip() {
+        check_keys_roundtrip(true);
+    }
+
+    #[test]
+    fn test_keys_roundtrip_single_wrapping() {
+        check_keys_roundtrip(false);
+    }
+
+    #[test]
+    fn test_kek_caching() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let factory = crypto_factory(Arc::clone(&calls));
+        let kms_config = KmsConnectionConfig::default();
+        let config = encryption_config(true);
+
+        let first = factory
+            .file_encryption_properties(&kms_config, &config)
+            .unwrap();
+        let second = factory
+            .file_encryption_properties(&kms_config, &config)
+            .unwrap();
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+        assert_ne!(first.footer_key(), second.footer_key());
+        let kek_id = |properties: &FileEncryptionProperties| {
+            KeyMaterial::deserialize(properties.footer_key_metadata().unwrap())
+                .unwrap()
+                .kek_id
+        };
+        assert_eq!(kek_id(&first), kek_id(&second));
+
+        // Key encryption keys are cached per access token
+        kms_config.refresh_key_access_token("token".to_string());
+        let third = factory
+            .file_encryption_properties(&kms_config, &config)
+            .unwrap();
+        assert_eq!(calls.load(Ordering::Relaxed), 6);
+        assert_ne!(kek_id(&first), kek_id(&third));
+
+        factory.remove_cache_entries_for_token("token");
+        factory
+            .file_encryption_properties(&kms_config, &config)
+            .unwrap();
+        assert_eq!(calls.load(Ordering::Relaxed), 9);
+
+        factory.remove_cache_entries_for_all_tokens();
+        let config = EncryptionConfiguration::builder("kf".to_string())
+            .with_cache_lifetime(Some(Duration::ZERO))
+            .build()
+            .unwrap();
+        for expected_calls in [10, 11] {
+            factory
+                .file_encryption_properties(&kms_config, &config)
+                .unwrap();
+            assert_eq!(calls.load(Ordering::Relaxed), expected_calls);
+        }
+    }
+
+    #[test]
+    fn test_kms_instance_from_footer_key_material() {
+        let configs = Arc::new(Mutex::new(vec![]));
+        let factory = {
+            let configs = Arc::clone(&configs);
+            let kms = InMemoryKms::new(HashMap::from([(
+                "kf".to_string(),
+                b"0123456789012345".to_vec(),
+            )]));
+            CryptoFactory::new(move |config: &KmsConnectionConfig| {
+                configs
+                    .lock()
+                    .unwrap()
+                    .push(config.kms_instance_id().to_string());
+                Ok(Arc::new(kms.clone()) as Arc<dyn KmsClient>)
+            })
+        };
+
+        let kms_config = KmsConnectionConfig::builder()
+            .with_kms_instance_id("instance".to_string())
+            .build();
+        let config = EncryptionConfiguration::builder("kf".to_string())
+            .build()
+            .unwrap();
+        let encryption_properties = factory
+            .file_encryption_properties(&kms_config, &config)
+            .unwrap();
+
+        let decryption_properties = factory
+            .file_decryption_properties(
+                &KmsConnectionConfig::default(),
+                &DecryptionConfiguration::default(),
+            )
+            .unwrap();
+        decryption_properties
+            .footer_key(
+                encryption_properties
+                    .footer_key_metadata()
+                    .map(Vec::as_slice),
+            )
+            .unwrap();
+        // The KMS instance ID of the footer key material is used to unwrap the footer key,
+        // so the client cached when writing is reused rather than creating one for "DEFAULT"
+        assert_eq!(*configs.lock().unwrap(), vec!["instance"]);
+    }
+
+    #[test]
+    fn test_multiple_master_keys_for_column() {
+        let err = EncryptionConfiguration::builder("kf".to_string())
+            .with_column_keys("kc1".to_string(), vec!["x".to_string()])
+            .with_column_keys("kc2".to_string(), vec!["x".to_string()])
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Multiple master keys set for column x"
+        );
+    }
+}
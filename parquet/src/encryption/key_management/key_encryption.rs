@@ -0,0 +1,102 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Local wrapping of keys with AES-GCM, compatible with the key tools of parquet-mr
+
+use crate::encryption::ciphers::{
+    BlockDecryptor, BlockEncryptor, RingGcmBlockDecryptor, RingGcmBlockEncryptor, NONCE_LEN,
+    SIZE_LEN, TAG_LEN,
+};
+use crate::errors::{ParquetError, Result};
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use ring::rand::{SecureRandom, SystemRandom};
+
+/// The length in bytes of generated data and key encryption keys
+pub(crate) const KEY_LEN: usize = 16;
+
+/// The length in bytes of generated key encryption key identifiers
+pub(crate) const KEK_ID_LEN: usize = 16;
+
+/// Returns `len` random bytes, for use as a key or key identifier
+pub(crate) fn random_bytes(len: usize) -> Result<Vec<u8>> {
+    let mut bytes = vec![0; len];
+    SystemRandom::new().fill(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// Encrypts `key` with `kek`, returning the base64 encoded nonce, ciphertext and tag
+pub(crate) fn encrypt_key_locally(key: &[u8], kek: &[u8], aad: &[u8]) -> Result<String> {
+    let mut encryptor = RingGcmBlockEncryptor::new(kek)?;
+    let ciphertext = encryptor.encrypt(key, aad)?;
+    // Wrapped keys do not include the length prefix of the ciphertext
+    Ok(BASE64_STANDARD.encode(&ciphertext[SIZE_LEN..]))
+}
+
+/// Decrypts a key encrypted by [`encrypt_key_locally`]
+pub(crate) fn decrypt_key_locally(wrapped_key: &str, kek: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+    let ciphertext = BASE64_STANDARD
+        .decode(wrapped_key)
+        .map_err(|e| general_err!("Invalid base64 in wrapped key: {}", e))?;
+    if ciphertext.len() < NONCE_LEN + TAG_LEN {
+        return Err(general_err!(
+            "Wrapped key of {} bytes is too short",
+            ciphertext.len()
+        ));
+    }
+
+    let mut length_and_ciphertext = Vec::with_capacity(SIZE_LEN + ciphertext.len());
+    length_and_ciphertext.extend((ciphertext.len() as u32).to_le_bytes());
+    length_and_ciphertext.extend(ciphertext);
+    RingGcmBlockDecryptor::new(kek)?
+        .decrypt(&length_and_ciphertext, aad)
+        .map_err(|_| general_err!("Failed to decrypt wrapped key"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEK: &[u8] = b"0123456789012345";
+    const KEY: &[u8] = b"1234567890123450";
+
+    #[test]
+    fn test_key_roundtrip() {
+        let wrapped = encrypt_key_locally(KEY, KEK, b"kf").unwrap();
+        assert_ne!(wrapped, encrypt_key_locally(KEY, KEK, b"kf").unwrap());
+        assert_eq!(decrypt_key_locally(&wrapped, KEK, b"kf").unwrap(), KEY);
+
+        let err = decrypt_key_locally(&wrapped, KEK, b"kc").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Failed to decrypt wrapped key"
+        );
+    }
+
+    #[test]
+    fn test_decrypt_key() {
+        // Encrypted with AES-GCM by an independent implementation
+        let wrapped = "AAECAwQFBgcICQoLkcYv7A4s2ZGEzp1tzarv5tXwCvkPY4Mr8b8m4a+myqI=";
+        assert_eq!(decrypt_key_locally(wrapped, KEK, b"kf").unwrap(), KEY);
+
+        let err = decrypt_key_locally("AAEC", KEK, b"kf").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Wrapped key of 3 bytes is too short"
+        );
+    }
+}
@@ -0,0 +1,199 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The JSON key material stored in the key metadata of encrypted files, in the
+//! format of the key tools of parquet-mr
+
+use crate::errors::{ParquetError, Result};
+use serde::{Deserialize, Serialize};
+
+const KEY_MATERIAL_TYPE: &str = "PKMT1";
+
+/// The fields common to all key material, used to validate it before parsing the rest
+#[derive(Debug, Deserialize)]
+struct KeyMaterialHeader {
+    #[serde(rename = "keyMaterialType")]
+    key_material_type: String,
+    #[serde(rename = "internalStorage")]
+    internal_storage: bool,
+}
+
+/// The material required to unwrap a data encryption key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct KeyMaterial {
+    #[serde(rename = "keyMaterialType")]
+    key_material_type: String,
+    #[serde(rename = "internalStorage")]
+    internal_storage: bool,
+    #[serde(rename = "isFooterKey")]
+    pub(crate) is_footer_key: bool,
+    #[serde(
+        rename = "kmsInstanceID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub(crate) kms_instance_id: Option<String>,
+    #[serde(
+        rename = "kmsInstanceURL",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub(crate) kms_instance_url: Option<String>,
+    #[serde(rename = "masterKeyID")]
+    pub(crate) master_key_id: String,
+    #[serde(rename = "wrappedDEK")]
+    pub(crate) wrapped_dek: String,
+    #[serde(rename = "doubleWrapping")]
+    pub(crate) double_wrapping: bool,
+    #[serde(
+        rename = "keyEncryptionKeyID",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub(crate) kek_id: Option<String>,
+    #[serde(
+        rename = "wrappedKEK",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub(crate) wrapped_kek: Option<String>,
+}
+
+impl KeyMaterial {
+    /// Creates the key material of a data encryption key wrapped directly by the KMS
+    pub(crate) fn new(master_key_id: String, wrapped_dek: String) -> Self {
+        Self {
+            key_material_type: KEY_MATERIAL_TYPE.to_string(),
+            internal_storage: true,
+            is_footer_key: false,
+            kms_instance_id: None,
+            kms_instance_url: None,
+            master_key_id,
+            wrapped_dek,
+            double_wrapping: false,
+            kek_id: None,
+            wrapped_kek: None,
+        }
+    }
+
+    /// Marks this as the material of the footer key, which also records the KMS instance
+    pub(crate) fn with_footer_key(
+        mut self,
+        kms_instance_id: String,
+        kms_instance_url: String,
+    ) -> Self {
+        self.is_footer_key = true;
+        self.kms_instance_id = Some(kms_instance_id);
+        self.kms_instance_url = Some(kms_instance_url);
+        self
+    }
+
+    /// Records that the data encryption key was wrapped locally with a key encryption key,
+    /// which was in turn wrapped by the KMS
+    pub(crate) fn with_double_wrapping(mut self, kek_id: String, wrapped_kek: String) -> Self {
+        self.double_wrapping = true;
+        self.kek_id = Some(kek_id);
+        self.wrapped_kek = Some(wrapped_kek);
+        self
+    }
+
+    /// Serializes the key material to the JSON stored as key metadata
+    pub(crate) fn serialize(&self) -> Result<Vec<u8>> {
+        serde_json::to_vec(self)
+            .map_err(|e| general_err!("Failed to serialize key material: {}", e))
+    }
+
+    /// Parses key material from the key metadata of an encrypted file
+    pub(crate) fn deserialize(key_metadata: &[u8]) -> Result<Self> {
+        let header: KeyMaterialHeader = serde_json::from_slice(key_metadata)
+            .map_err(|e| general_err!("Failed to parse key material: {}", e))?;
+        if header.key_material_type != KEY_MATERIAL_TYPE {
+            return Err(general_err!(
+                "Unsupported key material type {}",
+                header.key_material_type
+            ));
+        }
+        if !header.internal_storage {
+            return Err(general_err!(
+                "Key material stored outside the file is not supported"
+            ));
+        }
+
+        let material: Self = serde_json::from_slice(key_metadata)
+            .map_err(|e| general_err!("Failed to parse key material: {}", e))?;
+        if material.double_wrapping && (material.kek_id.is_none() || material.wrapped_kek.is_none())
+        {
+            return Err(general_err!(
+                "Double wrapped key material is missing the key encryption key"
+            ));
+        }
+        Ok(material)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_material_roundtrip() {
+        let material = KeyMaterial::new("kf".to_string(), "d3JhcHBlZA==".to_string())
+            .with_footer_key("DEFAULT".to_string(), "DEFAULT".to_string())
+            .with_double_wrapping("a2VrIGlk".to_string(), "d3JhcHBlZCBrZWs=".to_string());
+        let serialized = material.serialize().unwrap();
+        assert_eq!(KeyMaterial::deserialize(&serialized).unwrap(), material);
+
+        let material = KeyMaterial::new("kc".to_string(), "d3JhcHBlZA==".to_string());
+        let serialized = material.serialize().unwrap();
+        assert_eq!(
+            std::str::from_utf8(&serialized).unwrap(),
+            r#"{"keyMaterialType":"PKMT1","internalStorage":true,"isFooterKey":false,"masterKeyID":"kc","wrappedDEK":"d3JhcHBlZA==","doubleWrapping":false}"#
+        );
+        assert_eq!(KeyMaterial::deserialize(&serialized).unwrap(), material);
+    }
+
+    #[test]
+    fn test_parse_key_material() {
+        // Key material as written by parquet-mr, with fields in arbitrary order
+        let json = br#"{"keyMaterialType":"PKMT1","wrappedKEK":"a2Vr","doubleWrapping":true,"isFooterKey":false,"internalStorage":true,"masterKeyID":"kc1","keyEncryptionKeyID":"aWQ=","wrappedDEK":"ZGVr"}"#;
+        let material = KeyMaterial::deserialize(json).unwrap();
+        assert_eq!(material.master_key_id, "kc1");
+        assert!(material.double_wrapping);
+        assert_eq!(material.kek_id.as_deref(), Some("aWQ="));
+        assert_eq!(material.wrapped_kek.as_deref(), Some("a2Vr"));
+        assert_eq!(material.kms_instance_id, None);
+
+        let err = KeyMaterial::deserialize(b"kf").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Parquet error: Failed to parse key material"));
+
+        let json = br#"{"keyMaterialType":"PKMT1","internalStorage":false,"keyReference":"ref"}"#;
+        let err = KeyMaterial::deserialize(json).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Key material stored outside the file is not supported"
+        );
+
+        let json = br#"{"keyMaterialType":"PKMT2","internalStorage":true}"#;
+        let err = KeyMaterial::deserialize(json).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Unsupported key material type PKMT2"
+        );
+    }
+}
@@ -0,0 +1,182 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Interfaces for connecting to a Key Management Server (KMS)
+
+use crate::errors::Result;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// The value of KMS connection settings that have not been configured
+pub const DEFAULT_KMS_SETTING: &str = "DEFAULT";
+
+/// A client of a Key Management Server that wraps and unwraps keys with master keys
+/// stored in the KMS
+pub trait KmsClient: Send + Sync {
+    /// Wraps `key_bytes` with the master key identified by `master_key_identifier`,
+    /// returning the wrapped key encoded as a string
+    fn wrap_key(&self, key_bytes: &[u8], master_key_identifier: &str) -> Result<String>;
+
+    /// Unwraps a key wrapped by [`KmsClient::wrap_key`] with the master key identified
+    /// by `master_key_identifier`
+    fn unwrap_key(&self, wrapped_key: &str, master_key_identifier: &str) -> Result<Vec<u8>>;
+}
+
+/// Creates [`KmsClient`]s for a [`KmsConnectionConfig`]
+///
+/// This is implemented for closures taking a `&KmsConnectionConfig`
+pub trait KmsClientFactory: Send + Sync {
+    /// Creates a new client of the KMS described by `config`
+    fn create_client(&self, config: &KmsConnectionConfig) -> Result<Arc<dyn KmsClient>>;
+}
+
+impl<F> KmsClientFactory for F
+where
+    F: Fn(&KmsConnectionConfig) -> Result<Arc<dyn KmsClient>> + Send + Sync,
+{
+    fn create_client(&self, config: &KmsConnectionConfig) -> Result<Arc<dyn KmsClient>> {
+        self(config)
+    }
+}
+
+/// The settings used to connect to a KMS
+///
+/// Settings that are not configured have the value [`DEFAULT_KMS_SETTING`]. When reading a
+/// file, the KMS instance ID and URL default to those recorded in the key material of the
+/// footer key.
+#[derive(Debug, Clone)]
+pub struct KmsConnectionConfig {
+    kms_instance_id: String,
+    kms_instance_url: String,
+    key_access_token: Arc<RwLock<String>>,
+    custom_kms_conf: HashMap<String, String>,
+}
+
+impl Default for KmsConnectionConfig {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl KmsConnectionConfig {
+    /// Returns a new builder of a [`KmsConnectionConfig`]
+    pub fn builder() -> KmsConnectionConfigBuilder {
+        KmsConnectionConfigBuilder::default()
+    }
+
+    /// Returns the ID of the KMS instance
+    pub fn kms_instance_id(&self) -> &str {
+        &self.kms_instance_id
+    }
+
+    /// Returns the URL of the KMS instance
+    pub fn kms_instance_url(&self) -> &str {
+        &self.kms_instance_url
+    }
+
+    /// Returns the current token used to authorize access to the KMS
+    pub fn key_access_token(&self) -> String {
+        self.key_access_token.read().unwrap().clone()
+    }
+
+    /// Replaces the key access token, such as after it expired
+    ///
+    /// The new token is visible to all clones of this configuration, including those
+    /// held by [`KmsClient`]s.
+    pub fn refresh_key_access_token(&self, key_access_token: String) {
+        *self.key_access_token.write().unwrap() = key_access_token;
+    }
+
+    /// Returns the KMS specific configuration options
+    pub fn custom_kms_conf(&self) -> &HashMap<String, String> {
+        &self.custom_kms_conf
+    }
+
+    /// Returns this configuration with the KMS instance ID and URL set from key material,
+    /// unless they were configured
+    pub(crate) fn with_key_material_defaults(
+        &self,
+        kms_instance_id: Option<&str>,
+        kms_instance_url: Option<&str>,
+    ) -> Self {
+        let setting = |configured: &String, material: Option<&str>| match material {
+            Some(material) if configured == DEFAULT_KMS_SETTING => material.to_string(),
+            _ => configured.clone(),
+        };
+        Self {
+            kms_instance_id: setting(&self.kms_instance_id, kms_instance_id),
+            kms_instance_url: setting(&self.kms_instance_url, kms_instance_url),
+            ..self.clone()
+        }
+    }
+}
+
+/// Builder for [`KmsConnectionConfig`]
+#[derive(Debug)]
+pub struct KmsConnectionConfigBuilder {
+    kms_instance_id: String,
+    kms_instance_url: String,
+    key_access_token: String,
+    custom_kms_conf: HashMap<String, String>,
+}
+
+impl Default for KmsConnectionConfigBuilder {
+    fn default() -> Self {
+        Self {
+            kms_instance_id: DEFAULT_KMS_SETTING.to_string(),
+            kms_instance_url: DEFAULT_KMS_SETTING.to_string(),
+            key_access_token: DEFAULT_KMS_SETTING.to_string(),
+            custom_kms_conf: HashMap::new(),
+        }
+    }
+}
+
+impl KmsConnectionConfigBuilder {
+    /// Sets the ID of the KMS instance
+    pub fn with_kms_instance_id(mut self, kms_instance_id: String) -> Self {
+        self.kms_instance_id = kms_instance_id;
+        self
+    }
+
+    /// Sets the URL of the KMS instance
+    pub fn with_kms_instance_url(mut self, kms_instance_url: String) -> Self {
+        self.kms_instance_url = kms_instance_url;
+        self
+    }
+
+    /// Sets the token used to authorize access to the KMS
+    pub fn with_key_access_token(mut self, key_access_token: String) -> Self {
+        self.key_access_token = key_access_token;
+        self
+    }
+
+    /// Sets a KMS specific configuration option
+    pub fn with_custom_kms_conf_option(mut self, key: String, value: String) -> Self {
+        self.custom_kms_conf.insert(key, value);
+        self
+    }
+
+    /// Finalizes the configuration
+    pub fn build(self) -> KmsConnectionConfig {
+        KmsConnectionConfig {
+            kms_instance_id: self.kms_instance_id,
+            kms_instance_url: self.kms_instance_url,
+            key_access_token: Arc::new(RwLock::new(self.key_access_token)),
+            custom_kms_conf: self.custom_kms_conf,
+        }
+    }
+}
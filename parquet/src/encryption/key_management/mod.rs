@@ -0,0 +1,98 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Envelope encryption of Parquet files with keys managed by a Key Management Server (KMS)
+//!
+//! Rather than supplying encryption keys directly, a [`CryptoFactory`] generates random
+//! data encryption keys for each file, and stores them in the file's key metadata wrapped
+//! by master keys that never leave the KMS. Access to the KMS is abstracted by the
+//! [`KmsClient`] trait, which wraps and unwraps keys.
+//!
+//! By default, data encryption keys are double wrapped: they are encrypted locally with
+//! key encryption keys, which are in turn wrapped by the KMS and cached, reducing the
+//! number of calls to the KMS.
+//!
+//! The key metadata is stored in the JSON format of parquet-mr, so files are
+//! interoperable with the crypto factories of parquet-mr and PyArrow.
+//!
+//! # Example
+//!
+//! ```
+//! use arrow::array::{ArrayRef, Float32Array, Int32Array, RecordBatch};
+//! use parquet::arrow::arrow_reader::{ArrowReaderOptions, ParquetRecordBatchReaderBuilder};
+//! use parquet::arrow::ArrowWriter;
+//! use parquet::encryption::key_management::crypto_factory::{
+//!     CryptoFactory, DecryptionConfiguration, EncryptionConfiguration,
+//! };
+//! use parquet::encryption::key_management::kms::{KmsClient, KmsConnectionConfig};
+//! use parquet::encryption::key_management::test_kms::InMemoryKms;
+//! use parquet::file::properties::WriterProperties;
+//! use std::collections::HashMap;
+//! use std::sync::Arc;
+//!
+//! // Create a factory of KMS clients. A real application would connect to its KMS,
+//! // while for this example the master keys are held in memory.
+//! let kms = InMemoryKms::new(HashMap::from([
+//!     ("kf".to_string(), b"0123456789012345".to_vec()),
+//!     ("kc1".to_string(), b"1234567890123450".to_vec()),
+//! ]));
+//! let crypto_factory = CryptoFactory::new(move |_: &KmsConnectionConfig| {
+//!     Ok(Arc::new(kms.clone()) as Arc<dyn KmsClient>)
+//! });
+//! let kms_config = KmsConnectionConfig::default();
+//!
+//! // Encrypt the footer with a key wrapped by master key "kf",
+//! // and column "x" with a key wrapped by master key "kc1"
+//! let encryption_config = EncryptionConfiguration::builder("kf".to_string())
+//!     .with_column_keys("kc1".to_string(), vec!["x".to_string()])
+//!     .build()?;
+//! let encryption_properties =
+//!     crypto_factory.file_encryption_properties(&kms_config, &encryption_config)?;
+//!
+//! let batch = RecordBatch::try_from_iter(vec![
+//!     ("id", Arc::new(Int32Array::from(vec![0, 1, 2])) as ArrayRef),
+//!     ("x", Arc::new(Float32Array::from(vec![0.0, 0.1, 0.2])) as ArrayRef),
+//! ])?;
+//! let writer_properties = WriterProperties::builder()
+//!     .with_file_encryption_properties(encryption_properties)
+//!     .build();
+//! let mut buffer = Vec::new();
+//! let mut writer = ArrowWriter::try_new(&mut buffer, batch.schema(), Some(writer_properties))?;
+//! writer.write(&batch)?;
+//! writer.close()?;
+//!
+//! // Read the file, unwrapping its keys with the KMS
+//! let decryption_properties = crypto_factory
+//!     .file_decryption_properties(&kms_config, &DecryptionConfiguration::default())?;
+//! let options = ArrowReaderOptions::new().with_file_decryption_properties(decryption_properties);
+//! let reader =
+//!     ParquetRecordBatchReaderBuilder::try_new_with_options(bytes::Bytes::from(buffer), options)?
+//!         .build()?;
+//! let batches = reader.collect::<Result<Vec<_>, _>>()?;
+//! assert_eq!(batches, vec![batch]);
+//! # Ok::<(), parquet::errors::ParquetError>(())
+//! ```
+//!
+//! [`CryptoFactory`]: crypto_factory::CryptoFactory
+//! [`KmsClient`]: kms::KmsClient
+
+mod cache;
+pub mod crypto_factory;
+mod key_encryption;
+mod key_material;
+pub mod kms;
+pub mod test_kms;
@@ -0,0 +1,82 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A KMS client for testing that holds its master keys in memory
+
+use crate::encryption::key_management::key_encryption::{decrypt_key_locally, encrypt_key_locally};
+use crate::encryption::key_management::kms::KmsClient;
+use crate::errors::{ParquetError, Result};
+use std::collections::HashMap;
+
+/// A [`KmsClient`] that wraps keys locally with master keys held in memory
+///
+/// Keys are wrapped with AES-GCM using the master key identifier as additional
+/// authenticated data, like the `InMemoryKMS` of parquet-mr, so files written with the
+/// same master keys can be read by either.
+///
+/// This is intended for testing only, as the master keys are not protected by a KMS.
+#[derive(Debug, Clone)]
+pub struct InMemoryKms {
+    master_keys: HashMap<String, Vec<u8>>,
+}
+
+impl InMemoryKms {
+    /// Creates a client of the 16 byte master keys in `master_keys`, keyed by identifier
+    pub fn new(master_keys: HashMap<String, Vec<u8>>) -> Self {
+        Self { master_keys }
+    }
+
+    fn master_key(&self, master_key_identifier: &str) -> Result<&[u8]> {
+        self.master_keys
+            .get(master_key_identifier)
+            .map(Vec::as_slice)
+            .ok_or_else(|| general_err!("Unknown master key {}", master_key_identifier))
+    }
+}
+
+impl KmsClient for InMemoryKms {
+    fn wrap_key(&self, key_bytes: &[u8], master_key_identifier: &str) -> Result<String> {
+        let master_key = self.master_key(master_key_identifier)?;
+        encrypt_key_locally(key_bytes, master_key, master_key_identifier.as_bytes())
+    }
+
+    fn unwrap_key(&self, wrapped_key: &str, master_key_identifier: &str) -> Result<Vec<u8>> {
+        let master_key = self.master_key(master_key_identifier)?;
+        decrypt_key_locally(wrapped_key, master_key, master_key_identifier.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_memory_kms() {
+        let kms = InMemoryKms::new(HashMap::from([
+            ("kf".to_string(), b"0123456789012345".to_vec()),
+            ("kc".to_string(), b"1234567890123450".to_vec()),
+        ]));
+
+        let key = b"abcdefghijklmnop";
+        let wrapped = kms.wrap_key(key, "kf").unwrap();
+        assert_eq!(kms.unwrap_key(&wrapped, "kf").unwrap(), key);
+        assert!(kms.unwrap_key(&wrapped, "kc").is_err());
+
+        let err = kms.wrap_key(key, "kx").unwrap_err();
+        assert_eq!(err.to_string(), "Parquet error: Unknown master key kx");
+    }
+}
@@ -26,6 +26,7 @@ use crate::file::page_index::index::{Index, NativeIndex, PageIndex};
 use crate::file::page_index::offset_index::OffsetIndexMetaData;
 use crate::file::statistics::{Statistics, ValueStatistics};
 use crate::format::{BoundaryOrder, PageLocation, SortingColumn};
+use crate::geospatial::GeospatialStatistics;
 use std::sync::Arc;
 
 /// Trait for calculating the size of various containers
@@ -54,6 +55,12 @@ impl<T: HeapSize> HeapSize for Arc<T> {
     }
 }
 
+impl<T: HeapSize> HeapSize for Box<T> {
+    fn heap_size(&self) -> usize {
+        std::mem::size_of::<T>() + self.as_ref().heap_size()
+    }
+}
+
 impl<T: HeapSize> HeapSize for Option<T> {
     fn heap_size(&self) -> usize {
         self.as_ref().map(|inner| inner.heap_size()).unwrap_or(0)
@@ -97,6 +104,7 @@ impl HeapSize for ColumnChunkMetaData {
             + self.file_path.heap_size()
             + self.compression.heap_size()
             + self.statistics.heap_size()
+            + self.geo_statistics.heap_size()
             + self.encoding_stats.heap_size()
             + self.unencoded_byte_array_data_bytes.heap_size()
             + self.repetition_level_histogram.heap_size()
@@ -147,6 +155,14 @@ impl HeapSize for Statistics {
     }
 }
 
+impl HeapSize for GeospatialStatistics {
+    fn heap_size(&self) -> usize {
+        self.geospatial_types()
+            .map(std::mem::size_of_val)
+            .unwrap_or(0)
+    }
+}
+
 impl HeapSize for OffsetIndexMetaData {
     fn heap_size(&self) -> usize {
         self.page_locations.heap_size() + self.unencoded_byte_array_data_bytes.heap_size()
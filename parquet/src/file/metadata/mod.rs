@@ -114,6 +114,7 @@ use crate::format::{
     BoundaryOrder, ColumnChunk, ColumnIndex, ColumnMetaData, OffsetIndex, PageLocation, RowGroup,
     SizeStatistics, SortingColumn,
 };
+use crate::geospatial::{self, GeospatialStatistics};
 use crate::schema::types::{
     ColumnDescPtr, ColumnDescriptor, ColumnPath, SchemaDescPtr, SchemaDescriptor,
     Type as SchemaType,
@@ -835,6 +836,7 @@ pub struct ColumnChunkMetaData {
     index_page_offset: Option<i64>,
     dictionary_page_offset: Option<i64>,
     statistics: Option<Statistics>,
+    geo_statistics: Option<Box<GeospatialStatistics>>,
     encoding_stats: Option<Vec<PageEncodingStats>>,
     bloom_filter_offset: Option<i64>,
     bloom_filter_length: Option<i32>,
@@ -1060,6 +1062,15 @@ impl ColumnChunkMetaData {
         self.statistics.as_ref()
     }
 
+    /// Returns the geospatial statistics of a [`GEOMETRY`] or [`GEOGRAPHY`]
+    /// column chunk, or `None` if no geospatial statistics are available.
+    ///
+    /// [`GEOMETRY`]: crate::basic::LogicalType::Geometry
+    /// [`GEOGRAPHY`]: crate::basic::LogicalType::Geography
+    pub fn geo_statistics(&self) -> Option<&GeospatialStatistics> {
+        self.geo_statistics.as_deref()
+    }
+
     /// Returns the offset for the page encoding stats,
     /// or `None` if no page encoding stats are available.
     pub fn page_encoding_stats(&self) -> Option<&Vec<PageEncodingStats>> {
@@ -1164,6 +1175,8 @@ impl ColumnChunkMetaData {
         let index_page_offset = col_metadata.index_page_offset;
         let dictionary_page_offset = col_metadata.dictionary_page_offset;
         let statistics = statistics::from_thrift(column_type, col_metadata.statistics)?;
+        let geo_statistics =
+            geospatial::from_thrift(col_metadata.geospatial_statistics).map(Box::new);
         let encoding_stats = col_metadata
             .encoding_stats
             .as_ref()
@@ -1216,6 +1229,7 @@ impl ColumnChunkMetaData {
             index_page_offset,
             dictionary_page_offset,
             statistics,
+            geo_statistics,
             encoding_stats,
             bloom_filter_offset,
             bloom_filter_length,
@@ -1294,7 +1308,7 @@ impl ColumnChunkMetaData {
             bloom_filter_offset: self.bloom_filter_offset,
             bloom_filter_length: self.bloom_filter_length,
             size_statistics,
-            geospatial_statistics: None,
+            geospatial_statistics: geospatial::to_thrift(self.geo_statistics()),
         }
     }
 
@@ -1354,6 +1368,7 @@ impl ColumnChunkMetaDataBuilder {
             index_page_offset: None,
             dictionary_page_offset: None,
             statistics: None,
+            geo_statistics: None,
             encoding_stats: None,
             bloom_filter_offset: None,
             bloom_filter_length: None,
@@ -1435,6 +1450,18 @@ impl ColumnChunkMetaDataBuilder {
         self
     }
 
+    /// Sets geospatial statistics for this column chunk.
+    pub fn set_geo_statistics(mut self, value: Box<GeospatialStatistics>) -> Self {
+        self.0.geo_statistics = Some(value);
+        self
+    }
+
+    /// Clears the geospatial statistics for this column chunk.
+    pub fn clear_geo_statistics(mut self) -> Self {
+        self.0.geo_statistics = None;
+        self
+    }
+
     /// Sets page encoding stats for this column chunk.
     pub fn set_page_encoding_stats(mut self, value: Vec<PageEncodingStats>) -> Self {
         self.0.encoding_stats = Some(value);
@@ -1968,9 +1995,9 @@ mod tests {
             .build();
 
         #[cfg(not(feature = "encryption"))]
-        let base_expected_size = 2312;
+        let base_expected_size = 2344;
         #[cfg(feature = "encryption")]
-        let base_expected_size = 2648;
+        let base_expected_size = 2704;
 
         assert_eq!(parquet_meta.memory_size(), base_expected_size);
 
@@ -1998,9 +2025,9 @@ mod tests {
             .build();
 
         #[cfg(not(feature = "encryption"))]
-        let bigger_expected_size = 2816;
+        let bigger_expected_size = 2848;
         #[cfg(feature = "encryption")]
-        let bigger_expected_size = 3152;
+        let bigger_expected_size = 3208;
 
         // more set fields means more memory usage
         assert!(bigger_expected_size > base_expected_size);
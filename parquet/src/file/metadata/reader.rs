@@ -21,6 +21,7 @@ use crate::basic::ColumnOrder;
 #[cfg(feature = "encryption")]
 use crate::encryption::{
     decrypt::{FileDecryptionProperties, FileDecryptor},
+    encrypt::EncryptionAlgorithm as Algorithm,
     modules::create_footer_aad,
 };
 use bytes::Bytes;
@@ -915,7 +916,7 @@ impl ParquetMetaDataReader {
                         .map_err(|e| general_err!("Could not parse crypto metadata: {}", e))?;
                 let supply_aad_prefix = match &t_file_crypto_metadata.encryption_algorithm {
                     EncryptionAlgorithm::AESGCMV1(algo) => algo.supply_aad_prefix,
-                    _ => Some(false),
+                    EncryptionAlgorithm::AESGCMCTRV1(algo) => algo.supply_aad_prefix,
                 }
                 .unwrap_or(false);
                 if supply_aad_prefix && file_decryption_properties.aad_prefix().is_none() {
@@ -1067,28 +1068,31 @@ fn get_file_decryptor(
     footer_key_metadata: Option<&[u8]>,
     file_decryption_properties: &FileDecryptionProperties,
 ) -> Result<FileDecryptor> {
-    match encryption_algorithm {
+    let (algorithm, aad_file_unique, aad_prefix) = match encryption_algorithm {
         EncryptionAlgorithm::AESGCMV1(algo) => {
-            let aad_file_unique = algo
-                .aad_file_unique
-                .ok_or_else(|| general_err!("AAD unique file identifier is not set"))?;
-            let aad_prefix = if let Some(aad_prefix) = file_decryption_properties.aad_prefix() {
-                aad_prefix.clone()
-            } else {
-                algo.aad_prefix.unwrap_or_default()
-            };
-
-            FileDecryptor::new(
-                file_decryption_properties,
-                footer_key_metadata,
-                aad_file_unique,
-                aad_prefix,
-            )
+            (Algorithm::AesGcmV1, algo.aad_file_unique, algo.aad_prefix)
         }
-        EncryptionAlgorithm::AESGCMCTRV1(_) => Err(nyi_err!(
-            "The AES_GCM_CTR_V1 encryption algorithm is not yet supported"
-        )),
-    }
+        EncryptionAlgorithm::AESGCMCTRV1(algo) => (
+            Algorithm::AesGcmCtrV1,
+            algo.aad_file_unique,
+            algo.aad_prefix,
+        ),
+    };
+    let aad_file_unique =
+        aad_file_unique.ok_or_else(|| general_err!("AAD unique file identifier is not set"))?;
+    let aad_prefix = if let Some(aad_prefix) = file_decryption_properties.aad_prefix() {
+        aad_prefix.clone()
+    } else {
+        aad_prefix.unwrap_or_default()
+    };
+
+    FileDecryptor::new(
+        algorithm,
+        file_decryption_properties,
+        footer_key_metadata,
+        aad_file_unique,
+        aad_prefix,
+    )
 }
 
 #[cfg(test)]
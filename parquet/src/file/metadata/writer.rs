@@ -18,7 +18,8 @@
 #[cfg(feature = "encryption")]
 use crate::encryption::{
     encrypt::{
-        encrypt_object, encrypt_object_to_vec, write_signed_plaintext_object, FileEncryptor,
+        encrypt_object, encrypt_object_to_vec, write_signed_plaintext_object,
+        EncryptionAlgorithm as Algorithm, FileEncryptor,
     },
     modules::{create_footer_aad, create_module_aad, ModuleType},
 };
@@ -30,7 +31,7 @@ use crate::file::page_index::index::Index;
 use crate::file::writer::{get_file_magic, TrackedWrite};
 use crate::format::EncryptionAlgorithm;
 #[cfg(feature = "encryption")]
-use crate::format::{AesGcmV1, ColumnCryptoMetaData};
+use crate::format::{AesGcmCtrV1, AesGcmV1, ColumnCryptoMetaData};
 use crate::format::{ColumnChunk, ColumnIndex, FileMetaData, OffsetIndex, RowGroup};
 use crate::schema::types;
 use crate::schema::types::{SchemaDescPtr, SchemaDescriptor, TypePtr};
@@ -664,11 +665,19 @@ impl MetadataObjectWriter {
         } else {
             None
         };
-        EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
-            aad_prefix,
-            aad_file_unique: Some(file_encryptor.aad_file_unique().clone()),
-            supply_aad_prefix,
-        })
+        let aad_file_unique = Some(file_encryptor.aad_file_unique().clone());
+        match file_encryptor.properties().encryption_algorithm() {
+            Algorithm::AesGcmV1 => EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+                aad_prefix,
+                aad_file_unique,
+                supply_aad_prefix,
+            }),
+            Algorithm::AesGcmCtrV1 => EncryptionAlgorithm::AESGCMCTRV1(AesGcmCtrV1 {
+                aad_prefix,
+                aad_file_unique,
+                supply_aad_prefix,
+            }),
+        }
     }
 
     fn file_crypto_metadata(
@@ -18,6 +18,7 @@
 //! Configuration via [`WriterProperties`] and [`ReaderProperties`]
 use crate::basic::{Compression, Encoding};
 use crate::compression::{CodecOptions, CodecOptionsBuilder};
+use crate::distinct_count::{MAX_PRECISION, MIN_PRECISION};
 #[cfg(feature = "encryption")]
 use crate::encryption::encrypt::FileEncryptionProperties;
 use crate::file::metadata::KeyValue;
@@ -62,6 +63,22 @@ pub const DEFAULT_STATISTICS_TRUNCATE_LENGTH: Option<usize> = Some(64);
 pub const DEFAULT_OFFSET_INDEX_DISABLED: bool = false;
 /// Default values for [`WriterProperties::coerce_types`]
 pub const DEFAULT_COERCE_TYPES: bool = false;
+/// Default value for [`WriterProperties::timestamps_as_int96`]
+pub const DEFAULT_TIMESTAMPS_AS_INT96: bool = false;
+/// Default value for [`WriterProperties::write_page_checksum`]
+pub const DEFAULT_WRITE_PAGE_CHECKSUM: bool = false;
+/// Default value for [`WriterProperties::distinct_count_enabled`]
+pub const DEFAULT_DISTINCT_COUNT_ENABLED: bool = false;
+/// Default value for [`WriterProperties::distinct_count_precision`]
+pub const DEFAULT_DISTINCT_COUNT_PRECISION: u8 = 12;
+/// Default value for [`WriterProperties::write_distinct_count_sketches`]
+pub const DEFAULT_WRITE_DISTINCT_COUNT_SKETCHES: bool = false;
+/// Default value for [`CdcOptions::min_chunk_size`]
+pub const DEFAULT_CDC_MIN_CHUNK_SIZE: usize = 256 * 1024;
+/// Default value for [`CdcOptions::max_chunk_size`]
+pub const DEFAULT_CDC_MAX_CHUNK_SIZE: usize = 1024 * 1024;
+/// Default value for [`CdcOptions::norm_level`]
+pub const DEFAULT_CDC_NORM_LEVEL: i32 = 0;
 
 /// Parquet writer version.
 ///
@@ -169,6 +186,11 @@ pub struct WriterProperties {
     column_index_truncate_length: Option<usize>,
     statistics_truncate_length: Option<usize>,
     coerce_types: bool,
+    timestamps_as_int96: bool,
+    write_page_checksum: bool,
+    content_defined_chunking: Option<CdcOptions>,
+    distinct_count_precision: u8,
+    write_distinct_count_sketches: bool,
     #[cfg(feature = "encryption")]
     pub(crate) file_encryption_properties: Option<FileEncryptionProperties>,
 }
@@ -329,6 +351,42 @@ impl WriterProperties {
         self.coerce_types
     }
 
+    /// Returns `true` if timestamp columns are written as the legacy INT96 type.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_timestamps_as_int96`]
+    pub fn timestamps_as_int96(&self) -> bool {
+        self.timestamps_as_int96
+    }
+
+    /// Returns `true` if a CRC32 checksum is written in the header of each page.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_write_page_checksum`]
+    pub fn write_page_checksum(&self) -> bool {
+        self.write_page_checksum
+    }
+
+    /// Returns the [`CdcOptions`] if content-defined chunking of data pages is enabled.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_content_defined_chunking`]
+    pub fn content_defined_chunking(&self) -> Option<&CdcOptions> {
+        self.content_defined_chunking.as_ref()
+    }
+
+    /// Returns the precision of the sketches used to estimate distinct counts.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_distinct_count_precision`]
+    pub fn distinct_count_precision(&self) -> u8 {
+        self.distinct_count_precision
+    }
+
+    /// Returns `true` if the sketches used to estimate distinct counts are written to
+    /// the key value metadata of the file.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_write_distinct_count_sketches`]
+    pub fn write_distinct_count_sketches(&self) -> bool {
+        self.write_distinct_count_sketches
+    }
+
     /// Returns encoding for a data page, when dictionary encoding is enabled.
     ///
     /// This is not configurable.
@@ -423,6 +481,17 @@ impl WriterProperties {
             .or_else(|| self.default_column_properties.bloom_filter_properties())
     }
 
+    /// Returns `true` if the distinct count of the given column is estimated.
+    ///
+    /// For more details see [`WriterPropertiesBuilder::set_distinct_count_enabled`]
+    pub fn distinct_count_enabled(&self, col: &ColumnPath) -> bool {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.distinct_count_enabled())
+            .or_else(|| self.default_column_properties.distinct_count_enabled())
+            .unwrap_or(DEFAULT_DISTINCT_COUNT_ENABLED)
+    }
+
     /// Return file encryption properties
     ///
     /// For more details see [`WriterPropertiesBuilder::with_file_encryption_properties`]
@@ -451,6 +520,11 @@ pub struct WriterPropertiesBuilder {
     column_index_truncate_length: Option<usize>,
     statistics_truncate_length: Option<usize>,
     coerce_types: bool,
+    timestamps_as_int96: bool,
+    write_page_checksum: bool,
+    content_defined_chunking: Option<CdcOptions>,
+    distinct_count_precision: u8,
+    write_distinct_count_sketches: bool,
     #[cfg(feature = "encryption")]
     file_encryption_properties: Option<FileEncryptionProperties>,
 }
@@ -474,6 +548,11 @@ impl WriterPropertiesBuilder {
             column_index_truncate_length: DEFAULT_COLUMN_INDEX_TRUNCATE_LENGTH,
             statistics_truncate_length: DEFAULT_STATISTICS_TRUNCATE_LENGTH,
             coerce_types: DEFAULT_COERCE_TYPES,
+            timestamps_as_int96: DEFAULT_TIMESTAMPS_AS_INT96,
+            write_page_checksum: DEFAULT_WRITE_PAGE_CHECKSUM,
+            content_defined_chunking: None,
+            distinct_count_precision: DEFAULT_DISTINCT_COUNT_PRECISION,
+            write_distinct_count_sketches: DEFAULT_WRITE_DISTINCT_COUNT_SKETCHES,
             #[cfg(feature = "encryption")]
             file_encryption_properties: None,
         }
@@ -497,6 +576,11 @@ impl WriterPropertiesBuilder {
             column_index_truncate_length: self.column_index_truncate_length,
             statistics_truncate_length: self.statistics_truncate_length,
             coerce_types: self.coerce_types,
+            timestamps_as_int96: self.timestamps_as_int96,
+            write_page_checksum: self.write_page_checksum,
+            content_defined_chunking: self.content_defined_chunking,
+            distinct_count_precision: self.distinct_count_precision,
+            write_distinct_count_sketches: self.write_distinct_count_sketches,
             #[cfg(feature = "encryption")]
             file_encryption_properties: self.file_encryption_properties,
         }
@@ -691,6 +775,99 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Should the writer write timestamp columns as the deprecated INT96 physical type
+    /// (defaults to `false` via [`DEFAULT_TIMESTAMPS_AS_INT96`]).
+    ///
+    /// INT96 timestamps have nanosecond precision and no logical type, but are still
+    /// required by some legacy readers, such as older versions of Hive and Impala.
+    /// Values are written as UTC regardless of the timezone of the column.
+    ///
+    /// See [`ArrowSchemaConverter::with_timestamps_as_int96`] for more details
+    ///
+    /// [`ArrowSchemaConverter::with_timestamps_as_int96`]: crate::arrow::ArrowSchemaConverter::with_timestamps_as_int96
+    pub fn set_timestamps_as_int96(mut self, timestamps_as_int96: bool) -> Self {
+        self.timestamps_as_int96 = timestamps_as_int96;
+        self
+    }
+
+    /// Should the writer compute a CRC32 checksum of each data and dictionary page and
+    /// write it in the page header (defaults to `false` via [`DEFAULT_WRITE_PAGE_CHECKSUM`]).
+    ///
+    /// The checksum covers the page data as written to the file, after any compression
+    /// and encryption, and allows readers to detect corrupted pages. Readers with the
+    /// `crc` feature enabled verify it when decoding pages.
+    ///
+    /// This option is only available with the `crc` feature.
+    #[cfg(feature = "crc")]
+    pub fn set_write_page_checksum(mut self, write_page_checksum: bool) -> Self {
+        self.write_page_checksum = write_page_checksum;
+        self
+    }
+
+    /// Sets the options for content-defined chunking of data pages (defaults to `None`).
+    ///
+    /// By default, data pages are cut once they reach [`Self::set_data_page_size_limit`]
+    /// or [`Self::set_data_page_row_count_limit`], so inserting or removing a few rows
+    /// shifts the boundaries of all subsequent pages. When enabled, the
+    /// [`ArrowWriter`] instead places page boundaries where a rolling hash of the leaf
+    /// values and levels of each column matches, so files re-written after small changes
+    /// share most of their pages with the previous version, which allows deduplicating
+    /// storage systems to store them efficiently.
+    ///
+    /// Pages may still be cut earlier by the page size and row count limits, which should
+    /// be set larger than [`CdcOptions::max_chunk_size`] to avoid this.
+    ///
+    /// This follows the design of content-defined chunking in Arrow C++, but is not
+    /// guaranteed to place page boundaries at the same positions.
+    ///
+    /// [`ArrowWriter`]: crate::arrow::arrow_writer::ArrowWriter
+    pub fn set_content_defined_chunking(mut self, options: Option<CdcOptions>) -> Self {
+        self.content_defined_chunking = options;
+        self
+    }
+
+    /// Sets the precision of the sketches used to estimate distinct counts (defaults to
+    /// `12` via [`DEFAULT_DISTINCT_COUNT_PRECISION`]).
+    ///
+    /// A sketch with precision `p` uses `2^p` bytes of memory per column chunk, and has a
+    /// relative standard error of about `1.04 / sqrt(2^p)`. Only sketches of the same
+    /// precision can be merged.
+    ///
+    /// See [`Self::set_distinct_count_enabled`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// If `value` is not between [`MIN_PRECISION`] and [`MAX_PRECISION`]
+    ///
+    /// [`MIN_PRECISION`]: crate::distinct_count::MIN_PRECISION
+    /// [`MAX_PRECISION`]: crate::distinct_count::MAX_PRECISION
+    pub fn set_distinct_count_precision(mut self, value: u8) -> Self {
+        assert!(
+            (MIN_PRECISION..=MAX_PRECISION).contains(&value),
+            "distinct count precision must be between {MIN_PRECISION} and {MAX_PRECISION}, got {value}"
+        );
+        self.distinct_count_precision = value;
+        self
+    }
+
+    /// Should the sketches used to estimate distinct counts be written to the key value
+    /// metadata of the file (defaults to `false` via
+    /// [`DEFAULT_WRITE_DISTINCT_COUNT_SKETCHES`]).
+    ///
+    /// The sketches of all the row groups of each column with distinct counts enabled
+    /// are merged, and written base64 encoded, so that they can be merged with those of
+    /// other files to estimate the distinct count of a column across files. See
+    /// [`read_distinct_count_sketch`] for reading them.
+    ///
+    /// This option is only available with the `base64` feature.
+    ///
+    /// [`read_distinct_count_sketch`]: crate::distinct_count::read_distinct_count_sketch
+    #[cfg(feature = "base64")]
+    pub fn set_write_distinct_count_sketches(mut self, value: bool) -> Self {
+        self.write_distinct_count_sketches = value;
+        self
+    }
+
     /// Sets FileEncryptionProperties (defaults to `None`)
     #[cfg(feature = "encryption")]
     pub fn with_file_encryption_properties(
@@ -833,6 +1010,25 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets if the distinct count of all columns should be estimated (defaults to `false`
+    /// via [`DEFAULT_DISTINCT_COUNT_ENABLED`]).
+    ///
+    /// If enabled, the non-null values of each column chunk are inserted into a
+    /// HyperLogLog sketch, whose estimate is written as the distinct count of the column
+    /// chunk [`Statistics`], unless the distinct count was provided to the column writer.
+    /// The distinct count is only written if statistics are enabled for the column, see
+    /// [`Self::set_statistics_enabled`].
+    ///
+    /// See the [`distinct_count`] module for more details.
+    ///
+    /// [`Statistics`]: crate::file::statistics::Statistics
+    /// [`distinct_count`]: crate::distinct_count
+    pub fn set_distinct_count_enabled(mut self, value: bool) -> Self {
+        self.default_column_properties
+            .set_distinct_count_enabled(value);
+        self
+    }
+
     // ----------------------------------------------------------------------
     // Setters for a specific column
 
@@ -930,6 +1126,14 @@ impl WriterPropertiesBuilder {
         self.get_mut_props(col).set_bloom_filter_ndv(value);
         self
     }
+
+    /// Sets if the distinct count of a specific column should be estimated.
+    ///
+    /// Takes precedence over [`Self::set_distinct_count_enabled`].
+    pub fn set_column_distinct_count_enabled(mut self, col: ColumnPath, value: bool) -> Self {
+        self.get_mut_props(col).set_distinct_count_enabled(value);
+        self
+    }
 }
 
 /// Controls the level of statistics to be computed by the writer and stored in
@@ -1019,6 +1223,42 @@ impl Default for BloomFilterProperties {
     }
 }
 
+/// Options for content-defined chunking of data pages, see
+/// [`WriterPropertiesBuilder::set_content_defined_chunking`]
+///
+/// The chunking follows that of Arrow C++ and PyArrow, so that the same options and
+/// data produce the same page boundaries, and pages can be deduplicated between files
+/// written by either.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CdcOptions {
+    /// The minimum size in bytes of the values and levels in a chunk, before any encoding
+    /// or compression. Defaults to [`DEFAULT_CDC_MIN_CHUNK_SIZE`].
+    ///
+    /// No boundary is placed until a chunk reaches this size.
+    pub min_chunk_size: usize,
+    /// The maximum size in bytes of the values and levels in a chunk, before any encoding
+    /// or compression. Defaults to [`DEFAULT_CDC_MAX_CHUNK_SIZE`].
+    ///
+    /// A boundary is forced once a chunk reaches this size, regardless of its content.
+    pub max_chunk_size: usize,
+    /// Adjusts the number of bits of the rolling hash that must be zero for a boundary.
+    /// Defaults to [`DEFAULT_CDC_NORM_LEVEL`].
+    ///
+    /// Increasing this value makes boundaries more likely, making chunk sizes closer to
+    /// `min_chunk_size`, while decreasing it makes chunk sizes closer to `max_chunk_size`.
+    pub norm_level: i32,
+}
+
+impl Default for CdcOptions {
+    fn default() -> Self {
+        Self {
+            min_chunk_size: DEFAULT_CDC_MIN_CHUNK_SIZE,
+            max_chunk_size: DEFAULT_CDC_MAX_CHUNK_SIZE,
+            norm_level: DEFAULT_CDC_NORM_LEVEL,
+        }
+    }
+}
+
 /// Container for column properties that can be changed as part of writer.
 ///
 /// If a field is `None`, it means that no specific value has been set for this column,
@@ -1033,6 +1273,7 @@ struct ColumnProperties {
     write_page_header_statistics: Option<bool>,
     /// bloom filter related properties
     bloom_filter_properties: Option<BloomFilterProperties>,
+    distinct_count_enabled: Option<bool>,
 }
 
 impl ColumnProperties {
@@ -1113,6 +1354,11 @@ impl ColumnProperties {
             .ndv = value;
     }
 
+    /// Sets whether the distinct count of this column is estimated.
+    fn set_distinct_count_enabled(&mut self, enabled: bool) {
+        self.distinct_count_enabled = Some(enabled);
+    }
+
     /// Returns optional encoding for this column.
     fn encoding(&self) -> Option<Encoding> {
         self.encoding
@@ -1153,6 +1399,12 @@ impl ColumnProperties {
     fn bloom_filter_properties(&self) -> Option<&BloomFilterProperties> {
         self.bloom_filter_properties.as_ref()
     }
+
+    /// Returns `Some(true)` if the distinct count of this column is to be estimated. If
+    /// result is `None`, then no setting has been provided.
+    fn distinct_count_enabled(&self) -> Option<bool> {
+        self.distinct_count_enabled
+    }
 }
 
 /// Reference counted reader properties.
@@ -1292,6 +1544,20 @@ mod tests {
         assert!(props
             .bloom_filter_properties(&ColumnPath::from("col"))
             .is_none());
+        assert_eq!(props.write_page_checksum(), DEFAULT_WRITE_PAGE_CHECKSUM);
+        assert!(props.content_defined_chunking().is_none());
+        assert_eq!(
+            props.distinct_count_enabled(&ColumnPath::from("col")),
+            DEFAULT_DISTINCT_COUNT_ENABLED
+        );
+        assert_eq!(
+            props.distinct_count_precision(),
+            DEFAULT_DISTINCT_COUNT_PRECISION
+        );
+        assert_eq!(
+            props.write_distinct_count_sketches(),
+            DEFAULT_WRITE_DISTINCT_COUNT_SKETCHES
+        );
     }
 
     #[test]
@@ -1481,6 +1747,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_writer_properties_distinct_count() {
+        let props = WriterProperties::builder()
+            .set_distinct_count_enabled(true)
+            .set_column_distinct_count_enabled(ColumnPath::from("col"), false)
+            .set_distinct_count_precision(14)
+            .build();
+        assert!(props.distinct_count_enabled(&ColumnPath::from("a")));
+        assert!(!props.distinct_count_enabled(&ColumnPath::from("col")));
+        assert_eq!(props.distinct_count_precision(), 14);
+    }
+
+    #[test]
+    #[should_panic(expected = "distinct count precision must be between 4 and 18, got 3")]
+    fn test_writer_properties_panic_when_distinct_count_precision_is_invalid() {
+        WriterProperties::builder().set_distinct_count_precision(3);
+    }
+
     #[test]
     fn test_writer_properties_column_dictionary_page_size_limit() {
         let props = WriterProperties::builder()
@@ -339,6 +339,26 @@ impl<R: 'static + ChunkReader> RowGroupReader for SerializedRowGroupReader<'_, R
     }
 }
 
+/// Verifies the 32-bit CRC checksum of the page data in `buffer`, as stored in the file
+///
+/// The checksum covers the page data after any compression and encryption, so must be
+/// verified before the page is decrypted.
+#[cfg(feature = "crc")]
+fn verify_page_checksum(page_header: &PageHeader, buffer: &[u8]) -> Result<()> {
+    if let Some(expected_crc) = page_header.crc {
+        let crc = crc32fast::hash(buffer);
+        if crc != expected_crc as u32 {
+            return Err(general_err!("Page CRC checksum mismatch"));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "crc"))]
+fn verify_page_checksum(_page_header: &PageHeader, _buffer: &[u8]) -> Result<()> {
+    Ok(())
+}
+
 /// Decodes a [`Page`] from the provided `buffer`
 pub(crate) fn decode_page(
     page_header: PageHeader,
@@ -346,15 +366,6 @@ pub(crate) fn decode_page(
     physical_type: Type,
     decompressor: Option<&mut Box<dyn Codec>>,
 ) -> Result<Page> {
-    // Verify the 32-bit CRC checksum of the page
-    #[cfg(feature = "crc")]
-    if let Some(expected_crc) = page_header.crc {
-        let crc = crc32fast::hash(&buffer);
-        if crc != expected_crc as u32 {
-            return Err(general_err!("Page CRC checksum mismatch"));
-        }
-    }
-
     // When processing data page v2, depending on enabled compression for the
     // page, we should account for uncompressed data ('offset') of
     // repetition and definition levels.
@@ -759,15 +770,16 @@ impl SerializedPageReaderContext {
                 Ok(PageHeader::read_from_in_protocol(&mut prot)?)
             }
             Some(page_crypto_context) => {
-                let data_decryptor = page_crypto_context.data_decryptor();
+                let metadata_decryptor = page_crypto_context.metadata_decryptor();
                 let aad = page_crypto_context.create_page_header_aad()?;
 
-                let buf = read_and_decrypt(data_decryptor, input, aad.as_ref()).map_err(|_| {
-                    ParquetError::General(format!(
+                let buf =
+                    read_and_decrypt(metadata_decryptor, input, aad.as_ref()).map_err(|_| {
+                        ParquetError::General(format!(
                         "Error decrypting page header for column {}, decryption key may be wrong",
                         page_crypto_context.column_ordinal
                     ))
-                })?;
+                    })?;
 
                 let mut prot = TCompactSliceInputProtocol::new(buf.as_slice());
                 Ok(PageHeader::read_from_in_protocol(&mut prot)?)
@@ -889,6 +901,7 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
                         ));
                     }
 
+                    verify_page_checksum(&header, &buffer)?;
                     let buffer =
                         self.context
                             .decrypt_page_data(buffer, *page_index, *require_dictionary)?;
@@ -930,6 +943,7 @@ impl<R: ChunkReader> PageReader for SerializedPageReader<R> {
                         is_dictionary_page,
                     )?;
                     let bytes = buffer.slice(offset..);
+                    verify_page_checksum(&header, &bytes)?;
                     let bytes =
                         self.context
                             .decrypt_page_data(bytes, *page_index, is_dictionary_page)?;
@@ -19,6 +19,9 @@
 //! using row group writers and column writers respectively.
 
 use crate::bloom_filter::Sbbf;
+use crate::distinct_count::HyperLogLog;
+#[cfg(feature = "base64")]
+use crate::distinct_count::{distinct_count_sketch_key, encode_distinct_count_sketch};
 use crate::format as parquet;
 use crate::format::{ColumnIndex, OffsetIndex};
 use crate::thrift::TSerializable;
@@ -121,6 +124,8 @@ pub type OnCloseColumnChunk<'a> = Box<dyn FnOnce(ColumnCloseResult) -> Result<()
 /// Callback invoked on closing a row group, arguments are:
 ///
 /// - the row group metadata
+/// - the bloom filter for each column chunk
+/// - the distinct count sketch for each column chunk
 /// - the column index for each column chunk
 /// - the offset index for each column chunk
 pub type OnCloseRowGroup<'a, W> = Box<
@@ -128,6 +133,7 @@ pub type OnCloseRowGroup<'a, W> = Box<
             &'a mut TrackedWrite<W>,
             RowGroupMetaData,
             Vec<Option<Sbbf>>,
+            Vec<Option<HyperLogLog>>,
             Vec<Option<ColumnIndex>>,
             Vec<Option<OffsetIndex>>,
         ) -> Result<()>
@@ -154,6 +160,8 @@ pub struct SerializedFileWriter<W: Write> {
     props: WriterPropertiesPtr,
     row_groups: Vec<RowGroupMetaData>,
     bloom_filters: Vec<Vec<Option<Sbbf>>>,
+    /// The distinct count sketches of each column, merged across row groups
+    distinct_count_sketches: Vec<Option<HyperLogLog>>,
     column_indexes: Vec<Vec<Option<ColumnIndex>>>,
     offset_indexes: Vec<Vec<Option<OffsetIndex>>>,
     row_group_index: usize,
@@ -194,6 +202,7 @@ impl<W: Write + Send> SerializedFileWriter<W> {
             props: properties,
             row_groups: vec![],
             bloom_filters: vec![],
+            distinct_count_sketches: Vec::new(),
             column_indexes: Vec::new(),
             offset_indexes: Vec::new(),
             row_group_index: 0,
@@ -246,14 +255,20 @@ impl<W: Write + Send> SerializedFileWriter<W> {
         let bloom_filter_position = self.properties().bloom_filter_position();
         let row_groups = &mut self.row_groups;
         let row_bloom_filters = &mut self.bloom_filters;
+        let distinct_count_sketches = &mut self.distinct_count_sketches;
         let row_column_indexes = &mut self.column_indexes;
         let row_offset_indexes = &mut self.offset_indexes;
         let on_close = move |buf,
                              mut metadata,
                              row_group_bloom_filter,
+                             row_group_distinct_count_sketches: Vec<Option<HyperLogLog>>,
                              row_group_column_index,
                              row_group_offset_index| {
             row_bloom_filters.push(row_group_bloom_filter);
+            merge_distinct_count_sketches(
+                distinct_count_sketches,
+                row_group_distinct_count_sketches,
+            )?;
             row_column_indexes.push(row_group_column_index);
             row_offset_indexes.push(row_group_offset_index);
             // write bloom filters out immediately after the row group if requested
@@ -327,6 +342,23 @@ impl<W: Write + Send> SerializedFileWriter<W> {
             write_bloom_filters(&mut self.buf, &mut self.bloom_filters, row_group)?;
         }
 
+        #[cfg(feature = "base64")]
+        if self.props.write_distinct_count_sketches() {
+            for (column, sketch) in self
+                .descr
+                .columns()
+                .iter()
+                .zip(&self.distinct_count_sketches)
+            {
+                if let Some(sketch) = sketch {
+                    self.kv_metadatas.push(KeyValue::new(
+                        distinct_count_sketch_key(column.path()),
+                        encode_distinct_count_sketch(sketch),
+                    ));
+                }
+            }
+        }
+
         let key_value_metadata = match self.props.key_value_metadata() {
             Some(kv) => Some(kv.iter().chain(&self.kv_metadatas).cloned().collect()),
             None if self.kv_metadatas.is_empty() => None,
@@ -438,6 +470,24 @@ impl<W: Write + Send> SerializedFileWriter<W> {
     }
 }
 
+/// Merges the distinct count sketches of the columns of a row group into those of the file
+fn merge_distinct_count_sketches(
+    sketches: &mut Vec<Option<HyperLogLog>>,
+    row_group_sketches: Vec<Option<HyperLogLog>>,
+) -> Result<()> {
+    if sketches.len() < row_group_sketches.len() {
+        sketches.resize(row_group_sketches.len(), None);
+    }
+    for (sketch, row_group_sketch) in sketches.iter_mut().zip(row_group_sketches) {
+        match (sketch.as_mut(), row_group_sketch) {
+            (Some(sketch), Some(row_group_sketch)) => sketch.merge(&row_group_sketch)?,
+            (None, Some(row_group_sketch)) => *sketch = Some(row_group_sketch),
+            (_, None) => {}
+        }
+    }
+    Ok(())
+}
+
 /// Serialize all the bloom filters of the given row group to the given buffer,
 /// and returns the updated row group metadata.
 fn write_bloom_filters<W: Write + Send>(
@@ -499,6 +549,7 @@ pub struct SerializedRowGroupWriter<'a, W: Write> {
     row_group_metadata: Option<RowGroupMetaDataPtr>,
     column_chunks: Vec<ColumnChunkMetaData>,
     bloom_filters: Vec<Option<Sbbf>>,
+    distinct_count_sketches: Vec<Option<HyperLogLog>>,
     column_indexes: Vec<Option<ColumnIndex>>,
     offset_indexes: Vec<Option<OffsetIndex>>,
     row_group_index: i16,
@@ -538,6 +589,7 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
             row_group_metadata: None,
             column_chunks: Vec::with_capacity(num_columns),
             bloom_filters: Vec::with_capacity(num_columns),
+            distinct_count_sketches: Vec::with_capacity(num_columns),
             column_indexes: Vec::with_capacity(num_columns),
             offset_indexes: Vec::with_capacity(num_columns),
             total_bytes_written: 0,
@@ -573,6 +625,7 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
         let column_indexes = &mut self.column_indexes;
         let offset_indexes = &mut self.offset_indexes;
         let bloom_filters = &mut self.bloom_filters;
+        let distinct_count_sketches = &mut self.distinct_count_sketches;
 
         let on_close = |r: ColumnCloseResult| {
             // Update row group writer metrics
@@ -580,6 +633,7 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
             *total_uncompressed_bytes += r.metadata.uncompressed_size();
             column_chunks.push(r.metadata);
             bloom_filters.push(r.bloom_filter);
+            distinct_count_sketches.push(r.distinct_count_sketch);
             column_indexes.push(r.column_index);
             offset_indexes.push(r.offset_index);
 
@@ -744,6 +798,7 @@ impl<'a, W: Write + Send> SerializedRowGroupWriter<'a, W> {
                     self.buf,
                     row_group_metadata,
                     self.bloom_filters,
+                    self.distinct_count_sketches,
                     self.column_indexes,
                     self.offset_indexes,
                 )?
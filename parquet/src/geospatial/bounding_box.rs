@@ -0,0 +1,197 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::format::BoundingBox as TBoundingBox;
+use thrift::OrderedFloat;
+
+/// A bounding box of geospatial values in X, Y and optionally Z and M
+///
+/// For [`GEOGRAPHY`] columns X is longitude and Y latitude, in degrees. As a
+/// bounding box may span the antimeridian, `xmin` may be greater than `xmax`,
+/// in which case the box covers `xmin` to 180 and -180 to `xmax`.
+///
+/// [`GEOGRAPHY`]: crate::basic::LogicalType::Geography
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    x_range: (f64, f64),
+    y_range: (f64, f64),
+    z_range: Option<(f64, f64)>,
+    m_range: Option<(f64, f64)>,
+}
+
+impl BoundingBox {
+    /// Creates a bounding box of the given X and Y bounds
+    pub fn new(xmin: f64, xmax: f64, ymin: f64, ymax: f64) -> Self {
+        Self {
+            x_range: (xmin, xmax),
+            y_range: (ymin, ymax),
+            z_range: None,
+            m_range: None,
+        }
+    }
+
+    /// Sets the Z bounds of this bounding box
+    pub fn with_zrange(self, zmin: f64, zmax: f64) -> Self {
+        Self {
+            z_range: Some((zmin, zmax)),
+            ..self
+        }
+    }
+
+    /// Sets the M bounds of this bounding box
+    pub fn with_mrange(self, mmin: f64, mmax: f64) -> Self {
+        Self {
+            m_range: Some((mmin, mmax)),
+            ..self
+        }
+    }
+
+    /// Returns the minimum X value
+    pub fn xmin(&self) -> f64 {
+        self.x_range.0
+    }
+
+    /// Returns the maximum X value
+    pub fn xmax(&self) -> f64 {
+        self.x_range.1
+    }
+
+    /// Returns the minimum Y value
+    pub fn ymin(&self) -> f64 {
+        self.y_range.0
+    }
+
+    /// Returns the maximum Y value
+    pub fn ymax(&self) -> f64 {
+        self.y_range.1
+    }
+
+    /// Returns the minimum Z value, if known
+    pub fn zmin(&self) -> Option<f64> {
+        self.z_range.map(|r| r.0)
+    }
+
+    /// Returns the maximum Z value, if known
+    pub fn zmax(&self) -> Option<f64> {
+        self.z_range.map(|r| r.1)
+    }
+
+    /// Returns the minimum M value, if known
+    pub fn mmin(&self) -> Option<f64> {
+        self.m_range.map(|r| r.0)
+    }
+
+    /// Returns the maximum M value, if known
+    pub fn mmax(&self) -> Option<f64> {
+        self.m_range.map(|r| r.1)
+    }
+
+    /// Returns true if this bounding box may intersect `other`
+    ///
+    /// The Z and M bounds are only compared if known for both bounding boxes
+    pub fn intersects(&self, other: &Self) -> bool {
+        let overlaps = |a: Option<(f64, f64)>, b: Option<(f64, f64)>| match (a, b) {
+            (Some(a), Some(b)) => a.0 <= b.1 && b.0 <= a.1,
+            _ => true,
+        };
+
+        // A range wrapping around the antimeridian is split in two
+        let x_ranges = |(min, max): (f64, f64)| match min > max {
+            true => [Some((min, f64::INFINITY)), Some((f64::NEG_INFINITY, max))],
+            false => [Some((min, max)), None],
+        };
+        let x_overlaps = x_ranges(self.x_range).iter().flatten().any(|a| {
+            x_ranges(other.x_range)
+                .iter()
+                .flatten()
+                .any(|b| overlaps(Some(*a), Some(*b)))
+        });
+
+        x_overlaps
+            && overlaps(Some(self.y_range), Some(other.y_range))
+            && overlaps(self.z_range, other.z_range)
+            && overlaps(self.m_range, other.m_range)
+    }
+}
+
+impl From<TBoundingBox> for BoundingBox {
+    fn from(bbox: TBoundingBox) -> Self {
+        let range = |min: Option<OrderedFloat<f64>>, max: Option<OrderedFloat<f64>>| {
+            Some((min?.into(), max?.into()))
+        };
+        Self {
+            x_range: (bbox.xmin.into(), bbox.xmax.into()),
+            y_range: (bbox.ymin.into(), bbox.ymax.into()),
+            z_range: range(bbox.zmin, bbox.zmax),
+            m_range: range(bbox.mmin, bbox.mmax),
+        }
+    }
+}
+
+impl From<&BoundingBox> for TBoundingBox {
+    fn from(bbox: &BoundingBox) -> Self {
+        TBoundingBox::new(
+            bbox.xmin().into(),
+            bbox.xmax().into(),
+            bbox.ymin().into(),
+            bbox.ymax().into(),
+            bbox.zmin().map(Into::into),
+            bbox.zmax().map(Into::into),
+            bbox.mmin().map(Into::into),
+            bbox.mmax().map(Into::into),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersects() {
+        let bbox = BoundingBox::new(0., 10., 0., 10.);
+        assert!(bbox.intersects(&BoundingBox::new(5., 15., 5., 15.)));
+        assert!(bbox.intersects(&BoundingBox::new(10., 20., -5., 0.)));
+        assert!(!bbox.intersects(&BoundingBox::new(11., 20., 0., 10.)));
+        assert!(!bbox.intersects(&BoundingBox::new(0., 10., -5., -1.)));
+
+        // Z and M are only compared if known for both
+        let bbox = bbox.with_zrange(0., 1.);
+        assert!(bbox.intersects(&BoundingBox::new(0., 1., 0., 1.)));
+        assert!(bbox.intersects(&BoundingBox::new(0., 1., 0., 1.).with_zrange(1., 2.)));
+        assert!(!bbox.intersects(&BoundingBox::new(0., 1., 0., 1.).with_zrange(2., 3.)));
+        assert!(!bbox
+            .with_mrange(0., 1.)
+            .intersects(&BoundingBox::new(0., 1., 0., 1.).with_mrange(-2., -1.)));
+
+        // Spanning the antimeridian from 170 to -170
+        let bbox = BoundingBox::new(170., -170., -10., 10.);
+        assert!(bbox.intersects(&BoundingBox::new(175., 180., 0., 1.)));
+        assert!(bbox.intersects(&BoundingBox::new(-180., -175., 0., 1.)));
+        assert!(!bbox.intersects(&BoundingBox::new(-160., 160., 0., 1.)));
+        assert!(bbox.intersects(&BoundingBox::new(160., -160., 0., 1.)));
+    }
+
+    #[test]
+    fn test_thrift_roundtrip() {
+        let bbox = BoundingBox::new(-1., 1., -2., 2.).with_mrange(3., 4.);
+        let thrift = TBoundingBox::from(&bbox);
+        assert_eq!(thrift.zmin, None);
+        assert_eq!(thrift.mmax, Some(4.0.into()));
+        assert_eq!(BoundingBox::from(thrift), bbox);
+    }
+}
@@ -0,0 +1,40 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Geospatial statistics of [`GEOMETRY`] and [`GEOGRAPHY`] columns
+//!
+//! Both logical types annotate `BYTE_ARRAY` columns of geometries encoded as
+//! [Well-Known Binary] (WKB). The column writer computes [`GeospatialStatistics`]
+//! for each column chunk of such columns, which can be read with
+//! [`ColumnChunkMetaData::geo_statistics`].
+//!
+//! See the [Geospatial Definitions] of the parquet format for more details.
+//!
+//! [`GEOMETRY`]: crate::basic::LogicalType::Geometry
+//! [`GEOGRAPHY`]: crate::basic::LogicalType::Geography
+//! [`ColumnChunkMetaData::geo_statistics`]: crate::file::metadata::ColumnChunkMetaData::geo_statistics
+//! [Well-Known Binary]: https://libgeos.org/specifications/wkb/
+//! [Geospatial Definitions]: https://github.com/apache/parquet-format/blob/master/Geospatial.md
+
+mod bounding_box;
+mod statistics;
+mod wkb;
+
+pub use bounding_box::BoundingBox;
+pub(crate) use statistics::GeospatialAccumulator;
+pub use statistics::GeospatialStatistics;
+pub(crate) use statistics::{from_thrift, to_thrift};
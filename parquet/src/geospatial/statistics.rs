@@ -0,0 +1,231 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::BTreeSet;
+
+use crate::basic::LogicalType;
+use crate::format::GeospatialStatistics as TGeospatialStatistics;
+use crate::geospatial::wkb::read_wkb;
+use crate::geospatial::BoundingBox;
+use crate::schema::types::ColumnDescriptor;
+
+/// Statistics of the geospatial values of a column chunk
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GeospatialStatistics {
+    bbox: Option<BoundingBox>,
+    geospatial_types: Option<Vec<i32>>,
+}
+
+impl GeospatialStatistics {
+    /// Creates geospatial statistics of a bounding box and the ISO WKB geometry
+    /// type codes of the values, both optional
+    pub fn new(bbox: Option<BoundingBox>, geospatial_types: Option<Vec<i32>>) -> Self {
+        Self {
+            bbox,
+            geospatial_types,
+        }
+    }
+
+    /// Returns the bounding box of the values, if known
+    pub fn bbox(&self) -> Option<&BoundingBox> {
+        self.bbox.as_ref()
+    }
+
+    /// Returns the distinct ISO WKB geometry type codes of the values, such as
+    /// `1` for a point or `1003` for a polygon with Z values, if known
+    pub fn geospatial_types(&self) -> Option<&[i32]> {
+        self.geospatial_types.as_deref()
+    }
+}
+
+/// Converts thrift geospatial statistics to [`GeospatialStatistics`]
+pub(crate) fn from_thrift(stats: Option<TGeospatialStatistics>) -> Option<GeospatialStatistics> {
+    let stats = stats?;
+    Some(GeospatialStatistics {
+        bbox: stats.bbox.map(Into::into),
+        // An empty list means the types are not known
+        geospatial_types: stats.geospatial_types.filter(|t| !t.is_empty()),
+    })
+}
+
+/// Converts [`GeospatialStatistics`] to thrift geospatial statistics
+pub(crate) fn to_thrift(stats: Option<&GeospatialStatistics>) -> Option<TGeospatialStatistics> {
+    let stats = stats?;
+    Some(TGeospatialStatistics::new(
+        stats.bbox.as_ref().map(Into::into),
+        stats.geospatial_types.clone(),
+    ))
+}
+
+/// Accumulates the [`GeospatialStatistics`] of WKB values written to a column
+///
+/// The bounding box is only computed for [`LogicalType::Geometry`] columns, as
+/// the edges of [`LogicalType::Geography`] values may extend beyond the bounds of
+/// their coordinates. If any value is not valid WKB no statistics are returned.
+#[derive(Debug)]
+pub(crate) struct GeospatialAccumulator {
+    compute_bbox: bool,
+    /// The minimum and maximum of X, Y, Z and M
+    bounds: [(f64, f64); 4],
+    geospatial_types: BTreeSet<i32>,
+    invalid: bool,
+}
+
+impl GeospatialAccumulator {
+    /// Returns an accumulator for the column of `descr`, or `None` if it is not
+    /// a geospatial column
+    pub(crate) fn try_new(descr: &ColumnDescriptor) -> Option<Self> {
+        let compute_bbox = match descr.logical_type()? {
+            LogicalType::Geometry { .. } => true,
+            LogicalType::Geography { .. } => false,
+            _ => return None,
+        };
+        Some(Self {
+            compute_bbox,
+            bounds: [(f64::INFINITY, f64::NEG_INFINITY); 4],
+            geospatial_types: BTreeSet::new(),
+            invalid: false,
+        })
+    }
+
+    /// Updates the statistics with a WKB value
+    pub(crate) fn update(&mut self, wkb: &[u8]) {
+        if self.invalid {
+            return;
+        }
+
+        let bounds = &mut self.bounds;
+        let mut visit = |coord: [f64; 4]| {
+            for (value, (min, max)) in coord.into_iter().zip(bounds.iter_mut()) {
+                // NaN coordinates, such as those of empty points, are ignored
+                if !value.is_nan() {
+                    *min = min.min(value);
+                    *max = max.max(value);
+                }
+            }
+        };
+        match read_wkb(wkb, &mut visit) {
+            Ok(code) => {
+                self.geospatial_types.insert(code);
+            }
+            Err(_) => self.invalid = true,
+        }
+    }
+
+    /// Returns the accumulated statistics, or `None` if no valid values were written
+    pub(crate) fn finish(self) -> Option<GeospatialStatistics> {
+        if self.invalid || self.geospatial_types.is_empty() {
+            return None;
+        }
+
+        let range = |(min, max): (f64, f64)| (min <= max).then_some((min, max));
+        let [x, y, z, m] = self.bounds.map(range);
+        let bbox = match (self.compute_bbox, x, y) {
+            (true, Some(x), Some(y)) => {
+                let mut bbox = BoundingBox::new(x.0, x.1, y.0, y.1);
+                if let Some(z) = z {
+                    bbox = bbox.with_zrange(z.0, z.1);
+                }
+                if let Some(m) = m {
+                    bbox = bbox.with_mrange(m.0, m.1);
+                }
+                Some(bbox)
+            }
+            _ => None,
+        };
+
+        Some(GeospatialStatistics {
+            bbox,
+            geospatial_types: Some(self.geospatial_types.into_iter().collect()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::basic::{LogicalType, Type as PhysicalType};
+    use crate::geospatial::wkb::tests::{linestring, point};
+    use crate::schema::types::{ColumnPath, Type};
+    use std::sync::Arc;
+
+    fn descr(logical_type: Option<LogicalType>) -> ColumnDescriptor {
+        let tp = Type::primitive_type_builder("geo", PhysicalType::BYTE_ARRAY)
+            .with_logical_type(logical_type)
+            .build()
+            .unwrap();
+        ColumnDescriptor::new(Arc::new(tp), 0, 0, ColumnPath::from("geo"))
+    }
+
+    #[test]
+    fn test_geometry_accumulator() {
+        let descr = descr(Some(LogicalType::Geometry { crs: None }));
+        let mut acc = GeospatialAccumulator::try_new(&descr).unwrap();
+        acc.update(&point(1, &[1., 2.]));
+        acc.update(&point(1, &[f64::NAN, f64::NAN]));
+        acc.update(&linestring(&[(-3., 5.), (4., -1.)]));
+        acc.update(&point(1001, &[0., 0., 10.]));
+
+        let stats = acc.finish().unwrap();
+        assert_eq!(stats.geospatial_types(), Some([1, 2, 1001].as_slice()));
+        let expected = BoundingBox::new(-3., 4., -1., 5.).with_zrange(10., 10.);
+        assert_eq!(stats.bbox(), Some(&expected));
+
+        // Thrift roundtrip
+        let thrift = to_thrift(Some(&stats));
+        assert_eq!(from_thrift(thrift), Some(stats));
+    }
+
+    #[test]
+    fn test_geography_accumulator() {
+        let logical_type = LogicalType::Geography {
+            crs: None,
+            algorithm: None,
+        };
+        let descr = descr(Some(logical_type));
+        let mut acc = GeospatialAccumulator::try_new(&descr).unwrap();
+        acc.update(&point(1, &[1., 2.]));
+
+        let stats = acc.finish().unwrap();
+        assert_eq!(stats.geospatial_types(), Some([1].as_slice()));
+        assert_eq!(stats.bbox(), None);
+    }
+
+    #[test]
+    fn test_accumulator_invalid() {
+        assert!(GeospatialAccumulator::try_new(&descr(None)).is_none());
+
+        let descr = descr(Some(LogicalType::Geometry { crs: None }));
+        assert!(GeospatialAccumulator::try_new(&descr)
+            .unwrap()
+            .finish()
+            .is_none());
+
+        let mut acc = GeospatialAccumulator::try_new(&descr).unwrap();
+        acc.update(&point(1, &[1., 2.]));
+        acc.update(b"not wkb");
+        assert!(acc.finish().is_none());
+
+        // A pathologically nested geometry collection invalidates the statistics
+        let mut wkb = [1, 7, 0, 0, 0, 1, 0, 0, 0].repeat(100_000);
+        wkb.extend(point(1, &[1., 2.]));
+        let mut acc = GeospatialAccumulator::try_new(&descr).unwrap();
+        acc.update(&point(1, &[1., 2.]));
+        acc.update(&wkb);
+        assert!(acc.finish().is_none());
+    }
+}
@@ -0,0 +1,287 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A minimal reader of [Well-Known Binary] geometries
+//!
+//! Both ISO WKB and the extended WKB (EWKB) flags for Z, M and SRID are supported.
+//!
+//! [Well-Known Binary]: https://libgeos.org/specifications/wkb/
+
+use crate::errors::{ParquetError, Result};
+
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// The maximum nesting depth of geometry collections, beyond which the WKB is
+/// rejected rather than overflowing the stack
+const MAX_NESTING_DEPTH: usize = 32;
+
+/// A coordinate of a geometry, with `NaN` for absent Z or M values
+pub(crate) type Coord = [f64; 4];
+
+/// Reads the geometry encoded as WKB in `wkb`, calling `visit` with each of
+/// its coordinates, and returns its ISO WKB geometry type code
+pub(crate) fn read_wkb(wkb: &[u8], visit: &mut dyn FnMut(Coord)) -> Result<i32> {
+    let mut reader = WkbReader { buf: wkb };
+    let code = reader.read_geometry(0, visit)?;
+    if !reader.buf.is_empty() {
+        return Err(general_err!(
+            "Unexpected {} trailing bytes in WKB",
+            reader.buf.len()
+        ));
+    }
+    Ok(code)
+}
+
+struct WkbReader<'a> {
+    buf: &'a [u8],
+}
+
+impl WkbReader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let (bytes, rest) = self
+            .buf
+            .split_first_chunk::<N>()
+            .ok_or_else(|| general_err!("Unexpected end of WKB"))?;
+        self.buf = rest;
+        Ok(*bytes)
+    }
+
+    fn read_u32(&mut self, little_endian: bool) -> Result<u32> {
+        let bytes = self.take()?;
+        Ok(match little_endian {
+            true => u32::from_le_bytes(bytes),
+            false => u32::from_be_bytes(bytes),
+        })
+    }
+
+    fn read_f64(&mut self, little_endian: bool) -> Result<f64> {
+        let bytes = self.take()?;
+        Ok(match little_endian {
+            true => f64::from_le_bytes(bytes),
+            false => f64::from_be_bytes(bytes),
+        })
+    }
+
+    fn read_geometry(&mut self, depth: usize, visit: &mut dyn FnMut(Coord)) -> Result<i32> {
+        if depth > MAX_NESTING_DEPTH {
+            return Err(general_err!(
+                "WKB geometry collections nested more than {} deep",
+                MAX_NESTING_DEPTH
+            ));
+        }
+
+        let little_endian = match self.take::<1>()?[0] {
+            0 => false,
+            1 => true,
+            b => return Err(general_err!("Invalid WKB byte order {}", b)),
+        };
+
+        let raw = self.read_u32(little_endian)?;
+        if raw & EWKB_SRID != 0 {
+            self.read_u32(little_endian)?;
+        }
+        let code = raw & !(EWKB_Z | EWKB_M | EWKB_SRID);
+        let (base, dimension) = (code % 1000, code / 1000);
+        if dimension > 3 {
+            return Err(general_err!("Invalid WKB geometry type {}", raw));
+        }
+        let has_z = raw & EWKB_Z != 0 || dimension == 1 || dimension == 3;
+        let has_m = raw & EWKB_M != 0 || dimension == 2 || dimension == 3;
+        let dims = Dimensions { has_z, has_m };
+
+        match base {
+            1 => self.read_coord(little_endian, dims, visit)?,
+            2 => self.read_coords(little_endian, dims, visit)?,
+            3 => {
+                for _ in 0..self.read_u32(little_endian)? {
+                    self.read_coords(little_endian, dims, visit)?;
+                }
+            }
+            4..=7 => {
+                for _ in 0..self.read_u32(little_endian)? {
+                    self.read_geometry(depth + 1, visit)?;
+                }
+            }
+            _ => return Err(general_err!("Invalid WKB geometry type {}", raw)),
+        }
+
+        let dimension = match (has_z, has_m) {
+            (false, false) => 0,
+            (true, false) => 1,
+            (false, true) => 2,
+            (true, true) => 3,
+        };
+        Ok((base + dimension * 1000) as i32)
+    }
+
+    fn read_coords(
+        &mut self,
+        little_endian: bool,
+        dims: Dimensions,
+        visit: &mut dyn FnMut(Coord),
+    ) -> Result<()> {
+        for _ in 0..self.read_u32(little_endian)? {
+            self.read_coord(little_endian, dims, visit)?;
+        }
+        Ok(())
+    }
+
+    fn read_coord(
+        &mut self,
+        little_endian: bool,
+        dims: Dimensions,
+        visit: &mut dyn FnMut(Coord),
+    ) -> Result<()> {
+        let x = self.read_f64(little_endian)?;
+        let y = self.read_f64(little_endian)?;
+        let mut read_optional = |present: bool| match present {
+            true => self.read_f64(little_endian),
+            false => Ok(f64::NAN),
+        };
+        let z = read_optional(dims.has_z)?;
+        let m = read_optional(dims.has_m)?;
+        visit([x, y, z, m]);
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Dimensions {
+    has_z: bool,
+    has_m: bool,
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Encodes a little-endian ISO WKB point
+    pub(crate) fn point(code: u32, coords: &[f64]) -> Vec<u8> {
+        let mut wkb = vec![1];
+        wkb.extend_from_slice(&code.to_le_bytes());
+        coords
+            .iter()
+            .for_each(|c| wkb.extend_from_slice(&c.to_le_bytes()));
+        wkb
+    }
+
+    /// Encodes a little-endian ISO WKB linestring of XY coordinates
+    pub(crate) fn linestring(coords: &[(f64, f64)]) -> Vec<u8> {
+        let mut wkb = vec![1];
+        wkb.extend_from_slice(&2_u32.to_le_bytes());
+        wkb.extend_from_slice(&(coords.len() as u32).to_le_bytes());
+        for (x, y) in coords {
+            wkb.extend_from_slice(&x.to_le_bytes());
+            wkb.extend_from_slice(&y.to_le_bytes());
+        }
+        wkb
+    }
+
+    fn read(wkb: &[u8]) -> Result<(i32, Vec<Coord>)> {
+        let mut coords = vec![];
+        let code = read_wkb(wkb, &mut |c| coords.push(c))?;
+        Ok((code, coords))
+    }
+
+    #[test]
+    fn test_read_wkb() {
+        let (code, coords) = read(&point(1, &[1., 2.])).unwrap();
+        assert_eq!(code, 1);
+        assert_eq!(coords.len(), 1);
+        assert_eq!(&coords[0][..2], &[1., 2.]);
+        assert!(coords[0][2].is_nan() && coords[0][3].is_nan());
+
+        let (code, coords) = read(&point(3001, &[1., 2., 3., 4.])).unwrap();
+        assert_eq!(code, 3001);
+        assert_eq!(coords, vec![[1., 2., 3., 4.]]);
+
+        // EWKB big-endian point with M and an SRID
+        let mut wkb = vec![0];
+        wkb.extend_from_slice(&(1 | EWKB_M | EWKB_SRID).to_be_bytes());
+        wkb.extend_from_slice(&4326_u32.to_be_bytes());
+        [5., 6., 7.]
+            .iter()
+            .for_each(|c: &f64| wkb.extend_from_slice(&c.to_be_bytes()));
+        let (code, coords) = read(&wkb).unwrap();
+        assert_eq!(code, 2001);
+        assert_eq!(coords[0][..2], [5., 6.]);
+        assert_eq!(coords[0][3], 7.);
+
+        // A multipoint of two points
+        let mut wkb = vec![1];
+        wkb.extend_from_slice(&4_u32.to_le_bytes());
+        wkb.extend_from_slice(&2_u32.to_le_bytes());
+        wkb.extend(point(1, &[0., 1.]));
+        wkb.extend(point(1, &[2., 3.]));
+        let (code, coords) = read(&wkb).unwrap();
+        assert_eq!(code, 4);
+        assert_eq!(coords.len(), 2);
+
+        let (code, coords) = read(&linestring(&[(0., 0.), (1., 1.)])).unwrap();
+        assert_eq!(code, 2);
+        assert_eq!(coords.len(), 2);
+    }
+
+    #[test]
+    fn test_read_invalid_wkb() {
+        let err = read(&[]).unwrap_err();
+        assert_eq!(err.to_string(), "Parquet error: Unexpected end of WKB");
+
+        let err = read(&point(8, &[1., 2.])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid WKB geometry type 8"
+        );
+
+        let mut wkb = point(1, &[1., 2.]);
+        wkb.push(0);
+        let err = read(&wkb).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Unexpected 1 trailing bytes in WKB"
+        );
+    }
+
+    #[test]
+    fn test_read_nested_wkb() {
+        /// Encodes `depth` geometry collections, each containing the next, around a point
+        fn nested(depth: usize) -> Vec<u8> {
+            let mut wkb = vec![];
+            for _ in 0..depth {
+                wkb.push(1);
+                wkb.extend_from_slice(&7_u32.to_le_bytes());
+                wkb.extend_from_slice(&1_u32.to_le_bytes());
+            }
+            wkb.extend(point(1, &[1., 2.]));
+            wkb
+        }
+
+        let (code, coords) = read(&nested(MAX_NESTING_DEPTH)).unwrap();
+        assert_eq!(code, 7);
+        assert_eq!(coords.len(), 1);
+
+        let expected = "Parquet error: WKB geometry collections nested more than 32 deep";
+        let err = read(&nested(MAX_NESTING_DEPTH + 1)).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+
+        // Deeply nested collections are rejected without overflowing the stack
+        let err = read(&nested(1_000_000)).unwrap_err();
+        assert_eq!(err.to_string(), expected);
+    }
+}
@@ -170,11 +170,13 @@ pub mod column;
 experimental!(mod compression);
 experimental!(mod encodings);
 pub mod bloom_filter;
+pub mod distinct_count;
 
 #[cfg(feature = "encryption")]
 experimental!(pub mod encryption);
 
 pub mod file;
+pub mod geospatial;
 pub mod record;
 pub mod schema;
 
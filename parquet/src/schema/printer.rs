@@ -327,8 +327,18 @@ fn print_logical_and_converted(
             LogicalType::Map => "MAP".to_string(),
             LogicalType::Float16 => "FLOAT16".to_string(),
             LogicalType::Variant => "VARIANT".to_string(),
-            LogicalType::Geometry => "GEOMETRY".to_string(),
-            LogicalType::Geography => "GEOGRAPHY".to_string(),
+            LogicalType::Geometry { crs } => match crs {
+                Some(crs) => format!("GEOMETRY({crs})"),
+                None => "GEOMETRY".to_string(),
+            },
+            LogicalType::Geography { crs, algorithm } => match (crs, algorithm) {
+                (None, None) => "GEOGRAPHY".to_string(),
+                (crs, algorithm) => format!(
+                    "GEOGRAPHY({},{})",
+                    crs.as_deref().unwrap_or("OGC:CRS84"),
+                    algorithm.unwrap_or_default()
+                ),
+            },
             LogicalType::Unknown => "UNKNOWN".to_string(),
         },
         None => {
@@ -449,7 +459,7 @@ mod tests {
 
     use std::sync::Arc;
 
-    use crate::basic::{Repetition, Type as PhysicalType};
+    use crate::basic::{EdgeInterpolationAlgorithm, Repetition, Type as PhysicalType};
     use crate::errors::Result;
     use crate::schema::parser::parse_message_type;
 
@@ -1110,6 +1120,44 @@ mod tests {
         assert_print_parse_message(message);
     }
 
+    #[test]
+    fn test_print_and_parse_geospatial() {
+        let logical_types = [
+            LogicalType::Geometry { crs: None },
+            LogicalType::Geometry {
+                crs: Some("srid:4326".to_string()),
+            },
+            LogicalType::Geography {
+                crs: None,
+                algorithm: None,
+            },
+            LogicalType::Geography {
+                crs: Some("OGC:CRS84".to_string()),
+                algorithm: Some(EdgeInterpolationAlgorithm::KARNEY),
+            },
+        ];
+        let fields = logical_types
+            .into_iter()
+            .enumerate()
+            .map(|(idx, logical_type)| {
+                let field =
+                    Type::primitive_type_builder(&format!("f{idx}"), PhysicalType::BYTE_ARRAY)
+                        .with_repetition(Repetition::OPTIONAL)
+                        .with_logical_type(Some(logical_type))
+                        .build()
+                        .unwrap();
+                Arc::new(field)
+            })
+            .collect();
+
+        let message = Type::group_type_builder("schema")
+            .with_fields(fields)
+            .build()
+            .unwrap();
+
+        assert_print_parse_message(message);
+    }
+
     #[test]
     fn test_print_and_parse_decimal() {
         let f1 = Type::primitive_type_builder("f1", PhysicalType::INT32)
@@ -21,6 +21,15 @@ use std::path::PathBuf;
 
 use arrow::util::test_util::parquet_test_data;
 use parquet::arrow::arrow_reader::ArrowReaderBuilder;
+#[cfg(feature = "crc")]
+use {
+    arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray},
+    bytes::Bytes,
+    parquet::arrow::ArrowWriter,
+    parquet::basic::Compression,
+    parquet::file::properties::{WriterProperties, WriterVersion},
+    std::sync::Arc,
+};
 
 #[test]
 fn test_datapage_v1_corrupt_checksum() {
@@ -59,6 +68,79 @@ fn test_rle_dict_snappy_checksum() {
     assert_eq!(errors, [Ok(())]);
 }
 
+#[test]
+#[cfg(feature = "crc")]
+fn test_write_checksum_roundtrip() {
+    let mut compressions = vec![Compression::UNCOMPRESSED];
+    #[cfg(feature = "snap")]
+    compressions.push(Compression::SNAPPY);
+
+    for compression in compressions {
+        for writer_version in [WriterVersion::PARQUET_1_0, WriterVersion::PARQUET_2_0] {
+            let props = WriterProperties::builder()
+                .set_write_page_checksum(true)
+                .set_compression(compression)
+                .set_writer_version(writer_version)
+                .set_data_page_row_count_limit(100)
+                .set_write_batch_size(100)
+                .build();
+            let (batch, data) = write_checksum_file(props);
+
+            let reader = ArrowReaderBuilder::try_new(data.clone())
+                .unwrap()
+                .build()
+                .unwrap();
+            let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+            assert_eq!(batches, vec![batch]);
+
+            // Corrupt the last byte of the dictionary page and of the last data page
+            let builder = ArrowReaderBuilder::try_new(data.clone()).unwrap();
+            let column = builder.metadata().row_group(0).column(1);
+            let data_page_offset = column.data_page_offset() as usize;
+            assert!(column.dictionary_page_offset().is_some());
+            let chunk_end = column.byte_range().0 + column.byte_range().1;
+            for offset in [data_page_offset - 1, chunk_end as usize - 1] {
+                let mut corrupted = data.to_vec();
+                corrupted[offset] ^= 1;
+                let err = ArrowReaderBuilder::try_new(Bytes::from(corrupted))
+                    .unwrap()
+                    .build()
+                    .unwrap()
+                    .collect::<Result<Vec<_>, _>>()
+                    .unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    "Parquet argument error: Parquet error: Page CRC checksum mismatch"
+                );
+            }
+        }
+    }
+}
+
+/// Writes a file of an integer column and a dictionary encoded string column
+#[cfg(feature = "crc")]
+fn write_checksum_file(props: WriterProperties) -> (RecordBatch, Bytes) {
+    let batch = RecordBatch::try_from_iter([
+        (
+            "id",
+            Arc::new(Int32Array::from_iter_values(0..1000)) as ArrayRef,
+        ),
+        (
+            "name",
+            Arc::new(StringArray::from_iter_values(
+                (0..1000).map(|i| format!("name {}", i % 10)),
+            )) as ArrayRef,
+        ),
+    ])
+    .unwrap();
+
+    let mut data = Vec::new();
+    let mut writer = ArrowWriter::try_new(&mut data, batch.schema(), Some(props)).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    (batch, Bytes::from(data))
+}
+
 /// Reads a file and returns a vector with one element per record batch.
 /// The record batch data is replaced with () and errors are stringified.
 fn read_file_batch_errors(name: &str) -> Vec<Result<(), String>> {
@@ -28,15 +28,22 @@ use parquet::arrow::arrow_reader::{
     ArrowReaderMetadata, ArrowReaderOptions, ParquetRecordBatchReaderBuilder, RowSelection,
     RowSelector,
 };
+use parquet::arrow::arrow_writer::{ArrowWriterOptions, ThreadPoolExecutor};
 use parquet::arrow::ArrowWriter;
 use parquet::data_type::{ByteArray, ByteArrayType};
 use parquet::encryption::decrypt::FileDecryptionProperties;
-use parquet::encryption::encrypt::FileEncryptionProperties;
+use parquet::encryption::encrypt::{EncryptionAlgorithm, FileEncryptionProperties};
+use parquet::encryption::key_management::crypto_factory::{
+    CryptoFactory, DecryptionConfiguration, EncryptionConfiguration,
+};
+use parquet::encryption::key_management::kms::{KmsClient, KmsConnectionConfig};
+use parquet::encryption::key_management::test_kms::InMemoryKms;
 use parquet::errors::ParquetError;
 use parquet::file::metadata::ParquetMetaData;
 use parquet::file::properties::WriterProperties;
 use parquet::file::writer::SerializedFileWriter;
 use parquet::schema::parser::parse_message_type;
+use std::collections::HashMap;
 use std::fs::File;
 use std::sync::Arc;
 
@@ -224,18 +231,7 @@ fn test_aes_ctr_encryption() {
         .build()
         .unwrap();
 
-    let options =
-        ArrowReaderOptions::default().with_file_decryption_properties(decryption_properties);
-    let metadata = ArrowReaderMetadata::load(&file, options);
-
-    match metadata {
-        Err(parquet::errors::ParquetError::NYI(s)) => {
-            assert!(s.contains("AES_GCM_CTR_V1"));
-        }
-        _ => {
-            panic!("Expected ParquetError::NYI");
-        }
-    };
+    verify_encryption_test_file_read(file, decryption_properties);
 }
 
 #[test]
@@ -398,27 +394,38 @@ fn row_group_sizes(metadata: &ParquetMetaData) -> Vec<i64> {
 
 #[test]
 fn test_uniform_encryption_roundtrip() {
-    uniform_encryption_roundtrip(false, false).unwrap();
+    uniform_encryption_roundtrip(false, false, EncryptionAlgorithm::AesGcmV1).unwrap();
 }
 
 #[test]
 fn test_uniform_encryption_roundtrip_with_dictionary() {
-    uniform_encryption_roundtrip(false, true).unwrap();
+    uniform_encryption_roundtrip(false, true, EncryptionAlgorithm::AesGcmV1).unwrap();
 }
 
 #[test]
 fn test_uniform_encryption_roundtrip_with_page_index() {
-    uniform_encryption_roundtrip(true, false).unwrap();
+    uniform_encryption_roundtrip(true, false, EncryptionAlgorithm::AesGcmV1).unwrap();
 }
 
 #[test]
 fn test_uniform_encryption_roundtrip_with_page_index_and_dictionary() {
-    uniform_encryption_roundtrip(true, true).unwrap();
+    uniform_encryption_roundtrip(true, true, EncryptionAlgorithm::AesGcmV1).unwrap();
+}
+
+#[test]
+fn test_uniform_encryption_roundtrip_aes_ctr() {
+    uniform_encryption_roundtrip(false, false, EncryptionAlgorithm::AesGcmCtrV1).unwrap();
+}
+
+#[test]
+fn test_uniform_encryption_roundtrip_aes_ctr_with_page_index_and_dictionary() {
+    uniform_encryption_roundtrip(true, true, EncryptionAlgorithm::AesGcmCtrV1).unwrap();
 }
 
 fn uniform_encryption_roundtrip(
     page_index: bool,
     dictionary_encoding: bool,
+    algorithm: EncryptionAlgorithm,
 ) -> parquet::errors::Result<()> {
     let x0_arrays = [
         Int32Array::from((0..100).collect::<Vec<_>>()),
@@ -437,8 +444,9 @@ fn uniform_encryption_roundtrip(
     let file = tempfile::tempfile()?;
 
     let footer_key = b"0123456789012345";
-    let file_encryption_properties =
-        FileEncryptionProperties::builder(footer_key.to_vec()).build()?;
+    let file_encryption_properties = FileEncryptionProperties::builder(footer_key.to_vec())
+        .with_encryption_algorithm(algorithm)
+        .build()?;
 
     let props = WriterProperties::builder()
         // Ensure multiple row groups
@@ -514,6 +522,290 @@ fn uniform_encryption_roundtrip(
     Ok(())
 }
 
+#[test]
+fn test_non_uniform_encryption_roundtrip_aes_ctr() {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("x0", ArrowDataType::Int32, false),
+        Field::new("x1", ArrowDataType::Utf8, true),
+        Field::new("x2", ArrowDataType::Int32, false),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from_iter_values(0..100)),
+            Arc::new(StringArray::from_iter(
+                (0..100).map(|i| (i % 3 != 0).then(|| format!("value {}", i % 7))),
+            )),
+            Arc::new(Int32Array::from_iter_values(100..200)),
+        ],
+    )
+    .unwrap();
+
+    let footer_key = b"0123456789012345".to_vec();
+    let column_1_key = b"1234567890123450".to_vec();
+    let column_2_key = b"1234567890123451".to_vec();
+
+    let file_encryption_properties = FileEncryptionProperties::builder(footer_key.clone())
+        .with_encryption_algorithm(EncryptionAlgorithm::AesGcmCtrV1)
+        .with_column_key("x0", column_1_key.clone())
+        .with_column_key("x1", column_2_key.clone())
+        .with_plaintext_footer(true)
+        .build()
+        .unwrap();
+    let props = WriterProperties::builder()
+        .set_data_page_row_count_limit(30)
+        .set_write_batch_size(30)
+        .with_file_encryption_properties(file_encryption_properties)
+        .build();
+
+    let file = tempfile::tempfile().unwrap();
+    let mut writer = ArrowWriter::try_new(file.try_clone().unwrap(), schema, Some(props)).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    let decryption_properties = FileDecryptionProperties::builder(footer_key)
+        .with_column_key("x0", column_1_key)
+        .with_column_key("x1", column_2_key)
+        .build()
+        .unwrap();
+    let options = ArrowReaderOptions::new()
+        .with_file_decryption_properties(decryption_properties)
+        .with_page_index(true);
+    let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options)
+        .unwrap()
+        .build()
+        .unwrap();
+    let batches = reader.collect::<ArrowResult<Vec<_>>>().unwrap();
+    assert_eq!(batches, vec![batch]);
+}
+
+#[test]
+fn test_concurrent_encoding_encryption_roundtrip() {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("x0", ArrowDataType::Int32, false),
+        Field::new("x1", ArrowDataType::Utf8, true),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from_iter_values(0..500)),
+            Arc::new(StringArray::from_iter(
+                (0..500).map(|i| (i % 3 != 0).then(|| format!("value {}", i % 7))),
+            )),
+        ],
+    )
+    .unwrap();
+
+    let footer_key = b"0123456789012345".to_vec();
+    let column_key = b"1234567890123450".to_vec();
+
+    let file_encryption_properties = FileEncryptionProperties::builder(footer_key.clone())
+        .with_column_key("x1", column_key.clone())
+        .build()
+        .unwrap();
+    let props = WriterProperties::builder()
+        .set_max_row_group_size(100)
+        .set_data_page_row_count_limit(30)
+        .set_write_batch_size(30)
+        .with_file_encryption_properties(file_encryption_properties)
+        .build();
+    // Row groups are encrypted with their ordinal in the AAD, which must account for
+    // row groups that are still being closed
+    let options = ArrowWriterOptions::new()
+        .with_properties(props)
+        .with_encoding_executor(Arc::new(ThreadPoolExecutor::new(2)))
+        .with_max_concurrent_row_groups(3);
+
+    let file = tempfile::tempfile().unwrap();
+    let mut writer =
+        ArrowWriter::try_new_with_options(file.try_clone().unwrap(), schema, options).unwrap();
+    for offset in (0..500).step_by(50) {
+        writer.write(&batch.slice(offset, 50)).unwrap();
+    }
+    let metadata = writer.close().unwrap();
+    assert_eq!(metadata.row_groups.len(), 5);
+
+    let decryption_properties = FileDecryptionProperties::builder(footer_key)
+        .with_column_key("x1", column_key)
+        .build()
+        .unwrap();
+    let options = ArrowReaderOptions::new()
+        .with_file_decryption_properties(decryption_properties)
+        .with_page_index(true);
+    let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options)
+        .unwrap()
+        .with_batch_size(500)
+        .build()
+        .unwrap();
+    let batches = reader.collect::<ArrowResult<Vec<_>>>().unwrap();
+    assert_eq!(batches, vec![batch]);
+}
+
+#[test]
+#[cfg(feature = "crc")]
+fn test_write_page_checksum_encrypted() {
+    let batch = RecordBatch::try_from_iter([
+        (
+            "x0",
+            Arc::new(Int32Array::from_iter_values(0..100)) as ArrayRef,
+        ),
+        (
+            "x1",
+            Arc::new(StringArray::from_iter_values(
+                (0..100).map(|i| format!("value {}", i % 7)),
+            )) as ArrayRef,
+        ),
+    ])
+    .unwrap();
+    let footer_key = b"0123456789012345".to_vec();
+    let column_key = b"1234567890123450".to_vec();
+
+    for algorithm in [
+        EncryptionAlgorithm::AesGcmV1,
+        EncryptionAlgorithm::AesGcmCtrV1,
+    ] {
+        let file_encryption_properties = FileEncryptionProperties::builder(footer_key.clone())
+            .with_encryption_algorithm(algorithm)
+            .with_column_key("x1", column_key.clone())
+            .build()
+            .unwrap();
+        let props = WriterProperties::builder()
+            .set_write_page_checksum(true)
+            .set_data_page_row_count_limit(30)
+            .set_write_batch_size(30)
+            .with_file_encryption_properties(file_encryption_properties)
+            .build();
+        let mut data = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut data, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        let metadata = writer.close().unwrap();
+
+        let read = |data: Vec<u8>| {
+            let decryption_properties = FileDecryptionProperties::builder(footer_key.clone())
+                .with_column_key("x1", column_key.clone())
+                .build()
+                .unwrap();
+            let options =
+                ArrowReaderOptions::new().with_file_decryption_properties(decryption_properties);
+            ParquetRecordBatchReaderBuilder::try_new_with_options(bytes::Bytes::from(data), options)
+                .unwrap()
+                .build()
+                .unwrap()
+                .collect::<ArrowResult<Vec<_>>>()
+        };
+        assert_eq!(read(data.clone()).unwrap(), vec![batch.clone()]);
+
+        // The checksum of the encrypted page data is verified before it is decrypted
+        let column = &metadata.row_groups[0].columns[1];
+        let column_metadata = column.meta_data.as_ref().unwrap();
+        let chunk_start = column_metadata
+            .dictionary_page_offset
+            .unwrap_or(column_metadata.data_page_offset);
+        let chunk_end = chunk_start + column_metadata.total_compressed_size;
+        let mut corrupted = data;
+        corrupted[chunk_end as usize - 1] ^= 1;
+        assert_eq!(
+            read(corrupted).unwrap_err().to_string(),
+            "Parquet argument error: Parquet error: Page CRC checksum mismatch"
+        );
+    }
+}
+
+#[test]
+fn test_key_management_roundtrip() {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("x0", ArrowDataType::Int32, false),
+        Field::new("x1", ArrowDataType::Utf8, true),
+        Field::new("x2", ArrowDataType::Int32, false),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from_iter_values(0..100)),
+            Arc::new(StringArray::from_iter(
+                (0..100).map(|i| (i % 3 != 0).then(|| format!("value {}", i % 7))),
+            )),
+            Arc::new(Int32Array::from_iter_values(100..200)),
+        ],
+    )
+    .unwrap();
+
+    let master_keys = HashMap::from([
+        ("kf".to_string(), b"0123456789012345".to_vec()),
+        ("kc1".to_string(), b"1234567890123450".to_vec()),
+        ("kc2".to_string(), b"1234567890123451".to_vec()),
+    ]);
+    let crypto_factory = |master_keys: HashMap<String, Vec<u8>>| {
+        let kms = InMemoryKms::new(master_keys);
+        CryptoFactory::new(move |_: &KmsConnectionConfig| {
+            Ok(Arc::new(kms.clone()) as Arc<dyn KmsClient>)
+        })
+    };
+    let kms_config = KmsConnectionConfig::default();
+
+    for (algorithm, plaintext_footer, double_wrapping) in [
+        (EncryptionAlgorithm::AesGcmV1, false, true),
+        (EncryptionAlgorithm::AesGcmV1, true, false),
+        (EncryptionAlgorithm::AesGcmCtrV1, false, false),
+        (EncryptionAlgorithm::AesGcmCtrV1, true, true),
+    ] {
+        let encryption_config = EncryptionConfiguration::builder("kf".to_string())
+            .with_column_keys("kc1".to_string(), vec!["x0".to_string()])
+            .with_column_keys("kc2".to_string(), vec!["x1".to_string()])
+            .with_encryption_algorithm(algorithm)
+            .with_plaintext_footer(plaintext_footer)
+            .with_double_wrapping(double_wrapping)
+            .build()
+            .unwrap();
+        let file_encryption_properties = crypto_factory(master_keys.clone())
+            .file_encryption_properties(&kms_config, &encryption_config)
+            .unwrap();
+        let props = WriterProperties::builder()
+            .with_file_encryption_properties(file_encryption_properties)
+            .build();
+
+        let file = tempfile::tempfile().unwrap();
+        let mut writer =
+            ArrowWriter::try_new(file.try_clone().unwrap(), schema.clone(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let decryption_properties = crypto_factory(master_keys.clone())
+            .file_decryption_properties(&kms_config, &DecryptionConfiguration::default())
+            .unwrap();
+        let options = ArrowReaderOptions::new()
+            .with_file_decryption_properties(decryption_properties)
+            .with_page_index(true);
+        let reader = ParquetRecordBatchReaderBuilder::try_new_with_options(
+            file.try_clone().unwrap(),
+            options,
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+        let batches = reader.collect::<ArrowResult<Vec<_>>>().unwrap();
+        assert_eq!(batches, vec![batch.clone()]);
+
+        // Columns cannot be read without access to their master keys
+        let mut restricted_keys = master_keys.clone();
+        restricted_keys.remove("kc2");
+        let decryption_properties = crypto_factory(restricted_keys)
+            .file_decryption_properties(&kms_config, &DecryptionConfiguration::default())
+            .unwrap();
+        let options =
+            ArrowReaderOptions::new().with_file_decryption_properties(decryption_properties);
+        let result = ParquetRecordBatchReaderBuilder::try_new_with_options(file, options).and_then(
+            |builder| {
+                builder
+                    .build()?
+                    .collect::<ArrowResult<Vec<_>>>()
+                    .map_err(Into::into)
+            },
+        );
+        assert!(result.is_err());
+    }
+}
+
 #[test]
 fn test_uniform_encryption_page_skipping() {
     uniform_encryption_page_skipping(false).unwrap();
@@ -213,25 +213,17 @@ async fn test_aes_ctr_encryption() {
 
     let footer_key = "0123456789012345".as_bytes().to_vec();
     let column_1_key = "1234567890123450".as_bytes().to_vec();
-    //let column_2_key = "1234567890123451".as_bytes().to_vec();
+    let column_2_key = "1234567890123451".as_bytes().to_vec();
 
     let decryption_properties = FileDecryptionProperties::builder(footer_key)
-        .with_column_key("double_field", column_1_key.clone())
-        .with_column_key("float_field", column_1_key)
+        .with_column_key("double_field", column_1_key)
+        .with_column_key("float_field", column_2_key)
         .build()
         .unwrap();
 
-    let options = ArrowReaderOptions::new().with_file_decryption_properties(decryption_properties);
-    let metadata = ArrowReaderMetadata::load_async(&mut file, options).await;
-
-    match metadata {
-        Err(ParquetError::NYI(s)) => {
-            assert!(s.contains("AES_GCM_CTR_V1"));
-        }
-        _ => {
-            panic!("Expected ParquetError::NYI");
-        }
-    };
+    verify_encryption_test_file_read_async(&mut file, decryption_properties)
+        .await
+        .unwrap();
 }
 
 #[tokio::test]